@@ -0,0 +1,457 @@
+use crate::graph::Graph;
+use crate::node::Node;
+use std::collections::{HashMap, HashSet};
+
+/// URI and blank nodes of a graph and the edges between them.
+///
+/// Triples with literal objects are not part of the network.
+struct Network {
+    nodes: Vec<Node>,
+    edges: Vec<(usize, usize)>,
+}
+
+impl Network {
+    /// Builds the network of the graph, nodes are ordered.
+    fn new(graph: &Graph) -> Network {
+        let mut nodes: Vec<Node> = Vec::new();
+
+        for triple in graph.triples_iter() {
+            if let Node::LiteralNode { .. } = *triple.object() {
+                continue;
+            }
+
+            nodes.push(triple.subject().clone());
+            nodes.push(triple.object().clone());
+        }
+
+        nodes.sort();
+        nodes.dedup();
+
+        let indices: HashMap<&Node, usize> =
+            nodes.iter().enumerate().map(|(i, n)| (n, i)).collect();
+
+        let edges = graph
+            .triples_iter()
+            .filter_map(
+                |t| match (indices.get(t.subject()), indices.get(t.object())) {
+                    (Some(&source), Some(&target)) => Some((source, target)),
+                    _ => None,
+                },
+            )
+            .collect();
+
+        Network { nodes, edges }
+    }
+
+    /// Maps the scores to the nodes.
+    fn scores(self, scores: Vec<f64>) -> HashMap<Node, f64> {
+        self.nodes.into_iter().zip(scores).collect()
+    }
+}
+
+/// Computes the PageRank of all URI and blank nodes.
+///
+/// Each triple is a link from the subject to the object, triples with literal objects are ignored.
+/// The rank of nodes without outgoing links is distributed evenly to all nodes.
+///
+/// # Examples
+///
+/// ```
+/// use rdf::analytics;
+/// use rdf::graph::Graph;
+/// use rdf::triple::Triple;
+/// use rdf::uri::Uri;
+///
+/// let mut graph = Graph::new(None);
+///
+/// let knows = graph.create_uri_node(&Uri::new("http://xmlns.com/foaf/0.1/knows".to_string()));
+/// let a = graph.create_blank_node();
+/// let b = graph.create_blank_node();
+/// let c = graph.create_blank_node();
+///
+/// graph.add_triple(&Triple::new(&a, &knows, &c));
+/// graph.add_triple(&Triple::new(&b, &knows, &c));
+///
+/// let ranks = analytics::pagerank(&graph, 0.85, 20);
+///
+/// assert!(ranks[&c] > ranks[&a]);
+/// assert!((ranks.values().sum::<f64>() - 1.0).abs() < 1e-9);
+/// ```
+pub fn pagerank(graph: &Graph, damping: f64, iterations: usize) -> HashMap<Node, f64> {
+    let network = Network::new(graph);
+    let n = network.nodes.len();
+
+    if n == 0 {
+        return HashMap::new();
+    }
+
+    let mut out_degrees = vec![0usize; n];
+
+    for &(source, _) in &network.edges {
+        out_degrees[source] += 1;
+    }
+
+    let mut ranks = vec![1.0 / n as f64; n];
+
+    for _ in 0..iterations {
+        let dangling_rank: f64 = (0..n)
+            .filter(|&i| out_degrees[i] == 0)
+            .map(|i| ranks[i])
+            .sum();
+
+        let base = (1.0 - damping) / n as f64 + damping * dangling_rank / n as f64;
+        let mut next_ranks = vec![base; n];
+
+        for &(source, target) in &network.edges {
+            next_ranks[target] += damping * ranks[source] / out_degrees[source] as f64;
+        }
+
+        ranks = next_ranks;
+    }
+
+    network.scores(ranks)
+}
+
+/// Computes the degree centrality of all URI and blank nodes.
+///
+/// The number of incoming and outgoing links of a node is divided by the number of other nodes.
+///
+/// # Examples
+///
+/// ```
+/// use rdf::analytics;
+/// use rdf::graph::Graph;
+/// use rdf::triple::Triple;
+/// use rdf::uri::Uri;
+///
+/// let mut graph = Graph::new(None);
+///
+/// let knows = graph.create_uri_node(&Uri::new("http://xmlns.com/foaf/0.1/knows".to_string()));
+/// let a = graph.create_blank_node();
+/// let b = graph.create_blank_node();
+/// let c = graph.create_blank_node();
+///
+/// graph.add_triple(&Triple::new(&a, &knows, &b));
+/// graph.add_triple(&Triple::new(&a, &knows, &c));
+///
+/// let centrality = analytics::degree_centrality(&graph);
+///
+/// assert_eq!(centrality[&a], 1.0);
+/// assert_eq!(centrality[&b], 0.5);
+/// ```
+pub fn degree_centrality(graph: &Graph) -> HashMap<Node, f64> {
+    degree_centrality_of(graph, true, true)
+}
+
+/// Computes the in-degree centrality of all URI and blank nodes.
+///
+/// The number of incoming links of a node is divided by the number of other nodes.
+pub fn in_degree_centrality(graph: &Graph) -> HashMap<Node, f64> {
+    degree_centrality_of(graph, true, false)
+}
+
+/// Computes the out-degree centrality of all URI and blank nodes.
+///
+/// The number of outgoing links of a node is divided by the number of other nodes.
+pub fn out_degree_centrality(graph: &Graph) -> HashMap<Node, f64> {
+    degree_centrality_of(graph, false, true)
+}
+
+/// Computes the degree centrality considering incoming and/or outgoing links.
+fn degree_centrality_of(graph: &Graph, incoming: bool, outgoing: bool) -> HashMap<Node, f64> {
+    let network = Network::new(graph);
+    let n = network.nodes.len();
+    let mut degrees = vec![0.0; n];
+
+    for &(source, target) in &network.edges {
+        if outgoing {
+            degrees[source] += 1.0;
+        }
+
+        if incoming {
+            degrees[target] += 1.0;
+        }
+    }
+
+    if n > 1 {
+        for degree in &mut degrees {
+            *degree /= (n - 1) as f64;
+        }
+    }
+
+    network.scores(degrees)
+}
+
+/// Generates uniform random walks starting at each of the start nodes.
+///
+/// A walk is a sequence of the start node followed by up to `walk_len` steps, each step is the
+/// predicate and the object of an outgoing triple of the current node. Triples with literal
+/// objects are not followed and a walk ends early at nodes without outgoing triples.
+/// `n_walks` walks are generated per start node, the walks are the same for the same seed.
+///
+/// # Examples
+///
+/// ```
+/// use rdf::analytics;
+/// use rdf::graph::Graph;
+/// use rdf::triple::Triple;
+/// use rdf::uri::Uri;
+///
+/// let mut graph = Graph::new(None);
+///
+/// let knows = graph.create_uri_node(&Uri::new("http://xmlns.com/foaf/0.1/knows".to_string()));
+/// let a = graph.create_blank_node();
+/// let b = graph.create_blank_node();
+///
+/// graph.add_triple(&Triple::new(&a, &knows, &b));
+/// graph.add_triple(&Triple::new(&b, &knows, &a));
+///
+/// let walks = analytics::random_walks(&graph, &[a.clone()], 2, 3, 42);
+///
+/// assert_eq!(walks.len(), 3);
+/// assert_eq!(walks[0], vec![a.clone(), knows.clone(), b, knows, a]);
+/// ```
+pub fn random_walks(
+    graph: &Graph,
+    start_nodes: &[Node],
+    walk_len: usize,
+    n_walks: usize,
+    seed: u64,
+) -> Vec<Vec<Node>> {
+    node2vec_walks(graph, start_nodes, walk_len, n_walks, 1.0, 1.0, seed)
+}
+
+/// Generates second-order random walks as introduced by node2vec.
+///
+/// The walks have the same shape as those of `random_walks`, but the next node is chosen
+/// depending on the previous node: returning to it is weighted with `1 / p`, moving to a node
+/// linked to it with `1` and moving further away with `1 / q`. A low `q` leads to exploring
+/// walks, a low `p` to walks that stay in the neighborhood of the start node.
+///
+/// # Examples
+///
+/// ```
+/// use rdf::analytics;
+/// use rdf::graph::Graph;
+/// use rdf::triple::Triple;
+/// use rdf::uri::Uri;
+///
+/// let mut graph = Graph::new(None);
+///
+/// let p = graph.create_uri_node(&Uri::new("http://example.org/p".to_string()));
+/// let a = graph.create_blank_node();
+/// let b = graph.create_blank_node();
+/// let c = graph.create_blank_node();
+///
+/// graph.add_triple(&Triple::new(&a, &p, &b));
+/// graph.add_triple(&Triple::new(&b, &p, &a));
+/// graph.add_triple(&Triple::new(&b, &p, &c));
+///
+/// // with a very high return parameter the walks do not go back to `a`
+/// let walks = analytics::node2vec_walks(&graph, &[a.clone()], 2, 10, 1e9, 1.0, 7);
+///
+/// assert!(walks.iter().all(|walk| walk[4] == c));
+/// ```
+pub fn node2vec_walks(
+    graph: &Graph,
+    start_nodes: &[Node],
+    walk_len: usize,
+    n_walks: usize,
+    p: f64,
+    q: f64,
+    seed: u64,
+) -> Vec<Vec<Node>> {
+    let mut successors: HashMap<&Node, Vec<(&Node, &Node)>> = HashMap::new();
+    let mut links: HashSet<(&Node, &Node)> = HashSet::new();
+
+    for triple in graph.triples_iter() {
+        if let Node::LiteralNode { .. } = *triple.object() {
+            continue;
+        }
+
+        successors
+            .entry(triple.subject())
+            .or_default()
+            .push((triple.predicate(), triple.object()));
+        links.insert((triple.subject(), triple.object()));
+        links.insert((triple.object(), triple.subject()));
+    }
+
+    let mut rng = SplitMix64::new(seed);
+    let mut walks = Vec::with_capacity(start_nodes.len() * n_walks);
+
+    for _ in 0..n_walks {
+        for start in start_nodes {
+            let mut walk = vec![start.clone()];
+            let mut previous: Option<&Node> = None;
+            let mut current = start;
+
+            for _ in 0..walk_len {
+                let steps = match successors.get(current) {
+                    Some(steps) => steps,
+                    None => break,
+                };
+
+                let weights: Vec<f64> = steps
+                    .iter()
+                    .map(|&(_, next)| match previous {
+                        Some(previous) if next == previous => 1.0 / p,
+                        Some(previous) if links.contains(&(previous, next)) => 1.0,
+                        Some(_) => 1.0 / q,
+                        None => 1.0,
+                    })
+                    .collect();
+
+                let (predicate, next) = steps[rng.choose_weighted(&weights)];
+                walk.push(predicate.clone());
+                walk.push(next.clone());
+
+                previous = Some(current);
+                current = next;
+            }
+
+            walks.push(walk);
+        }
+    }
+
+    walks
+}
+
+/// Small pseudo-random number generator, so that walks are reproducible from a seed.
+struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    fn new(seed: u64) -> SplitMix64 {
+        SplitMix64 { state: seed }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a number in `[0, 1)`.
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Returns the index of a weight, chosen with a probability proportional to the weight.
+    fn choose_weighted(&mut self, weights: &[f64]) -> usize {
+        let mut target = self.next_f64() * weights.iter().sum::<f64>();
+
+        for (i, weight) in weights.iter().enumerate() {
+            if target < *weight {
+                return i;
+            }
+            target -= weight;
+        }
+
+        weights.len() - 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::analytics::*;
+    use crate::graph::Graph;
+    use crate::triple::Triple;
+    use crate::uri::Uri;
+
+    #[test]
+    fn pagerank_of_empty_graph() {
+        assert!(pagerank(&Graph::new(None), 0.85, 10).is_empty());
+    }
+
+    #[test]
+    fn pagerank_of_cycle_is_uniform() {
+        let mut graph = Graph::new(None);
+
+        let p = graph.create_uri_node(&Uri::new("http://example.org/p".to_string()));
+        let a = graph.create_blank_node();
+        let b = graph.create_blank_node();
+        let c = graph.create_blank_node();
+        let literal = graph.create_literal_node("ignored".to_string());
+
+        graph.add_triple(&Triple::new(&a, &p, &b));
+        graph.add_triple(&Triple::new(&b, &p, &c));
+        graph.add_triple(&Triple::new(&c, &p, &a));
+        graph.add_triple(&Triple::new(&c, &p, &literal));
+
+        let ranks = pagerank(&graph, 0.85, 50);
+
+        assert_eq!(ranks.len(), 3);
+
+        for rank in ranks.values() {
+            assert!((rank - 1.0 / 3.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn in_and_out_degree_centrality() {
+        let mut graph = Graph::new(None);
+
+        let p = graph.create_uri_node(&Uri::new("http://example.org/p".to_string()));
+        let a = graph.create_blank_node();
+        let b = graph.create_blank_node();
+        let c = graph.create_blank_node();
+
+        graph.add_triple(&Triple::new(&a, &p, &b));
+        graph.add_triple(&Triple::new(&a, &p, &c));
+        graph.add_triple(&Triple::new(&b, &p, &c));
+
+        let in_degrees = in_degree_centrality(&graph);
+        let out_degrees = out_degree_centrality(&graph);
+
+        assert_eq!(in_degrees[&a], 0.0);
+        assert_eq!(in_degrees[&c], 1.0);
+        assert_eq!(out_degrees[&a], 1.0);
+        assert_eq!(out_degrees[&c], 0.0);
+    }
+
+    #[test]
+    fn random_walks_are_reproducible_and_stop_at_sinks() {
+        let mut graph = Graph::new(None);
+
+        let p = graph.create_uri_node(&Uri::new("http://example.org/p".to_string()));
+        let q = graph.create_uri_node(&Uri::new("http://example.org/q".to_string()));
+        let a = graph.create_blank_node();
+        let b = graph.create_blank_node();
+        let c = graph.create_blank_node();
+        let literal = graph.create_literal_node("ignored".to_string());
+
+        graph.add_triple(&Triple::new(&a, &p, &b));
+        graph.add_triple(&Triple::new(&a, &q, &c));
+        graph.add_triple(&Triple::new(&b, &p, &a));
+        graph.add_triple(&Triple::new(&b, &p, &literal));
+
+        let walks = random_walks(&graph, &[a.clone(), c.clone()], 10, 20, 1);
+
+        assert_eq!(walks.len(), 40);
+        assert_eq!(
+            walks,
+            random_walks(&graph, &[a.clone(), c.clone()], 10, 20, 1)
+        );
+        assert_ne!(
+            walks,
+            random_walks(&graph, &[a.clone(), c.clone()], 10, 20, 2)
+        );
+
+        for walk in &walks {
+            assert_eq!(walk.len() % 2, 1);
+            assert!(walk.len() <= 21);
+            assert!(!walk.contains(&literal));
+
+            // only c has no outgoing triples, so shorter walks end there
+            if walk.len() < 21 {
+                assert_eq!(walk.last(), Some(&c));
+            }
+        }
+
+        assert_eq!(walks[1], vec![c.clone()]);
+    }
+}
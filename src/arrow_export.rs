@@ -0,0 +1,197 @@
+use crate::error::{Error, ErrorType};
+use crate::graph::Graph;
+use crate::node::Node;
+use crate::table::node_value;
+use crate::Result;
+use arrow_array::{ArrayRef, RecordBatch, StringArray};
+use arrow_schema::{DataType, Field, Schema};
+use parquet::arrow::ArrowWriter;
+use std::io::Write;
+use std::sync::Arc;
+
+/// Returns the schema of record batches containing triples.
+///
+/// Each triple is a row with the columns `subject`, `predicate`, `object`, `object_kind`
+/// (`uri`, `literal`, `blank` or `triple`), `data_type` and `language`. Blank nodes are written as `_:id`
+/// and quoted triples as `<< subject predicate object >>` in N-Triples syntax.
+pub fn triples_schema() -> Schema {
+    Schema::new(vec![
+        Field::new("subject", DataType::Utf8, false),
+        Field::new("predicate", DataType::Utf8, false),
+        Field::new("object", DataType::Utf8, false),
+        Field::new("object_kind", DataType::Utf8, false),
+        Field::new("data_type", DataType::Utf8, true),
+        Field::new("language", DataType::Utf8, true),
+    ])
+}
+
+/// Converts all triples of the graph into an Arrow record batch.
+///
+/// # Examples
+///
+/// ```
+/// use rdf::arrow_export;
+/// use rdf::graph::Graph;
+/// use rdf::triple::Triple;
+/// use rdf::uri::Uri;
+///
+/// let mut graph = Graph::new(None);
+///
+/// let subject = graph.create_blank_node();
+/// let predicate = graph.create_uri_node(&Uri::new("http://example.org/show/localName".to_string()));
+/// let object = graph.create_literal_node_with_language("literal".to_string(), "en".to_string());
+///
+/// graph.add_triple(&Triple::new(&subject, &predicate, &object));
+///
+/// let batch = arrow_export::triples_to_record_batch(&graph).unwrap();
+///
+/// assert_eq!(batch.num_rows(), 1);
+/// assert_eq!(batch.num_columns(), 6);
+/// ```
+///
+/// # Failures
+///
+/// - The columns do not conform to the schema.
+///
+pub fn triples_to_record_batch(graph: &Graph) -> Result<RecordBatch> {
+    let mut subjects = Vec::with_capacity(graph.count());
+    let mut predicates = Vec::with_capacity(graph.count());
+    let mut objects = Vec::with_capacity(graph.count());
+    let mut object_kinds = Vec::with_capacity(graph.count());
+    let mut data_types = Vec::with_capacity(graph.count());
+    let mut languages = Vec::with_capacity(graph.count());
+
+    for triple in graph.triples_iter() {
+        subjects.push(Some(node_value(triple.subject())));
+        predicates.push(Some(node_value(triple.predicate())));
+        objects.push(Some(node_value(triple.object())));
+
+        match *triple.object() {
+            Node::UriNode { .. } => {
+                object_kinds.push(Some("uri"));
+                data_types.push(None);
+                languages.push(None);
+            }
+            Node::LiteralNode {
+                ref data_type,
+                ref language,
+                ..
+            } => {
+                object_kinds.push(Some("literal"));
+                data_types.push(data_type.as_ref().map(|dt| dt.to_string().as_str()));
+                languages.push(language.as_ref().map(|lang| lang.as_str()));
+            }
+            Node::BlankNode { .. } => {
+                object_kinds.push(Some("blank"));
+                data_types.push(None);
+                languages.push(None);
+            }
+            Node::TripleNode { .. } => {
+                object_kinds.push(Some("triple"));
+                data_types.push(None);
+                languages.push(None);
+            }
+        }
+    }
+
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(subjects.into_iter().collect::<StringArray>()),
+        Arc::new(predicates.into_iter().collect::<StringArray>()),
+        Arc::new(objects.into_iter().collect::<StringArray>()),
+        Arc::new(object_kinds.into_iter().collect::<StringArray>()),
+        Arc::new(data_types.into_iter().collect::<StringArray>()),
+        Arc::new(languages.into_iter().collect::<StringArray>()),
+    ];
+
+    RecordBatch::try_new(Arc::new(triples_schema()), columns).map_err(|err| {
+        Error::new(
+            ErrorType::InvalidWriterOutput,
+            "Cannot create Arrow record batch.",
+        )
+        .with_source(err)
+    })
+}
+
+/// Writes all triples of the graph as Parquet file.
+///
+/// # Examples
+///
+/// ```
+/// use rdf::arrow_export;
+/// use rdf::graph::Graph;
+///
+/// let graph = Graph::new(None);
+/// let mut output = Vec::new();
+///
+/// arrow_export::write_parquet(&graph, &mut output).unwrap();
+///
+/// assert!(output.starts_with(b"PAR1"));
+/// ```
+///
+/// # Failures
+///
+/// - The Parquet output cannot be written.
+///
+pub fn write_parquet<W: Write + Send>(graph: &Graph, output: W) -> Result<()> {
+    let batch = triples_to_record_batch(graph)?;
+
+    let parquet_error = |err| {
+        Error::new(
+            ErrorType::InvalidWriterOutput,
+            "Cannot write Parquet output.",
+        )
+        .with_source(err)
+    };
+
+    let mut writer = ArrowWriter::try_new(output, batch.schema(), None).map_err(parquet_error)?;
+
+    writer.write(&batch).map_err(parquet_error)?;
+    writer.close().map_err(parquet_error)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::arrow_export::*;
+    use crate::graph::Graph;
+    use crate::triple::Triple;
+    use crate::uri::Uri;
+    use arrow_array::{Array, StringArray};
+
+    #[test]
+    fn record_batch_columns() {
+        let mut graph = Graph::new(None);
+
+        let subject = graph.create_uri_node(&Uri::new("http://example.org/a".to_string()));
+        let predicate = graph.create_uri_node(&Uri::new("http://example.org/b".to_string()));
+        let literal = graph.create_literal_node_with_data_type(
+            "1".to_string(),
+            &Uri::new("http://www.w3.org/2001/XMLSchema#integer".to_string()),
+        );
+        let blank = graph.create_blank_node();
+
+        graph.add_triple(&Triple::new(&subject, &predicate, &literal));
+        graph.add_triple(&Triple::new(&subject, &predicate, &blank));
+
+        let batch = triples_to_record_batch(&graph).unwrap();
+        let column = |name: &str| {
+            batch
+                .column_by_name(name)
+                .unwrap()
+                .as_any()
+                .downcast_ref::<StringArray>()
+                .unwrap()
+                .clone()
+        };
+
+        assert_eq!(column("object").value(1), "_:auto0");
+        assert_eq!(column("object_kind").value(0), "literal");
+        assert_eq!(
+            column("data_type").value(0),
+            "http://www.w3.org/2001/XMLSchema#integer"
+        );
+        assert!(column("data_type").is_null(1));
+        assert!(column("language").is_null(0));
+    }
+}
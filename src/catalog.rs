@@ -0,0 +1,407 @@
+use crate::error::{Error, ErrorType};
+use crate::graph::Graph;
+use crate::ontology::{parse_file, ImportResolver};
+use crate::uri::Uri;
+use crate::Result;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+/// Redirects IRIs to local files, similar to OASIS XML catalogs.
+///
+/// IRIs are either mapped individually or by rewriting an IRI prefix to a path prefix.
+/// IRIs without a local location are never fetched from the network.
+#[derive(Debug, Default)]
+pub struct IriResolver {
+    mappings: HashMap<Uri, PathBuf>,
+    rewrites: Vec<(String, String)>,
+}
+
+impl IriResolver {
+    /// Constructor of `IriResolver`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::catalog::IriResolver;
+    ///
+    /// let resolver = IriResolver::new();
+    /// ```
+    pub fn new() -> IriResolver {
+        IriResolver {
+            mappings: HashMap::new(),
+            rewrites: Vec::new(),
+        }
+    }
+
+    /// Creates a resolver from an XML catalog file.
+    ///
+    /// Supports `uri` and `rewriteURI` entries. Relative locations are resolved against
+    /// the directory of the catalog file.
+    ///
+    /// # Failures
+    ///
+    /// - The catalog file cannot be read.
+    /// - The catalog contains invalid entries.
+    ///
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<IriResolver> {
+        let path = path.as_ref();
+        let mut input = String::new();
+
+        if let Err(err) = File::open(path).and_then(|mut f| f.read_to_string(&mut input)) {
+            return Err(Error::new(
+                ErrorType::UnresolvableIri,
+                format!("Cannot read catalog {}.", path.display()),
+            )
+            .with_source(err));
+        }
+
+        let base_dir = path.parent().unwrap_or_else(|| Path::new(""));
+
+        IriResolver::from_catalog_string(&input, base_dir)
+    }
+
+    /// Creates a resolver from the content of an XML catalog.
+    ///
+    /// Relative locations are resolved against the provided directory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::catalog::IriResolver;
+    /// use rdf::uri::Uri;
+    /// use std::path::{Path, PathBuf};
+    ///
+    /// let catalog = "<catalog xmlns=\"urn:oasis:names:tc:entity:xmlns:xml:catalog\">
+    ///   <uri name=\"http://xmlns.com/foaf/0.1/\" uri=\"foaf.ttl\"/>
+    ///   <rewriteURI uriStartString=\"http://purl.org/dc/\" rewritePrefix=\"dc/\"/>
+    /// </catalog>";
+    ///
+    /// let resolver = IriResolver::from_catalog_string(catalog, Path::new("vocab")).unwrap();
+    ///
+    /// assert_eq!(resolver.resolve_location(&Uri::new("http://xmlns.com/foaf/0.1/".to_string())),
+    ///            Some(PathBuf::from("vocab/foaf.ttl")));
+    /// assert_eq!(resolver.resolve_location(&Uri::new("http://purl.org/dc/terms.ttl".to_string())),
+    ///            Some(PathBuf::from("vocab/dc/terms.ttl")));
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - The catalog contains invalid entries.
+    ///
+    pub fn from_catalog_string(input: &str, base_dir: &Path) -> Result<IriResolver> {
+        let mut resolver = IriResolver::new();
+
+        for (name, attributes) in catalog_elements(input)? {
+            match name.as_str() {
+                "uri" => match (attributes.get("name"), attributes.get("uri")) {
+                    (Some(iri), Some(location)) => resolver.add_mapping(
+                        &Uri::new(iri.to_owned()),
+                        base_dir.join(strip_file_scheme(location)),
+                    ),
+                    _ => return Err(invalid_catalog_entry(&name)),
+                },
+                "rewriteURI" => {
+                    match (
+                        attributes.get("uriStartString"),
+                        attributes.get("rewritePrefix"),
+                    ) {
+                        (Some(iri_prefix), Some(path_prefix)) => {
+                            let path_prefix = base_dir.join(strip_file_scheme(path_prefix));
+                            resolver.add_rewrite(iri_prefix, &path_prefix.to_string_lossy())
+                        }
+                        _ => return Err(invalid_catalog_entry(&name)),
+                    }
+                }
+                _ => {} // ignore unsupported entries
+            }
+        }
+
+        Ok(resolver)
+    }
+
+    /// Maps a single IRI to a local file.
+    pub fn add_mapping<P: AsRef<Path>>(&mut self, iri: &Uri, path: P) {
+        self.mappings
+            .insert(iri.clone(), path.as_ref().to_path_buf());
+    }
+
+    /// Maps all IRIs starting with the IRI prefix to local files starting with the path prefix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::catalog::IriResolver;
+    /// use rdf::uri::Uri;
+    /// use std::path::PathBuf;
+    ///
+    /// let mut resolver = IriResolver::new();
+    /// resolver.add_rewrite("http://example.org/", "/srv/example/");
+    ///
+    /// assert_eq!(resolver.resolve_location(&Uri::new("http://example.org/a.ttl".to_string())),
+    ///            Some(PathBuf::from("/srv/example/a.ttl")));
+    /// assert_eq!(resolver.resolve_location(&Uri::new("http://example.com/a.ttl".to_string())), None);
+    /// ```
+    pub fn add_rewrite(&mut self, iri_prefix: &str, path_prefix: &str) {
+        self.rewrites
+            .push((iri_prefix.to_owned(), path_prefix.to_owned()));
+    }
+
+    /// Returns the local file for the IRI.
+    ///
+    /// Individual mappings take precedence over rewrites, of which the longest
+    /// matching IRI prefix is used.
+    pub fn resolve_location(&self, iri: &Uri) -> Option<PathBuf> {
+        if let Some(path) = self.mappings.get(iri) {
+            return Some(path.clone());
+        }
+
+        let iri = iri.to_string();
+
+        self.rewrites
+            .iter()
+            .filter(|(iri_prefix, _)| iri.starts_with(iri_prefix.as_str()))
+            .max_by_key(|(iri_prefix, _)| iri_prefix.len())
+            .map(|(iri_prefix, path_prefix)| {
+                PathBuf::from(path_prefix.to_owned() + &iri[iri_prefix.len()..])
+            })
+    }
+}
+
+impl ImportResolver for IriResolver {
+    /// Parses the local file of the IRI.
+    ///
+    /// # Failures
+    ///
+    /// - No local file exists for the IRI.
+    /// - The file cannot be parsed.
+    ///
+    fn resolve(&mut self, iri: &Uri) -> Result<Graph> {
+        match self.resolve_location(iri) {
+            Some(path) => parse_file(&path),
+            None => Err(Error::new(
+                ErrorType::UnresolvableIri,
+                "No local location for IRI: ".to_string() + iri.to_string(),
+            )),
+        }
+    }
+}
+
+/// Removes the `file://` scheme from a location.
+fn strip_file_scheme(location: &str) -> &str {
+    location.trim_start_matches("file://")
+}
+
+/// Returns the error for an incomplete catalog entry.
+fn invalid_catalog_entry(name: &str) -> Error {
+    Error::new(
+        ErrorType::InvalidReaderInput,
+        "Missing attributes for catalog entry: ".to_string() + name,
+    )
+}
+
+/// Returns the name and attributes of all elements of a catalog.
+fn catalog_elements(input: &str) -> Result<Vec<(String, HashMap<String, String>)>> {
+    let mut elements = Vec::new();
+    let mut rest = input;
+
+    while let Some(start) = rest.find('<') {
+        rest = &rest[start + 1..];
+
+        // skip comments, processing instructions, declarations and closing tags
+        if rest.starts_with("!--") {
+            match rest.find("-->") {
+                Some(end) => rest = &rest[end + 3..],
+                None => break,
+            }
+            continue;
+        }
+
+        let end = match rest.find('>') {
+            Some(end) => end,
+            None => {
+                return Err(Error::new(
+                    ErrorType::InvalidReaderInput,
+                    "Unterminated element in catalog.",
+                ))
+            }
+        };
+
+        let tag = rest[..end].trim_end_matches('/');
+        rest = &rest[end + 1..];
+
+        if tag.starts_with('?') || tag.starts_with('!') || tag.starts_with('/') {
+            continue;
+        }
+
+        let name_end = tag.find(char::is_whitespace).unwrap_or(tag.len());
+        let name = tag[..name_end].to_owned();
+
+        elements.push((name, element_attributes(&tag[name_end..])?));
+    }
+
+    Ok(elements)
+}
+
+/// Parses the attributes of an element.
+fn element_attributes(input: &str) -> Result<HashMap<String, String>> {
+    let mut attributes = HashMap::new();
+    let mut rest = input.trim_start();
+
+    while !rest.is_empty() {
+        let invalid_attribute = || {
+            Error::new(
+                ErrorType::InvalidReaderInput,
+                "Invalid attribute in catalog: ".to_string() + input.trim(),
+            )
+        };
+
+        let equals = rest.find('=').ok_or_else(invalid_attribute)?;
+        let name = rest[..equals].trim().to_owned();
+        rest = rest[equals + 1..].trim_start();
+
+        let quote = rest.chars().next().ok_or_else(invalid_attribute)?;
+
+        if quote != '"' && quote != '\'' {
+            return Err(invalid_attribute());
+        }
+
+        let value_end = rest[1..].find(quote).ok_or_else(invalid_attribute)?;
+        let value = decode_entities(&rest[1..=value_end]);
+        rest = rest[value_end + 2..].trim_start();
+
+        attributes.insert(name, value);
+    }
+
+    Ok(attributes)
+}
+
+/// Replaces the predefined XML entities.
+fn decode_entities(value: &str) -> String {
+    value
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::catalog::IriResolver;
+    use crate::ontology::{load_with_imports, ImportResolver};
+    use crate::uri::Uri;
+    use std::env;
+    use std::fs::{self, File};
+    use std::io::Write;
+    use std::path::{Path, PathBuf};
+    use std::process;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    #[test]
+    fn parse_catalog_with_comments_and_entities() {
+        let catalog = "<?xml version=\"1.0\" encoding=\"UTF-8\"?>
+            <!-- <uri name=\"http://ignored.org/\" uri=\"ignored.ttl\"/> -->
+            <catalog prefer='public'>
+              <uri name='http://example.org/a?x=1&amp;y=2' uri='file://a.ttl'/>
+              <group>
+                <uri id=\"b\" name=\"http://example.org/b\" uri=\"b.ttl\"></uri>
+              </group>
+            </catalog>";
+
+        let resolver = IriResolver::from_catalog_string(catalog, Path::new("")).unwrap();
+
+        assert_eq!(
+            resolver.resolve_location(&Uri::new("http://example.org/a?x=1&y=2".to_string())),
+            Some(PathBuf::from("a.ttl"))
+        );
+        assert_eq!(
+            resolver.resolve_location(&Uri::new("http://example.org/b".to_string())),
+            Some(PathBuf::from("b.ttl"))
+        );
+        assert_eq!(
+            resolver.resolve_location(&Uri::new("http://ignored.org/".to_string())),
+            None
+        );
+    }
+
+    #[test]
+    fn parse_invalid_catalog() {
+        assert!(IriResolver::from_catalog_string("<uri name=\"a\"/>", Path::new("")).is_err());
+        assert!(IriResolver::from_catalog_string("<uri name=a uri=b/>", Path::new("")).is_err());
+    }
+
+    #[test]
+    fn resolve_longest_rewrite_prefix() {
+        let mut resolver = IriResolver::new();
+        resolver.add_rewrite("http://example.org/", "all/");
+        resolver.add_rewrite("http://example.org/vocab/", "vocab/");
+
+        assert_eq!(
+            resolver.resolve_location(&Uri::new("http://example.org/vocab/a.ttl".to_string())),
+            Some(PathBuf::from("vocab/a.ttl"))
+        );
+    }
+
+    #[test]
+    fn resolve_unmapped_iri() {
+        let mut resolver = IriResolver::new();
+
+        assert!(resolver
+            .resolve(&Uri::new("http://example.org/".to_string()))
+            .is_err());
+    }
+
+    #[test]
+    fn load_imports_from_catalog_file() {
+        // removes the directory when the test ends, also if it fails
+        struct TempDir(PathBuf);
+
+        impl Drop for TempDir {
+            fn drop(&mut self) {
+                let _ = fs::remove_dir_all(&self.0);
+            }
+        }
+
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .subsec_nanos();
+        let temp_dir = TempDir(env::temp_dir().join(format!(
+            "rdf-rs-catalog-test-{}-{}",
+            process::id(),
+            nanos
+        )));
+        let dir = &temp_dir.0;
+        fs::create_dir_all(dir).unwrap();
+
+        let files = vec![
+            (
+                "catalog.xml",
+                "<catalog>
+                   <uri name=\"http://example.org/a\" uri=\"a.ttl\"/>
+                   <rewriteURI uriStartString=\"http://example.org/vocab/\" rewritePrefix=\"vocab-\"/>
+                 </catalog>",
+            ),
+            (
+                "a.ttl",
+                "<http://example.org/a> <http://www.w3.org/2002/07/owl#imports> <http://example.org/vocab/b.nt> .",
+            ),
+            ("vocab-b.nt", "<http://example.org/b> <http://example.org/p> \"b\" ."),
+        ];
+
+        for (name, content) in files {
+            let mut file = File::create(dir.join(name)).unwrap();
+            file.write_all(content.as_bytes()).unwrap();
+        }
+
+        let mut resolver = IriResolver::from_file(dir.join("catalog.xml")).unwrap();
+        let closure =
+            load_with_imports(&Uri::new("http://example.org/a".to_string()), &mut resolver)
+                .unwrap();
+
+        assert_eq!(closure.documents().len(), 2);
+        assert_eq!(closure.graph().count(), 2);
+    }
+}
@@ -0,0 +1,561 @@
+#[cfg(feature = "ntriples")]
+use crate::error::{Error, ErrorType};
+use crate::graph::Graph;
+use crate::node::Node;
+#[cfg(feature = "ntriples")]
+use crate::reader::n_triples_parser::NTriplesParser;
+#[cfg(feature = "ntriples")]
+use crate::reader::rdf_parser::RdfParser;
+use crate::triple::Triple;
+#[cfg(feature = "ntriples")]
+use crate::writer::n_triples_writer::NTriplesWriter;
+#[cfg(feature = "ntriples")]
+use crate::Result;
+use std::fmt;
+use std::sync::mpsc::{channel, Receiver, Sender};
+
+/// A change of a graph that is recorded in a change log.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Change {
+    /// The triple is added to the graph.
+    Insert(Triple),
+
+    /// The triple is removed from the graph.
+    Delete(Triple),
+
+    /// The changes are applied together.
+    Transaction(Vec<Change>),
+}
+
+impl Change {
+    /// Applies the change to the graph.
+    pub fn apply(&self, graph: &mut Graph) {
+        match *self {
+            Change::Insert(ref triple) => graph.add_triple(triple),
+            Change::Delete(ref triple) => graph.remove_triple(triple),
+            Change::Transaction(ref changes) => {
+                for change in changes {
+                    change.apply(graph);
+                }
+            }
+        }
+    }
+}
+
+/// Identifies a watch that is registered at a change log.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct WatchId(usize);
+
+/// Triple pattern with the callback that is called for matching insertions and deletions.
+struct Watch {
+    id: WatchId,
+    subject: Option<Node>,
+    predicate: Option<Node>,
+    object: Option<Node>,
+    callback: Box<dyn FnMut(&Change) + Send>,
+}
+
+impl Watch {
+    /// Checks if the triple matches the pattern, `None` matches any node.
+    fn matches(&self, triple: &Triple) -> bool {
+        self.subject.as_ref().is_none_or(|s| s == triple.subject())
+            && self
+                .predicate
+                .as_ref()
+                .is_none_or(|p| p == triple.predicate())
+            && self.object.as_ref().is_none_or(|o| o == triple.object())
+    }
+
+    /// Calls the callback for all matching insertions and deletions of the change.
+    fn notify(&mut self, change: &Change) {
+        match *change {
+            Change::Insert(ref triple) | Change::Delete(ref triple) => {
+                if self.matches(triple) {
+                    (self.callback)(change);
+                }
+            }
+            Change::Transaction(ref changes) => {
+                for change in changes {
+                    self.notify(change);
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Debug for Watch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Watch")
+            .field("id", &self.id)
+            .field("subject", &self.subject)
+            .field("predicate", &self.predicate)
+            .field("object", &self.object)
+            .finish()
+    }
+}
+
+/// Append-only log of graph changes.
+///
+/// Replaying the log on an empty graph rebuilds the state of the graph. Consumers can follow
+/// the changes by remembering the position up to which they have read the log, by subscribing
+/// to the log or by watching a triple pattern.
+#[derive(Debug, Default)]
+pub struct ChangeLog {
+    changes: Vec<Change>,
+    subscribers: Vec<Sender<Change>>,
+    watches: Vec<Watch>,
+    next_watch_id: usize,
+}
+
+impl Clone for ChangeLog {
+    /// Clones the recorded changes, subscribers and watches stay with the original log.
+    fn clone(&self) -> ChangeLog {
+        ChangeLog {
+            changes: self.changes.clone(),
+            subscribers: Vec::new(),
+            watches: Vec::new(),
+            next_watch_id: 0,
+        }
+    }
+}
+
+impl ChangeLog {
+    /// Constructor of `ChangeLog`.
+    pub fn new() -> ChangeLog {
+        ChangeLog::default()
+    }
+
+    /// Returns the number of recorded changes.
+    pub fn len(&self) -> usize {
+        self.changes.len()
+    }
+
+    /// Checks if no changes are recorded.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Appends the change to the log, notifies subscribers and watches and returns its position.
+    pub fn append(&mut self, change: Change) -> usize {
+        // subscribers that dropped their receiver are removed
+        self.subscribers
+            .retain(|subscriber| subscriber.send(change.clone()).is_ok());
+
+        for watch in &mut self.watches {
+            watch.notify(&change);
+        }
+
+        self.changes.push(change);
+        self.changes.len() - 1
+    }
+
+    /// Applies the change to the graph and appends it to the log.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::change_log::{Change, ChangeLog};
+    /// use rdf::graph::Graph;
+    /// use rdf::triple::Triple;
+    /// use rdf::uri::Uri;
+    ///
+    /// let mut graph = Graph::new(None);
+    /// let mut log = ChangeLog::new();
+    ///
+    /// let subject = graph.create_blank_node();
+    /// let predicate = graph.create_uri_node(&Uri::new("http://example.org/p".to_string()));
+    /// let object = graph.create_literal_node("o".to_string());
+    /// let triple = Triple::new(&subject, &predicate, &object);
+    ///
+    /// log.record(&mut graph, Change::Insert(triple));
+    ///
+    /// let mut replayed = Graph::new(None);
+    /// log.replay(&mut replayed);
+    ///
+    /// assert_eq!(replayed.count(), graph.count());
+    /// ```
+    pub fn record(&mut self, graph: &mut Graph, change: Change) -> usize {
+        change.apply(graph);
+        self.append(change)
+    }
+
+    /// Returns a receiver of all changes that are appended from now on.
+    ///
+    /// Transactions are received as a whole once they are appended.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::change_log::{Change, ChangeLog};
+    /// use rdf::node::Node;
+    /// use rdf::triple::Triple;
+    /// use rdf::uri::Uri;
+    ///
+    /// let subject = Node::BlankNode { id: "a".to_string() };
+    /// let predicate = Node::UriNode { uri: Uri::new("http://example.org/p".to_string()) };
+    /// let object = Node::LiteralNode { literal: "o".to_string(), data_type: None, language: None };
+    /// let triple = Triple::new(&subject, &predicate, &object);
+    ///
+    /// let mut log = ChangeLog::new();
+    /// let changes = log.subscribe();
+    ///
+    /// log.append(Change::Insert(triple.clone()));
+    ///
+    /// assert_eq!(changes.try_recv().unwrap(), Change::Insert(triple));
+    /// assert!(changes.try_recv().is_err());
+    /// ```
+    pub fn subscribe(&mut self) -> Receiver<Change> {
+        let (sender, receiver) = channel();
+        self.subscribers.push(sender);
+        receiver
+    }
+
+    /// Registers a callback that is called for every appended insertion or deletion of a triple
+    /// that matches the pattern, `None` matches any node.
+    ///
+    /// Changes of transactions are passed one by one, the callback is called while appending.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::change_log::{Change, ChangeLog};
+    /// use rdf::node::Node;
+    /// use rdf::triple::Triple;
+    /// use rdf::uri::Uri;
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// let subject = Node::BlankNode { id: "a".to_string() };
+    /// let name = Node::UriNode { uri: Uri::new("http://example.org/name".to_string()) };
+    /// let age = Node::UriNode { uri: Uri::new("http://example.org/age".to_string()) };
+    /// let object = Node::LiteralNode { literal: "o".to_string(), data_type: None, language: None };
+    ///
+    /// let mut log = ChangeLog::new();
+    /// let names = Arc::new(Mutex::new(Vec::new()));
+    /// let watched = Arc::clone(&names);
+    ///
+    /// log.watch(None, Some(&name), None, move |change| watched.lock().unwrap().push(change.clone()));
+    ///
+    /// log.append(Change::Transaction(vec![
+    ///     Change::Insert(Triple::new(&subject, &name, &object)),
+    ///     Change::Insert(Triple::new(&subject, &age, &object)),
+    /// ]));
+    ///
+    /// assert_eq!(*names.lock().unwrap(),
+    ///            vec![Change::Insert(Triple::new(&subject, &name, &object))]);
+    /// ```
+    pub fn watch<F>(
+        &mut self,
+        subject: Option<&Node>,
+        predicate: Option<&Node>,
+        object: Option<&Node>,
+        callback: F,
+    ) -> WatchId
+    where
+        F: FnMut(&Change) + Send + 'static,
+    {
+        let id = WatchId(self.next_watch_id);
+        self.next_watch_id += 1;
+
+        self.watches.push(Watch {
+            id,
+            subject: subject.cloned(),
+            predicate: predicate.cloned(),
+            object: object.cloned(),
+            callback: Box::new(callback),
+        });
+
+        id
+    }
+
+    /// Removes the watch, returns `false` if it was not registered.
+    pub fn unwatch(&mut self, id: WatchId) -> bool {
+        let count = self.watches.len();
+        self.watches.retain(|watch| watch.id != id);
+        self.watches.len() != count
+    }
+
+    /// Returns all recorded changes.
+    pub fn changes(&self) -> &[Change] {
+        &self.changes
+    }
+
+    /// Returns the changes that were appended at or after the position.
+    ///
+    /// Positions are only stable until the log is compacted.
+    pub fn changes_since(&self, position: usize) -> &[Change] {
+        if position >= self.changes.len() {
+            &[]
+        } else {
+            &self.changes[position..]
+        }
+    }
+
+    /// Applies all recorded changes to the graph.
+    pub fn replay(&self, graph: &mut Graph) {
+        for change in &self.changes {
+            change.apply(graph);
+        }
+    }
+
+    /// Replaces the recorded changes by the insertions that rebuild the same state.
+    ///
+    /// Triples that were deleted again are dropped from the log.
+    pub fn compact(&mut self) {
+        let mut graph = Graph::new(None);
+        self.replay(&mut graph);
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            changes = self.changes.len(),
+            triples = graph.count(),
+            "Compacted change log"
+        );
+
+        self.changes = graph.triples_iter().cloned().map(Change::Insert).collect();
+    }
+
+    /// Writes the log with one change per line.
+    ///
+    /// Insertions and deletions are written as N-Triples prefixed with `+` or `-`, transactions
+    /// are enclosed by `BEGIN` and `COMMIT`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::change_log::{Change, ChangeLog};
+    /// use rdf::node::Node;
+    /// use rdf::triple::Triple;
+    /// use rdf::uri::Uri;
+    ///
+    /// let subject = Node::BlankNode { id: "a".to_string() };
+    /// let predicate = Node::UriNode { uri: Uri::new("http://example.org/p".to_string()) };
+    /// let object = Node::LiteralNode { literal: "o".to_string(), data_type: None, language: None };
+    /// let triple = Triple::new(&subject, &predicate, &object);
+    ///
+    /// let mut log = ChangeLog::new();
+    /// log.append(Change::Insert(triple.clone()));
+    /// log.append(Change::Transaction(vec![Change::Delete(triple)]));
+    ///
+    /// assert_eq!(log.write_to_string().unwrap(),
+    ///            "+ _:a <http://example.org/p> \"o\" .\nBEGIN\n- _:a <http://example.org/p> \"o\" .\nCOMMIT\n".to_string());
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - A triple cannot be written as N-Triples.
+    ///
+    #[cfg(feature = "ntriples")]
+    pub fn write_to_string(&self) -> Result<String> {
+        let writer = NTriplesWriter::new();
+        let mut output_string = "".to_string();
+
+        for change in &self.changes {
+            ChangeLog::write_change(&writer, change, &mut output_string)?;
+        }
+
+        Ok(output_string)
+    }
+
+    /// Reads a log that was written by `write_to_string`.
+    ///
+    /// # Failures
+    ///
+    /// - A line is not a valid change.
+    /// - A transaction is not committed.
+    ///
+    #[cfg(feature = "ntriples")]
+    pub fn from_string(input: &str) -> Result<ChangeLog> {
+        // changes of the open transactions
+        let mut transactions: Vec<Vec<Change>> = vec![Vec::new()];
+
+        for line in input.lines().map(|l| l.trim()).filter(|l| !l.is_empty()) {
+            let change = if line == "BEGIN" {
+                transactions.push(Vec::new());
+                continue;
+            } else if line == "COMMIT" && transactions.len() > 1 {
+                Change::Transaction(transactions.pop().unwrap_or_default())
+            } else if let Some(triple) = line.strip_prefix("+ ") {
+                Change::Insert(ChangeLog::read_triple(triple)?)
+            } else if let Some(triple) = line.strip_prefix("- ") {
+                Change::Delete(ChangeLog::read_triple(triple)?)
+            } else {
+                return Err(Error::new(
+                    ErrorType::InvalidReaderInput,
+                    "Invalid change: ".to_string() + line,
+                ));
+            };
+
+            if let Some(changes) = transactions.last_mut() {
+                changes.push(change);
+            }
+        }
+
+        if transactions.len() > 1 {
+            return Err(Error::new(
+                ErrorType::InvalidReaderInput,
+                "Transaction is not committed.",
+            ));
+        }
+
+        Ok(ChangeLog {
+            changes: transactions.pop().unwrap_or_default(),
+            ..ChangeLog::default()
+        })
+    }
+
+    /// Appends the lines of a change to the output.
+    #[cfg(feature = "ntriples")]
+    fn write_change(
+        writer: &NTriplesWriter,
+        change: &Change,
+        output_string: &mut String,
+    ) -> Result<()> {
+        match *change {
+            Change::Insert(ref triple) => {
+                output_string.push_str("+ ");
+                output_string.push_str(&writer.triple_to_n_triples(triple)?);
+                output_string.push('\n');
+            }
+            Change::Delete(ref triple) => {
+                output_string.push_str("- ");
+                output_string.push_str(&writer.triple_to_n_triples(triple)?);
+                output_string.push('\n');
+            }
+            Change::Transaction(ref changes) => {
+                output_string.push_str("BEGIN\n");
+
+                for change in changes {
+                    ChangeLog::write_change(writer, change, output_string)?;
+                }
+
+                output_string.push_str("COMMIT\n");
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Reads a single triple in N-Triples syntax.
+    #[cfg(feature = "ntriples")]
+    fn read_triple(input: &str) -> Result<Triple> {
+        let graph = NTriplesParser::from_string(input).decode()?;
+
+        match graph.triples_iter().next() {
+            Some(triple) if graph.count() == 1 => Ok(triple.clone()),
+            _ => Err(Error::new(
+                ErrorType::InvalidReaderInput,
+                "Expected exactly one triple: ".to_string() + input,
+            )),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "ntriples"))]
+mod tests {
+    use crate::change_log::*;
+    use crate::node::Node;
+    use crate::uri::Uri;
+
+    fn triple(object: &str) -> Triple {
+        Triple::new(
+            &Node::BlankNode {
+                id: "s".to_string(),
+            },
+            &Node::UriNode {
+                uri: Uri::new("http://example.org/p".to_string()),
+            },
+            &Node::LiteralNode {
+                literal: object.to_string(),
+                data_type: None,
+                language: None,
+            },
+        )
+    }
+
+    #[test]
+    fn compact_keeps_state() {
+        let mut log = ChangeLog::new();
+
+        log.append(Change::Insert(triple("a")));
+        log.append(Change::Transaction(vec![
+            Change::Insert(triple("b")),
+            Change::Delete(triple("a")),
+        ]));
+
+        assert_eq!(log.changes_since(1).len(), 1);
+        assert!(log.changes_since(5).is_empty());
+
+        log.compact();
+
+        assert_eq!(log.changes(), &[Change::Insert(triple("b"))]);
+    }
+
+    #[test]
+    fn read_written_log() {
+        let mut log = ChangeLog::new();
+
+        log.append(Change::Insert(triple("a")));
+        log.append(Change::Transaction(vec![
+            Change::Delete(triple("a")),
+            Change::Transaction(vec![Change::Insert(triple("b"))]),
+        ]));
+
+        let output = log.write_to_string().unwrap();
+
+        assert_eq!(
+            ChangeLog::from_string(&output).unwrap().changes(),
+            log.changes()
+        );
+        assert!(ChangeLog::from_string("BEGIN\n").is_err());
+        assert!(ChangeLog::from_string("COMMIT\n").is_err());
+        assert!(ChangeLog::from_string("* _:s <http://example.org/p> \"a\" .").is_err());
+    }
+
+    #[test]
+    fn dropped_subscribers_are_removed() {
+        let mut log = ChangeLog::new();
+
+        let kept = log.subscribe();
+        let dropped = log.subscribe();
+        drop(dropped);
+
+        log.append(Change::Insert(triple("a")));
+        log.clone().append(Change::Insert(triple("b")));
+
+        assert_eq!(log.subscribers.len(), 1);
+        assert_eq!(
+            kept.try_iter().collect::<Vec<Change>>(),
+            vec![Change::Insert(triple("a"))]
+        );
+    }
+
+    #[test]
+    fn watches_receive_matching_changes_until_removed() {
+        let mut log = ChangeLog::new();
+        let (sender, receiver) = channel();
+
+        let object = Node::LiteralNode {
+            literal: "a".to_string(),
+            data_type: None,
+            language: None,
+        };
+        let id = log.watch(None, None, Some(&object), move |change| {
+            let _ = sender.send(change.clone());
+        });
+
+        log.append(Change::Insert(triple("a")));
+        log.append(Change::Transaction(vec![
+            Change::Insert(triple("b")),
+            Change::Delete(triple("a")),
+        ]));
+
+        assert!(log.unwatch(id));
+        assert!(!log.unwatch(id));
+        log.append(Change::Insert(triple("a")));
+
+        assert_eq!(
+            receiver.try_iter().collect::<Vec<Change>>(),
+            vec![Change::Insert(triple("a")), Change::Delete(triple("a"))]
+        );
+    }
+}
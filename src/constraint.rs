@@ -0,0 +1,376 @@
+use crate::graph::Graph;
+use crate::node::Node;
+use crate::shacl::{ConstraintComponent, ValidationReport, ValidationResult};
+use crate::specs::rdf_syntax_specs::RdfSyntaxDataTypes;
+use crate::specs::xml_specs::XmlDataTypes;
+use crate::uri::Uri;
+
+/// Constraints on one property of the instances of a class.
+#[derive(Debug, Clone, PartialEq)]
+struct PropertyConstraint {
+    path: Node,
+    min_count: usize,
+    max_count: Option<usize>,
+    data_type: Option<Uri>,
+    class: Option<Node>,
+    values: Option<Vec<Node>>,
+}
+
+/// Application-level constraints on the instances of a class, written in Rust instead of
+/// a SHACL shapes graph.
+///
+/// Each property is added with `require` or `property`, the following methods constrain the
+/// property that was added last. Instances are the subjects with an `rdf:type` of the class,
+/// subclasses are not taken into account.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "turtle")]
+/// # {
+/// use rdf::constraint::Constraint;
+/// use rdf::reader::rdf_parser::RdfParser;
+/// use rdf::reader::turtle_parser::TurtleParser;
+/// use rdf::uri::Uri;
+///
+/// let input = "@prefix ex: <http://example.org/> .
+///              @prefix foaf: <http://xmlns.com/foaf/0.1/> .
+///
+///              ex:alice a foaf:Person ; foaf:name \"Alice\" .
+///              ex:bob foaf:name \"Bob\" , \"Robert\" ; a foaf:Person .
+///              ex:carol a foaf:Person .";
+///
+/// let graph = TurtleParser::from_string(input).decode().unwrap();
+///
+/// let person = Uri::new("http://xmlns.com/foaf/0.1/Person".to_string());
+/// let name = Uri::new("http://xmlns.com/foaf/0.1/name".to_string());
+///
+/// let report = Constraint::on_class(person).require(name).max_count(1).validate(&graph);
+///
+/// assert!(!report.conforms());
+/// assert_eq!(report.results().len(), 2);
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Constraint {
+    class: Node,
+    properties: Vec<PropertyConstraint>,
+}
+
+impl Constraint {
+    /// Starts the constraints on the instances of the class.
+    pub fn on_class(class: Uri) -> Constraint {
+        Constraint {
+            class: Node::UriNode { uri: class },
+            properties: Vec::new(),
+        }
+    }
+
+    /// Adds a property that every instance must have at least once.
+    pub fn require(self, property: Uri) -> Constraint {
+        self.property(property).min_count(1)
+    }
+
+    /// Adds an optional property.
+    pub fn property(mut self, property: Uri) -> Constraint {
+        self.properties.push(PropertyConstraint {
+            path: Node::UriNode { uri: property },
+            min_count: 0,
+            max_count: None,
+            data_type: None,
+            class: None,
+            values: None,
+        });
+        self
+    }
+
+    /// Sets the minimum number of values of the last property.
+    ///
+    /// # Panics
+    ///
+    /// - No property has been added.
+    ///
+    pub fn min_count(mut self, count: usize) -> Constraint {
+        self.last_property().min_count = count;
+        self
+    }
+
+    /// Sets the maximum number of values of the last property.
+    ///
+    /// # Panics
+    ///
+    /// - No property has been added.
+    ///
+    pub fn max_count(mut self, count: usize) -> Constraint {
+        self.last_property().max_count = Some(count);
+        self
+    }
+
+    /// Requires the values of the last property to be literals of the data type.
+    ///
+    /// Literals without data type count as `xsd:string`, literals with language as `rdf:langString`.
+    ///
+    /// # Panics
+    ///
+    /// - No property has been added.
+    ///
+    pub fn datatype(mut self, data_type: Uri) -> Constraint {
+        self.last_property().data_type = Some(data_type);
+        self
+    }
+
+    /// Requires the values of the last property to be instances of the class.
+    ///
+    /// # Panics
+    ///
+    /// - No property has been added.
+    ///
+    pub fn class(mut self, class: Uri) -> Constraint {
+        self.last_property().class = Some(Node::UriNode { uri: class });
+        self
+    }
+
+    /// Requires the values of the last property to be one of the nodes.
+    ///
+    /// # Panics
+    ///
+    /// - No property has been added.
+    ///
+    pub fn one_of(mut self, values: Vec<Node>) -> Constraint {
+        self.last_property().values = Some(values);
+        self
+    }
+
+    /// Returns the constraints of the property that was added last.
+    fn last_property(&mut self) -> &mut PropertyConstraint {
+        self.properties
+            .last_mut()
+            .expect("Constraint without property, add one with require or property first.")
+    }
+
+    /// Validates the instances of the class in the graph.
+    pub fn validate(&self, graph: &Graph) -> ValidationReport {
+        let rdf_type = Node::UriNode {
+            uri: RdfSyntaxDataTypes::A.to_uri(),
+        };
+
+        let mut instances: Vec<&Node> = graph
+            .get_triples_with_predicate_and_object(&rdf_type, &self.class)
+            .into_iter()
+            .map(|t| t.subject())
+            .collect();
+        instances.sort();
+        instances.dedup();
+
+        let mut results = Vec::new();
+
+        for instance in instances {
+            for property in &self.properties {
+                validate_property(graph, instance, property, &mut results);
+            }
+        }
+
+        ValidationReport::new(results)
+    }
+}
+
+/// Validates all constraints against the graph and combines their reports.
+///
+/// # Examples
+///
+/// ```
+/// use rdf::constraint::{self, Constraint};
+/// use rdf::graph::Graph;
+/// use rdf::uri::Uri;
+///
+/// let constraints = vec![
+///     Constraint::on_class(Uri::new("http://example.org/Person".to_string())),
+///     Constraint::on_class(Uri::new("http://example.org/Task".to_string())),
+/// ];
+///
+/// assert!(constraint::validate_all(&constraints, &Graph::new(None)).conforms());
+/// ```
+pub fn validate_all(constraints: &[Constraint], graph: &Graph) -> ValidationReport {
+    let mut report = ValidationReport::default();
+
+    for constraint in constraints {
+        report.merge(constraint.validate(graph));
+    }
+
+    report
+}
+
+/// Adds the violations of the property constraints by the instance to the results.
+fn validate_property(
+    graph: &Graph,
+    instance: &Node,
+    property: &PropertyConstraint,
+    results: &mut Vec<ValidationResult>,
+) {
+    let mut values: Vec<&Node> = graph
+        .get_triples_with_subject_and_predicate(instance, &property.path)
+        .into_iter()
+        .map(|t| t.object())
+        .collect();
+    values.sort();
+    values.dedup();
+
+    let mut violation = |value: Option<&Node>, component, message: String| {
+        results.push(ValidationResult::new(
+            instance,
+            &property.path,
+            value,
+            component,
+            message,
+        ));
+    };
+
+    if values.len() < property.min_count {
+        violation(
+            None,
+            ConstraintComponent::MinCount,
+            format!(
+                "Expected at least {} values of {}, found {}.",
+                property.min_count,
+                node_name(&property.path),
+                values.len()
+            ),
+        );
+    }
+
+    if let Some(max_count) = property.max_count {
+        if values.len() > max_count {
+            violation(
+                None,
+                ConstraintComponent::MaxCount,
+                format!(
+                    "Expected at most {} values of {}, found {}.",
+                    max_count,
+                    node_name(&property.path),
+                    values.len()
+                ),
+            );
+        }
+    }
+
+    for value in values {
+        if let Some(ref data_type) = property.data_type {
+            if !has_data_type(value, data_type) {
+                violation(
+                    Some(value),
+                    ConstraintComponent::Datatype,
+                    format!("Value is not a literal of type {}.", data_type.to_string()),
+                );
+            }
+        }
+
+        if let Some(ref class) = property.class {
+            let rdf_type = Node::UriNode {
+                uri: RdfSyntaxDataTypes::A.to_uri(),
+            };
+
+            if graph
+                .get_triples_with_subject_and_object(value, class)
+                .iter()
+                .all(|t| *t.predicate() != rdf_type)
+            {
+                violation(
+                    Some(value),
+                    ConstraintComponent::Class,
+                    format!("Value is not an instance of {}.", node_name(class)),
+                );
+            }
+        }
+
+        if let Some(ref allowed) = property.values {
+            if !allowed.contains(value) {
+                violation(
+                    Some(value),
+                    ConstraintComponent::In,
+                    "Value is not one of the allowed values.".to_string(),
+                );
+            }
+        }
+    }
+}
+
+/// Checks if the node is a literal of the data type.
+fn has_data_type(node: &Node, data_type: &Uri) -> bool {
+    match *node {
+        Node::LiteralNode {
+            data_type: Some(ref literal_type),
+            ..
+        } => literal_type == data_type,
+        Node::LiteralNode {
+            language: Some(_), ..
+        } => *data_type == RdfSyntaxDataTypes::LangString.to_uri(),
+        Node::LiteralNode { .. } => *data_type == XmlDataTypes::String.to_uri(),
+        _ => false,
+    }
+}
+
+/// Returns the URI of a URI node or the debug representation of other nodes for messages.
+fn node_name(node: &Node) -> String {
+    match *node {
+        Node::UriNode { ref uri } => uri.to_string().clone(),
+        _ => format!("{:?}", node),
+    }
+}
+
+#[cfg(all(test, feature = "turtle"))]
+mod tests {
+    use crate::constraint::*;
+    use crate::reader::rdf_parser::RdfParser;
+    use crate::reader::turtle_parser::TurtleParser;
+
+    #[test]
+    fn values_are_checked_against_data_type_class_and_allowed_values() {
+        let input = "@prefix ex: <http://example.org/> .
+                     @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+
+                     ex:task ex:assignee ex:alice , ex:robot .
+                     ex:task a ex:Task ;
+                         ex:title \"Write\" ;
+                         ex:effort \"high\" ;
+                         ex:status ex:Blocked .
+                     ex:alice a ex:Person .";
+
+        let graph = TurtleParser::from_string(input).decode().unwrap();
+        let uri = |name: &str| Uri::new("http://example.org/".to_string() + name);
+
+        let report = Constraint::on_class(uri("Task"))
+            .require(uri("title"))
+            .datatype(XmlDataTypes::String.to_uri())
+            .property(uri("effort"))
+            .datatype(XmlDataTypes::Integer.to_uri())
+            .property(uri("assignee"))
+            .class(uri("Person"))
+            .property(uri("status"))
+            .one_of(vec![
+                Node::UriNode { uri: uri("Open") },
+                Node::UriNode { uri: uri("Done") },
+            ])
+            .validate(&graph);
+
+        let components: Vec<_> = report.results().iter().map(|r| r.component()).collect();
+
+        assert_eq!(
+            components,
+            vec![
+                ConstraintComponent::Datatype,
+                ConstraintComponent::Class,
+                ConstraintComponent::In,
+            ]
+        );
+        assert_eq!(
+            report.results()[1].value(),
+            &Some(Node::UriNode { uri: uri("robot") })
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn modifier_without_property() {
+        Constraint::on_class(Uri::new("http://example.org/Task".to_string())).max_count(1);
+    }
+}
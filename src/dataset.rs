@@ -0,0 +1,520 @@
+use crate::error::{Error, ErrorType};
+use crate::graph::Graph;
+use crate::node::Node;
+use crate::triple::Triple;
+use crate::Result;
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex, Weak};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, SystemTime};
+
+/// Triple that belongs to the default graph or to a named graph of a dataset.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Quad {
+    triple: Triple,
+    graph_name: Option<Node>,
+}
+
+impl Quad {
+    /// Constructor of `Quad`, without graph name the triple belongs to the default graph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::dataset::Quad;
+    /// use rdf::node::Node;
+    /// use rdf::triple::Triple;
+    ///
+    /// let node = Node::BlankNode { id: "a".to_string() };
+    /// let quad = Quad::new(&Triple::new(&node, &node, &node), Some(&node));
+    ///
+    /// assert_eq!(quad.graph_name(), &Some(node));
+    /// ```
+    pub fn new(triple: &Triple, graph_name: Option<&Node>) -> Quad {
+        Quad {
+            triple: triple.clone(),
+            graph_name: graph_name.cloned(),
+        }
+    }
+
+    /// Returns the triple of the quad.
+    pub fn triple(&self) -> &Triple {
+        &self.triple
+    }
+
+    /// Returns the name of the graph of the triple, `None` for the default graph.
+    pub fn graph_name(&self) -> &Option<Node> {
+        &self.graph_name
+    }
+}
+
+/// Limit of a dataset that can be exceeded by a write.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Quota {
+    /// Maximum number of triples of a named graph.
+    TriplesPerGraph(usize),
+
+    /// Maximum number of named graphs.
+    NamedGraphs(usize),
+
+    /// Maximum number of characters of a literal.
+    LiteralLength(usize),
+}
+
+/// Limits that are enforced by `Dataset::try_add_quad`.
+///
+/// By default no limit is set.
+#[derive(Debug, Clone, Default)]
+pub struct DatasetLimits {
+    max_triples_per_graph: Option<usize>,
+    max_named_graphs: Option<usize>,
+    max_literal_length: Option<usize>,
+}
+
+impl DatasetLimits {
+    /// Constructor of `DatasetLimits` without any limit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::dataset::DatasetLimits;
+    ///
+    /// let mut limits = DatasetLimits::new();
+    /// limits.set_max_triples_per_graph(Some(10_000));
+    /// limits.set_max_named_graphs(Some(100));
+    /// limits.set_max_literal_length(Some(4096));
+    /// ```
+    pub fn new() -> DatasetLimits {
+        DatasetLimits::default()
+    }
+
+    /// Returns the maximum number of triples of a named graph.
+    pub fn max_triples_per_graph(&self) -> Option<usize> {
+        self.max_triples_per_graph
+    }
+
+    /// Sets the maximum number of triples of a named graph, the default graph is not limited.
+    pub fn set_max_triples_per_graph(&mut self, max_triples_per_graph: Option<usize>) {
+        self.max_triples_per_graph = max_triples_per_graph;
+    }
+
+    /// Returns the maximum number of named graphs.
+    pub fn max_named_graphs(&self) -> Option<usize> {
+        self.max_named_graphs
+    }
+
+    /// Sets the maximum number of named graphs.
+    pub fn set_max_named_graphs(&mut self, max_named_graphs: Option<usize>) {
+        self.max_named_graphs = max_named_graphs;
+    }
+
+    /// Returns the maximum number of characters of a literal.
+    pub fn max_literal_length(&self) -> Option<usize> {
+        self.max_literal_length
+    }
+
+    /// Sets the maximum number of characters of a literal, including literals in quoted triples.
+    pub fn set_max_literal_length(&mut self, max_literal_length: Option<usize>) {
+        self.max_literal_length = max_literal_length;
+    }
+
+    /// Returns the quota the literals of the node exceed, if any.
+    fn check_literals(&self, node: &Node) -> Option<Quota> {
+        let max = self.max_literal_length?;
+
+        match *node {
+            Node::LiteralNode { ref literal, .. } if literal.chars().count() > max => {
+                Some(Quota::LiteralLength(max))
+            }
+            Node::TripleNode { ref triple } => self
+                .check_literals(triple.subject())
+                .or_else(|| self.check_literals(triple.predicate()))
+                .or_else(|| self.check_literals(triple.object())),
+            _ => None,
+        }
+    }
+}
+
+/// Collection of a default graph and named graphs.
+///
+/// # Examples
+///
+/// ```
+/// use rdf::dataset::{Dataset, Quad};
+/// use rdf::node::Node;
+/// use rdf::triple::Triple;
+///
+/// let mut dataset = Dataset::new();
+///
+/// let node = |id: &str| Node::BlankNode { id: id.to_string() };
+/// let triple = Triple::new(&node("a"), &node("p"), &node("b"));
+///
+/// dataset.add_quad(&Quad::new(&triple, None));
+/// dataset.add_quad(&Quad::new(&triple, Some(&node("g"))));
+///
+/// assert_eq!(dataset.default_graph().count(), 1);
+/// assert_eq!(dataset.named_graph(&node("g")).unwrap().count(), 1);
+/// assert_eq!(dataset.count(), 2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Dataset {
+    default_graph: Graph,
+    named_graphs: BTreeMap<Node, Graph>,
+    expiries: BTreeMap<Node, SystemTime>,
+    limits: DatasetLimits,
+}
+
+impl Default for Dataset {
+    fn default() -> Dataset {
+        Dataset::new()
+    }
+}
+
+impl Dataset {
+    /// Constructor of an empty `Dataset`.
+    pub fn new() -> Dataset {
+        Dataset {
+            default_graph: Graph::new(None),
+            named_graphs: BTreeMap::new(),
+            expiries: BTreeMap::new(),
+            limits: DatasetLimits::new(),
+        }
+    }
+
+    /// Returns the default graph.
+    pub fn default_graph(&self) -> &Graph {
+        &self.default_graph
+    }
+
+    /// Returns the default graph for modifications.
+    pub fn default_graph_mut(&mut self) -> &mut Graph {
+        &mut self.default_graph
+    }
+
+    /// Returns the named graph, if it exists.
+    pub fn named_graph(&self, name: &Node) -> Option<&Graph> {
+        self.named_graphs.get(name)
+    }
+
+    /// Returns the named graph for modifications, an empty graph is added if it does not exist.
+    pub fn named_graph_mut(&mut self, name: &Node) -> &mut Graph {
+        self.named_graphs
+            .entry(name.clone())
+            .or_insert_with(|| Graph::new(None))
+    }
+
+    /// Returns the names of the named graphs in order.
+    pub fn graph_names(&self) -> Vec<&Node> {
+        self.named_graphs.keys().collect()
+    }
+
+    /// Returns the named graphs ordered by name.
+    pub fn named_graphs(&self) -> &BTreeMap<Node, Graph> {
+        &self.named_graphs
+    }
+
+    /// Removes the named graph together with its expiry and returns it, if it exists.
+    pub fn remove_named_graph(&mut self, name: &Node) -> Option<Graph> {
+        self.expiries.remove(name);
+        self.named_graphs.remove(name)
+    }
+
+    /// Sets the point in time after which the named graph is removed by `purge_expired`.
+    ///
+    /// Replaces a previously set expiry of the graph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::dataset::Dataset;
+    /// use rdf::node::Node;
+    /// use std::time::{Duration, SystemTime};
+    ///
+    /// let mut dataset = Dataset::new();
+    /// let name = Node::BlankNode { id: "g".to_string() };
+    /// let expires_at = SystemTime::now() + Duration::from_secs(60);
+    ///
+    /// dataset.set_expiry(&name, expires_at);
+    ///
+    /// assert_eq!(dataset.expiry(&name), Some(&expires_at));
+    /// ```
+    pub fn set_expiry(&mut self, name: &Node, expires_at: SystemTime) {
+        self.expiries.insert(name.clone(), expires_at);
+    }
+
+    /// Returns the expiry of the named graph, if one is set.
+    pub fn expiry(&self, name: &Node) -> Option<&SystemTime> {
+        self.expiries.get(name)
+    }
+
+    /// Removes the expiry of the named graph, so that it is kept until removed explicitly.
+    pub fn clear_expiry(&mut self, name: &Node) -> Option<SystemTime> {
+        self.expiries.remove(name)
+    }
+
+    /// Removes all named graphs whose expiry has passed and returns their names.
+    pub fn purge_expired(&mut self) -> Vec<Node> {
+        self.purge_expired_at(SystemTime::now())
+    }
+
+    /// Removes all named graphs that expire at or before `now` and returns their names in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::dataset::{Dataset, Quad};
+    /// use rdf::node::Node;
+    /// use rdf::triple::Triple;
+    /// use std::time::{Duration, SystemTime};
+    ///
+    /// let mut dataset = Dataset::new();
+    ///
+    /// let node = |id: &str| Node::BlankNode { id: id.to_string() };
+    /// let triple = Triple::new(&node("a"), &node("p"), &node("b"));
+    /// let now = SystemTime::now();
+    ///
+    /// dataset.add_quad(&Quad::new(&triple, Some(&node("cached"))));
+    /// dataset.add_quad(&Quad::new(&triple, Some(&node("fresh"))));
+    /// dataset.set_expiry(&node("cached"), now - Duration::from_secs(1));
+    /// dataset.set_expiry(&node("fresh"), now + Duration::from_secs(60));
+    ///
+    /// assert_eq!(dataset.purge_expired_at(now), vec![node("cached")]);
+    /// assert_eq!(dataset.graph_names(), vec![&node("fresh")]);
+    /// ```
+    pub fn purge_expired_at(&mut self, now: SystemTime) -> Vec<Node> {
+        let expired: Vec<Node> = self
+            .expiries
+            .iter()
+            .filter(|&(_, expires_at)| *expires_at <= now)
+            .map(|(name, _)| name.clone())
+            .collect();
+
+        for name in &expired {
+            self.remove_named_graph(name);
+        }
+
+        expired
+    }
+
+    /// Starts a background thread that purges expired named graphs of the shared dataset
+    /// every `interval`.
+    ///
+    /// The thread only keeps a weak reference and stops once the dataset is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::dataset::Dataset;
+    /// use std::sync::{Arc, Mutex};
+    /// use std::time::Duration;
+    ///
+    /// let dataset = Arc::new(Mutex::new(Dataset::new()));
+    /// let sweep = Dataset::spawn_expiry_sweep(&dataset, Duration::from_millis(10));
+    ///
+    /// drop(dataset);
+    /// sweep.join().unwrap();
+    /// ```
+    pub fn spawn_expiry_sweep(dataset: &Arc<Mutex<Dataset>>, interval: Duration) -> JoinHandle<()> {
+        let dataset: Weak<Mutex<Dataset>> = Arc::downgrade(dataset);
+
+        thread::spawn(move || loop {
+            thread::sleep(interval);
+
+            match dataset.upgrade() {
+                Some(dataset) => {
+                    // a poisoned lock means a writer panicked, the sweep stops as well
+                    match dataset.lock() {
+                        Ok(mut dataset) => {
+                            dataset.purge_expired();
+                        }
+                        Err(_) => return,
+                    }
+                }
+                None => return,
+            }
+        })
+    }
+
+    /// Returns the limits enforced by `try_add_quad`.
+    pub fn limits(&self) -> &DatasetLimits {
+        &self.limits
+    }
+
+    /// Sets the limits enforced by `try_add_quad`.
+    ///
+    /// Existing graphs are not checked against the new limits.
+    pub fn set_limits(&mut self, limits: DatasetLimits) {
+        self.limits = limits;
+    }
+
+    /// Adds the triple of the quad to the graph of the quad.
+    ///
+    /// The limits of the dataset are not checked, see `try_add_quad`.
+    pub fn add_quad(&mut self, quad: &Quad) {
+        match quad.graph_name {
+            Some(ref name) => self.named_graph_mut(name).add_triple(&quad.triple),
+            None => self.default_graph.add_triple(&quad.triple),
+        }
+    }
+
+    /// Adds the triple of the quad to the graph of the quad if no limit of the dataset is exceeded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::dataset::{Dataset, DatasetLimits, Quad, Quota};
+    /// use rdf::error::ErrorType;
+    /// use rdf::node::Node;
+    /// use rdf::triple::Triple;
+    ///
+    /// let mut limits = DatasetLimits::new();
+    /// limits.set_max_named_graphs(Some(1));
+    ///
+    /// let mut dataset = Dataset::new();
+    /// dataset.set_limits(limits);
+    ///
+    /// let node = |id: &str| Node::BlankNode { id: id.to_string() };
+    /// let triple = Triple::new(&node("a"), &node("p"), &node("b"));
+    ///
+    /// assert!(dataset.try_add_quad(&Quad::new(&triple, Some(&node("g1")))).is_ok());
+    ///
+    /// let err = dataset.try_add_quad(&Quad::new(&triple, Some(&node("g2")))).unwrap_err();
+    /// assert!(matches!(err.error_type(), ErrorType::QuotaExceeded(Quota::NamedGraphs(1))));
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - The quad would add a named graph, a triple to a full named graph or contains a too long literal.
+    ///
+    pub fn try_add_quad(&mut self, quad: &Quad) -> Result<()> {
+        if let Some(quota) = self.exceeded_quota(quad) {
+            return Err(Error::new(
+                ErrorType::QuotaExceeded(quota),
+                format!("Dataset quota exceeded: {:?}", quota),
+            ));
+        }
+
+        self.add_quad(quad);
+        Ok(())
+    }
+
+    /// Returns the quota that adding the quad would exceed, if any.
+    fn exceeded_quota(&self, quad: &Quad) -> Option<Quota> {
+        let triple = &quad.triple;
+        let literal_quota = self
+            .limits
+            .check_literals(triple.subject())
+            .or_else(|| self.limits.check_literals(triple.predicate()))
+            .or_else(|| self.limits.check_literals(triple.object()));
+
+        if literal_quota.is_some() {
+            return literal_quota;
+        }
+
+        let name = quad.graph_name.as_ref()?;
+
+        match self.named_graphs.get(name) {
+            Some(graph) => self
+                .limits
+                .max_triples_per_graph
+                .filter(|max| graph.count() >= *max)
+                .map(Quota::TriplesPerGraph),
+            None => self
+                .limits
+                .max_named_graphs
+                .filter(|max| self.named_graphs.len() >= *max)
+                .map(Quota::NamedGraphs)
+                .or_else(|| {
+                    self.limits
+                        .max_triples_per_graph
+                        .filter(|max| *max == 0)
+                        .map(Quota::TriplesPerGraph)
+                }),
+        }
+    }
+
+    /// Returns the quads of the default graph followed by the quads of the named graphs.
+    pub fn quads(&self) -> Vec<Quad> {
+        let default_quads = self
+            .default_graph
+            .triples_iter()
+            .map(|triple| Quad::new(triple, None));
+
+        let named_quads = self.named_graphs.iter().flat_map(|(name, graph)| {
+            graph
+                .triples_iter()
+                .map(move |triple| Quad::new(triple, Some(name)))
+        });
+
+        default_quads.chain(named_quads).collect()
+    }
+
+    /// Returns the number of triples of all graphs.
+    pub fn count(&self) -> usize {
+        self.default_graph.count()
+            + self
+                .named_graphs
+                .values()
+                .map(|graph| graph.count())
+                .sum::<usize>()
+    }
+
+    /// Checks if no graph contains triples.
+    pub fn is_empty(&self) -> bool {
+        self.count() == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dataset::{Dataset, DatasetLimits, Quad, Quota};
+    use crate::error::ErrorType;
+    use crate::node::Node;
+    use crate::triple::Triple;
+
+    fn quota_of(dataset: &mut Dataset, quad: &Quad) -> Option<Quota> {
+        match dataset.try_add_quad(quad) {
+            Ok(()) => None,
+            Err(err) => match *err.error_type() {
+                ErrorType::QuotaExceeded(quota) => Some(quota),
+                _ => panic!("unexpected error: {}", err),
+            },
+        }
+    }
+
+    #[test]
+    fn writes_exceeding_limits_are_rejected() {
+        let mut limits = DatasetLimits::new();
+        limits.set_max_triples_per_graph(Some(1));
+        limits.set_max_literal_length(Some(3));
+
+        let mut dataset = Dataset::new();
+        dataset.set_limits(limits);
+
+        let node = |id: &str| Node::BlankNode { id: id.to_string() };
+        let literal = |literal: &str| Node::LiteralNode {
+            literal: literal.to_string(),
+            data_type: None,
+            language: None,
+        };
+        let graph = node("g");
+        let first = Triple::new(&node("a"), &node("p"), &literal("abc"));
+        let second = Triple::new(&node("a"), &node("p"), &literal("b"));
+        let long = Triple::new(&node("a"), &node("p"), &literal("abcd"));
+
+        assert_eq!(
+            quota_of(&mut dataset, &Quad::new(&first, Some(&graph))),
+            None
+        );
+        assert_eq!(
+            quota_of(&mut dataset, &Quad::new(&second, Some(&graph))),
+            Some(Quota::TriplesPerGraph(1))
+        );
+        assert_eq!(quota_of(&mut dataset, &Quad::new(&second, None)), None);
+        assert_eq!(
+            quota_of(&mut dataset, &Quad::new(&long, None)),
+            Some(Quota::LiteralLength(3))
+        );
+        assert_eq!(dataset.count(), 2);
+    }
+}
@@ -0,0 +1,416 @@
+use crate::error::{Error, ErrorType};
+use crate::specs::xml_specs::WhiteSpace;
+use crate::Result;
+use std::cmp::Ordering;
+use std::fmt;
+
+use self::coefficient::Coefficient;
+
+/// Number of fractional digits that are kept when dividing decimals.
+const DIVISION_SCALE: u32 = 18;
+
+/// Exact decimal number that is parsed from an `xsd:decimal` or `xsd:integer` literal.
+///
+/// Decimals are stored as integer coefficient and number of fractional digits. By default
+/// the coefficient is an `i128` that holds up to 38 digits and arithmetic returns `None` on
+/// overflow; with the `bignum` feature the precision is arbitrary. Decimals with the same
+/// value are equal regardless of trailing zeros, e.g. `1.50` and `1.5`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Decimal {
+    coefficient: Coefficient,
+    scale: u32,
+}
+
+impl Decimal {
+    /// Parses the lexical form of an `xsd:decimal`, e.g. `-12.50`.
+    ///
+    /// Surrounding whitespace is removed as required by the whiteSpace facet of the data type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::decimal::Decimal;
+    ///
+    /// let price = Decimal::parse("19.90").unwrap();
+    ///
+    /// assert_eq!(price.to_string(), "19.9");
+    /// assert_eq!(Decimal::parse("42").unwrap().to_string(), "42.0");
+    /// assert!(Decimal::parse("1e3").is_err());
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - The literal is not a valid `xsd:decimal`.
+    /// - The literal has more digits than the coefficient can hold.
+    ///
+    pub fn parse(literal: &str) -> Result<Decimal> {
+        let collapsed = WhiteSpace::Collapse.apply(literal);
+
+        Decimal::parse_lexical_form(&collapsed).ok_or_else(|| {
+            Error::new(
+                ErrorType::InvalidLiteral,
+                "Invalid xsd:decimal: ".to_string() + literal,
+            )
+        })
+    }
+
+    /// Creates a decimal from an integer.
+    pub fn from_integer(integer: i64) -> Decimal {
+        Decimal {
+            coefficient: Coefficient::from(integer),
+            scale: 0,
+        }
+    }
+
+    /// Returns the number of fractional digits without trailing zeros.
+    pub fn scale(&self) -> u32 {
+        self.scale
+    }
+
+    /// Returns `true` if the decimal is below zero.
+    pub fn is_negative(&self) -> bool {
+        self.coefficient < coefficient::zero()
+    }
+
+    /// Adds two decimals and returns `None` on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::decimal::Decimal;
+    ///
+    /// let a = Decimal::parse("0.1").unwrap();
+    /// let b = Decimal::parse("0.2").unwrap();
+    ///
+    /// assert_eq!(a.checked_add(&b), Some(Decimal::parse("0.3").unwrap()));
+    /// ```
+    pub fn checked_add(&self, other: &Decimal) -> Option<Decimal> {
+        let (a, b, scale) = align(self, other)?;
+        Some(Decimal::new(coefficient::add(&a, &b)?, scale))
+    }
+
+    /// Subtracts two decimals and returns `None` on overflow.
+    pub fn checked_sub(&self, other: &Decimal) -> Option<Decimal> {
+        let (a, b, scale) = align(self, other)?;
+        Some(Decimal::new(coefficient::sub(&a, &b)?, scale))
+    }
+
+    /// Multiplies two decimals and returns `None` on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::decimal::Decimal;
+    ///
+    /// let price = Decimal::parse("19.99").unwrap();
+    /// let quantity = Decimal::from_integer(3);
+    ///
+    /// assert_eq!(price.checked_mul(&quantity).unwrap().to_string(), "59.97");
+    /// ```
+    pub fn checked_mul(&self, other: &Decimal) -> Option<Decimal> {
+        Some(Decimal::new(
+            coefficient::mul(&self.coefficient, &other.coefficient)?,
+            self.scale + other.scale,
+        ))
+    }
+
+    /// Divides two decimals and returns `None` on division by zero or overflow.
+    ///
+    /// The quotient is truncated after 18 fractional digits, or after the number of fractional
+    /// digits of the operands if they have more.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::decimal::Decimal;
+    ///
+    /// let one = Decimal::from_integer(1);
+    ///
+    /// assert_eq!(one.checked_div(&Decimal::from_integer(8)).unwrap().to_string(), "0.125");
+    /// assert_eq!(one.checked_div(&Decimal::from_integer(3)).unwrap().to_string(),
+    ///            "0.333333333333333333");
+    /// assert_eq!(one.checked_div(&Decimal::from_integer(0)), None);
+    /// ```
+    pub fn checked_div(&self, other: &Decimal) -> Option<Decimal> {
+        if other.coefficient == coefficient::zero() {
+            return None;
+        }
+
+        // coefficient / other.coefficient has the scale self.scale - other.scale
+        let scale = DIVISION_SCALE.max(self.scale).max(other.scale);
+        let dividend = coefficient::mul(
+            &self.coefficient,
+            &coefficient::pow10(scale + other.scale - self.scale)?,
+        )?;
+
+        Some(Decimal::new(
+            coefficient::div(&dividend, &other.coefficient),
+            scale,
+        ))
+    }
+
+    /// Creates a normalized decimal without trailing zeros in the fraction.
+    fn new(mut coefficient: Coefficient, mut scale: u32) -> Decimal {
+        let ten = Coefficient::from(10);
+
+        while scale > 0 && coefficient::rem(&coefficient, &ten) == coefficient::zero() {
+            coefficient = coefficient::div(&coefficient, &ten);
+            scale -= 1;
+        }
+
+        Decimal { coefficient, scale }
+    }
+
+    /// Parses the lexical form and returns `None` if it is invalid.
+    fn parse_lexical_form(literal: &str) -> Option<Decimal> {
+        let (negative, number) = match literal.as_bytes().first() {
+            Some(b'-') => (true, &literal[1..]),
+            Some(b'+') => (false, &literal[1..]),
+            _ => (false, literal),
+        };
+
+        let (integer, fraction) = match number.find('.') {
+            Some(point) => (&number[..point], &number[point + 1..]),
+            None => (number, ""),
+        };
+
+        if (integer.is_empty() && fraction.is_empty())
+            || !integer.chars().all(|c| c.is_ascii_digit())
+            || !fraction.chars().all(|c| c.is_ascii_digit())
+        {
+            return None;
+        }
+
+        let digits = integer.to_string() + fraction;
+        let coefficient = coefficient::parse(if negative {
+            "-".to_string() + &digits
+        } else {
+            digits
+        })?;
+
+        Some(Decimal::new(coefficient, fraction.len() as u32))
+    }
+}
+
+impl Ord for Decimal {
+    fn cmp(&self, other: &Decimal) -> Ordering {
+        match align(self, other) {
+            Some((a, b, _)) => a.cmp(&b),
+            // aligning only overflows if the integer parts differ
+            None => {
+                let a = coefficient::div(&self.coefficient, &pow10_or_max(self.scale));
+                let b = coefficient::div(&other.coefficient, &pow10_or_max(other.scale));
+                a.cmp(&b)
+            }
+        }
+    }
+}
+
+impl PartialOrd for Decimal {
+    fn partial_cmp(&self, other: &Decimal) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for Decimal {
+    /// Formats the decimal in its canonical form, which always contains a decimal point.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let digits = self.coefficient.to_string();
+        let (sign, digits) = match digits.strip_prefix('-') {
+            Some(digits) => ("-", digits),
+            None => ("", digits.as_str()),
+        };
+
+        let scale = self.scale as usize;
+
+        if scale == 0 {
+            return write!(f, "{}{}.0", sign, digits);
+        }
+
+        let digits = format!("{:0>width$}", digits, width = scale + 1);
+        let (integer, fraction) = digits.split_at(digits.len() - scale);
+
+        write!(f, "{}{}.{}", sign, integer, fraction)
+    }
+}
+
+/// Returns the coefficients of both decimals with the same scale and the scale.
+fn align(a: &Decimal, b: &Decimal) -> Option<(Coefficient, Coefficient, u32)> {
+    let scale = a.scale.max(b.scale);
+
+    Some((
+        coefficient::mul(&a.coefficient, &coefficient::pow10(scale - a.scale)?)?,
+        coefficient::mul(&b.coefficient, &coefficient::pow10(scale - b.scale)?)?,
+        scale,
+    ))
+}
+
+/// Returns the power of ten, or the largest power the coefficient can hold.
+fn pow10_or_max(exponent: u32) -> Coefficient {
+    (0..=exponent)
+        .rev()
+        .find_map(coefficient::pow10)
+        .unwrap_or_else(|| Coefficient::from(1))
+}
+
+#[cfg(not(feature = "bignum"))]
+mod coefficient {
+    /// Fixed precision coefficient of decimals.
+    pub type Coefficient = i128;
+
+    pub fn zero() -> Coefficient {
+        0
+    }
+
+    pub fn parse(digits: String) -> Option<Coefficient> {
+        digits.parse().ok()
+    }
+
+    pub fn pow10(exponent: u32) -> Option<Coefficient> {
+        10i128.checked_pow(exponent)
+    }
+
+    pub fn add(a: &Coefficient, b: &Coefficient) -> Option<Coefficient> {
+        a.checked_add(*b)
+    }
+
+    pub fn sub(a: &Coefficient, b: &Coefficient) -> Option<Coefficient> {
+        a.checked_sub(*b)
+    }
+
+    pub fn mul(a: &Coefficient, b: &Coefficient) -> Option<Coefficient> {
+        a.checked_mul(*b)
+    }
+
+    pub fn div(a: &Coefficient, b: &Coefficient) -> Coefficient {
+        a / b
+    }
+
+    pub fn rem(a: &Coefficient, b: &Coefficient) -> Coefficient {
+        a % b
+    }
+}
+
+#[cfg(feature = "bignum")]
+mod coefficient {
+    /// Arbitrary precision coefficient of decimals.
+    pub type Coefficient = num_bigint::BigInt;
+
+    pub fn zero() -> Coefficient {
+        Coefficient::from(0)
+    }
+
+    pub fn parse(digits: String) -> Option<Coefficient> {
+        digits.parse().ok()
+    }
+
+    pub fn pow10(exponent: u32) -> Option<Coefficient> {
+        Some(Coefficient::from(10).pow(exponent))
+    }
+
+    pub fn add(a: &Coefficient, b: &Coefficient) -> Option<Coefficient> {
+        Some(a + b)
+    }
+
+    pub fn sub(a: &Coefficient, b: &Coefficient) -> Option<Coefficient> {
+        Some(a - b)
+    }
+
+    pub fn mul(a: &Coefficient, b: &Coefficient) -> Option<Coefficient> {
+        Some(a * b)
+    }
+
+    pub fn div(a: &Coefficient, b: &Coefficient) -> Coefficient {
+        a / b
+    }
+
+    pub fn rem(a: &Coefficient, b: &Coefficient) -> Coefficient {
+        a % b
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::decimal::Decimal;
+
+    fn decimal(literal: &str) -> Decimal {
+        Decimal::parse(literal).unwrap()
+    }
+
+    #[test]
+    fn parse_and_format_decimals() {
+        let literals = vec![
+            ("0", "0.0"),
+            ("-0.0", "0.0"),
+            ("+001.500", "1.5"),
+            (".5", "0.5"),
+            ("-3.", "-3.0"),
+            ("-0.0012", "-0.0012"),
+            (" \n 12.30\n", "12.3"),
+        ];
+
+        for (literal, canonical) in literals {
+            assert_eq!(decimal(literal).to_string(), canonical, "{}", literal);
+        }
+
+        for literal in ["", ".", "-", "1.2.3", "1e3", "1 2", "INF"] {
+            assert!(Decimal::parse(literal).is_err(), "{}", literal);
+        }
+    }
+
+    #[test]
+    fn decimal_arithmetic_is_exact() {
+        assert_eq!(
+            decimal("0.1").checked_add(&decimal("0.2")),
+            Some(decimal("0.3"))
+        );
+        assert_eq!(
+            decimal("1").checked_sub(&decimal("1.25")),
+            Some(decimal("-0.25"))
+        );
+        assert_eq!(
+            decimal("-1.5").checked_mul(&decimal("-0.2")),
+            Some(decimal("0.3"))
+        );
+        assert_eq!(
+            decimal("-7").checked_div(&decimal("2")),
+            Some(decimal("-3.5"))
+        );
+        assert_eq!(
+            decimal("1.5").checked_sub(&decimal("1.50")),
+            Some(decimal("0"))
+        );
+    }
+
+    #[test]
+    fn compare_decimals() {
+        assert!(decimal("1.10") == decimal("1.1"));
+        assert!(decimal("-2") < decimal("-1.999"));
+        assert!(decimal("0.1") < decimal("0.11"));
+        assert!(
+            decimal("1000000000000000000000000000000000000")
+                > decimal("0.00000000000000000000000000000000001")
+        );
+    }
+
+    #[cfg(not(feature = "bignum"))]
+    #[test]
+    fn overflow_returns_none() {
+        let large = decimal("99999999999999999999999999999999999999");
+
+        assert_eq!(large.checked_add(&large), None);
+        assert_eq!(large.checked_mul(&decimal("10")), None);
+        assert!(Decimal::parse("999999999999999999999999999999999999999").is_err());
+    }
+
+    #[cfg(feature = "bignum")]
+    #[test]
+    fn arbitrary_precision() {
+        let large = decimal("99999999999999999999999999999999999999");
+
+        assert_eq!(
+            large.checked_add(&decimal("0.1")).unwrap().to_string(),
+            "99999999999999999999999999999999999999.1"
+        );
+    }
+}
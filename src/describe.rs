@@ -0,0 +1,436 @@
+use crate::graph::Graph;
+use crate::node::Node;
+use crate::specs::json_specs::JsonSpecs;
+use crate::specs::xml_specs::XmlDataTypes;
+use crate::triple::Triple;
+use crate::uri::Uri;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+/// Naming of the keys that represent predicates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyStyle {
+    /// The full IRI of the predicate.
+    Iri,
+    /// A QName if a namespace of the graph matches, otherwise the full IRI.
+    CompactIri,
+    /// The part of the IRI after the last `#` or `/`, predicates with the same local name share
+    /// a key.
+    LocalName,
+}
+
+/// Options controlling how the description of a subject is written as JSON.
+#[derive(Debug, Clone)]
+pub struct DescribeOptions {
+    key_style: KeyStyle,
+    language_maps: bool,
+    always_arrays: bool,
+    max_depth: usize,
+    field_names: HashMap<Uri, String>,
+    mapped_fields_only: bool,
+}
+
+impl Default for DescribeOptions {
+    fn default() -> DescribeOptions {
+        DescribeOptions {
+            key_style: KeyStyle::CompactIri,
+            language_maps: true,
+            always_arrays: false,
+            max_depth: 8,
+            field_names: HashMap::new(),
+            mapped_fields_only: false,
+        }
+    }
+}
+
+impl DescribeOptions {
+    /// Constructor of `DescribeOptions`.
+    ///
+    /// By default keys are QNames, values with language tags are written as language maps,
+    /// single values are not wrapped in arrays and blank nodes are inlined up to 8 levels.
+    pub fn new() -> DescribeOptions {
+        DescribeOptions::default()
+    }
+
+    /// Returns the naming of the keys.
+    pub fn key_style(&self) -> KeyStyle {
+        self.key_style
+    }
+
+    /// Sets the naming of the keys.
+    pub fn set_key_style(&mut self, key_style: KeyStyle) {
+        self.key_style = key_style;
+    }
+
+    /// Returns if the values of a predicate that all have a language tag are written as object
+    /// with the language tags as keys.
+    pub fn language_maps(&self) -> bool {
+        self.language_maps
+    }
+
+    /// Sets if values with language tags are written as language maps.
+    pub fn set_language_maps(&mut self, language_maps: bool) {
+        self.language_maps = language_maps;
+    }
+
+    /// Returns if single values are written as arrays as well.
+    pub fn always_arrays(&self) -> bool {
+        self.always_arrays
+    }
+
+    /// Sets if single values are written as arrays as well.
+    pub fn set_always_arrays(&mut self, always_arrays: bool) {
+        self.always_arrays = always_arrays;
+    }
+
+    /// Returns the number of nested blank nodes that are inlined.
+    pub fn max_depth(&self) -> usize {
+        self.max_depth
+    }
+
+    /// Sets the number of nested blank nodes that are inlined, deeper blank nodes are written
+    /// as `_:id`.
+    pub fn set_max_depth(&mut self, max_depth: usize) {
+        self.max_depth = max_depth;
+    }
+
+    /// Returns the key that is used for the predicate instead of the key style, if any.
+    pub fn field_name(&self, predicate: &Uri) -> Option<&str> {
+        self.field_names.get(predicate).map(|name| name.as_str())
+    }
+
+    /// Sets the key that is used for the predicate instead of the key style.
+    ///
+    /// Predicates that are mapped to the same key share it.
+    pub fn set_field_name(&mut self, predicate: &Uri, name: &str) {
+        self.field_names.insert(predicate.clone(), name.to_string());
+    }
+
+    /// Returns if only predicates with a field name are described.
+    pub fn mapped_fields_only(&self) -> bool {
+        self.mapped_fields_only
+    }
+
+    /// Sets if only predicates with a field name are described, other predicates are omitted.
+    pub fn set_mapped_fields_only(&mut self, mapped_fields_only: bool) {
+        self.mapped_fields_only = mapped_fields_only;
+    }
+}
+
+/// Writes the triples of the subject as JSON object.
+///
+/// The object has the subject as `@id` and a member for each predicate. URIs are written as
+/// strings, blank nodes as nested objects and literals as strings, or as numbers and booleans
+/// if their data type is numeric or `xsd:boolean`. Blank nodes that are already described on
+/// the path from the subject are written as `_:id`.
+pub fn describe_as_json(graph: &Graph, subject: &Node, options: &DescribeOptions) -> String {
+    let mut path = HashSet::new();
+    let description = describe(graph, subject, options, &mut path);
+
+    let id = match *subject {
+        Node::UriNode { ref uri } => uri.to_string().clone(),
+        _ => reference(subject),
+    };
+
+    let id_member = "\"@id\": ".to_string() + &JsonSpecs::escape_string(&id);
+
+    match description.len() {
+        2 => "{".to_string() + &id_member + "}",
+        _ => "{".to_string() + &id_member + ", " + &description[1..],
+    }
+}
+
+/// Returns the JSON object with the members of the node.
+fn describe<'a>(
+    graph: &'a Graph,
+    node: &'a Node,
+    options: &DescribeOptions,
+    path: &mut HashSet<&'a Node>,
+) -> String {
+    path.insert(node);
+
+    let mut triples: Vec<&Triple> = graph.get_triples_with_subject(node);
+    triples.sort();
+
+    let mut values: BTreeMap<String, Vec<&Node>> = BTreeMap::new();
+
+    for triple in &triples {
+        if let Some(key) = key(graph, triple.predicate(), options) {
+            values.entry(key).or_default().push(triple.object());
+        }
+    }
+
+    let mut members = Vec::new();
+
+    for (key, objects) in values {
+        let is_language_map = options.language_maps
+            && objects.iter().all(|object| {
+                matches!(
+                    *object,
+                    Node::LiteralNode {
+                        language: Some(_),
+                        ..
+                    }
+                )
+            });
+
+        let value = if is_language_map {
+            language_map(&objects, options)
+        } else {
+            let values = objects
+                .iter()
+                .map(|object| value(graph, object, options, path))
+                .collect();
+
+            array(values, options)
+        };
+
+        members.push(JsonSpecs::escape_string(&key) + ": " + &value);
+    }
+
+    path.remove(node);
+
+    "{".to_string() + &members.join(", ") + "}"
+}
+
+/// Returns the JSON value of an object.
+fn value<'a>(
+    graph: &'a Graph,
+    node: &'a Node,
+    options: &DescribeOptions,
+    path: &mut HashSet<&'a Node>,
+) -> String {
+    match *node {
+        Node::UriNode { ref uri } => JsonSpecs::escape_string(uri.to_string()),
+        Node::BlankNode { .. } if path.contains(node) || path.len() > options.max_depth => {
+            JsonSpecs::escape_string(&reference(node))
+        }
+        Node::BlankNode { .. } => describe(graph, node, options, path),
+        Node::LiteralNode {
+            ref literal,
+            ref data_type,
+            ..
+        } => {
+            let is_native = match *data_type {
+                Some(ref data_type) if *data_type == XmlDataTypes::Boolean.to_uri() => {
+                    literal == "true" || literal == "false"
+                }
+                Some(ref data_type) => is_numeric(data_type.to_string()) && is_json_number(literal),
+                None => false,
+            };
+
+            if is_native {
+                literal.clone()
+            } else {
+                JsonSpecs::escape_string(literal)
+            }
+        }
+        Node::TripleNode { .. } => JsonSpecs::escape_string(&reference(node)),
+    }
+}
+
+/// Returns the values grouped by language tag.
+fn language_map(objects: &[&Node], options: &DescribeOptions) -> String {
+    let mut languages: BTreeMap<&str, Vec<String>> = BTreeMap::new();
+
+    for object in objects {
+        if let Node::LiteralNode {
+            ref literal,
+            language: Some(ref language),
+            ..
+        } = **object
+        {
+            languages
+                .entry(language)
+                .or_default()
+                .push(JsonSpecs::escape_string(literal));
+        }
+    }
+
+    let members: Vec<String> = languages
+        .into_iter()
+        .map(|(language, values)| {
+            JsonSpecs::escape_string(language) + ": " + &array(values, options)
+        })
+        .collect();
+
+    "{".to_string() + &members.join(", ") + "}"
+}
+
+/// Returns the values as array, or the single value if arrays are not enforced.
+fn array(values: Vec<String>, options: &DescribeOptions) -> String {
+    if values.len() == 1 && !options.always_arrays {
+        return values.into_iter().next().unwrap_or_default();
+    }
+
+    "[".to_string() + &values.join(", ") + "]"
+}
+
+/// Returns the key of a predicate, or `None` if the predicate is omitted.
+fn key(graph: &Graph, predicate: &Node, options: &DescribeOptions) -> Option<String> {
+    let iri = match *predicate {
+        Node::UriNode { ref uri } => uri,
+        _ => return Some(reference(predicate)).filter(|_| !options.mapped_fields_only),
+    };
+
+    if let Some(name) = options.field_name(iri) {
+        return Some(name.to_string());
+    }
+
+    if options.mapped_fields_only {
+        return None;
+    }
+
+    let iri = iri.to_string();
+
+    let key = match options.key_style {
+        KeyStyle::Iri => iri.clone(),
+        KeyStyle::CompactIri => graph
+            .namespaces()
+            .iter()
+            .filter(|&(_, ns)| iri.starts_with(ns.to_string().as_str()))
+            .max_by_key(|&(prefix, ns)| (ns.to_string().len(), prefix))
+            .map(|(prefix, ns)| prefix.to_string() + ":" + &iri[ns.to_string().len()..])
+            .unwrap_or_else(|| iri.clone()),
+        KeyStyle::LocalName => iri[iri.rfind(['#', '/']).map_or(0, |i| i + 1)..].to_string(),
+    };
+
+    Some(key)
+}
+
+/// Returns the N-Triples like reference of a node that is not described.
+fn reference(node: &Node) -> String {
+    match *node {
+        Node::UriNode { ref uri } => "<".to_string() + uri.to_string() + ">",
+        Node::BlankNode { ref id } => "_:".to_string() + id,
+        Node::LiteralNode { ref literal, .. } => literal.clone(),
+        Node::TripleNode { ref triple } => {
+            "<< ".to_string()
+                + &reference(triple.subject())
+                + " "
+                + &reference(triple.predicate())
+                + " "
+                + &reference(triple.object())
+                + " >>"
+        }
+    }
+}
+
+/// Returns if the data type is a numeric XML Schema data type.
+fn is_numeric(data_type: &str) -> bool {
+    [
+        XmlDataTypes::Integer,
+        XmlDataTypes::Int,
+        XmlDataTypes::Long,
+        XmlDataTypes::UnsignedLong,
+        XmlDataTypes::Decimal,
+        XmlDataTypes::Double,
+    ]
+    .iter()
+    .any(|numeric| numeric.to_string() == data_type)
+}
+
+/// Returns if the literal is a number in JSON syntax.
+fn is_json_number(literal: &str) -> bool {
+    let number = literal.strip_prefix('-').unwrap_or(literal);
+    let (mantissa, exponent) = match number.find(['e', 'E']) {
+        Some(e) => (&number[..e], Some(&number[e + 1..])),
+        None => (number, None),
+    };
+    let (integer, fraction) = match mantissa.find('.') {
+        Some(point) => (&mantissa[..point], Some(&mantissa[point + 1..])),
+        None => (mantissa, None),
+    };
+
+    let is_digits = |digits: &str| !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit());
+
+    is_digits(integer)
+        && (integer == "0" || !integer.starts_with('0'))
+        && fraction.is_none_or(is_digits)
+        && exponent
+            .is_none_or(|exponent| is_digits(exponent.strip_prefix(['+', '-']).unwrap_or(exponent)))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::describe::{is_json_number, DescribeOptions, KeyStyle};
+    use crate::graph::Graph;
+    use crate::namespace::Namespace;
+    use crate::specs::xml_specs::XmlDataTypes;
+    use crate::triple::Triple;
+    use crate::uri::Uri;
+
+    #[test]
+    fn describe_with_nested_blank_nodes_and_language_maps() {
+        let mut graph = Graph::new(None);
+        graph.add_namespace(&Namespace::new(
+            "ex".to_string(),
+            Uri::new("http://example.org/".to_string()),
+        ));
+
+        let book = graph.create_uri_node(&Uri::new("http://example.org/book".to_string()));
+        let author = graph.create_blank_node_with_id("author".to_string());
+        let title = graph.create_uri_node(&Uri::new("http://example.org/title".to_string()));
+        let pages = graph.create_uri_node(&Uri::new("http://example.org/pages".to_string()));
+        let by = graph.create_uri_node(&Uri::new("http://example.org/by".to_string()));
+        let wrote = graph.create_uri_node(&Uri::new("http://example.org/wrote".to_string()));
+        let integer = XmlDataTypes::Integer.to_uri();
+
+        graph.add_triple(&Triple::new(
+            &book,
+            &title,
+            &graph.create_literal_node_with_language("Der Prozess".to_string(), "de".to_string()),
+        ));
+        graph.add_triple(&Triple::new(
+            &book,
+            &title,
+            &graph.create_literal_node_with_language("The Trial".to_string(), "en".to_string()),
+        ));
+        graph.add_triple(&Triple::new(
+            &book,
+            &pages,
+            &graph.create_literal_node_with_data_type("255".to_string(), &integer),
+        ));
+        graph.add_triple(&Triple::new(&book, &by, &author));
+        graph.add_triple(&Triple::new(&author, &wrote, &book));
+        graph.add_triple(&Triple::new(
+            &author,
+            &pages,
+            &graph.create_literal_node_with_data_type("+1".to_string(), &integer),
+        ));
+
+        assert_eq!(
+            graph.describe_as_json(&book, &DescribeOptions::new()),
+            "{\"@id\": \"http://example.org/book\", \
+              \"ex:by\": {\"ex:pages\": \"+1\", \"ex:wrote\": \"http://example.org/book\"}, \
+              \"ex:pages\": 255, \
+              \"ex:title\": {\"de\": \"Der Prozess\", \"en\": \"The Trial\"}}"
+        );
+
+        let mut options = DescribeOptions::new();
+        options.set_key_style(KeyStyle::LocalName);
+        options.set_language_maps(false);
+        options.set_always_arrays(true);
+        options.set_max_depth(0);
+
+        assert_eq!(
+            graph.describe_as_json(&book, &options),
+            "{\"@id\": \"http://example.org/book\", \"by\": [\"_:author\"], \"pages\": [255], \
+              \"title\": [\"Der Prozess\", \"The Trial\"]}"
+        );
+        assert_eq!(
+            graph.describe_as_json(&title, &options),
+            "{\"@id\": \"http://example.org/title\"}"
+        );
+    }
+
+    #[test]
+    fn json_numbers() {
+        for number in &["0", "-12", "1.5", "2e10", "-0.5E-3"] {
+            assert!(is_json_number(number), "{}", number);
+        }
+
+        for literal in &["", "+1", "01", "1.", ".5", "1e", "NaN", "1_000"] {
+            assert!(!is_json_number(literal), "{}", literal);
+        }
+    }
+}
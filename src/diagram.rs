@@ -0,0 +1,290 @@
+use crate::graph::Graph;
+use crate::node::Node;
+use std::collections::HashMap;
+
+/// Options controlling how graphs are rendered as diagrams.
+#[derive(Debug, Clone, Default)]
+pub struct DiagramOptions {
+    literal_length: Option<usize>,
+    predicates: Option<Vec<Node>>,
+}
+
+impl DiagramOptions {
+    /// Constructor of `DiagramOptions`.
+    ///
+    /// By default literals are not truncated and all predicates are rendered.
+    pub fn new() -> DiagramOptions {
+        DiagramOptions::default()
+    }
+
+    /// Returns the number of characters after which literals are truncated.
+    pub fn literal_length(&self) -> Option<usize> {
+        self.literal_length
+    }
+
+    /// Sets the number of characters after which literals are truncated.
+    pub fn set_literal_length(&mut self, literal_length: Option<usize>) {
+        self.literal_length = literal_length;
+    }
+
+    /// Returns the predicates that are rendered, `None` if all predicates are rendered.
+    pub fn predicates(&self) -> &Option<Vec<Node>> {
+        &self.predicates
+    }
+
+    /// Restricts the rendered triples to the provided predicates.
+    pub fn set_predicates(&mut self, predicates: Option<Vec<Node>>) {
+        self.predicates = predicates;
+    }
+}
+
+/// Nodes and labeled edges of a graph as they are rendered.
+struct Diagram {
+    // label of each node and whether it is a literal
+    nodes: Vec<(String, bool)>,
+    edges: Vec<(usize, usize, String)>,
+}
+
+impl Diagram {
+    /// Collects the nodes and edges of the graph.
+    ///
+    /// Resources are rendered once, literals are rendered separately for each triple.
+    fn new(graph: &Graph, options: &DiagramOptions) -> Diagram {
+        let mut triples: Vec<_> = graph
+            .triples_iter()
+            .filter(|t| match options.predicates {
+                Some(ref predicates) => predicates.contains(t.predicate()),
+                None => true,
+            })
+            .collect();
+        triples.sort();
+
+        let mut nodes = Vec::new();
+        let mut indices: HashMap<&Node, usize> = HashMap::new();
+        let mut edges = Vec::new();
+
+        for triple in triples {
+            let source = node_index(graph, options, &mut nodes, &mut indices, triple.subject());
+            let target = node_index(graph, options, &mut nodes, &mut indices, triple.object());
+
+            edges.push((source, target, label(graph, triple.predicate(), options)));
+        }
+
+        Diagram { nodes, edges }
+    }
+}
+
+/// Returns the index of the node and adds it to the nodes if it does not exist yet.
+///
+/// Literals are added each time.
+fn node_index<'a>(
+    graph: &Graph,
+    options: &DiagramOptions,
+    nodes: &mut Vec<(String, bool)>,
+    indices: &mut HashMap<&'a Node, usize>,
+    node: &'a Node,
+) -> usize {
+    if let Node::LiteralNode { .. } = *node {
+        nodes.push((label(graph, node, options), true));
+        return nodes.len() - 1;
+    }
+
+    if let Some(index) = indices.get(node) {
+        return *index;
+    }
+
+    nodes.push((label(graph, node, options), false));
+    indices.insert(node, nodes.len() - 1);
+    nodes.len() - 1
+}
+
+/// Renders the graph in the DOT language of GraphViz.
+///
+/// URIs are abbreviated to QNames if a namespace of the graph matches, literals are drawn as boxes.
+pub fn to_dot(graph: &Graph, options: &DiagramOptions) -> String {
+    let diagram = Diagram::new(graph, options);
+    let mut output_string = "digraph {\n".to_string();
+
+    for (i, &(ref label, literal)) in diagram.nodes.iter().enumerate() {
+        let shape = if literal { ", shape=box" } else { "" };
+
+        output_string.push_str(&format!(
+            "    n{} [label=\"{}\"{}];\n",
+            i,
+            escape_dot(label),
+            shape
+        ));
+    }
+
+    for &(source, target, ref label) in &diagram.edges {
+        output_string.push_str(&format!(
+            "    n{} -> n{} [label=\"{}\"];\n",
+            source,
+            target,
+            escape_dot(label)
+        ));
+    }
+
+    output_string.push_str("}\n");
+    output_string
+}
+
+/// Renders the graph as Mermaid flowchart for embedding in Markdown.
+///
+/// URIs are abbreviated to QNames if a namespace of the graph matches, resources are drawn
+/// with rounded corners and literals as rectangles.
+pub fn to_mermaid(graph: &Graph, options: &DiagramOptions) -> String {
+    let diagram = Diagram::new(graph, options);
+    let mut output_string = "flowchart LR\n".to_string();
+
+    for (i, &(ref label, literal)) in diagram.nodes.iter().enumerate() {
+        let (open, close) = if literal { ("[", "]") } else { ("(", ")") };
+
+        output_string.push_str(&format!(
+            "    n{}{}\"{}\"{}\n",
+            i,
+            open,
+            escape_mermaid(label),
+            close
+        ));
+    }
+
+    for &(source, target, ref label) in &diagram.edges {
+        output_string.push_str(&format!(
+            "    n{} -->|\"{}\"| n{}\n",
+            source,
+            escape_mermaid(label),
+            target
+        ));
+    }
+
+    output_string
+}
+
+/// Returns the label of a node, URIs are abbreviated to QNames where possible.
+fn label(graph: &Graph, node: &Node, options: &DiagramOptions) -> String {
+    match *node {
+        Node::UriNode { ref uri } => {
+            let uri = uri.to_string();
+
+            // prefer the longest matching namespace
+            let qname = graph
+                .namespaces()
+                .iter()
+                .filter(|&(_, ns)| uri.starts_with(ns.to_string().as_str()))
+                .max_by_key(|&(prefix, ns)| (ns.to_string().len(), prefix))
+                .map(|(prefix, ns)| prefix.to_string() + ":" + &uri[ns.to_string().len()..]);
+
+            qname.unwrap_or_else(|| "<".to_string() + uri + ">")
+        }
+        Node::LiteralNode { ref literal, .. } => {
+            let truncated = match options.literal_length {
+                Some(length) if literal.chars().count() > length => {
+                    literal.chars().take(length).collect::<String>() + "…"
+                }
+                _ => literal.to_string(),
+            };
+
+            "\"".to_string() + &truncated + "\""
+        }
+        Node::BlankNode { ref id } => "_:".to_string() + id,
+        Node::TripleNode { ref triple } => {
+            "<< ".to_string()
+                + &label(graph, triple.subject(), options)
+                + " "
+                + &label(graph, triple.predicate(), options)
+                + " "
+                + &label(graph, triple.object(), options)
+                + " >>"
+        }
+    }
+}
+
+/// Escapes a label for a quoted DOT string.
+fn escape_dot(label: &str) -> String {
+    label
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// Escapes a label for a quoted Mermaid string with entity codes.
+fn escape_mermaid(label: &str) -> String {
+    label
+        .replace('#', "#35;")
+        .replace('"', "#quot;")
+        .replace('<', "#lt;")
+        .replace('>', "#gt;")
+        .replace('\n', " ")
+}
+
+#[cfg(all(test, feature = "turtle"))]
+mod tests {
+    use crate::diagram::*;
+    use crate::namespace::Namespace;
+    use crate::reader::rdf_parser::RdfParser;
+    use crate::reader::turtle_parser::TurtleParser;
+    use crate::triple::Triple;
+    use crate::uri::Uri;
+
+    #[test]
+    fn dot_with_truncated_literals_and_filtered_predicates() {
+        let mut graph = Graph::new(None);
+        graph.add_namespace(&Namespace::new(
+            "ex".to_string(),
+            Uri::new("http://example.org/".to_string()),
+        ));
+
+        let art = graph.create_uri_node(&Uri::new("http://example.org/art".to_string()));
+        let name = graph.create_uri_node(&Uri::new("http://example.org/name".to_string()));
+        let knows = graph.create_uri_node(&Uri::new("http://other.org/knows".to_string()));
+        let dan = graph.create_blank_node_with_id("dan".to_string());
+        let literal = graph.create_literal_node("Art \"the\" Barstow".to_string());
+
+        graph.add_triple(&Triple::new(&art, &name, &literal));
+        graph.add_triple(&Triple::new(&art, &knows, &dan));
+
+        let mut options = DiagramOptions::new();
+        options.set_literal_length(Some(3));
+
+        assert_eq!(
+            to_dot(&graph, &options),
+            "digraph {\n    n0 [label=\"ex:art\"];\n    n1 [label=\"\\\"Art…\\\"\", shape=box];\n    \
+             n2 [label=\"_:dan\"];\n    n0 -> n1 [label=\"ex:name\"];\n    \
+             n0 -> n2 [label=\"<http://other.org/knows>\"];\n}\n"
+        );
+
+        options.set_predicates(Some(vec![knows]));
+
+        assert_eq!(
+            to_dot(&graph, &options),
+            "digraph {\n    n0 [label=\"ex:art\"];\n    n1 [label=\"_:dan\"];\n    \
+             n0 -> n1 [label=\"<http://other.org/knows>\"];\n}\n"
+        );
+
+        let graph =
+            TurtleParser::from_string("@prefix ex: <http://example.org/> . ex:a ex:b ex:c .")
+                .decode()
+                .unwrap();
+
+        assert!(to_dot(&graph, &DiagramOptions::new()).contains("[label=\"ex:b\"]"));
+    }
+
+    #[test]
+    fn mermaid_escapes_labels() {
+        let mut graph = Graph::new(None);
+
+        let art = graph.create_uri_node(&Uri::new("http://example.org/#art".to_string()));
+        let name = graph.create_uri_node(&Uri::new("http://example.org/name".to_string()));
+        let literal = graph.create_literal_node("Art \"the\"\nBarstow".to_string());
+
+        graph.add_triple(&Triple::new(&art, &name, &literal));
+
+        assert_eq!(
+            to_mermaid(&graph, &DiagramOptions::new()),
+            "flowchart LR\n    n0(\"#lt;http://example.org/#35;art#gt;\")\n    \
+             n1[\"#quot;Art #quot;the#quot; Barstow#quot;\"]\n    \
+             n0 -->|\"#lt;http://example.org/name#gt;\"| n1\n"
+        );
+    }
+}
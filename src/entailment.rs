@@ -0,0 +1,530 @@
+use crate::node::Node;
+use crate::specs::owl_specs::OwlVocabulary;
+use crate::specs::rdf_syntax_specs::RdfSyntaxDataTypes;
+use crate::specs::rdfs_specs::RdfsVocabulary;
+use crate::store::GraphStore;
+use crate::triple::{Triple, TripleStore};
+use crate::uri::Uri;
+use crate::Result;
+use std::cell::{Ref, RefCell};
+use std::collections::{HashMap, HashSet};
+
+/// Entailment regime that derives the triples queries see in addition to the stored triples.
+///
+/// Implementations are plugged into an `EntailmentStore`, which matches query patterns against
+/// the stored and the entailed triples.
+pub trait Entailment {
+    /// Returns the triples that are entailed by the stored triples but are not stored themselves.
+    fn infer(&self, triples: &[Triple]) -> Vec<Triple>;
+}
+
+/// Simple entailment, queries only see the stored triples.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SimpleEntailment;
+
+impl Entailment for SimpleEntailment {
+    fn infer(&self, _triples: &[Triple]) -> Vec<Triple> {
+        Vec::new()
+    }
+}
+
+/// RDFS entailment of class and property hierarchies, domains and ranges.
+///
+/// Implements the rules `rdfs2`, `rdfs3`, `rdfs5`, `rdfs7`, `rdfs9` and `rdfs11`. The axiomatic
+/// triples and the rules that only type resources as `rdfs:Resource`, `rdfs:Class` or
+/// `rdf:Property` are left out, as they add many triples that are rarely queried.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct RdfsEntailment;
+
+impl Entailment for RdfsEntailment {
+    fn infer(&self, triples: &[Triple]) -> Vec<Triple> {
+        saturate(triples, RDFS_RULES)
+    }
+}
+
+/// Entailment by the OWL 2 RL rules for property characteristics, equivalence and
+/// `owl:sameAs`, in addition to `RdfsEntailment`.
+///
+/// Implements the rules `prp-symp`, `prp-trp`, `prp-inv1`, `prp-inv2`, `scm-eqc1`, `scm-eqp1`,
+/// `eq-sym`, `eq-trans`, `eq-rep-s` and `eq-rep-o`. Class expressions like restrictions and
+/// intersections are not supported.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct OwlRlEntailment;
+
+impl Entailment for OwlRlEntailment {
+    fn infer(&self, triples: &[Triple]) -> Vec<Triple> {
+        let rules: Vec<Rule> = RDFS_RULES.iter().chain(OWL_RL_RULES).cloned().collect();
+        saturate(triples, &rules)
+    }
+}
+
+/// Store that answers pattern queries with the stored triples and the triples entailed by them.
+///
+/// The entailed triples are computed when the store is queried first and kept separately,
+/// so they are never added to the underlying store. They are recomputed after triples are
+/// added or removed through this store. Entailed triples cannot be removed, only the stored
+/// triples they are derived from.
+///
+/// # Examples
+///
+/// ```
+/// use rdf::entailment::{EntailmentStore, RdfsEntailment};
+/// use rdf::graph::Graph;
+/// use rdf::node::Node;
+/// use rdf::specs::rdf_syntax_specs::RdfSyntaxDataTypes;
+/// use rdf::specs::rdfs_specs::RdfsVocabulary;
+/// use rdf::store::GraphStore;
+/// use rdf::triple::{Triple, TripleStore};
+/// use rdf::uri::Uri;
+///
+/// let uri = |uri: &str| Node::UriNode { uri: Uri::new(uri.to_string()) };
+/// let rdf_type = Node::UriNode { uri: RdfSyntaxDataTypes::A.to_uri() };
+/// let sub_class_of = Node::UriNode { uri: RdfsVocabulary::SubClassOf.to_uri() };
+///
+/// let mut store = TripleStore::new();
+/// store.add_triple(&Triple::new(&uri("http://example.org/Cat"), &sub_class_of, &uri("http://example.org/Animal")));
+/// store.add_triple(&Triple::new(&uri("http://example.org/tom"), &rdf_type, &uri("http://example.org/Cat")));
+///
+/// let graph = Graph::with_store(None, EntailmentStore::new(store, RdfsEntailment));
+///
+/// let animals = graph.triples_matching(None, Some(&rdf_type), Some(&uri("http://example.org/Animal")));
+///
+/// assert_eq!(animals.unwrap().len(), 1);
+/// assert_eq!(graph.store().store().len().unwrap(), 2);
+/// ```
+#[derive(Debug)]
+pub struct EntailmentStore<S: GraphStore, E: Entailment> {
+    store: S,
+    entailment: E,
+    inferred: RefCell<Option<TripleStore>>,
+}
+
+impl<S: GraphStore, E: Entailment> EntailmentStore<S, E> {
+    /// Constructor of `EntailmentStore`.
+    pub fn new(store: S, entailment: E) -> EntailmentStore<S, E> {
+        EntailmentStore {
+            store,
+            entailment,
+            inferred: RefCell::new(None),
+        }
+    }
+
+    /// Returns the underlying store.
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    /// Returns the entailment regime.
+    pub fn entailment(&self) -> &E {
+        &self.entailment
+    }
+
+    /// Returns the underlying store and discards the entailed triples.
+    pub fn into_store(self) -> S {
+        self.store
+    }
+
+    /// Discards the entailed triples, e.g. after the underlying store was modified elsewhere.
+    pub fn invalidate(&self) {
+        *self.inferred.borrow_mut() = None;
+    }
+
+    /// Returns the entailed triples and computes them if necessary.
+    fn inferred(&self) -> Result<Ref<'_, TripleStore>> {
+        if self.inferred.borrow().is_none() {
+            let mut inferred = TripleStore::new();
+
+            for triple in self
+                .entailment
+                .infer(&self.store.matching(None, None, None)?)
+            {
+                inferred.add_triple(&triple);
+            }
+
+            *self.inferred.borrow_mut() = Some(inferred);
+        }
+
+        Ok(Ref::map(self.inferred.borrow(), |inferred| {
+            inferred.as_ref().expect("entailed triples are computed")
+        }))
+    }
+}
+
+impl<S: GraphStore, E: Entailment> GraphStore for EntailmentStore<S, E> {
+    fn insert(&mut self, triple: &Triple) -> Result<()> {
+        self.invalidate();
+        self.store.insert(triple)
+    }
+
+    fn remove(&mut self, triple: &Triple) -> Result<()> {
+        self.invalidate();
+        self.store.remove(triple)
+    }
+
+    fn matching(
+        &self,
+        subject: Option<&Node>,
+        predicate: Option<&Node>,
+        object: Option<&Node>,
+    ) -> Result<Vec<Triple>> {
+        let mut triples = self.store.matching(subject, predicate, object)?;
+        triples.append(&mut self.inferred()?.matching(subject, predicate, object)?);
+
+        Ok(triples)
+    }
+
+    /// Returns the number of stored and entailed triples.
+    fn len(&self) -> Result<usize> {
+        Ok(self.store.len()? + self.inferred()?.len()?)
+    }
+}
+
+/// Rule that derives triples from the indexed triples.
+type Rule = fn(&Index) -> Vec<Triple>;
+
+const RDFS_RULES: &[Rule] = &[
+    rdfs_domain,
+    rdfs_range,
+    rdfs_sub_property_transitivity,
+    rdfs_sub_property,
+    rdfs_sub_class,
+    rdfs_sub_class_transitivity,
+];
+
+const OWL_RL_RULES: &[Rule] = &[
+    owl_symmetric_property,
+    owl_transitive_property,
+    owl_inverse_of,
+    owl_equivalence,
+    owl_same_as,
+];
+
+/// Triples grouped by their predicate.
+struct Index {
+    by_predicate: HashMap<Node, Vec<(Node, Node)>>,
+}
+
+impl Index {
+    /// Returns the subjects and objects of the triples with the predicate.
+    fn pairs(&self, predicate: &Node) -> &[(Node, Node)] {
+        self.by_predicate
+            .get(predicate)
+            .map(|pairs| pairs.as_slice())
+            .unwrap_or(&[])
+    }
+
+    /// Returns the subjects and objects of the triples with the predicate grouped by subject.
+    fn objects_by_subject(&self, predicate: &Node) -> HashMap<&Node, Vec<&Node>> {
+        let mut objects: HashMap<&Node, Vec<&Node>> = HashMap::new();
+
+        for (subject, object) in self.pairs(predicate) {
+            objects.entry(subject).or_default().push(object);
+        }
+
+        objects
+    }
+
+    /// Returns the subjects that have the object as `rdf:type`.
+    fn instances_of(&self, class: &Node) -> Vec<&Node> {
+        self.pairs(&rdf_type())
+            .iter()
+            .filter(|(_, object)| object == class)
+            .map(|(subject, _)| subject)
+            .collect()
+    }
+}
+
+/// Applies the rules until no new triples are derived and returns all derived triples.
+fn saturate(triples: &[Triple], rules: &[Rule]) -> Vec<Triple> {
+    let mut known: HashSet<Triple> = triples.iter().cloned().collect();
+    let mut derived = Vec::new();
+
+    loop {
+        let mut index = Index {
+            by_predicate: HashMap::new(),
+        };
+
+        for triple in &known {
+            index
+                .by_predicate
+                .entry(triple.predicate().clone())
+                .or_default()
+                .push((triple.subject().clone(), triple.object().clone()));
+        }
+
+        let new_triples: Vec<Triple> = rules
+            .iter()
+            .flat_map(|rule| rule(&index))
+            .filter(|triple| !matches!(*triple.subject(), Node::LiteralNode { .. }))
+            .filter(|triple| known.insert(triple.clone()))
+            .collect();
+
+        if new_triples.is_empty() {
+            return derived;
+        }
+
+        derived.extend(new_triples);
+    }
+}
+
+/// `rdfs2`: the subjects of a property are instances of its domain.
+fn rdfs_domain(index: &Index) -> Vec<Triple> {
+    let mut triples = Vec::new();
+
+    for (property, class) in index.pairs(&vocabulary(RdfsVocabulary::Domain.to_uri())) {
+        for (subject, _) in index.pairs(property) {
+            triples.push(Triple::new(subject, &rdf_type(), class));
+        }
+    }
+
+    triples
+}
+
+/// `rdfs3`: the objects of a property are instances of its range, unless they are literals.
+fn rdfs_range(index: &Index) -> Vec<Triple> {
+    let mut triples = Vec::new();
+
+    for (property, class) in index.pairs(&vocabulary(RdfsVocabulary::Range.to_uri())) {
+        for (_, object) in index.pairs(property) {
+            triples.push(Triple::new(object, &rdf_type(), class));
+        }
+    }
+
+    triples
+}
+
+/// `rdfs5`: `rdfs:subPropertyOf` is transitive.
+fn rdfs_sub_property_transitivity(index: &Index) -> Vec<Triple> {
+    transitivity(index, &vocabulary(RdfsVocabulary::SubPropertyOf.to_uri()))
+}
+
+/// `rdfs7`: triples of a property also hold for its super properties.
+fn rdfs_sub_property(index: &Index) -> Vec<Triple> {
+    let mut triples = Vec::new();
+
+    for (property, super_property) in
+        index.pairs(&vocabulary(RdfsVocabulary::SubPropertyOf.to_uri()))
+    {
+        for (subject, object) in index.pairs(property) {
+            triples.push(Triple::new(subject, super_property, object));
+        }
+    }
+
+    triples
+}
+
+/// `rdfs9`: instances of a class are instances of its super classes.
+fn rdfs_sub_class(index: &Index) -> Vec<Triple> {
+    let mut triples = Vec::new();
+
+    for (class, super_class) in index.pairs(&vocabulary(RdfsVocabulary::SubClassOf.to_uri())) {
+        for instance in index.instances_of(class) {
+            triples.push(Triple::new(instance, &rdf_type(), super_class));
+        }
+    }
+
+    triples
+}
+
+/// `rdfs11`: `rdfs:subClassOf` is transitive.
+fn rdfs_sub_class_transitivity(index: &Index) -> Vec<Triple> {
+    transitivity(index, &vocabulary(RdfsVocabulary::SubClassOf.to_uri()))
+}
+
+/// `prp-symp`: symmetric properties also hold in the other direction.
+fn owl_symmetric_property(index: &Index) -> Vec<Triple> {
+    let mut triples = Vec::new();
+
+    for property in index.instances_of(&vocabulary(OwlVocabulary::SymmetricProperty.to_uri())) {
+        for (subject, object) in index.pairs(property) {
+            triples.push(Triple::new(object, property, subject));
+        }
+    }
+
+    triples
+}
+
+/// `prp-trp`: transitive properties hold along chains.
+fn owl_transitive_property(index: &Index) -> Vec<Triple> {
+    index
+        .instances_of(&vocabulary(OwlVocabulary::TransitiveProperty.to_uri()))
+        .into_iter()
+        .flat_map(|property| transitivity(index, property))
+        .collect()
+}
+
+/// `prp-inv1` and `prp-inv2`: inverse properties hold in the other direction.
+fn owl_inverse_of(index: &Index) -> Vec<Triple> {
+    let mut triples = Vec::new();
+
+    for (property, inverse) in index.pairs(&vocabulary(OwlVocabulary::InverseOf.to_uri())) {
+        for (subject, object) in index.pairs(property) {
+            triples.push(Triple::new(object, inverse, subject));
+        }
+        for (subject, object) in index.pairs(inverse) {
+            triples.push(Triple::new(object, property, subject));
+        }
+    }
+
+    triples
+}
+
+/// `scm-eqc1` and `scm-eqp1`: equivalent classes and properties are sub classes and sub
+/// properties of each other.
+fn owl_equivalence(index: &Index) -> Vec<Triple> {
+    let mut triples = Vec::new();
+
+    for (equivalence, sub) in [
+        (OwlVocabulary::EquivalentClass, RdfsVocabulary::SubClassOf),
+        (
+            OwlVocabulary::EquivalentProperty,
+            RdfsVocabulary::SubPropertyOf,
+        ),
+    ] {
+        let sub = vocabulary(sub.to_uri());
+
+        for (a, b) in index.pairs(&vocabulary(equivalence.to_uri())) {
+            triples.push(Triple::new(a, &sub, b));
+            triples.push(Triple::new(b, &sub, a));
+        }
+    }
+
+    triples
+}
+
+/// `eq-sym`, `eq-trans`, `eq-rep-s` and `eq-rep-o`: `owl:sameAs` is symmetric and transitive,
+/// and the triples of a node also hold for the nodes that are the same.
+fn owl_same_as(index: &Index) -> Vec<Triple> {
+    let same_as = vocabulary(OwlVocabulary::SameAs.to_uri());
+    let mut triples = transitivity(index, &same_as);
+    let mut same_nodes: HashMap<&Node, Vec<&Node>> = HashMap::new();
+
+    for (a, b) in index.pairs(&same_as) {
+        triples.push(Triple::new(b, &same_as, a));
+        same_nodes.entry(a).or_default().push(b);
+    }
+
+    for (predicate, pairs) in &index.by_predicate {
+        for (subject, object) in pairs {
+            for same in same_nodes.get(subject).into_iter().flatten() {
+                triples.push(Triple::new(same, predicate, object));
+            }
+            for same in same_nodes.get(object).into_iter().flatten() {
+                triples.push(Triple::new(subject, predicate, same));
+            }
+        }
+    }
+
+    triples
+}
+
+/// Derives the triples that make the property transitive, one step at a time.
+fn transitivity(index: &Index, property: &Node) -> Vec<Triple> {
+    let objects = index.objects_by_subject(property);
+    let mut triples = Vec::new();
+
+    for (subject, object) in index.pairs(property) {
+        for next in objects.get(object).into_iter().flatten() {
+            triples.push(Triple::new(subject, property, next));
+        }
+    }
+
+    triples
+}
+
+/// Returns the node of a vocabulary term.
+fn vocabulary(uri: Uri) -> Node {
+    Node::UriNode { uri }
+}
+
+/// Returns the `rdf:type` node.
+fn rdf_type() -> Node {
+    vocabulary(RdfSyntaxDataTypes::A.to_uri())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::entailment::{Entailment, OwlRlEntailment, RdfsEntailment, SimpleEntailment};
+    use crate::node::Node;
+    use crate::specs::owl_specs::OwlVocabulary;
+    use crate::specs::rdf_syntax_specs::RdfSyntaxDataTypes;
+    use crate::specs::rdfs_specs::RdfsVocabulary;
+    use crate::triple::Triple;
+    use crate::uri::Uri;
+
+    fn node(name: &str) -> Node {
+        Node::UriNode {
+            uri: Uri::new("http://example.org/".to_string() + name),
+        }
+    }
+
+    fn term(uri: Uri) -> Node {
+        Node::UriNode { uri }
+    }
+
+    #[test]
+    fn infer_rdfs_and_owl_rl_triples() {
+        let rdf_type = term(RdfSyntaxDataTypes::A.to_uri());
+        let triples = vec![
+            Triple::new(
+                &node("parentOf"),
+                &term(RdfsVocabulary::Domain.to_uri()),
+                &node("Parent"),
+            ),
+            Triple::new(
+                &node("parentOf"),
+                &term(RdfsVocabulary::SubPropertyOf.to_uri()),
+                &node("relativeOf"),
+            ),
+            Triple::new(
+                &node("relativeOf"),
+                &rdf_type,
+                &term(OwlVocabulary::SymmetricProperty.to_uri()),
+            ),
+            Triple::new(
+                &node("Parent"),
+                &term(RdfsVocabulary::SubClassOf.to_uri()),
+                &node("Person"),
+            ),
+            Triple::new(&node("ann"), &node("parentOf"), &node("bob")),
+            Triple::new(
+                &node("bob"),
+                &term(OwlVocabulary::SameAs.to_uri()),
+                &node("robert"),
+            ),
+        ];
+
+        assert!(SimpleEntailment.infer(&triples).is_empty());
+
+        let rdfs = RdfsEntailment.infer(&triples);
+        assert!(rdfs.contains(&Triple::new(&node("ann"), &rdf_type, &node("Person"))));
+        assert!(rdfs.contains(&Triple::new(
+            &node("ann"),
+            &node("relativeOf"),
+            &node("bob")
+        )));
+        assert!(!rdfs.contains(&Triple::new(
+            &node("bob"),
+            &node("relativeOf"),
+            &node("ann")
+        )));
+        assert!(!rdfs.iter().any(|triple| triples.contains(triple)));
+
+        let owl_rl = OwlRlEntailment.infer(&triples);
+        assert!(owl_rl.contains(&Triple::new(
+            &node("bob"),
+            &node("relativeOf"),
+            &node("ann")
+        )));
+        assert!(owl_rl.contains(&Triple::new(
+            &node("robert"),
+            &node("relativeOf"),
+            &node("ann")
+        )));
+        assert!(owl_rl.contains(&Triple::new(
+            &node("ann"),
+            &node("parentOf"),
+            &node("robert")
+        )));
+    }
+}
@@ -1,4 +1,5 @@
-use reader::input_reader::InputChars;
+use crate::dataset::Quota;
+use crate::reader::input_reader::InputChars;
 use std::error::Error as StdError;
 use std::fmt;
 
@@ -22,24 +23,105 @@ pub enum ErrorType {
 
     /// Incorrect namespace.
     InvalidNamespace,
+
+    /// The document identified by an IRI cannot be retrieved.
+    UnresolvableIri,
+
+    /// A literal does not conform to its data type (e.g. an invalid date and time).
+    InvalidLiteral,
+
+    /// A SHACL shape is malformed (e.g. a property shape without path).
+    InvalidShape,
+
+    /// A graph store does not support the operation (e.g. modifying a read-only store).
+    UnsupportedOperation,
+
+    /// A query cannot be evaluated (e.g. a SPARQL endpoint returns an invalid result).
+    InvalidQuery,
+
+    /// A write to a dataset exceeds one of its configured limits.
+    QuotaExceeded(Quota),
+}
+
+/// Position of a character in the input of a reader.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Position {
+    offset: usize,
+    line: usize,
+    column: usize,
+}
+
+impl Default for Position {
+    fn default() -> Position {
+        Position::new(0, 1, 1)
+    }
+}
+
+impl Position {
+    /// Constructor of `Position` from the byte offset and the line and column number.
+    ///
+    /// Lines and columns are counted from 1, the byte offset from 0.
+    pub fn new(offset: usize, line: usize, column: usize) -> Position {
+        Position {
+            offset,
+            line,
+            column,
+        }
+    }
+
+    /// Returns the number of bytes before the position.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the line number.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Returns the column number, counted in characters.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    /// Returns the position after the character `c`.
+    pub(crate) fn advance(&self, c: char) -> Position {
+        match c {
+            '\n' => Position::new(self.offset + 1, self.line + 1, 1),
+            c => Position::new(self.offset + c.len_utf8(), self.line, self.column + 1),
+        }
+    }
+}
+
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
 }
 
 /// An error related to the rdf-rs module.
+///
+/// Errors caused by another error, like an IO error of the input, return it as `source()`,
+/// so error reporters like `anyhow` show the whole chain of causes.
 #[derive(Debug)]
 pub struct Error {
     error_type: ErrorType,
-    error: Box<StdError>,
+    error: Box<dyn StdError + Send + Sync>,
+    position: Option<Position>,
+    source: Option<Box<dyn StdError + Send + Sync>>,
 }
 
 impl Error {
     /// Constructor of `Error`.
     pub fn new<E>(error_type: ErrorType, error: E) -> Error
     where
-        E: Into<Box<StdError>>,
+        E: Into<Box<dyn StdError + Send + Sync>>,
     {
         Error {
             error_type,
             error: error.into(),
+            position: None,
+            source: None,
         }
     }
 
@@ -47,16 +129,127 @@ impl Error {
     pub fn error_type(&self) -> &ErrorType {
         &self.error_type
     }
+
+    /// Returns the position in the input where the error occurred, if it is known.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "turtle")]
+    /// # {
+    /// use rdf::reader::rdf_parser::RdfParser;
+    /// use rdf::reader::turtle_parser::TurtleParser;
+    ///
+    /// let input = "@prefix ex: <http://example.org/> .
+    /// ex:a ex:b ex:c .
+    /// ex:d ex:e , .";
+    ///
+    /// let err = TurtleParser::from_string(input).decode().unwrap_err();
+    /// let position = err.position().unwrap();
+    ///
+    /// assert_eq!((position.line(), position.column()), (3, 11));
+    /// # }
+    /// ```
+    pub fn position(&self) -> Option<Position> {
+        self.position
+    }
+
+    /// Returns the error message without the position.
+    pub fn message(&self) -> String {
+        self.error.to_string()
+    }
+
+    /// Sets the position in the input where the error occurred.
+    pub fn with_position(mut self, position: Position) -> Error {
+        self.position = Some(position);
+        self
+    }
+
+    /// Sets the error that caused this error, it is returned by `source()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::error::{Error, ErrorType};
+    /// use std::error::Error as StdError;
+    /// use std::io;
+    ///
+    /// let cause = io::Error::new(io::ErrorKind::NotFound, "no such file");
+    /// let err = Error::new(ErrorType::UnresolvableIri, "Cannot open ontology.").with_source(cause);
+    ///
+    /// assert_eq!(err.to_string(), "Cannot open ontology.");
+    /// assert_eq!(err.source().unwrap().to_string(), "no such file");
+    /// ```
+    pub fn with_source<E>(mut self, source: E) -> Error
+    where
+        E: Into<Box<dyn StdError + Send + Sync>>,
+    {
+        self.source = Some(source.into());
+        self
+    }
 }
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        self.error.fmt(f)
+        match self.position {
+            Some(ref position) => write!(f, "{} (at {})", self.error, position),
+            None => self.error.fmt(f),
+        }
     }
 }
 
 impl StdError for Error {
-    fn description(&self) -> &str {
-        self.error.description()
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn StdError + 'static))
+    }
+}
+
+#[cfg(all(test, feature = "turtle"))]
+mod tests {
+    use crate::reader::rdf_parser::RdfParser;
+    use crate::reader::turtle_parser::TurtleParser;
+    use std::io::{self, Read};
+
+    fn count_triples(input: &str) -> anyhow::Result<usize> {
+        Ok(TurtleParser::from_string(input).decode()?.count())
+    }
+
+    #[test]
+    fn errors_propagate_into_anyhow() {
+        assert_eq!(
+            count_triples("_:a <http://example.org/p> _:b .").unwrap(),
+            1
+        );
+
+        let err = count_triples("_:a <http://example.org/p>").unwrap_err();
+        assert!(err.downcast_ref::<super::Error>().is_some());
+    }
+
+    #[test]
+    fn io_errors_are_the_source_of_reader_errors() {
+        struct FailingReader;
+
+        impl Read for FailingReader {
+            fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+                Err(io::Error::new(
+                    io::ErrorKind::ConnectionReset,
+                    "connection reset",
+                ))
+            }
+        }
+
+        let err: anyhow::Error = TurtleParser::from_reader(FailingReader)
+            .decode()
+            .unwrap_err()
+            .into();
+        let causes: Vec<String> = err.chain().map(|cause| cause.to_string()).collect();
+
+        assert_eq!(causes.len(), 3);
+        assert!(causes[0].starts_with("Error while parsing Turtle syntax."));
+        assert_eq!(causes[1], "Error while reading input.");
+        assert_eq!(causes[2], "connection reset");
+        assert!(err.root_cause().downcast_ref::<io::Error>().is_some());
     }
 }
@@ -0,0 +1,109 @@
+use crate::graph::Graph;
+use crate::node::Node;
+use crate::triple::Triple;
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// Returns a hash of the triples of the graph that does not depend on the order of the triples
+/// or on the labels of blank nodes.
+///
+/// The hash is computed per triple and the triple hashes are summed up. All blank nodes hash
+/// to the same value, so graphs that only differ in blank node structure can collide, use an
+/// isomorphism check if that matters. The hash is stable across platforms and releases, but it
+/// is not cryptographic.
+pub fn fingerprint(graph: &Graph) -> u64 {
+    graph
+        .triples_iter()
+        .map(|triple| mix(hash_triple(triple, FNV_OFFSET_BASIS)))
+        .fold(0, u64::wrapping_add)
+}
+
+/// Continues the FNV-1a hash with the nodes of the triple.
+fn hash_triple(triple: &Triple, hash: u64) -> u64 {
+    let hash = hash_node(triple.subject(), hash);
+    let hash = hash_node(triple.predicate(), hash);
+    hash_node(triple.object(), hash)
+}
+
+/// Continues the FNV-1a hash with a tag for the node type and the content of the node.
+fn hash_node(node: &Node, hash: u64) -> u64 {
+    match *node {
+        Node::UriNode { ref uri } => hash_str(uri.to_string(), hash_bytes(b"u", hash)),
+        Node::BlankNode { .. } => hash_bytes(b"b", hash),
+        Node::LiteralNode {
+            ref literal,
+            ref data_type,
+            ref language,
+        } => {
+            let hash = hash_str(literal, hash_bytes(b"l", hash));
+            let hash = hash_str(data_type.as_ref().map_or("", |dt| dt.to_string()), hash);
+            hash_str(language.as_deref().unwrap_or(""), hash)
+        }
+        Node::TripleNode { ref triple } => {
+            hash_bytes(b")", hash_triple(triple, hash_bytes(b"(", hash)))
+        }
+    }
+}
+
+/// Continues the FNV-1a hash with the string followed by a separator.
+fn hash_str(value: &str, hash: u64) -> u64 {
+    hash_bytes(&[0xff], hash_bytes(value.as_bytes(), hash))
+}
+
+/// Continues the FNV-1a hash with the bytes.
+fn hash_bytes(bytes: &[u8], hash: u64) -> u64 {
+    bytes.iter().fold(hash, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+    })
+}
+
+/// Spreads the bits of a triple hash, so that sums of similar hashes do not cancel out.
+fn mix(hash: u64) -> u64 {
+    // finalizer of SplitMix64
+    let hash = (hash ^ (hash >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    let hash = (hash ^ (hash >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    hash ^ (hash >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::Graph;
+    use crate::triple::Triple;
+    use crate::uri::Uri;
+
+    #[test]
+    fn fingerprint_ignores_order_and_blank_node_labels() {
+        let uri = |uri: &str| Uri::new("http://example.org/".to_string() + uri);
+
+        let mut first = Graph::new(None);
+        let a = first.create_uri_node(&uri("a"));
+        let p = first.create_uri_node(&uri("p"));
+        let b = first.create_blank_node_with_id("b1".to_string());
+        let literal = first.create_literal_node_with_language("x".to_string(), "en".to_string());
+
+        first.add_triple(&Triple::new(&a, &p, &b));
+        first.add_triple(&Triple::new(&b, &p, &literal));
+
+        let mut second = Graph::new(None);
+        let c = second.create_blank_node_with_id("other".to_string());
+
+        second.add_triple(&Triple::new(&c, &p, &literal));
+        second.add_triple(&Triple::new(&a, &p, &c));
+
+        assert_eq!(first.fingerprint(), second.fingerprint());
+        assert_ne!(first.fingerprint(), Graph::new(None).fingerprint());
+
+        let mut third = Graph::new(None);
+        let english = third.create_literal_node("x".to_string());
+
+        third.add_triple(&Triple::new(&a, &p, &c));
+        third.add_triple(&Triple::new(&c, &p, &english));
+
+        assert_ne!(first.fingerprint(), third.fingerprint());
+
+        third.add_triple(&Triple::new(&p, &a, &c));
+
+        assert_ne!(first.fingerprint(), third.fingerprint());
+    }
+}
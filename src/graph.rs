@@ -1,19 +1,60 @@
-use Result;
-use namespace::*;
-use node::*;
-use std::collections::HashMap;
+use crate::describe::{self, DescribeOptions};
+use crate::diagram::{self, DiagramOptions};
+use crate::error::{Error, ErrorType};
+use crate::fingerprint;
+use crate::literals::{self, LanguageCoverage};
+use crate::namespace::*;
+use crate::node::*;
+use crate::schema_induction::{self, InducedSchema};
+use crate::search_export::{self, BulkExportOptions};
+use crate::specs::rdf_syntax_specs::{RdfSyntaxDataTypes, RdfSyntaxSpecs};
+use crate::specs::schema_specs::SchemaVocabulary;
+use crate::specs::xml_specs::XmlDataTypes;
+use crate::store::{GraphStore, InMemoryStore};
+use crate::table::{MultiValues, Row};
+use crate::temporal::DateTime;
+use crate::triple::*;
+use crate::uri::Uri;
+use crate::Result;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::fmt;
 use std::slice::Iter;
-use triple::*;
-use uri::Uri;
 
 /// Representation of an RDF graph.
-#[derive(Debug)]
-pub struct Graph {
+///
+/// Cloning a graph is cheap: the clone shares the triples with the original graph
+/// and they are only copied once one of the graphs is modified.
+///
+/// # Examples
+///
+/// ```
+/// use rdf::graph::Graph;
+/// use rdf::uri::Uri;
+/// use rdf::triple::Triple;
+///
+/// let mut graph = Graph::new(None);
+///
+/// let subject = graph.create_blank_node();
+/// let predicate = graph.create_uri_node(&Uri::new("http://example.org/show/localName".to_string()));
+/// let object = graph.create_blank_node();
+///
+/// graph.add_triple(&Triple::new(&subject, &predicate, &object));
+///
+/// let snapshot = graph.clone();
+/// graph.remove_triple(&Triple::new(&subject, &predicate, &object));
+///
+/// assert_eq!(graph.count(), 0);
+/// assert_eq!(snapshot.count(), 1);
+/// ```
+///
+/// The triples are kept in memory unless another backend is used with `Graph::with_store`.
+#[derive(Clone)]
+pub struct Graph<S = TripleStore> {
     /// Base URI of the RDF graph.
     base_uri: Option<Uri>,
 
     /// All triples of the RDF graph.
-    triples: TripleStore,
+    triples: S,
 
     /// All namespaces associated to the RDF graph.
     namespaces: NamespaceStore,
@@ -33,18 +74,44 @@ impl Graph {
     /// let graph = Graph::new(None);
     /// ```
     pub fn new(base_uri: Option<&Uri>) -> Graph {
-        let cloned_uri = match base_uri {
-            None => None,
-            Some(u) => Some(u.clone()),
-        };
-
         Graph {
-            base_uri: cloned_uri,
+            base_uri: base_uri.cloned(),
             triples: TripleStore::new(),
             namespaces: NamespaceStore::new(),
             next_id: 0,
         }
     }
+}
+
+impl<S: GraphStore> Graph<S> {
+    /// Constructor for an RDF graph whose triples are kept in the store.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::Graph;
+    /// use rdf::triple::TripleStore;
+    ///
+    /// let graph = Graph::with_store(None, TripleStore::new());
+    /// ```
+    pub fn with_store(base_uri: Option<&Uri>, store: S) -> Graph<S> {
+        Graph {
+            base_uri: base_uri.cloned(),
+            triples: store,
+            namespaces: NamespaceStore::new(),
+            next_id: 0,
+        }
+    }
+
+    /// Returns the store of the triples.
+    pub fn store(&self) -> &S {
+        &self.triples
+    }
+
+    /// Returns the store of the triples for modifications.
+    pub fn store_mut(&mut self) -> &mut S {
+        &mut self.triples
+    }
 
     /// Returns `true` if the graph does not contain any triples.
     ///
@@ -52,28 +119,105 @@ impl Graph {
     ///
     /// ```
     /// use rdf::graph::Graph;
+    /// use rdf::triple::TripleStore;
     ///
-    /// let graph = Graph::new(None);
+    /// let graph = Graph::with_store(None, TripleStore::new());
     ///
-    /// assert_eq!(graph.is_empty(), true);
+    /// assert_eq!(graph.try_is_empty().unwrap(), true);
     /// ```
-    pub fn is_empty(&self) -> bool {
+    ///
+    /// # Failures
+    ///
+    /// - The store cannot be queried.
+    ///
+    pub fn try_is_empty(&self) -> Result<bool> {
         self.triples.is_empty()
     }
 
-    /// Returns the number of triples that are stored in the graph.
+    /// Returns the number of distinct triples that are stored in the graph.
     ///
     /// # Examples
     ///
     /// ```
     /// use rdf::graph::Graph;
+    /// use rdf::triple::TripleStore;
     ///
-    /// let graph = Graph::new(None);
+    /// let graph = Graph::with_store(None, TripleStore::new());
     ///
-    /// assert_eq!(graph.count(), 0);
+    /// assert_eq!(graph.try_count().unwrap(), 0);
     /// ```
-    pub fn count(&self) -> usize {
-        self.triples.count()
+    ///
+    /// # Failures
+    ///
+    /// - The store cannot be queried.
+    ///
+    pub fn try_count(&self) -> Result<usize> {
+        self.triples.len()
+    }
+
+    /// Adds a triple to the graph, adding a triple that is already stored has no effect.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::Graph;
+    /// use rdf::triple::{Triple, TripleStore};
+    /// use rdf::uri::Uri;
+    ///
+    /// let mut graph = Graph::with_store(None, TripleStore::new());
+    ///
+    /// let subject = graph.create_blank_node();
+    /// let predicate = graph.create_uri_node(&Uri::new("http://example.org/show/localName".to_string()));
+    /// let object = graph.create_blank_node();
+    /// let triple = Triple::new(&subject, &predicate, &object);
+    ///
+    /// graph.try_add_triple(&triple).unwrap();
+    /// graph.try_add_triple(&triple).unwrap();
+    ///
+    /// assert_eq!(graph.try_count().unwrap(), 1);
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - The store is read-only or cannot be modified.
+    ///
+    pub fn try_add_triple(&mut self, triple: &Triple) -> Result<()> {
+        self.triples.insert(triple)
+    }
+
+    /// Returns the distinct triples of the store that match the pattern, `None` matches any node.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::Graph;
+    /// use rdf::uri::Uri;
+    /// use rdf::triple::Triple;
+    ///
+    /// let mut graph = Graph::new(None);
+    ///
+    /// let subject = graph.create_blank_node();
+    /// let predicate = graph.create_uri_node(&Uri::new("http://example.org/show/localName".to_string()));
+    /// let object = graph.create_blank_node();
+    ///
+    /// graph.add_triple(&Triple::new(&subject, &predicate, &object));
+    /// graph.add_triple(&Triple::new(&object, &predicate, &subject));
+    ///
+    /// assert_eq!(graph.triples_matching(Some(&subject), None, None).unwrap(),
+    ///            vec![Triple::new(&subject, &predicate, &object)]);
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - The store cannot be queried.
+    ///
+    pub fn triples_matching(
+        &self,
+        subject: Option<&Node>,
+        predicate: Option<&Node>,
+        object: Option<&Node>,
+    ) -> Result<Vec<Triple>> {
+        self.triples.matching(subject, predicate, object)
     }
 
     /// Returns the base URI of the graph.
@@ -305,80 +449,40 @@ impl Graph {
         Node::UriNode { uri: uri.clone() }
     }
 
-    /// Adds a triple to the graph.
+    /// Creates a new node for quoted triples of RDF-star.
     ///
     /// # Examples
     ///
     /// ```
     /// use rdf::graph::Graph;
-    /// use rdf::uri::Uri;
+    /// use rdf::node::Node;
     /// use rdf::triple::Triple;
-    ///
-    /// let mut graph = Graph::new(None);
-    ///
-    /// let subject = graph.create_blank_node();
-    /// let predicate = graph.create_uri_node(&Uri::new("http://example.org/show/localName".to_string()));
-    /// let object = graph.create_blank_node();
-    /// let triple = Triple::new(&subject, &predicate, &object);
-    ///
-    /// graph.add_triple(&triple);
-    ///
-    /// assert_eq!(graph.count(), 1);
-    /// ```
-    pub fn add_triple(&mut self, triple: &Triple) {
-        self.triples.add_triple(triple);
-    }
-
-    /// Adds a vector of triples.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use rdf::graph::Graph;
     /// use rdf::uri::Uri;
-    /// use rdf::triple::Triple;
     ///
     /// let mut graph = Graph::new(None);
     ///
     /// let subject = graph.create_blank_node();
     /// let predicate = graph.create_uri_node(&Uri::new("http://example.org/show/localName".to_string()));
-    /// let object = graph.create_blank_node();
-    ///
-    /// let triple1 = Triple::new(&subject, &predicate, &object);
-    /// let triple2 = Triple::new(&subject, &predicate, &object);
+    /// let object = graph.create_literal_node("literal".to_string());
     ///
-    /// graph.add_triples(&vec![triple1, triple2]);
+    /// let triple = Triple::new(&subject, &predicate, &object);
+    /// let triple_node = graph.create_triple_node(&triple);
     ///
-    /// assert_eq!(graph.count(), 2);
+    /// assert_eq!(triple_node, Node::TripleNode {
+    ///   triple: Box::new(triple)
+    /// });
     /// ```
-    pub fn add_triples(&mut self, triples: &[Triple]) {
-        for triple in triples {
-            self.add_triple(triple);
+    pub fn create_triple_node(&self, triple: &Triple) -> Node {
+        Node::TripleNode {
+            triple: Box::new(triple.clone()),
         }
     }
+}
 
-    /// Deletes the triple from the graph.
-    ///
-    /// # Examples
-    /// ```
-    /// use rdf::graph::Graph;
-    /// use rdf::uri::Uri;
-    /// use rdf::triple::Triple;
-    ///
-    /// let mut graph = Graph::new(None);
-    ///
-    /// let subject = graph.create_blank_node();
-    /// let predicate = graph.create_uri_node(&Uri::new("http://example.org/show/localName".to_string()));
-    /// let object = graph.create_blank_node();
-    /// let triple = Triple::new(&subject, &predicate, &object);
-    ///
-    /// graph.add_triple(&triple);
-    /// graph.remove_triple(&triple);
-    ///
-    /// assert_eq!(graph.count(), 0);
-    /// ```
-    pub fn remove_triple(&mut self, triple: &Triple) {
-        self.triples.remove_triple(triple);
+impl<S: InMemoryStore> Graph<S> {
+    /// Returns an iterator over the triples of the graph.
+    pub fn triples_iter(&self) -> Iter<'_, Triple> {
+        self.triples.iter()
     }
 
     /// Returns all triples from the store that have the specified subject node.
@@ -405,7 +509,7 @@ impl Graph {
     /// assert_eq!(graph.get_triples_with_subject(&subject1), vec![&triple1]);
     /// ```
     pub fn get_triples_with_subject(&self, node: &Node) -> Vec<&Triple> {
-        self.triples.get_triples_with_subject(node)
+        self.triples.lookup(Some(node), None, None)
     }
 
     /// Returns all triples from the store that have the specified predicate node.
@@ -432,7 +536,7 @@ impl Graph {
     /// assert_eq!(graph.get_triples_with_predicate(&predicate), vec![&triple1, &triple2]);
     /// ```
     pub fn get_triples_with_predicate(&self, node: &Node) -> Vec<&Triple> {
-        self.triples.get_triples_with_predicate(node)
+        self.triples.lookup(None, Some(node), None)
     }
 
     /// Returns all triples from the store that have the specified object node.
@@ -459,7 +563,7 @@ impl Graph {
     /// assert_eq!(graph.get_triples_with_object(&object), vec![&triple1, &triple2]);
     /// ```
     pub fn get_triples_with_object(&self, node: &Node) -> Vec<&Triple> {
-        self.triples.get_triples_with_object(node)
+        self.triples.lookup(None, None, Some(node))
     }
 
     /// Returns all triples from the triple store where the subject and object nodes match the provided nodes.
@@ -491,7 +595,7 @@ impl Graph {
         object_node: &Node,
     ) -> Vec<&Triple> {
         self.triples
-            .get_triples_with_subject_and_object(subject_node, object_node)
+            .lookup(Some(subject_node), None, Some(object_node))
     }
 
     /// Returns all triples from the triple store where the subject and predicate nodes match the provided nodes.
@@ -523,7 +627,7 @@ impl Graph {
         predicate_node: &Node,
     ) -> Vec<&Triple> {
         self.triples
-            .get_triples_with_subject_and_predicate(subject_node, predicate_node)
+            .lookup(Some(subject_node), Some(predicate_node), None)
     }
 
     /// Returns all triples from the triple store where the predicate and object nodes match the provided nodes.
@@ -555,51 +659,1290 @@ impl Graph {
         object_node: &Node,
     ) -> Vec<&Triple> {
         self.triples
-            .get_triples_with_predicate_and_object(predicate_node, object_node)
-    }
-
-    /// Returns an iterator over the triples of the graph.
-    pub fn triples_iter(&self) -> Iter<Triple> {
-        self.triples.iter()
+            .lookup(None, Some(predicate_node), Some(object_node))
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use graph::Graph;
-    use node::*;
-
-    #[test]
-    fn empty_graph() {
-        let graph = Graph::new(None);
-        assert_eq!(graph.is_empty(), true);
+impl Graph {
+    /// Returns `true` if the graph does not contain any triples.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::Graph;
+    ///
+    /// let graph = Graph::new(None);
+    ///
+    /// assert_eq!(graph.is_empty(), true);
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.triples.is_empty()
     }
 
-    #[test]
-    fn create_literal_node() {
-        let graph = Graph::new(None);
-        let literal_node = graph.create_literal_node("literal".to_string());
+    /// Returns the number of distinct triples that are stored in the graph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::Graph;
+    ///
+    /// let graph = Graph::new(None);
+    ///
+    /// assert_eq!(graph.count(), 0);
+    /// ```
+    pub fn count(&self) -> usize {
+        self.triples.count()
+    }
 
-        assert_eq!(
-            literal_node,
-            Node::LiteralNode {
-                literal: "literal".to_string(),
-                data_type: None,
-                language: None,
-            }
-        );
+    /// Adds a triple to the graph, adding a triple that is already stored has no effect.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::Graph;
+    /// use rdf::uri::Uri;
+    /// use rdf::triple::Triple;
+    ///
+    /// let mut graph = Graph::new(None);
+    ///
+    /// let subject = graph.create_blank_node();
+    /// let predicate = graph.create_uri_node(&Uri::new("http://example.org/show/localName".to_string()));
+    /// let object = graph.create_blank_node();
+    /// let triple = Triple::new(&subject, &predicate, &object);
+    ///
+    /// graph.add_triple(&triple);
+    ///
+    /// assert_eq!(graph.count(), 1);
+    /// ```
+    pub fn add_triple(&mut self, triple: &Triple) {
+        self.triples.add_triple(triple);
     }
 
-    #[test]
-    fn create_multiple_blank_nodes() {
-        let mut graph = Graph::new(None);
+    /// Adds a vector of triples.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::Graph;
+    /// use rdf::uri::Uri;
+    /// use rdf::triple::Triple;
+    ///
+    /// let mut graph = Graph::new(None);
+    ///
+    /// let subject = graph.create_blank_node();
+    /// let predicate = graph.create_uri_node(&Uri::new("http://example.org/show/localName".to_string()));
+    /// let object = graph.create_blank_node();
+    ///
+    /// let triple1 = Triple::new(&subject, &predicate, &object);
+    /// let triple2 = Triple::new(&object, &predicate, &subject);
+    ///
+    /// graph.add_triples(&vec![triple1, triple2]);
+    ///
+    /// assert_eq!(graph.count(), 2);
+    /// ```
+    pub fn add_triples(&mut self, triples: &[Triple]) {
+        self.insert_batch(triples.iter().cloned());
+    }
 
-        let blank_node_0 = graph.create_blank_node();
-        let blank_node_1 = graph.create_blank_node();
+    /// Adds all triples of the iterator at once.
+    ///
+    /// The storage is prepared once for the whole batch instead of for every single triple,
+    /// which speeds up loading large amounts of triples.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::Graph;
+    /// use rdf::uri::Uri;
+    /// use rdf::triple::Triple;
+    ///
+    /// let mut graph = Graph::new(None);
+    ///
+    /// let predicate = graph.create_uri_node(&Uri::new("http://example.org/show/localName".to_string()));
+    /// let object = graph.create_literal_node("literal".to_string());
+    /// let subjects: Vec<_> = (0..100).map(|_| graph.create_blank_node()).collect();
+    ///
+    /// graph.insert_batch(subjects.iter().map(|subject| Triple::new(subject, &predicate, &object)));
+    ///
+    /// assert_eq!(graph.count(), 100);
+    /// ```
+    pub fn insert_batch<I: IntoIterator<Item = Triple>>(&mut self, triples: I) {
+        self.triples.insert_batch(triples);
+    }
 
-        assert_eq!(
-            blank_node_0,
-            Node::BlankNode {
+    /// Adds all triples and namespaces of another graph.
+    ///
+    /// Blank nodes of the other graph are replaced by new blank nodes so that they do not
+    /// clash with the blank nodes of this graph. Prefixes that are already defined are kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::Graph;
+    /// use rdf::uri::Uri;
+    /// use rdf::triple::Triple;
+    ///
+    /// let mut graph = Graph::new(None);
+    /// let mut other = Graph::new(None);
+    ///
+    /// let subject = other.create_blank_node();
+    /// let predicate = other.create_uri_node(&Uri::new("http://example.org/show/localName".to_string()));
+    /// let object = other.create_blank_node();
+    ///
+    /// other.add_triple(&Triple::new(&subject, &predicate, &object));
+    ///
+    /// graph.merge(&other);
+    /// graph.merge(&other);
+    ///
+    /// assert_eq!(graph.count(), 2);
+    /// ```
+    pub fn merge(&mut self, other: &Graph) {
+        for (prefix, uri) in other.namespaces() {
+            if !self.namespaces().contains_key(prefix) {
+                self.add_namespace(&Namespace::new(prefix.to_owned(), uri.to_owned()));
+            }
+        }
+
+        let used_ids: HashSet<String> = self
+            .triples
+            .get_blank_nodes()
+            .into_iter()
+            .filter_map(|node| match *node {
+                Node::BlankNode { ref id } => Some(id.to_owned()),
+                _ => None,
+            })
+            .collect();
+
+        let mut renamed: HashMap<Node, Node> = HashMap::new();
+
+        for triple in other.triples_iter() {
+            let subject = self.rename_blank_node(triple.subject(), &mut renamed, &used_ids);
+            let predicate = self.rename_blank_node(triple.predicate(), &mut renamed, &used_ids);
+            let object = self.rename_blank_node(triple.object(), &mut renamed, &used_ids);
+
+            self.add_triple(&Triple::new(&subject, &predicate, &object));
+        }
+    }
+
+    /// Removes all triples from the graph and returns them, namespaces and base URI are kept.
+    #[cfg(feature = "turtle")]
+    pub(crate) fn take_triples(&mut self) -> Vec<Triple> {
+        std::mem::replace(&mut self.triples, TripleStore::new()).into_vec()
+    }
+
+    /// Returns a graph with the triples returned by the closure for each triple of this graph.
+    ///
+    /// Triples for which the closure returns `None` are left out, so rewriting and filtering
+    /// happen in one pass. The base URI, namespaces and blank node IDs are kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::Graph;
+    /// use rdf::node::Node;
+    /// use rdf::triple::Triple;
+    /// use rdf::uri::Uri;
+    ///
+    /// let mut graph = Graph::new(None);
+    ///
+    /// let subject = graph.create_blank_node();
+    /// let name = graph.create_uri_node(&Uri::new("http://example.org/name".to_string()));
+    ///
+    /// graph.add_triple(&Triple::new(&subject, &name, &graph.create_literal_node("  Alice ".to_string())));
+    /// graph.add_triple(&Triple::new(&subject, &name, &graph.create_literal_node(" ".to_string())));
+    ///
+    /// let cleaned = graph.map_triples(|triple| match *triple.object() {
+    ///     Node::LiteralNode { ref literal, .. } if literal.trim().is_empty() => None,
+    ///     Node::LiteralNode { ref literal, .. } => {
+    ///         let trimmed = Node::LiteralNode { literal: literal.trim().to_string(), data_type: None, language: None };
+    ///         Some(Triple::new(triple.subject(), triple.predicate(), &trimmed))
+    ///     }
+    ///     _ => Some(triple.clone()),
+    /// });
+    ///
+    /// assert_eq!(cleaned.count(), 1);
+    /// assert!(cleaned.contains(&Triple::new(&subject, &name, &graph.create_literal_node("Alice".to_string()))));
+    /// ```
+    pub fn map_triples<F>(&self, f: F) -> Graph
+    where
+        F: FnMut(&Triple) -> Option<Triple>,
+    {
+        let mut graph = Graph {
+            base_uri: self.base_uri.clone(),
+            triples: TripleStore::new(),
+            namespaces: self.namespaces.clone(),
+            next_id: self.next_id,
+        };
+
+        graph.insert_batch(self.triples_iter().filter_map(f));
+        graph
+    }
+
+    /// Returns a graph in which each node is replaced by the node returned by the closure,
+    /// e.g. to translate IRIs or to fix data types.
+    ///
+    /// The closure is called for each subject, predicate and object. The nodes of quoted
+    /// triples are mapped first, then the closure is called for the quoted triple with the
+    /// mapped nodes. The base URI, namespaces and blank node IDs are kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::Graph;
+    /// use rdf::node::Node;
+    /// use rdf::triple::Triple;
+    /// use rdf::uri::Uri;
+    ///
+    /// let mut graph = Graph::new(None);
+    ///
+    /// let subject = graph.create_uri_node(&Uri::new("http://old.example.org/alice".to_string()));
+    /// let predicate = graph.create_uri_node(&Uri::new("http://xmlns.com/foaf/0.1/knows".to_string()));
+    /// let object = graph.create_uri_node(&Uri::new("http://old.example.org/bob".to_string()));
+    ///
+    /// graph.add_triple(&Triple::new(&subject, &predicate, &object));
+    ///
+    /// let translated = graph.map_terms(|node| match *node {
+    ///     Node::UriNode { ref uri } => Node::UriNode {
+    ///         uri: Uri::new(uri.to_string().replace("http://old.example.org/", "http://example.org/")),
+    ///     },
+    ///     _ => node.clone(),
+    /// });
+    ///
+    /// let alice = graph.create_uri_node(&Uri::new("http://example.org/alice".to_string()));
+    /// let bob = graph.create_uri_node(&Uri::new("http://example.org/bob".to_string()));
+    ///
+    /// assert!(translated.contains(&Triple::new(&alice, &predicate, &bob)));
+    /// ```
+    pub fn map_terms<F>(&self, mut f: F) -> Graph
+    where
+        F: FnMut(&Node) -> Node,
+    {
+        self.map_triples(|triple| Some(map_triple_terms(triple, &mut f)))
+    }
+
+    /// Returns the replacement of a blank node that is merged from another graph.
+    ///
+    /// Blank nodes within quoted triples are replaced as well, other nodes are returned unchanged.
+    fn rename_blank_node(
+        &mut self,
+        node: &Node,
+        renamed: &mut HashMap<Node, Node>,
+        used_ids: &HashSet<String>,
+    ) -> Node {
+        if let Node::BlankNode { .. } = *node {
+            if let Some(replacement) = renamed.get(node) {
+                return replacement.clone();
+            }
+
+            let replacement = loop {
+                let candidate = self.create_blank_node();
+
+                let clashes = match candidate {
+                    Node::BlankNode { ref id } => used_ids.contains(id),
+                    _ => false,
+                };
+
+                if !clashes {
+                    break candidate;
+                }
+            };
+
+            renamed.insert(node.clone(), replacement.clone());
+            replacement
+        } else if let Node::TripleNode { ref triple } = *node {
+            let subject = self.rename_blank_node(triple.subject(), renamed, used_ids);
+            let predicate = self.rename_blank_node(triple.predicate(), renamed, used_ids);
+            let object = self.rename_blank_node(triple.object(), renamed, used_ids);
+
+            self.create_triple_node(&Triple::new(&subject, &predicate, &object))
+        } else {
+            node.clone()
+        }
+    }
+
+    /// Deletes the triple from the graph.
+    ///
+    /// # Examples
+    /// ```
+    /// use rdf::graph::Graph;
+    /// use rdf::uri::Uri;
+    /// use rdf::triple::Triple;
+    ///
+    /// let mut graph = Graph::new(None);
+    ///
+    /// let subject = graph.create_blank_node();
+    /// let predicate = graph.create_uri_node(&Uri::new("http://example.org/show/localName".to_string()));
+    /// let object = graph.create_blank_node();
+    /// let triple = Triple::new(&subject, &predicate, &object);
+    ///
+    /// graph.add_triple(&triple);
+    /// graph.remove_triple(&triple);
+    ///
+    /// assert_eq!(graph.count(), 0);
+    /// ```
+    pub fn remove_triple(&mut self, triple: &Triple) {
+        self.triples.remove_triple(triple);
+    }
+
+    /// Checks if the graph contains the triple.
+    ///
+    /// The check uses the indexes of the graph and takes constant time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::Graph;
+    /// use rdf::uri::Uri;
+    /// use rdf::triple::Triple;
+    ///
+    /// let mut graph = Graph::new(None);
+    ///
+    /// let subject = graph.create_blank_node();
+    /// let predicate = graph.create_uri_node(&Uri::new("http://example.org/show/localName".to_string()));
+    /// let object = graph.create_blank_node();
+    /// let triple = Triple::new(&subject, &predicate, &object);
+    ///
+    /// graph.add_triple(&triple);
+    ///
+    /// assert!(graph.contains(&triple));
+    /// assert!(!graph.contains(&Triple::new(&object, &predicate, &subject)));
+    /// ```
+    pub fn contains(&self, triple: &Triple) -> bool {
+        self.triples.contains(triple)
+    }
+
+    /// Checks if the graph contains a triple that matches the pattern, `None` matches any node.
+    ///
+    /// The check uses the indexes of the graph and takes constant time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::Graph;
+    /// use rdf::uri::Uri;
+    /// use rdf::triple::Triple;
+    ///
+    /// let mut graph = Graph::new(None);
+    ///
+    /// let subject = graph.create_blank_node();
+    /// let predicate = graph.create_uri_node(&Uri::new("http://example.org/show/localName".to_string()));
+    /// let object = graph.create_literal_node("literal".to_string());
+    ///
+    /// graph.add_triple(&Triple::new(&subject, &predicate, &object));
+    ///
+    /// assert!(graph.contains_matching(Some(&subject), None, Some(&object)));
+    /// assert!(graph.contains_matching(None, Some(&predicate), None));
+    /// assert!(!graph.contains_matching(Some(&object), None, None));
+    /// ```
+    pub fn contains_matching(
+        &self,
+        subject: Option<&Node>,
+        predicate: Option<&Node>,
+        object: Option<&Node>,
+    ) -> bool {
+        self.triples.contains_matching(subject, predicate, object)
+    }
+
+    /// Returns an iterator over the distinct subjects of the graph in arbitrary order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::Graph;
+    /// use rdf::uri::Uri;
+    /// use rdf::triple::Triple;
+    ///
+    /// let mut graph = Graph::new(None);
+    ///
+    /// let subject = graph.create_blank_node();
+    /// let name = graph.create_uri_node(&Uri::new("http://example.org/name".to_string()));
+    /// let nick = graph.create_uri_node(&Uri::new("http://example.org/nick".to_string()));
+    /// let object = graph.create_literal_node("literal".to_string());
+    ///
+    /// graph.add_triple(&Triple::new(&subject, &name, &object));
+    /// graph.add_triple(&Triple::new(&subject, &nick, &object));
+    ///
+    /// assert_eq!(graph.subjects().collect::<Vec<_>>(), vec![&subject]);
+    /// assert_eq!(graph.predicates().count(), 2);
+    /// assert_eq!(graph.objects().count(), 1);
+    /// ```
+    pub fn subjects(&self) -> impl Iterator<Item = &Node> {
+        self.triples.subjects()
+    }
+
+    /// Returns an iterator over the distinct predicates of the graph in arbitrary order.
+    pub fn predicates(&self) -> impl Iterator<Item = &Node> {
+        self.triples.predicates()
+    }
+
+    /// Returns an iterator over the distinct objects of the graph in arbitrary order.
+    pub fn objects(&self) -> impl Iterator<Item = &Node> {
+        self.triples.objects()
+    }
+
+    /// Returns an iterator over the distinct predicate and object pairs of the subject in arbitrary order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::Graph;
+    /// use rdf::uri::Uri;
+    /// use rdf::triple::Triple;
+    ///
+    /// let mut graph = Graph::new(None);
+    ///
+    /// let subject = graph.create_blank_node();
+    /// let predicate = graph.create_uri_node(&Uri::new("http://example.org/show/localName".to_string()));
+    /// let object = graph.create_literal_node("literal".to_string());
+    ///
+    /// graph.add_triples(&vec![Triple::new(&subject, &predicate, &object); 2]);
+    ///
+    /// assert_eq!(graph.predicate_objects(&subject).collect::<Vec<_>>(), vec![(&predicate, &object)]);
+    /// assert_eq!(graph.predicate_objects(&object).count(), 0);
+    /// ```
+    pub fn predicate_objects<'a>(
+        &'a self,
+        subject: &Node,
+    ) -> impl Iterator<Item = (&'a Node, &'a Node)> {
+        self.triples.predicate_objects(subject)
+    }
+
+    /// Returns a table with a row for each subject of the class and a column for each predicate.
+    ///
+    /// Rows are ordered by subject, several values of a predicate are handled according to `multi_values`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::Graph;
+    /// use rdf::table::MultiValues;
+    /// use rdf::triple::Triple;
+    /// use rdf::uri::Uri;
+    ///
+    /// let mut graph = Graph::new(None);
+    ///
+    /// let rdf_type = graph.create_uri_node(&Uri::new("http://www.w3.org/1999/02/22-rdf-syntax-ns#type".to_string()));
+    /// let person = graph.create_uri_node(&Uri::new("http://xmlns.com/foaf/0.1/Person".to_string()));
+    /// let name = graph.create_uri_node(&Uri::new("http://xmlns.com/foaf/0.1/name".to_string()));
+    ///
+    /// let art = graph.create_blank_node();
+    /// let art_name = graph.create_literal_node("Art".to_string());
+    /// let art_nick = graph.create_literal_node("Arty".to_string());
+    ///
+    /// graph.add_triple(&Triple::new(&art, &rdf_type, &person));
+    /// graph.add_triple(&Triple::new(&art, &name, &art_name));
+    /// graph.add_triple(&Triple::new(&art, &name, &art_nick));
+    ///
+    /// assert_eq!(graph.to_table(&person, &[name.clone()], &MultiValues::First)[0].cell(0), &[art_name]);
+    /// assert_eq!(graph.to_table(&person, &[name.clone()], &MultiValues::All)[0].cell(0).len(), 2);
+    /// assert_eq!(graph.to_table(&person, &[name], &MultiValues::Expand).len(), 2);
+    /// ```
+    pub fn to_table(
+        &self,
+        subject_class: &Node,
+        predicates: &[Node],
+        multi_values: &MultiValues,
+    ) -> Vec<Row> {
+        let rdf_type = Node::UriNode {
+            uri: RdfSyntaxDataTypes::A.to_uri(),
+        };
+
+        let mut subjects: Vec<&Node> = self
+            .get_triples_with_predicate_and_object(&rdf_type, subject_class)
+            .into_iter()
+            .map(|t| t.subject())
+            .collect();
+        subjects.sort();
+        subjects.dedup();
+
+        let mut rows = Vec::new();
+
+        for subject in subjects {
+            let mut cells: Vec<Vec<Node>> = predicates
+                .iter()
+                .map(|predicate| {
+                    let mut values: Vec<Node> = self
+                        .get_triples_with_subject_and_predicate(subject, predicate)
+                        .into_iter()
+                        .map(|t| t.object().clone())
+                        .collect();
+                    values.sort();
+                    values.dedup();
+                    values
+                })
+                .collect();
+
+            match *multi_values {
+                MultiValues::First => {
+                    for cell in &mut cells {
+                        cell.truncate(1);
+                    }
+
+                    rows.push(Row::new(subject, cells));
+                }
+                MultiValues::All => rows.push(Row::new(subject, cells)),
+                MultiValues::Expand => {
+                    // build the cartesian product of the values of all cells
+                    let mut expanded: Vec<Vec<Vec<Node>>> = vec![Vec::new()];
+
+                    for cell in cells {
+                        if cell.is_empty() {
+                            for partial_row in &mut expanded {
+                                partial_row.push(Vec::new());
+                            }
+                        } else {
+                            expanded = expanded
+                                .into_iter()
+                                .flat_map(|partial_row| {
+                                    cell.iter().map(move |value| {
+                                        let mut partial_row = partial_row.clone();
+                                        partial_row.push(vec![value.clone()]);
+                                        partial_row
+                                    })
+                                })
+                                .collect();
+                        }
+                    }
+
+                    for cells in expanded {
+                        rows.push(Row::new(subject, cells));
+                    }
+                }
+            }
+        }
+
+        rows
+    }
+
+    /// Renders the graph in the DOT language of GraphViz for visualization.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::diagram::DiagramOptions;
+    /// use rdf::graph::Graph;
+    /// use rdf::namespace::Namespace;
+    /// use rdf::triple::Triple;
+    /// use rdf::uri::Uri;
+    ///
+    /// let mut graph = Graph::new(None);
+    /// graph.add_namespace(&Namespace::new("foaf".to_string(), Uri::new("http://xmlns.com/foaf/0.1/".to_string())));
+    ///
+    /// let art = graph.create_blank_node_with_id("art".to_string());
+    /// let name = graph.create_uri_node(&Uri::new("http://xmlns.com/foaf/0.1/name".to_string()));
+    /// let literal = graph.create_literal_node("Art Barstow".to_string());
+    ///
+    /// graph.add_triple(&Triple::new(&art, &name, &literal));
+    ///
+    /// let mut options = DiagramOptions::new();
+    /// options.set_literal_length(Some(3));
+    ///
+    /// assert_eq!(graph.to_dot(&options),
+    ///            "digraph {\n    n0 [label=\"_:art\"];\n    n1 [label=\"\\\"Art…\\\"\", shape=box];\n    \
+    ///             n0 -> n1 [label=\"foaf:name\"];\n}\n");
+    /// ```
+    pub fn to_dot(&self, options: &DiagramOptions) -> String {
+        diagram::to_dot(self, options)
+    }
+
+    /// Renders the graph as Mermaid flowchart for embedding in Markdown documentation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::diagram::DiagramOptions;
+    /// use rdf::graph::Graph;
+    /// use rdf::namespace::Namespace;
+    /// use rdf::triple::Triple;
+    /// use rdf::uri::Uri;
+    ///
+    /// let mut graph = Graph::new(None);
+    /// graph.add_namespace(&Namespace::new("foaf".to_string(), Uri::new("http://xmlns.com/foaf/0.1/".to_string())));
+    ///
+    /// let art = graph.create_blank_node_with_id("art".to_string());
+    /// let knows = graph.create_uri_node(&Uri::new("http://xmlns.com/foaf/0.1/knows".to_string()));
+    /// let dan = graph.create_blank_node_with_id("dan".to_string());
+    ///
+    /// graph.add_triple(&Triple::new(&art, &knows, &dan));
+    ///
+    /// assert_eq!(graph.to_mermaid(&DiagramOptions::new()),
+    ///            "flowchart LR\n    n0(\"_:art\")\n    n1(\"_:dan\")\n    n0 -->|\"foaf:knows\"| n1\n");
+    /// ```
+    pub fn to_mermaid(&self, options: &DiagramOptions) -> String {
+        diagram::to_mermaid(self, options)
+    }
+
+    /// Describes the subject as nested JSON object for REST APIs and search indexes.
+    ///
+    /// Predicates are keys, blank nodes are inlined as nested objects and values with language
+    /// tags are grouped by language, see `DescribeOptions`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::describe::DescribeOptions;
+    /// use rdf::graph::Graph;
+    /// use rdf::namespace::Namespace;
+    /// use rdf::triple::Triple;
+    /// use rdf::uri::Uri;
+    ///
+    /// let mut graph = Graph::new(None);
+    /// graph.add_namespace(&Namespace::new("foaf".to_string(), Uri::new("http://xmlns.com/foaf/0.1/".to_string())));
+    ///
+    /// let art = graph.create_uri_node(&Uri::new("http://example.org/art".to_string()));
+    /// let name = graph.create_uri_node(&Uri::new("http://xmlns.com/foaf/0.1/name".to_string()));
+    /// let knows = graph.create_uri_node(&Uri::new("http://xmlns.com/foaf/0.1/knows".to_string()));
+    /// let dan = graph.create_blank_node();
+    ///
+    /// graph.add_triple(&Triple::new(&art, &name, &graph.create_literal_node_with_language("Art".to_string(), "en".to_string())));
+    /// graph.add_triple(&Triple::new(&art, &knows, &dan));
+    /// graph.add_triple(&Triple::new(&dan, &name, &graph.create_literal_node("Dan".to_string())));
+    ///
+    /// assert_eq!(graph.describe_as_json(&art, &DescribeOptions::new()),
+    ///            "{\"@id\": \"http://example.org/art\", \"foaf:knows\": {\"foaf:name\": \"Dan\"}, \
+    ///              \"foaf:name\": {\"en\": \"Art\"}}");
+    /// ```
+    pub fn describe_as_json(&self, subject: &Node, options: &DescribeOptions) -> String {
+        describe::describe_as_json(self, subject, options)
+    }
+
+    /// Writes the instances of the classes selected in the options as NDJSON for the bulk API
+    /// of Elasticsearch and OpenSearch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::Graph;
+    /// use rdf::search_export::BulkExportOptions;
+    /// use rdf::specs::rdf_syntax_specs::RdfSyntaxDataTypes;
+    /// use rdf::triple::Triple;
+    /// use rdf::uri::Uri;
+    ///
+    /// let mut graph = Graph::new(None);
+    ///
+    /// let art = graph.create_uri_node(&Uri::new("http://example.org/art".to_string()));
+    /// let rdf_type = graph.create_uri_node(&RdfSyntaxDataTypes::A.to_uri());
+    /// let person = graph.create_uri_node(&Uri::new("http://example.org/Person".to_string()));
+    ///
+    /// graph.add_triple(&Triple::new(&art, &rdf_type, &person));
+    ///
+    /// let mut options = BulkExportOptions::new("people");
+    /// options.add_class(&Uri::new("http://example.org/Person".to_string()));
+    ///
+    /// assert_eq!(graph.export_bulk(&options),
+    ///            "{\"index\": {\"_index\": \"people\", \"_id\": \"http://example.org/art\"}}\n\
+    ///             {\"@id\": \"http://example.org/art\", \
+    ///              \"http://www.w3.org/1999/02/22-rdf-syntax-ns#type\": \"http://example.org/Person\"}\n");
+    /// ```
+    pub fn export_bulk(&self, options: &BulkExportOptions) -> String {
+        search_export::export_bulk(self, options)
+    }
+
+    /// Returns an order-independent hash of the triples, where blank node labels are ignored.
+    ///
+    /// The fingerprint is meant for cheap change detection and cache keys. Graphs that are
+    /// equal up to the order of triples and blank node labels have the same fingerprint.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::Graph;
+    /// use rdf::triple::Triple;
+    /// use rdf::uri::Uri;
+    ///
+    /// let mut graph = Graph::new(None);
+    ///
+    /// let subject = graph.create_blank_node();
+    /// let predicate = graph.create_uri_node(&Uri::new("http://example.org/p".to_string()));
+    /// let object = graph.create_literal_node("a".to_string());
+    ///
+    /// let empty = graph.fingerprint();
+    /// graph.add_triple(&Triple::new(&subject, &predicate, &object));
+    ///
+    /// assert_ne!(graph.fingerprint(), empty);
+    /// ```
+    pub fn fingerprint(&self) -> u64 {
+        fingerprint::fingerprint(self)
+    }
+
+    /// Infers a schema summary from the instance data of the graph.
+    ///
+    /// The summary has the observed classes, the properties of their instances with the
+    /// cardinalities and the distribution of value types. It can be converted to an RDFS and
+    /// SHACL sketch with `InducedSchema::to_graph`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::Graph;
+    /// use rdf::schema_induction::ValueType;
+    /// use rdf::specs::rdf_syntax_specs::RdfSyntaxDataTypes;
+    /// use rdf::triple::Triple;
+    /// use rdf::uri::Uri;
+    ///
+    /// let mut graph = Graph::new(None);
+    ///
+    /// let art = graph.create_uri_node(&Uri::new("http://example.org/art".to_string()));
+    /// let person = graph.create_uri_node(&Uri::new("http://xmlns.com/foaf/0.1/Person".to_string()));
+    /// let rdf_type = graph.create_uri_node(&RdfSyntaxDataTypes::A.to_uri());
+    /// let name = graph.create_uri_node(&Uri::new("http://xmlns.com/foaf/0.1/name".to_string()));
+    /// let literal = graph.create_literal_node("Art Barstow".to_string());
+    ///
+    /// graph.add_triple(&Triple::new(&art, &rdf_type, &person));
+    /// graph.add_triple(&Triple::new(&art, &name, &literal));
+    ///
+    /// let schema = graph.induce_schema();
+    /// let name_summary = schema.class(&person).unwrap().property(&name).unwrap();
+    ///
+    /// assert_eq!(name_summary.max_count(), 1);
+    /// assert_eq!(name_summary.value_types().keys().next(), Some(&ValueType::Literal(
+    ///     Uri::new("http://www.w3.org/2001/XMLSchema#string".to_string()))));
+    /// ```
+    pub fn induce_schema(&self) -> InducedSchema {
+        schema_induction::induce_schema(self, None)
+    }
+
+    /// Infers a schema summary from at most `sample_size` instances of each class.
+    ///
+    /// Instance counts cover all instances, property statistics only the sampled instances.
+    pub fn induce_schema_from_sample(&self, sample_size: usize) -> InducedSchema {
+        schema_induction::induce_schema(self, Some(sample_size))
+    }
+
+    /// Reports per language tag how many subjects carry a label, e.g. to track how complete
+    /// the translations of a vocabulary are.
+    ///
+    /// Labels are the literal objects of the label predicates, see
+    /// `literals::language_coverage`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::Graph;
+    /// use rdf::triple::Triple;
+    /// use rdf::uri::Uri;
+    ///
+    /// let mut graph = Graph::new(None);
+    ///
+    /// let label = graph.create_uri_node(&Uri::new("http://www.w3.org/2000/01/rdf-schema#label".to_string()));
+    /// let red = graph.create_uri_node(&Uri::new("http://example.org/red".to_string()));
+    /// let literal = graph.create_literal_node_with_language("rot".to_string(), "de".to_string());
+    ///
+    /// graph.add_triple(&Triple::new(&red, &label, &literal));
+    ///
+    /// assert_eq!(graph.language_coverage(&[label]).coverage("de"), 1.0);
+    /// ```
+    pub fn language_coverage(&self, label_predicates: &[Node]) -> LanguageCoverage {
+        literals::language_coverage(self, label_predicates)
+    }
+
+    /// Adds the triple and annotates it with the interval in which it is valid.
+    ///
+    /// The interval is attached to the quoted triple with `schema:validFrom` and `schema:validThrough`
+    /// as `xsd:dateTime` literals, both ends are inclusive. Without end the triple stays valid.
+    /// An existing interval of the triple is replaced.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::Graph;
+    /// use rdf::uri::Uri;
+    /// use rdf::triple::Triple;
+    ///
+    /// let mut graph = Graph::new(None);
+    ///
+    /// let subject = graph.create_uri_node(&Uri::new("http://example.org/alice".to_string()));
+    /// let predicate = graph.create_uri_node(&Uri::new("http://example.org/worksFor".to_string()));
+    /// let acme = graph.create_uri_node(&Uri::new("http://example.org/acme".to_string()));
+    /// let initech = graph.create_uri_node(&Uri::new("http://example.org/initech".to_string()));
+    ///
+    /// let before = Triple::new(&subject, &predicate, &acme);
+    /// let after = Triple::new(&subject, &predicate, &initech);
+    ///
+    /// graph.assert_during(&before, "2015-01-01T00:00:00Z", Some("2019-12-31T23:59:59Z")).unwrap();
+    /// graph.assert_during(&after, "2020-01-01T00:00:00Z", None).unwrap();
+    ///
+    /// assert_eq!(graph.valid_at("2017-06-01T00:00:00Z").unwrap(), vec![&before]);
+    /// assert_eq!(graph.valid_at("2021-06-01T00:00:00Z").unwrap(), vec![&after]);
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - Start or end is not a valid `xsd:dateTime`.
+    /// - The end is before the start.
+    ///
+    pub fn assert_during(&mut self, triple: &Triple, from: &str, to: Option<&str>) -> Result<()> {
+        let valid_from = DateTime::parse(from)?;
+
+        if let Some(to) = to {
+            if DateTime::parse(to)? < valid_from {
+                return Err(Error::new(
+                    ErrorType::InvalidLiteral,
+                    "End of validity interval is before its start.",
+                ));
+            }
+        }
+
+        let quoted = self.create_triple_node(triple);
+        let valid_from_predicate = Node::UriNode {
+            uri: SchemaVocabulary::ValidFrom.to_uri(),
+        };
+        let valid_through_predicate = Node::UriNode {
+            uri: SchemaVocabulary::ValidThrough.to_uri(),
+        };
+
+        // replace the existing interval
+        let existing_interval: Vec<Triple> = self
+            .triples_iter()
+            .filter(|t| {
+                *t.subject() == quoted
+                    && (*t.predicate() == valid_from_predicate
+                        || *t.predicate() == valid_through_predicate)
+            })
+            .cloned()
+            .collect();
+
+        for interval_triple in &existing_interval {
+            self.remove_triple(interval_triple);
+        }
+
+        if !self.triples_iter().any(|t| t == triple) {
+            self.add_triple(triple);
+        }
+
+        let date_time = XmlDataTypes::DateTime.to_uri();
+
+        let from = self.create_literal_node_with_data_type(from.to_string(), &date_time);
+        self.add_triple(&Triple::new(&quoted, &valid_from_predicate, &from));
+
+        if let Some(to) = to {
+            let to = self.create_literal_node_with_data_type(to.to_string(), &date_time);
+            self.add_triple(&Triple::new(&quoted, &valid_through_predicate, &to));
+        }
+
+        Ok(())
+    }
+
+    /// Returns all triples that are valid at the provided `xsd:dateTime`.
+    ///
+    /// Triples without validity interval are always valid. The annotations describing the
+    /// validity intervals are not returned.
+    ///
+    /// # Failures
+    ///
+    /// - The instant or a stored interval is not a valid `xsd:dateTime`.
+    ///
+    pub fn valid_at(&self, instant: &str) -> Result<Vec<&Triple>> {
+        let instant = DateTime::parse(instant)?;
+
+        let valid_from_predicate = Node::UriNode {
+            uri: SchemaVocabulary::ValidFrom.to_uri(),
+        };
+        let valid_through_predicate = Node::UriNode {
+            uri: SchemaVocabulary::ValidThrough.to_uri(),
+        };
+
+        let is_interval = |t: &Triple| {
+            matches!(*t.subject(), Node::TripleNode { .. })
+                && (*t.predicate() == valid_from_predicate
+                    || *t.predicate() == valid_through_predicate)
+        };
+
+        let mut intervals: HashMap<&Triple, (Option<DateTime>, Option<DateTime>)> = HashMap::new();
+
+        for interval_triple in self.triples_iter().filter(|t| is_interval(t)) {
+            let date_time = match *interval_triple.object() {
+                Node::LiteralNode { ref literal, .. } => DateTime::parse(literal)?,
+                _ => {
+                    return Err(Error::new(
+                        ErrorType::InvalidLiteral,
+                        "Validity interval is not a literal.",
+                    ))
+                }
+            };
+
+            if let Node::TripleNode { ref triple } = *interval_triple.subject() {
+                let interval = intervals.entry(&**triple).or_insert((None, None));
+
+                if *interval_triple.predicate() == valid_from_predicate {
+                    interval.0 = Some(date_time);
+                } else {
+                    interval.1 = Some(date_time);
+                }
+            }
+        }
+
+        Ok(self
+            .triples_iter()
+            .filter(|t| !is_interval(t))
+            .filter(|t| match intervals.get(*t) {
+                Some(&(from, through)) => {
+                    from.is_none_or(|from| from <= instant)
+                        && through.is_none_or(|through| instant <= through)
+                }
+                None => true,
+            })
+            .collect())
+    }
+
+    /// Returns the cycles among blank nodes, each as sorted list of the blank nodes involved.
+    ///
+    /// Blank nodes of a cycle cannot be written inline with nested syntax like `[ ... ]`
+    /// without looping forever, serializers need to write them with labels.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::Graph;
+    /// use rdf::triple::Triple;
+    /// use rdf::uri::Uri;
+    ///
+    /// let mut graph = Graph::new(None);
+    ///
+    /// let knows = graph.create_uri_node(&Uri::new("http://xmlns.com/foaf/0.1/knows".to_string()));
+    /// let a = graph.create_blank_node();
+    /// let b = graph.create_blank_node();
+    /// let c = graph.create_blank_node();
+    ///
+    /// graph.add_triple(&Triple::new(&a, &knows, &b));
+    /// graph.add_triple(&Triple::new(&b, &knows, &a));
+    /// graph.add_triple(&Triple::new(&b, &knows, &c));
+    ///
+    /// assert_eq!(graph.blank_node_cycles(), vec![vec![a, b]]);
+    /// ```
+    pub fn blank_node_cycles(&self) -> Vec<Vec<Node>> {
+        let mut successors: HashMap<&Node, Vec<&Node>> = HashMap::new();
+
+        for triple in self.triples_iter() {
+            if let (&Node::BlankNode { .. }, &Node::BlankNode { .. }) =
+                (triple.subject(), triple.object())
+            {
+                successors
+                    .entry(triple.subject())
+                    .or_default()
+                    .push(triple.object());
+            }
+        }
+
+        let mut nodes: Vec<&Node> = successors.keys().cloned().collect();
+        nodes.sort();
+
+        // iterative variant of Tarjan's algorithm for strongly connected components,
+        // blank node chains like RDF lists can be too long for recursion
+        let mut indices: HashMap<&Node, (usize, usize)> = HashMap::new();
+        let mut stack: Vec<&Node> = Vec::new();
+        let mut on_stack: HashSet<&Node> = HashSet::new();
+        let mut cycles = Vec::new();
+        let no_successors = Vec::new();
+
+        for root in nodes {
+            if indices.contains_key(root) {
+                continue;
+            }
+
+            let mut call_stack: Vec<(&Node, usize)> = vec![(root, 0)];
+
+            while let Some((node, next_successor)) = call_stack.pop() {
+                if next_successor == 0 {
+                    let index = indices.len();
+                    indices.insert(node, (index, index));
+                    stack.push(node);
+                    on_stack.insert(node);
+                }
+
+                let node_successors = successors.get(node).unwrap_or(&no_successors);
+
+                if let Some(&successor) = node_successors.get(next_successor) {
+                    call_stack.push((node, next_successor + 1));
+
+                    if !indices.contains_key(successor) {
+                        call_stack.push((successor, 0));
+                    } else if on_stack.contains(successor) {
+                        let successor_index = indices[successor].0;
+                        let entry = indices.get_mut(node).unwrap();
+                        entry.1 = entry.1.min(successor_index);
+                    }
+
+                    continue;
+                }
+
+                let (index, low_link) = indices[node];
+
+                if let Some(&(parent, _)) = call_stack.last() {
+                    let entry = indices.get_mut(parent).unwrap();
+                    entry.1 = entry.1.min(low_link);
+                }
+
+                if index == low_link {
+                    let mut component = Vec::new();
+
+                    while let Some(member) = stack.pop() {
+                        on_stack.remove(member);
+                        component.push(member.clone());
+
+                        if member == node {
+                            break;
+                        }
+                    }
+
+                    if component.len() > 1 || node_successors.contains(&node) {
+                        component.sort();
+                        cycles.push(component);
+                    }
+                }
+            }
+        }
+
+        cycles.sort();
+        cycles
+    }
+
+    /// Returns the blank nodes that can be written inline with nested syntax like `[ ... ]`.
+    ///
+    /// These blank nodes are the object of exactly one triple, are never used within
+    /// quoted triples and are not part of a cycle.
+    pub fn inlinable_blank_nodes(&self) -> HashSet<Node> {
+        let mut references: HashMap<&Node, usize> = HashMap::new();
+        let mut quoted: HashSet<Node> = HashSet::new();
+
+        for triple in self.triples_iter() {
+            if let Node::BlankNode { .. } = *triple.object() {
+                *references.entry(triple.object()).or_insert(0) += 1;
+            }
+
+            for node in &[triple.subject(), triple.object()] {
+                if let Node::TripleNode { ref triple } = **node {
+                    collect_blank_nodes(triple, &mut quoted);
+                }
+            }
+        }
+
+        let cyclic: HashSet<Node> = self.blank_node_cycles().into_iter().flatten().collect();
+
+        references
+            .into_iter()
+            .filter(|&(node, count)| count == 1 && !cyclic.contains(node) && !quoted.contains(node))
+            .map(|(node, _)| node.clone())
+            .collect()
+    }
+}
+
+/// Number of triples that are shown by the `Debug` output of a graph.
+const DEBUG_PREVIEW_TRIPLES: usize = 20;
+
+/// Maximum number of characters of a literal that are shown by the `Debug` output of a graph.
+const DEBUG_LITERAL_LENGTH: usize = 40;
+
+/// Returns the triple with each node replaced by the node returned by the closure.
+fn map_triple_terms<F: FnMut(&Node) -> Node>(triple: &Triple, f: &mut F) -> Triple {
+    Triple::from_nodes(
+        map_term(triple.subject(), f),
+        map_term(triple.predicate(), f),
+        map_term(triple.object(), f),
+    )
+}
+
+/// Returns the node returned by the closure, the nodes of quoted triples are mapped first.
+fn map_term<F: FnMut(&Node) -> Node>(node: &Node, f: &mut F) -> Node {
+    match *node {
+        Node::TripleNode { ref triple } => {
+            let quoted = Node::TripleNode {
+                triple: Box::new(map_triple_terms(triple, f)),
+            };
+            f(&quoted)
+        }
+        _ => f(node),
+    }
+}
+
+impl<S: GraphStore> fmt::Debug for Graph<S> {
+    /// Writes a preview of the graph instead of its internal structure.
+    ///
+    /// The first triples in sorted order are written in Turtle-like syntax with the prefixes of
+    /// the graph, long literals are shortened.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::Graph;
+    /// use rdf::namespace::Namespace;
+    /// use rdf::triple::Triple;
+    /// use rdf::uri::Uri;
+    ///
+    /// let mut graph = Graph::new(None);
+    /// graph.add_namespace(&Namespace::new("ex".to_string(), Uri::new("http://example.org/".to_string())));
+    ///
+    /// let subject = graph.create_uri_node(&Uri::new("http://example.org/a".to_string()));
+    /// let predicate = graph.create_uri_node(&Uri::new("http://example.org/name".to_string()));
+    /// let object = graph.create_literal_node_with_language("A".to_string(), "en".to_string());
+    ///
+    /// graph.add_triple(&Triple::new(&subject, &predicate, &object));
+    ///
+    /// assert_eq!(format!("{:?}", graph),
+    ///            "Graph (1 triples, 1 namespaces) {\n    ex:a ex:name \"A\"@en .\n}");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let namespaces = self.namespaces();
+
+        let triples = match self.triples.matching(None, None, None) {
+            Ok(triples) => triples,
+            Err(err) => return write!(f, "Graph (store cannot be queried: {})", err),
+        };
+
+        // keep the smallest lines only, the heap returns the largest line first
+        let mut preview: BinaryHeap<String> = BinaryHeap::new();
+
+        for triple in &triples {
+            let line = debug_triple(triple, namespaces);
+
+            if preview.len() < DEBUG_PREVIEW_TRIPLES {
+                preview.push(line);
+            } else if preview.peek().is_some_and(|largest| line < *largest) {
+                preview.pop();
+                preview.push(line);
+            }
+        }
+
+        if triples.len() > DEBUG_PREVIEW_TRIPLES {
+            write!(
+                f,
+                "Graph (first {} of {} triples",
+                DEBUG_PREVIEW_TRIPLES,
+                triples.len()
+            )?;
+        } else {
+            write!(f, "Graph ({} triples", triples.len())?;
+        }
+
+        write!(f, ", {} namespaces", namespaces.len())?;
+
+        if let Some(ref base_uri) = self.base_uri {
+            write!(f, ", base <{}>", base_uri.to_string())?;
+        }
+
+        writeln!(f, ") {{")?;
+
+        for line in preview.into_sorted_vec() {
+            writeln!(f, "    {} .", line)?;
+        }
+
+        if triples.len() > DEBUG_PREVIEW_TRIPLES {
+            writeln!(f, "    ...")?;
+        }
+
+        write!(f, "}}")
+    }
+}
+
+/// Writes the triple in Turtle-like syntax for the `Debug` output of a graph.
+fn debug_triple(triple: &Triple, namespaces: &HashMap<String, Uri>) -> String {
+    format!(
+        "{} {} {}",
+        debug_node(triple.subject(), namespaces),
+        debug_node(triple.predicate(), namespaces),
+        debug_node(triple.object(), namespaces)
+    )
+}
+
+/// Writes the node in Turtle-like syntax for the `Debug` output of a graph.
+fn debug_node(node: &Node, namespaces: &HashMap<String, Uri>) -> String {
+    match *node {
+        Node::UriNode { ref uri } => debug_uri(uri, namespaces),
+        Node::BlankNode { ref id } => format!("_:{}", id),
+        Node::LiteralNode {
+            ref literal,
+            ref data_type,
+            ref language,
+        } => {
+            let mut output = if literal.chars().count() > DEBUG_LITERAL_LENGTH {
+                let shortened: String = literal.chars().take(DEBUG_LITERAL_LENGTH).collect();
+                format!("\"{}...\"", RdfSyntaxSpecs::escape_literal(&shortened))
+            } else {
+                format!("\"{}\"", RdfSyntaxSpecs::escape_literal(literal))
+            };
+
+            if let Some(ref language) = *language {
+                output.push('@');
+                output.push_str(language);
+            } else if let Some(ref data_type) = *data_type {
+                output.push_str("^^");
+                output.push_str(&debug_uri(data_type, namespaces));
+            }
+
+            output
+        }
+        Node::TripleNode { ref triple } => format!("<< {} >>", debug_triple(triple, namespaces)),
+    }
+}
+
+/// Writes the URI as prefixed name if one of the namespaces matches, the longest namespace
+/// URI wins.
+fn debug_uri(uri: &Uri, namespaces: &HashMap<String, Uri>) -> String {
+    let uri = uri.to_string();
+
+    namespaces
+        .iter()
+        .filter_map(|(prefix, namespace)| {
+            uri.strip_prefix(namespace.to_string().as_str())
+                .filter(|local| !local.is_empty() && !local.contains(['/', '#', ' ']))
+                .map(|local| (namespace.to_string().len(), prefix, local))
+        })
+        .max_by(|a, b| a.0.cmp(&b.0).then_with(|| b.1.cmp(a.1)))
+        .map(|(_, prefix, local)| format!("{}:{}", prefix, local))
+        .unwrap_or_else(|| format!("<{}>", uri))
+}
+
+/// Collects the blank nodes of a quoted triple including nested quoted triples.
+fn collect_blank_nodes(triple: &Triple, blank_nodes: &mut HashSet<Node>) {
+    for node in &[triple.subject(), triple.object()] {
+        match **node {
+            Node::BlankNode { .. } => {
+                blank_nodes.insert((*node).clone());
+            }
+            Node::TripleNode { ref triple } => collect_blank_nodes(triple, blank_nodes),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::Graph;
+    use crate::node::*;
+    use crate::triple::Triple;
+    use crate::uri::Uri;
+
+    #[test]
+    fn empty_graph() {
+        let graph = Graph::new(None);
+        assert_eq!(graph.is_empty(), true);
+    }
+
+    #[test]
+    fn create_literal_node() {
+        let graph = Graph::new(None);
+        let literal_node = graph.create_literal_node("literal".to_string());
+
+        assert_eq!(
+            literal_node,
+            Node::LiteralNode {
+                literal: "literal".to_string(),
+                data_type: None,
+                language: None,
+            }
+        );
+    }
+
+    #[test]
+    fn create_multiple_blank_nodes() {
+        let mut graph = Graph::new(None);
+
+        let blank_node_0 = graph.create_blank_node();
+        let blank_node_1 = graph.create_blank_node();
+
+        assert_eq!(
+            blank_node_0,
+            Node::BlankNode {
                 id: "auto0".to_string(),
             }
         );
@@ -611,4 +1954,231 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn assert_during_replaces_interval() {
+        let mut graph = Graph::new(None);
+
+        let subject = graph.create_uri_node(&Uri::new("http://example.org/s".to_string()));
+        let predicate = graph.create_uri_node(&Uri::new("http://example.org/p".to_string()));
+        let object = graph.create_literal_node("o".to_string());
+        let triple = Triple::new(&subject, &predicate, &object);
+
+        graph
+            .assert_during(
+                &triple,
+                "2020-01-01T00:00:00Z",
+                Some("2020-12-31T23:59:59Z"),
+            )
+            .unwrap();
+        graph
+            .assert_during(&triple, "2021-01-01T00:00:00Z", None)
+            .unwrap();
+
+        assert_eq!(graph.count(), 2);
+        assert!(graph.valid_at("2020-06-01T00:00:00Z").unwrap().is_empty());
+        assert_eq!(
+            graph.valid_at("2030-01-01T00:00:00Z").unwrap(),
+            vec![&triple]
+        );
+
+        assert!(graph
+            .assert_during(
+                &triple,
+                "2021-01-01T00:00:00Z",
+                Some("2020-01-01T00:00:00Z")
+            )
+            .is_err());
+        assert!(graph.valid_at("yesterday").is_err());
+    }
+
+    #[test]
+    fn blank_node_cycles_and_inlinable_blank_nodes() {
+        let mut graph = Graph::new(None);
+
+        let p = graph.create_uri_node(&Uri::new("http://example.org/p".to_string()));
+        let a = graph.create_blank_node();
+        let b = graph.create_blank_node();
+        let c = graph.create_blank_node();
+        let d = graph.create_blank_node();
+        let e = graph.create_blank_node();
+
+        graph.add_triple(&Triple::new(&a, &p, &a));
+        graph.add_triple(&Triple::new(&b, &p, &c));
+        graph.add_triple(&Triple::new(&c, &p, &d));
+        graph.add_triple(&Triple::new(&d, &p, &b));
+        graph.add_triple(&Triple::new(&d, &p, &e));
+
+        assert_eq!(
+            graph.blank_node_cycles(),
+            vec![vec![a.clone()], vec![b.clone(), c.clone(), d.clone()]]
+        );
+        assert_eq!(
+            graph.inlinable_blank_nodes(),
+            vec![e.clone()].into_iter().collect()
+        );
+
+        let quoted = graph.create_triple_node(&Triple::new(&e, &p, &p));
+        graph.add_triple(&Triple::new(&quoted, &p, &p));
+
+        assert!(graph.inlinable_blank_nodes().is_empty());
+    }
+
+    #[test]
+    fn long_blank_node_chain_has_no_cycles() {
+        let mut graph = Graph::new(None);
+
+        let p = graph.create_uri_node(&Uri::new("http://example.org/p".to_string()));
+        let mut previous = graph.create_blank_node();
+
+        for _ in 0..100_000 {
+            let next = graph.create_blank_node();
+            graph.add_triple(&Triple::new(&previous, &p, &next));
+            previous = next;
+        }
+
+        assert!(graph.blank_node_cycles().is_empty());
+        assert_eq!(graph.inlinable_blank_nodes().len(), 100_000);
+    }
+
+    #[test]
+    fn debug_output_is_a_bounded_sorted_preview() {
+        let base_uri = Uri::new("http://example.org/".to_string());
+        let mut graph = Graph::new(Some(&base_uri));
+        graph.add_namespace(&crate::namespace::Namespace::new(
+            "ex".to_string(),
+            Uri::new("http://example.org/".to_string()),
+        ));
+
+        let p = graph.create_uri_node(&Uri::new("http://example.org/p".to_string()));
+        let other = graph.create_uri_node(&Uri::new("http://other.org/x".to_string()));
+
+        for i in (0..30).rev() {
+            let subject = graph.create_blank_node_with_id(format!("b{:02}", i));
+            graph.add_triple(&Triple::new(&subject, &p, &other));
+        }
+
+        let quoted = graph.create_triple_node(&Triple::new(&other, &p, &other));
+        let long = graph.create_literal_node("x".repeat(50));
+        graph.add_triple(&Triple::new(&quoted, &p, &long));
+
+        let output = format!("{:?}", graph);
+        let lines: Vec<&str> = output.lines().collect();
+
+        assert_eq!(
+            lines[0],
+            "Graph (first 20 of 31 triples, 1 namespaces, base <http://example.org/>) {"
+        );
+        assert_eq!(
+            lines[1],
+            format!(
+                "    << <http://other.org/x> ex:p <http://other.org/x> >> ex:p \"{}...\" .",
+                "x".repeat(40)
+            )
+        );
+        assert_eq!(lines[2], "    _:b00 ex:p <http://other.org/x> .");
+        assert_eq!(lines[20], "    _:b18 ex:p <http://other.org/x> .");
+        assert_eq!(&lines[21..], &["    ...", "}"]);
+    }
+
+    #[test]
+    fn map_terms_of_quoted_triples_and_keep_blank_node_ids() {
+        let mut graph = Graph::new(None);
+        let uri = |uri: &str| Node::UriNode {
+            uri: Uri::new(uri.to_string()),
+        };
+
+        let blank = graph.create_blank_node();
+        let quoted = graph.create_triple_node(&Triple::new(
+            &uri("http://old.org/a"),
+            &uri("http://old.org/p"),
+            &blank,
+        ));
+        graph.add_triple(&Triple::new(
+            &quoted,
+            &uri("http://old.org/p"),
+            &uri("http://old.org/b"),
+        ));
+
+        let mut calls = 0;
+        let mut mapped = graph.map_terms(|node| {
+            calls += 1;
+            match *node {
+                Node::UriNode { ref uri } => Node::UriNode {
+                    uri: Uri::new(uri.to_string().replace("old.org", "new.org")),
+                },
+                _ => node.clone(),
+            }
+        });
+
+        let expected_quoted = mapped.create_triple_node(&Triple::new(
+            &uri("http://new.org/a"),
+            &uri("http://new.org/p"),
+            &blank,
+        ));
+
+        assert_eq!(calls, 6);
+        assert_eq!(
+            mapped.triples_iter().collect::<Vec<_>>(),
+            vec![&Triple::new(
+                &expected_quoted,
+                &uri("http://new.org/p"),
+                &uri("http://new.org/b")
+            )]
+        );
+        assert_ne!(mapped.create_blank_node(), blank);
+    }
+
+    #[test]
+    fn merge_does_not_reuse_blank_nodes_of_quoted_triples() {
+        let mut graph = Graph::new(None);
+        let mut other = Graph::new(None);
+
+        let p = graph.create_uri_node(&Uri::new("http://example.org/p".to_string()));
+        let quoted_blank = graph.create_blank_node_with_id("auto0".to_string());
+        let quoted = graph.create_triple_node(&Triple::new(&quoted_blank, &p, &p));
+        graph.add_triple(&Triple::new(&quoted, &p, &p));
+
+        let blank = other.create_blank_node_with_id("x".to_string());
+        other.add_triple(&Triple::new(&blank, &p, &p));
+
+        graph.merge(&other);
+
+        let merged = graph.get_triples_with_predicate(&p);
+        assert_eq!(merged.len(), 2);
+        assert!(merged
+            .iter()
+            .all(|triple| *triple.subject() != quoted_blank));
+    }
+
+    #[test]
+    #[cfg(feature = "store")]
+    fn graphs_with_other_stores_delegate_to_the_store() {
+        use crate::store::{CachedStore, GraphStore};
+        use crate::triple::TripleStore;
+
+        let mut graph = Graph::with_store(None, CachedStore::new(TripleStore::new(), 4));
+
+        let subject = graph.create_blank_node();
+        let object = graph.create_blank_node();
+
+        assert!(graph.try_is_empty().unwrap());
+
+        graph
+            .try_add_triple(&Triple::new(&subject, &subject, &object))
+            .unwrap();
+        graph
+            .try_add_triple(&Triple::new(&subject, &subject, &object))
+            .unwrap();
+
+        assert_eq!(graph.try_count().unwrap(), 1);
+        assert_eq!(graph.store().store().len().unwrap(), 1);
+        assert_eq!(
+            graph
+                .triples_matching(Some(&subject), None, None)
+                .unwrap()
+                .len(),
+            1
+        );
+    }
 }
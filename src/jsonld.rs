@@ -0,0 +1,292 @@
+use crate::graph::Graph;
+use crate::node::Node;
+use crate::specs::json_specs::JsonSpecs;
+use crate::specs::rdf_syntax_specs::RdfSyntaxDataTypes;
+use crate::uri::Uri;
+use std::collections::{BTreeMap, HashMap};
+
+/// Definition of a term of a JSON-LD context.
+#[derive(Debug, PartialEq, Clone)]
+pub struct TermDefinition {
+    iri: Uri,
+    type_coercion: Option<String>,
+}
+
+impl TermDefinition {
+    /// Constructor of `TermDefinition`.
+    ///
+    /// The type coercion is `@id` or the IRI of a data type.
+    pub fn new(iri: &Uri, type_coercion: Option<String>) -> TermDefinition {
+        TermDefinition {
+            iri: iri.clone(),
+            type_coercion,
+        }
+    }
+
+    /// Returns the IRI the term expands to.
+    pub fn iri(&self) -> &Uri {
+        &self.iri
+    }
+
+    /// Returns the type values of the term are coerced to.
+    pub fn type_coercion(&self) -> &Option<String> {
+        &self.type_coercion
+    }
+}
+
+/// A JSON-LD `@context` with prefixes and term definitions.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Context {
+    prefixes: BTreeMap<String, Uri>,
+    terms: BTreeMap<String, TermDefinition>,
+}
+
+impl Context {
+    /// Constructor of an empty `Context`.
+    pub fn new() -> Context {
+        Context::default()
+    }
+
+    /// Returns the prefixes of the context.
+    pub fn prefixes(&self) -> &BTreeMap<String, Uri> {
+        &self.prefixes
+    }
+
+    /// Returns the term definitions of the context.
+    pub fn terms(&self) -> &BTreeMap<String, TermDefinition> {
+        &self.terms
+    }
+
+    /// Adds a prefix, an existing prefix with the same name is replaced.
+    pub fn add_prefix(&mut self, prefix: &str, iri: &Uri) {
+        self.prefixes.insert(prefix.to_string(), iri.clone());
+    }
+
+    /// Adds a term definition, an existing definition of the term is replaced.
+    pub fn add_term(&mut self, term: &str, definition: TermDefinition) {
+        self.terms.insert(term.to_string(), definition);
+    }
+
+    /// Returns the IRI as compact IRI if a prefix matches, otherwise the IRI itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::jsonld::Context;
+    /// use rdf::uri::Uri;
+    ///
+    /// let mut context = Context::new();
+    /// context.add_prefix("foaf", &Uri::new("http://xmlns.com/foaf/0.1/".to_string()));
+    ///
+    /// assert_eq!(context.compact_iri("http://xmlns.com/foaf/0.1/name"), "foaf:name".to_string());
+    /// assert_eq!(context.compact_iri("http://example.org/a"), "http://example.org/a".to_string());
+    /// ```
+    pub fn compact_iri(&self, iri: &str) -> String {
+        self.prefixes
+            .iter()
+            .filter(|&(_, prefix_iri)| {
+                iri.starts_with(prefix_iri.to_string().as_str())
+                    && iri.len() > prefix_iri.to_string().len()
+            })
+            .max_by_key(|&(prefix, prefix_iri)| (prefix_iri.to_string().len(), prefix))
+            .map(|(prefix, prefix_iri)| {
+                prefix.to_string() + ":" + &iri[prefix_iri.to_string().len()..]
+            })
+            .unwrap_or_else(|| iri.to_string())
+    }
+
+    /// Returns the context as JSON object with a single `@context` member.
+    pub fn to_json(&self) -> String {
+        let mut members = Vec::new();
+
+        for (prefix, iri) in &self.prefixes {
+            members.push(
+                JsonSpecs::escape_string(prefix)
+                    + ": "
+                    + &JsonSpecs::escape_string(iri.to_string()),
+            );
+        }
+
+        for (term, definition) in &self.terms {
+            let iri = self.compact_iri(definition.iri.to_string());
+
+            let value = match definition.type_coercion {
+                Some(ref type_coercion) => {
+                    "{\"@id\": ".to_string()
+                        + &JsonSpecs::escape_string(&iri)
+                        + ", \"@type\": "
+                        + &JsonSpecs::escape_string(&self.compact_iri(type_coercion))
+                        + "}"
+                }
+                None => JsonSpecs::escape_string(&iri),
+            };
+
+            members.push(JsonSpecs::escape_string(term) + ": " + &value);
+        }
+
+        if members.is_empty() {
+            return "{\"@context\": {}}".to_string();
+        }
+
+        "{\"@context\": {\n  ".to_string() + &members.join(",\n  ") + "\n}}"
+    }
+}
+
+/// Derives a compact JSON-LD context from the namespaces of the graph and its predicates.
+///
+/// Each predicate gets a term named after its local name unless the name is ambiguous. Values
+/// are coerced to `@id` if all objects are resources, or to the data type shared by all objects.
+///
+/// # Examples
+///
+/// ```
+/// use rdf::graph::Graph;
+/// use rdf::jsonld;
+/// use rdf::namespace::Namespace;
+/// use rdf::specs::xml_specs::XmlDataTypes;
+/// use rdf::triple::Triple;
+/// use rdf::uri::Uri;
+///
+/// let mut graph = Graph::new(None);
+/// graph.add_namespace(&Namespace::new("foaf".to_string(), Uri::new("http://xmlns.com/foaf/0.1/".to_string())));
+/// graph.add_namespace(&Namespace::new("xsd".to_string(), Uri::new("http://www.w3.org/2001/XMLSchema#".to_string())));
+///
+/// let art = graph.create_blank_node();
+/// let dan = graph.create_blank_node();
+/// let knows = graph.create_uri_node(&Uri::new("http://xmlns.com/foaf/0.1/knows".to_string()));
+/// let age = graph.create_uri_node(&Uri::new("http://xmlns.com/foaf/0.1/age".to_string()));
+/// let literal = graph.create_literal_node_with_data_type("42".to_string(), &XmlDataTypes::Integer.to_uri());
+///
+/// graph.add_triple(&Triple::new(&art, &knows, &dan));
+/// graph.add_triple(&Triple::new(&art, &age, &literal));
+///
+/// assert_eq!(jsonld::derive_context(&graph).to_json(),
+///            "{\"@context\": {\n  \
+///               \"foaf\": \"http://xmlns.com/foaf/0.1/\",\n  \
+///               \"xsd\": \"http://www.w3.org/2001/XMLSchema#\",\n  \
+///               \"age\": {\"@id\": \"foaf:age\", \"@type\": \"xsd:integer\"},\n  \
+///               \"knows\": {\"@id\": \"foaf:knows\", \"@type\": \"@id\"}\n}}");
+/// ```
+pub fn derive_context(graph: &Graph) -> Context {
+    let mut context = Context::new();
+
+    for (prefix, iri) in graph.namespaces() {
+        context.add_prefix(prefix, iri);
+    }
+
+    let rdf_type = Node::UriNode {
+        uri: RdfSyntaxDataTypes::A.to_uri(),
+    };
+
+    // type coercion of each predicate, `None` if the objects have different types
+    let mut coercions: BTreeMap<&Uri, Option<String>> = BTreeMap::new();
+
+    for triple in graph.triples_iter() {
+        let predicate = match *triple.predicate() {
+            Node::UriNode { ref uri } if *triple.predicate() != rdf_type => uri,
+            _ => continue,
+        };
+
+        let coercion = match *triple.object() {
+            Node::UriNode { .. } | Node::BlankNode { .. } => Some("@id".to_string()),
+            Node::LiteralNode {
+                data_type: Some(ref data_type),
+                language: None,
+                ..
+            } => Some(data_type.to_string().to_owned()),
+            _ => None,
+        };
+
+        coercions
+            .entry(predicate)
+            .and_modify(|existing| {
+                if *existing != coercion {
+                    *existing = None;
+                }
+            })
+            .or_insert(coercion);
+    }
+
+    let mut terms: HashMap<String, Vec<&Uri>> = HashMap::new();
+
+    for predicate in coercions.keys() {
+        if let Some(term) = local_name(predicate.to_string()) {
+            terms.entry(term.to_string()).or_default().push(predicate);
+        }
+    }
+
+    for (term, predicates) in terms {
+        // ambiguous terms are left out, the predicates are written as compact IRIs
+        if predicates.len() != 1 || context.prefixes.contains_key(&term) {
+            continue;
+        }
+
+        let coercion = coercions[predicates[0]].clone();
+        context.add_term(&term, TermDefinition::new(predicates[0], coercion));
+    }
+
+    context
+}
+
+/// Returns the part of the IRI after the last `#` or `/` if it can be used as term.
+fn local_name(iri: &str) -> Option<&str> {
+    let local_name = &iri[iri.rfind(['#', '/']).map_or(0, |i| i + 1)..];
+
+    if local_name.is_empty() || local_name.starts_with('@') || local_name.contains(':') {
+        None
+    } else {
+        Some(local_name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::Graph;
+    use crate::jsonld::*;
+    #[cfg(feature = "turtle")]
+    use crate::reader::rdf_parser::RdfParser;
+    #[cfg(feature = "turtle")]
+    use crate::reader::turtle_parser::TurtleParser;
+    use crate::triple::Triple;
+
+    #[test]
+    fn ambiguous_terms_and_mixed_types_are_not_defined() {
+        let mut graph = Graph::new(None);
+
+        let subject = graph.create_blank_node();
+        let first_name = graph.create_uri_node(&Uri::new("http://example.org/name".to_string()));
+        let second_name = graph.create_uri_node(&Uri::new("http://example.com/name".to_string()));
+        let value = graph.create_uri_node(&Uri::new("http://example.org/value".to_string()));
+        let literal = graph.create_literal_node("a".to_string());
+
+        graph.add_triple(&Triple::new(&subject, &first_name, &literal));
+        graph.add_triple(&Triple::new(&subject, &second_name, &literal));
+        graph.add_triple(&Triple::new(&subject, &value, &literal));
+        graph.add_triple(&Triple::new(&subject, &value, &subject));
+
+        let context = derive_context(&graph);
+
+        assert_eq!(context.terms().len(), 1);
+        assert_eq!(
+            context.terms()["value"],
+            TermDefinition::new(&Uri::new("http://example.org/value".to_string()), None)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "turtle")]
+    fn prefixes_of_parsed_graphs() {
+        let graph =
+            TurtleParser::from_string("@prefix ex: <http://example.org/> . ex:a ex:name \"a\" .")
+                .decode()
+                .unwrap();
+
+        let context = derive_context(&graph);
+
+        assert!(context.prefixes().contains_key("ex"));
+        assert_eq!(
+            context.terms()["name"].iri().to_string(),
+            "http://example.org/name"
+        );
+    }
+}
@@ -0,0 +1,511 @@
+use crate::graph::Graph;
+use crate::node::Node;
+use crate::reader::incremental_parser::TurtleDocument;
+use crate::specs::rdf_syntax_specs::RdfSyntaxDataTypes;
+use crate::specs::rdfs_specs::RdfsVocabulary;
+use crate::uri::Uri;
+use std::collections::{BTreeMap, HashMap};
+use std::ops::Range;
+
+/// Position in a text as zero-based line and character offset in UTF-16 code units.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Position {
+    line: usize,
+    character: usize,
+}
+
+impl Position {
+    /// Constructor of `Position`.
+    pub fn new(line: usize, character: usize) -> Position {
+        Position { line, character }
+    }
+
+    /// Returns the position of the byte offset in the text.
+    ///
+    /// Offsets inside a multi-byte character are moved back to the start of the character.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::lang::Position;
+    ///
+    /// let text = "ex:a ex:p \"ä\" ;\n  ex:q ex:b .";
+    ///
+    /// assert_eq!(Position::from_offset(text, 14), Position::new(0, 13));
+    /// assert_eq!(Position::from_offset(text, 19), Position::new(1, 2));
+    /// ```
+    pub fn from_offset(text: &str, offset: usize) -> Position {
+        let mut offset = offset.min(text.len());
+
+        while !text.is_char_boundary(offset) {
+            offset -= 1;
+        }
+
+        let before = &text[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+
+        Position {
+            line: before.matches('\n').count(),
+            character: before[line_start..].encode_utf16().count(),
+        }
+    }
+
+    /// Returns the byte offset of the position in the text.
+    ///
+    /// Positions after the end of a line are moved to the end of the line.
+    pub fn to_offset(&self, text: &str) -> usize {
+        let mut line_start = 0;
+
+        for _ in 0..self.line {
+            match text[line_start..].find('\n') {
+                Some(i) => line_start += i + 1,
+                None => return text.len(),
+            }
+        }
+
+        let line = text[line_start..].split('\n').next().unwrap_or("");
+        let mut units = 0;
+
+        for (i, c) in line.char_indices() {
+            if units >= self.character {
+                return line_start + i;
+            }
+
+            units += c.len_utf16();
+        }
+
+        line_start + line.len()
+    }
+
+    /// Returns the zero-based line.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Returns the zero-based character offset in UTF-16 code units.
+    pub fn character(&self) -> usize {
+        self.character
+    }
+}
+
+/// Range of a text between two positions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TextRange {
+    start: Position,
+    end: Position,
+}
+
+impl TextRange {
+    /// Returns the range of the byte range in the text.
+    pub fn from_offsets(text: &str, range: &Range<usize>) -> TextRange {
+        TextRange {
+            start: Position::from_offset(text, range.start),
+            end: Position::from_offset(text, range.end),
+        }
+    }
+
+    /// Returns the start of the range.
+    pub fn start(&self) -> Position {
+        self.start
+    }
+
+    /// Returns the exclusive end of the range.
+    pub fn end(&self) -> Position {
+        self.end
+    }
+}
+
+/// Kinds of symbols of a Turtle document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolKind {
+    /// Prefix directive.
+    Prefix,
+
+    /// Base directive.
+    Base,
+
+    /// Subject of a statement.
+    Subject,
+}
+
+/// Symbol of a Turtle document for outlines and navigation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DocumentSymbol {
+    name: String,
+    kind: SymbolKind,
+    range: TextRange,
+}
+
+impl DocumentSymbol {
+    /// Returns the name of the symbol, i.e. the prefix, the base IRI or the subject as written.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the kind of the symbol.
+    pub fn kind(&self) -> SymbolKind {
+        self.kind
+    }
+
+    /// Returns the range of the statement that declares the symbol.
+    pub fn range(&self) -> TextRange {
+        self.range
+    }
+}
+
+/// Kinds of completion candidates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompletionKind {
+    /// Prefix of a namespace, completed with the colon.
+    Prefix,
+
+    /// Term of a vocabulary, completed as prefixed name.
+    Term,
+}
+
+/// Candidate for completing the text that is typed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompletionItem {
+    label: String,
+    kind: CompletionKind,
+    detail: Option<String>,
+}
+
+impl CompletionItem {
+    /// Returns the text that is inserted.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Returns the kind of the candidate.
+    pub fn kind(&self) -> CompletionKind {
+        self.kind
+    }
+
+    /// Returns the IRI of a prefix or the label of a term.
+    pub fn detail(&self) -> &Option<String> {
+        &self.detail
+    }
+}
+
+/// Returns the directives and the subjects of the statements of the document.
+///
+/// # Examples
+///
+/// ```
+/// use rdf::lang::{self, SymbolKind};
+/// use rdf::reader::incremental_parser::TurtleDocument;
+///
+/// let document = TurtleDocument::parse("@prefix ex: <http://example.org/> .
+///                                       ex:a ex:p ex:b .");
+///
+/// let symbols = lang::document_symbols(&document);
+///
+/// assert_eq!(symbols[0].name(), "ex:");
+/// assert_eq!(symbols[0].kind(), SymbolKind::Prefix);
+/// assert_eq!(symbols[1].name(), "ex:a");
+/// assert_eq!(symbols[1].range().start().line(), 1);
+/// ```
+pub fn document_symbols(document: &TurtleDocument) -> Vec<DocumentSymbol> {
+    let text = document.text();
+    let mut symbols = Vec::new();
+
+    for range in document.statement_ranges() {
+        let statement = &text[range.clone()];
+        let mut words = statement.split_whitespace();
+        let first = words.next().unwrap_or("");
+
+        let (name, kind) =
+            if first.eq_ignore_ascii_case("@prefix") || first.eq_ignore_ascii_case("prefix") {
+                (words.next().unwrap_or(""), SymbolKind::Prefix)
+            } else if first.eq_ignore_ascii_case("@base") || first.eq_ignore_ascii_case("base") {
+                (words.next().unwrap_or(""), SymbolKind::Base)
+            } else {
+                (token_at(statement, 0).unwrap_or(first), SymbolKind::Subject)
+            };
+
+        symbols.push(DocumentSymbol {
+            name: name.to_string(),
+            kind,
+            range: TextRange::from_offsets(text, &range),
+        });
+    }
+
+    symbols
+}
+
+/// Returns the syntax errors of the document with their ranges.
+pub fn diagnostics(document: &TurtleDocument) -> Vec<(TextRange, String)> {
+    document
+        .diagnostics()
+        .into_iter()
+        .map(|diagnostic| {
+            (
+                TextRange::from_offsets(document.text(), diagnostic.range()),
+                diagnostic.message().to_string(),
+            )
+        })
+        .collect()
+}
+
+/// Returns the candidates for completing the prefix or prefixed name that is typed.
+///
+/// Without colon the prefixes of the document and of the vocabularies are candidates,
+/// otherwise all terms of the vocabularies in the namespace of the prefix.
+///
+/// # Examples
+///
+/// ```
+/// use rdf::lang;
+/// use rdf::reader::incremental_parser::TurtleDocument;
+/// use rdf::reader::rdf_parser::RdfParser;
+/// use rdf::reader::turtle_parser::TurtleParser;
+///
+/// let vocabulary = TurtleParser::from_string("@prefix foaf: <http://xmlns.com/foaf/0.1/> .
+///     @prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+///     foaf:name rdfs:label \"name\" .
+///     foaf:knows rdfs:label \"knows\" .").decode().unwrap();
+///
+/// let document = TurtleDocument::parse("@prefix f: <http://xmlns.com/foaf/0.1/> .\n");
+///
+/// let items = lang::completions(&document, &[&vocabulary], "f:na");
+///
+/// assert_eq!(items.len(), 1);
+/// assert_eq!(items[0].label(), "f:name");
+/// assert_eq!(items[0].detail(), &Some("name".to_string()));
+/// assert_eq!(lang::completions(&document, &[&vocabulary], "fo")[0].label(), "foaf:");
+/// ```
+pub fn completions(
+    document: &TurtleDocument,
+    vocabularies: &[&Graph],
+    typed: &str,
+) -> Vec<CompletionItem> {
+    let document_namespaces = document.namespaces();
+    let namespaces = prefixes(&document_namespaces);
+
+    let (prefix, local_name) = match typed.find(':') {
+        Some(i) => (&typed[..i], &typed[i + 1..]),
+        None => {
+            let mut candidates: BTreeMap<&str, &Uri> = BTreeMap::new();
+
+            for vocabulary in vocabularies {
+                candidates.extend(prefixes(vocabulary.namespaces()));
+            }
+
+            candidates.extend(namespaces);
+
+            return candidates
+                .into_iter()
+                .filter(|&(prefix, _)| prefix.starts_with(typed))
+                .map(|(prefix, iri)| CompletionItem {
+                    label: prefix.to_string() + ":",
+                    kind: CompletionKind::Prefix,
+                    detail: Some(iri.to_string().to_string()),
+                })
+                .collect();
+        }
+    };
+
+    let namespace = match namespaces.get(prefix) {
+        Some(namespace) => namespace.to_string(),
+        None => return Vec::new(),
+    };
+
+    let mut terms: BTreeMap<String, Option<String>> = BTreeMap::new();
+
+    for vocabulary in vocabularies {
+        for triple in vocabulary.triples_iter() {
+            if let Node::UriNode { ref uri } = *triple.subject() {
+                let name = match uri.to_string().strip_prefix(namespace.as_str()) {
+                    Some(name) if name.starts_with(local_name) && !name.is_empty() => name,
+                    _ => continue,
+                };
+
+                let label = describe(&[vocabulary], uri).0;
+                let detail = terms.entry(name.to_string()).or_insert(None);
+
+                if detail.is_none() {
+                    *detail = label;
+                }
+            }
+        }
+    }
+
+    terms
+        .into_iter()
+        .map(|(name, detail)| CompletionItem {
+            label: prefix.to_string() + ":" + &name,
+            kind: CompletionKind::Term,
+            detail,
+        })
+        .collect()
+}
+
+/// Returns a Markdown description of the IRI or prefixed name at the byte offset.
+///
+/// The description contains the `rdfs:label` and `rdfs:comment` found in the vocabularies
+/// and the expanded IRI.
+///
+/// # Examples
+///
+/// ```
+/// use rdf::lang;
+/// use rdf::reader::incremental_parser::TurtleDocument;
+/// use rdf::reader::rdf_parser::RdfParser;
+/// use rdf::reader::turtle_parser::TurtleParser;
+///
+/// let vocabulary = TurtleParser::from_string("@prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+///     <http://xmlns.com/foaf/0.1/name> rdfs:label \"name\" ;
+///         rdfs:comment \"A name for some thing.\" .").decode().unwrap();
+///
+/// let document = TurtleDocument::parse("@prefix foaf: <http://xmlns.com/foaf/0.1/> .
+///                                       _:a foaf:name \"Art\" .");
+/// let offset = document.text().find("foaf:name").unwrap() + 2;
+///
+/// assert_eq!(lang::hover(&document, &[&vocabulary], offset),
+///            Some("**name**\n\nA name for some thing.\n\n<http://xmlns.com/foaf/0.1/name>".to_string()));
+/// ```
+pub fn hover(document: &TurtleDocument, vocabularies: &[&Graph], offset: usize) -> Option<String> {
+    let token = token_at(document.text(), offset)?;
+
+    let iri = if token.starts_with('<') && token.ends_with('>') && token.len() > 1 {
+        token[1..token.len() - 1].to_string()
+    } else if token == "a" {
+        RdfSyntaxDataTypes::A.to_uri().to_string().to_string()
+    } else {
+        let i = token.find(':')?;
+        prefixes(&document.namespaces())
+            .get(&token[..i])?
+            .to_string()
+            .to_string()
+            + &token[i + 1..]
+    };
+
+    let (label, comment) = describe(vocabularies, &Uri::new(iri.clone()));
+    let mut description = String::new();
+
+    if let Some(label) = label {
+        description.push_str(&("**".to_string() + &label + "**\n\n"));
+    }
+
+    if let Some(comment) = comment {
+        description.push_str(&(comment + "\n\n"));
+    }
+
+    Some(description + "<" + &iri + ">")
+}
+
+/// Returns the namespaces sorted by prefix.
+fn prefixes(namespaces: &HashMap<String, Uri>) -> BTreeMap<&str, &Uri> {
+    namespaces
+        .iter()
+        .map(|(prefix, iri)| (prefix.as_str(), iri))
+        .collect()
+}
+
+/// Returns the IRI or prefixed name that contains the byte offset.
+fn token_at(text: &str, offset: usize) -> Option<&str> {
+    if offset > text.len() || !text.is_char_boundary(offset) {
+        return None;
+    }
+
+    let is_delimiter = |c: char| c.is_whitespace() || "\"'()[],;".contains(c);
+
+    let start = text[..offset].rfind(is_delimiter).map_or(0, |i| i + 1);
+    let end = text[offset..]
+        .find(is_delimiter)
+        .map_or(text.len(), |i| offset + i);
+    let mut token = &text[start..end];
+
+    // the dot terminating a statement can follow a name without whitespace
+    if token.ends_with('.') {
+        token = &token[..token.len() - 1];
+    }
+
+    if token.is_empty() || token.starts_with("_:") || token.starts_with('@') {
+        None
+    } else {
+        Some(token)
+    }
+}
+
+/// Returns the `rdfs:label` and the `rdfs:comment` of the IRI in the vocabularies.
+fn describe(vocabularies: &[&Graph], iri: &Uri) -> (Option<String>, Option<String>) {
+    let subject = Node::UriNode { uri: iri.clone() };
+    let mut values: HashMap<Uri, String> = HashMap::new();
+
+    for vocabulary in vocabularies {
+        for predicate in &[
+            RdfsVocabulary::Label.to_uri(),
+            RdfsVocabulary::Comment.to_uri(),
+        ] {
+            let predicate_node = Node::UriNode {
+                uri: predicate.clone(),
+            };
+
+            let mut literals: Vec<&String> = vocabulary
+                .get_triples_with_subject_and_predicate(&subject, &predicate_node)
+                .into_iter()
+                .filter_map(|triple| match *triple.object() {
+                    Node::LiteralNode { ref literal, .. } => Some(literal),
+                    _ => None,
+                })
+                .collect();
+            literals.sort();
+
+            if let Some(literal) = literals.first() {
+                values
+                    .entry(predicate.clone())
+                    .or_insert_with(|| literal.to_string());
+            }
+        }
+    }
+
+    (
+        values.remove(&RdfsVocabulary::Label.to_uri()),
+        values.remove(&RdfsVocabulary::Comment.to_uri()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::lang::*;
+
+    #[test]
+    fn positions_count_utf16_code_units() {
+        let text = "_:a <http://example.org/p> \"😀\" .\n_:b";
+
+        for offset in &[0, 27, 32, 33, 34, text.len()] {
+            let position = Position::from_offset(text, *offset);
+            assert_eq!(position.to_offset(text), *offset);
+        }
+
+        assert_eq!(Position::from_offset(text, 32).character(), 30);
+        assert_eq!(Position::new(5, 0).to_offset(text), text.len());
+
+        // offsets inside the emoji are moved to its start
+        for offset in 29..32 {
+            assert_eq!(Position::from_offset(text, offset), Position::new(0, 28));
+        }
+    }
+
+    #[test]
+    fn symbols_and_diagnostics_of_invalid_document() {
+        let document =
+            TurtleDocument::parse("BASE <http://example.org/>\n[ <p> <o> ] <p> <o> .\n<s> <p> .");
+
+        let symbols = document_symbols(&document);
+
+        assert_eq!(symbols.len(), 3);
+        assert_eq!(symbols[0].kind(), SymbolKind::Base);
+        assert_eq!(symbols[0].name(), "<http://example.org/>");
+        assert_eq!(symbols[1].name(), "[");
+        assert_eq!(symbols[2].name(), "<s>");
+
+        let diagnostics = diagnostics(&document);
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].0.start(), Position::new(2, 0));
+        assert_eq!(diagnostics[0].0.end(), Position::new(2, 9));
+    }
+}
@@ -9,14 +9,26 @@
 //!
 //! Currently supported RDF formats are:
 //!
-//! * Turtle
-//! * N-Triples
-//!
+//! * Turtle and TriG (feature `turtle`)
+//! * N-Triples and N-Quads (feature `ntriples`)
+//! * RDF/XML (feature `xml`)
+//! * JSON-LD (feature `jsonld`)
+//!
+//! The formats, the SPARQL solution modifiers (feature `query`), SPARQL endpoint stores
+//! (feature `http`) and the combined graph stores (feature `store`) are opt-in. Without any
+//! features only the RDF model, the graph and the modules that do not depend on an RDF syntax
+//! are compiled:
+//!
+//! ```toml
+//! [dependencies]
+//! rdf = { version = "0.1", features = ["ntriples", "turtle"] }
+//! ```
 //!
 //! ## Usage
 //!
 //! Each module contains a comprehensive documentation with usage examples.
 //! In the following, only the basic functionality is introduced.
+//! The commonly used types and traits can be imported at once with `use rdf::prelude::*;`.
 //!
 //! RDF triples can be stored and represented in a graph.
 //!
@@ -38,6 +50,8 @@
 //! RDF graphs can be serialized to a supported format.
 //!
 //! ```
+//! # #[cfg(feature = "ntriples")]
+//! # {
 //! use rdf::writer::n_triples_writer::NTriplesWriter;
 //! use rdf::writer::rdf_writer::RdfWriter;
 //! use rdf::graph::Graph;
@@ -56,11 +70,14 @@
 //!
 //! assert_eq!(writer.write_to_string(&graph).unwrap(),
 //!            "_:auto0 <http://example.org/show/localName> _:auto1 .\n".to_string());
+//! # }
 //! ```
 //!
 //! RDF syntax can also be parsed and transformed into an RDF graph.
 //!
 //! ```
+//! # #[cfg(feature = "turtle")]
+//! # {
 //! use rdf::reader::turtle_parser::TurtleParser;
 //! use rdf::reader::rdf_parser::RdfParser;
 //! use rdf::uri::Uri;
@@ -83,48 +100,143 @@
 //!   },
 //!   Err(_) => assert!(false)
 //! }
+//! # }
 //! ```
 
 use std::result;
 
+pub mod analytics;
+#[cfg(feature = "arrow")]
+pub mod arrow_export;
+#[cfg(all(feature = "ntriples", feature = "turtle"))]
+pub mod catalog;
+pub mod change_log;
+pub mod constraint;
+pub mod dataset;
+pub mod decimal;
+pub mod describe;
+pub mod diagram;
+#[cfg(feature = "store")]
+pub mod entailment;
 pub mod error;
+pub mod fingerprint;
 pub mod graph;
+#[cfg(feature = "jsonld")]
+pub mod jsonld;
+#[cfg(feature = "turtle")]
+pub mod lang;
+pub mod lint;
+pub mod literals;
+pub mod metrics;
 pub mod namespace;
 pub mod node;
+pub mod ontology;
+#[cfg(feature = "petgraph")]
+pub mod petgraph_interop;
+pub mod prelude;
+pub mod property_graph;
+pub mod schema_induction;
+pub mod search_export;
+pub mod shacl;
+#[cfg(feature = "query")]
+pub mod solutions;
+#[cfg(feature = "http")]
+pub mod sparql_store;
+pub mod store;
+pub mod table;
+pub mod temporal;
 pub mod triple;
 pub mod uri;
+pub mod void;
+
+/// Result of the operations of this crate, the error defaults to `rdf::Error`.
+///
+/// `rdf::Error` is `Send + Sync + 'static`, so it can be propagated with `?` into
+/// `Box<dyn std::error::Error + Send + Sync>` or `anyhow::Error`.
+pub type Result<T, E = error::Error> = result::Result<T, E>;
 
-pub type Result<T> = result::Result<T, error::Error>;
+/// Returns an empty graph without base URI.
+///
+/// # Examples
+///
+/// ```
+/// assert!(rdf::graph().is_empty());
+/// ```
+pub fn graph() -> graph::Graph {
+    graph::Graph::new(None)
+}
+
+/// Returns the URI of the string.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(rdf::uri("http://example.org/").to_string(), "http://example.org/");
+/// ```
+pub fn uri<S: Into<String>>(uri: S) -> uri::Uri {
+    uri::Uri::new(uri.into())
+}
 
 pub mod writer {
     pub mod formatter {
         pub mod n_triples_formatter;
         pub mod rdf_formatter;
+        #[cfg(feature = "turtle")]
         pub mod turtle_formatter;
     }
 
+    #[cfg(feature = "jsonld")]
+    pub mod json_ld_writer;
+    #[cfg(feature = "ntriples")]
+    pub mod n_quads_writer;
+    #[cfg(feature = "ntriples")]
     pub mod n_triples_writer;
     pub mod rdf_writer;
+    #[cfg(feature = "turtle")]
+    pub mod trig_writer;
+    #[cfg(feature = "turtle")]
     pub mod turtle_writer;
+    pub mod writer_options;
 }
 
 pub mod reader {
     pub mod lexer {
+        #[cfg(feature = "ntriples")]
         pub mod n_triples_lexer;
         pub mod rdf_lexer;
         pub mod token;
+        #[cfg(feature = "turtle")]
         pub mod turtle_lexer;
     }
 
+    #[cfg(feature = "turtle")]
+    pub mod incremental_parser;
     pub mod input_reader;
+    #[cfg(feature = "jsonld")]
+    pub mod jsonld_parser;
+    #[cfg(feature = "ntriples")]
+    pub mod n_quads_parser;
+    #[cfg(feature = "ntriples")]
     pub mod n_triples_parser;
+    pub mod parser_config;
     pub mod rdf_parser;
+    #[cfg(feature = "xml")]
+    pub mod rdf_xml_parser;
+    #[cfg(feature = "turtle")]
+    pub mod trig_parser;
+    #[cfg(feature = "turtle")]
     pub mod turtle_parser;
 }
 
 pub mod specs {
+    pub mod json_specs;
+    pub mod owl_specs;
     pub mod rdf_syntax_specs;
+    pub mod rdfs_specs;
+    pub mod schema_specs;
+    pub mod shacl_specs;
     pub mod turtle_specs;
+    pub mod void_specs;
     pub mod xml_specs;
 }
 
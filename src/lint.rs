@@ -0,0 +1,306 @@
+use crate::graph::Graph;
+use crate::node::Node;
+use crate::specs::rdf_syntax_specs::RdfSyntaxDataTypes;
+use crate::specs::turtle_specs::TurtleSpecs;
+use crate::triple::Triple;
+use crate::uri::Uri;
+use std::collections::HashSet;
+
+/// Types of modeling errors that are reported by the graph checker.
+#[derive(Debug, PartialEq, Clone)]
+pub enum LintKind {
+    /// A literal is used as subject of a triple.
+    LiteralSubject,
+
+    /// A blank node or literal is used as predicate of a triple.
+    InvalidPredicate,
+
+    /// A literal without data type and language looks like a number.
+    UntypedNumericLiteral,
+
+    /// A class is used with `rdf:type` that is not defined by the vocabulary of its namespace.
+    UndefinedClass,
+
+    /// A property is used that is not defined by the vocabulary of its namespace.
+    UndefinedProperty,
+
+    /// A blank node is referenced as object but is never described by any triple.
+    OrphanBlankNode,
+}
+
+/// A modeling error found in a graph.
+#[derive(Debug, PartialEq, Clone)]
+pub struct LintIssue {
+    kind: LintKind,
+    triple: Triple,
+}
+
+impl LintIssue {
+    /// Constructor of `LintIssue`.
+    pub fn new(kind: LintKind, triple: &Triple) -> LintIssue {
+        LintIssue {
+            kind,
+            triple: triple.clone(),
+        }
+    }
+
+    /// Returns the type of the issue.
+    pub fn kind(&self) -> &LintKind {
+        &self.kind
+    }
+
+    /// Returns the triple that caused the issue.
+    pub fn triple(&self) -> &Triple {
+        &self.triple
+    }
+}
+
+/// Checks the graph for common modeling errors.
+///
+/// Classes and properties are not checked since no vocabularies are provided,
+/// use `check_with_vocabularies` for that.
+///
+/// # Examples
+///
+/// ```
+/// use rdf::graph::Graph;
+/// use rdf::lint::{self, LintKind};
+/// use rdf::triple::Triple;
+/// use rdf::uri::Uri;
+///
+/// let mut graph = Graph::new(None);
+///
+/// let subject = graph.create_uri_node(&Uri::new("http://example.org/a".to_string()));
+/// let predicate = graph.create_uri_node(&Uri::new("http://example.org/age".to_string()));
+/// let object = graph.create_literal_node("42".to_string());
+///
+/// graph.add_triple(&Triple::new(&subject, &predicate, &object));
+///
+/// let issues = lint::check(&graph);
+///
+/// assert_eq!(issues.len(), 1);
+/// assert_eq!(issues[0].kind(), &LintKind::UntypedNumericLiteral);
+/// ```
+pub fn check(graph: &Graph) -> Vec<LintIssue> {
+    check_with_vocabularies(graph, &[])
+}
+
+/// Checks the graph for common modeling errors including classes and properties that
+/// are not defined by the provided vocabularies.
+///
+/// A term is only checked if its namespace is the namespace of at least one term
+/// that is described in the vocabularies.
+///
+/// # Examples
+///
+/// ```
+/// use rdf::graph::Graph;
+/// use rdf::lint::{self, LintKind};
+/// use rdf::triple::Triple;
+/// use rdf::uri::Uri;
+///
+/// let mut vocabulary = Graph::new(None);
+/// let name = vocabulary.create_uri_node(&Uri::new("http://xmlns.com/foaf/0.1/name".to_string()));
+/// let label = vocabulary.create_uri_node(&Uri::new("http://www.w3.org/2000/01/rdf-schema#label".to_string()));
+/// let name_label = vocabulary.create_literal_node("name".to_string());
+/// vocabulary.add_triple(&Triple::new(&name, &label, &name_label));
+///
+/// let mut graph = Graph::new(None);
+/// let subject = graph.create_blank_node();
+/// let nick = graph.create_uri_node(&Uri::new("http://xmlns.com/foaf/0.1/nickname".to_string()));
+/// let object = graph.create_literal_node("Art".to_string());
+/// graph.add_triple(&Triple::new(&subject, &nick, &object));
+///
+/// let issues = lint::check_with_vocabularies(&graph, &[&vocabulary]);
+///
+/// assert_eq!(issues.len(), 1);
+/// assert_eq!(issues[0].kind(), &LintKind::UndefinedProperty);
+/// ```
+pub fn check_with_vocabularies(graph: &Graph, vocabularies: &[&Graph]) -> Vec<LintIssue> {
+    let mut issues = Vec::new();
+
+    // terms and namespaces described by the vocabularies
+    let mut defined_terms: HashSet<&Uri> = HashSet::new();
+    let mut vocabulary_namespaces: HashSet<&str> = HashSet::new();
+
+    for vocabulary in vocabularies {
+        for triple in vocabulary.triples_iter() {
+            if let Node::UriNode { ref uri } = *triple.subject() {
+                defined_terms.insert(uri);
+                vocabulary_namespaces.insert(namespace_of(uri));
+            }
+        }
+    }
+
+    let is_undefined = |uri: &Uri| {
+        vocabulary_namespaces.contains(namespace_of(uri)) && !defined_terms.contains(uri)
+    };
+
+    // blank nodes that are described by at least one triple
+    let described_blank_nodes: HashSet<&Node> = graph
+        .triples_iter()
+        .map(|t| t.subject())
+        .filter(|n| matches!(**n, Node::BlankNode { .. }))
+        .collect();
+
+    let rdf_type = RdfSyntaxDataTypes::A.to_uri();
+
+    for triple in graph.triples_iter() {
+        if let Node::LiteralNode { .. } = *triple.subject() {
+            issues.push(LintIssue::new(LintKind::LiteralSubject, triple));
+        }
+
+        match *triple.predicate() {
+            Node::UriNode { ref uri } => {
+                if is_undefined(uri) {
+                    issues.push(LintIssue::new(LintKind::UndefinedProperty, triple));
+                }
+            }
+            _ => issues.push(LintIssue::new(LintKind::InvalidPredicate, triple)),
+        }
+
+        match *triple.object() {
+            Node::LiteralNode {
+                ref literal,
+                data_type: None,
+                language: None,
+            } if is_numeric_looking(literal) => {
+                issues.push(LintIssue::new(LintKind::UntypedNumericLiteral, triple));
+            }
+            Node::UriNode { ref uri } => {
+                let is_type_triple = match *triple.predicate() {
+                    Node::UriNode { uri: ref p } => *p == rdf_type,
+                    _ => false,
+                };
+
+                if is_type_triple && is_undefined(uri) {
+                    issues.push(LintIssue::new(LintKind::UndefinedClass, triple));
+                }
+            }
+            Node::BlankNode { .. } if !described_blank_nodes.contains(triple.object()) => {
+                issues.push(LintIssue::new(LintKind::OrphanBlankNode, triple));
+            }
+            _ => {}
+        }
+    }
+
+    issues
+}
+
+/// Returns the namespace part of the URI, which ends with the last '#' or '/'.
+fn namespace_of(uri: &Uri) -> &str {
+    let uri = uri.to_string();
+
+    match uri.rfind(['#', '/']) {
+        Some(i) => &uri[..=i],
+        None => uri,
+    }
+}
+
+/// Returns `true` if the literal would be parsed as number.
+fn is_numeric_looking(literal: &str) -> bool {
+    // `f64` parsing also accepts "inf" and "NaN" which are unlikely to be intended as numbers
+    literal.chars().any(|c| c.is_ascii_digit()) && TurtleSpecs::is_double_literal(literal.trim())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::Graph;
+    use crate::lint::*;
+    use crate::triple::Triple;
+    use crate::uri::Uri;
+
+    #[test]
+    fn lint_clean_graph() {
+        let mut graph = Graph::new(None);
+
+        let subject = graph.create_blank_node();
+        let predicate = graph.create_uri_node(&Uri::new("http://example.org/name".to_string()));
+        let object = graph.create_literal_node("Art".to_string());
+
+        graph.add_triple(&Triple::new(&subject, &predicate, &object));
+
+        assert!(check(&graph).is_empty());
+    }
+
+    #[test]
+    fn lint_invalid_positions() {
+        let mut graph = Graph::new(None);
+
+        let literal = graph.create_literal_node("a".to_string());
+        let blank = graph.create_blank_node();
+        let uri = graph.create_uri_node(&Uri::new("http://example.org/a".to_string()));
+
+        graph.add_triple(&Triple::new(&literal, &uri, &uri));
+        graph.add_triple(&Triple::new(&uri, &blank, &uri));
+
+        let kinds: Vec<LintKind> = check(&graph).iter().map(|i| i.kind().clone()).collect();
+
+        assert_eq!(
+            kinds,
+            vec![LintKind::LiteralSubject, LintKind::InvalidPredicate]
+        );
+    }
+
+    #[test]
+    fn lint_orphan_blank_nodes() {
+        let mut graph = Graph::new(None);
+
+        let described = graph.create_blank_node();
+        let orphan = graph.create_blank_node();
+        let uri = graph.create_uri_node(&Uri::new("http://example.org/a".to_string()));
+
+        graph.add_triple(&Triple::new(&uri, &uri, &described));
+        graph.add_triple(&Triple::new(&described, &uri, &orphan));
+
+        let issues = check(&graph);
+
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].kind(), &LintKind::OrphanBlankNode);
+        assert_eq!(issues[0].triple().object(), &orphan);
+    }
+
+    #[test]
+    fn lint_numeric_looking_literals() {
+        let mut graph = Graph::new(None);
+
+        let subject = graph.create_blank_node();
+        let predicate = graph.create_uri_node(&Uri::new("http://example.org/a".to_string()));
+
+        for literal in &["12", "-1.5e3", "NaN", "inf", "12 apples"] {
+            let object = graph.create_literal_node(literal.to_string());
+            graph.add_triple(&Triple::new(&subject, &predicate, &object));
+        }
+
+        assert_eq!(check(&graph).len(), 2);
+    }
+
+    #[test]
+    fn lint_undefined_classes() {
+        let mut vocabulary = Graph::new(None);
+        let person =
+            vocabulary.create_uri_node(&Uri::new("http://xmlns.com/foaf/0.1/Person".to_string()));
+        let rdf_type = vocabulary.create_uri_node(&Uri::new(
+            "http://www.w3.org/1999/02/22-rdf-syntax-ns#type".to_string(),
+        ));
+        let class = vocabulary.create_uri_node(&Uri::new(
+            "http://www.w3.org/2000/01/rdf-schema#Class".to_string(),
+        ));
+        vocabulary.add_triple(&Triple::new(&person, &rdf_type, &class));
+
+        let mut graph = Graph::new(None);
+        let subject = graph.create_blank_node();
+        let persn = graph.create_uri_node(&Uri::new("http://xmlns.com/foaf/0.1/Persn".to_string()));
+        let other = graph.create_uri_node(&Uri::new("http://example.org/Thing".to_string()));
+
+        graph.add_triple(&Triple::new(&subject, &rdf_type, &person));
+        graph.add_triple(&Triple::new(&subject, &rdf_type, &persn));
+        graph.add_triple(&Triple::new(&subject, &rdf_type, &other));
+
+        let issues = check_with_vocabularies(&graph, &[&vocabulary]);
+
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].kind(), &LintKind::UndefinedClass);
+        assert_eq!(issues[0].triple().object(), &persn);
+    }
+}
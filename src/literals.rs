@@ -0,0 +1,335 @@
+use crate::graph::Graph;
+use crate::node::Node;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Returns the literals whose language tag matches the language range.
+///
+/// Implements the basic filtering of RFC 4647, i.e. the range `de` matches the tags `de` and
+/// `de-CH` and the range `*` matches all literals with a language tag. Tags are compared
+/// case-insensitively, non-literal nodes are never matched.
+///
+/// # Examples
+///
+/// ```
+/// use rdf::literals;
+/// use rdf::node::Node;
+///
+/// let literal = |value: &str, language: &str| Node::LiteralNode {
+///     literal: value.to_string(),
+///     data_type: None,
+///     language: Some(language.to_string()),
+/// };
+///
+/// let labels = vec![literal("Farbe", "de"), literal("Farbe", "de-CH"), literal("colour", "en-GB")];
+///
+/// assert_eq!(literals::filter_by_language(&labels, "DE").len(), 2);
+/// assert_eq!(literals::filter_by_language(&labels, "en").len(), 1);
+/// assert_eq!(literals::filter_by_language(&labels, "*").len(), 3);
+/// ```
+pub fn filter_by_language<'a, I>(candidates: I, language_range: &str) -> Vec<&'a Node>
+where
+    I: IntoIterator<Item = &'a Node>,
+{
+    let range = language_range.trim().to_lowercase();
+
+    candidates
+        .into_iter()
+        .filter(|candidate| match language(candidate) {
+            Some(tag) => {
+                let tag = tag.to_lowercase();
+                range == "*" || tag == range || tag.starts_with(&(range.clone() + "-"))
+            }
+            None => false,
+        })
+        .collect()
+}
+
+/// Selects the literal that matches the languages of an HTTP `Accept-Language` header best.
+///
+/// Implements the lookup of RFC 4647: the language ranges are tried in the order of their
+/// quality values and each range is shortened subtag by subtag until a literal with that exact
+/// language tag is found. Ranges with quality `0` are excluded. If nothing matches, or for the
+/// range `*`, a literal without language tag is returned as default.
+///
+/// # Examples
+///
+/// ```
+/// use rdf::literals;
+/// use rdf::node::Node;
+///
+/// let literal = |value: &str, language: Option<&str>| Node::LiteralNode {
+///     literal: value.to_string(),
+///     data_type: None,
+///     language: language.map(|l| l.to_string()),
+/// };
+///
+/// let labels = vec![literal("color", None), literal("colour", Some("en-GB")), literal("Farbe", Some("de"))];
+///
+/// assert_eq!(literals::best_language_match(&labels, "de-CH, en;q=0.8"), Some(&labels[2]));
+/// assert_eq!(literals::best_language_match(&labels, "en-GB;q=0.5, de;q=0.4"), Some(&labels[1]));
+/// assert_eq!(literals::best_language_match(&labels, "fr, de;q=0"), Some(&labels[0]));
+/// ```
+pub fn best_language_match<'a, I>(candidates: I, accept_language_header: &str) -> Option<&'a Node>
+where
+    I: IntoIterator<Item = &'a Node>,
+{
+    let candidates: Vec<&Node> = candidates
+        .into_iter()
+        .filter(|candidate| matches!(**candidate, Node::LiteralNode { .. }))
+        .collect();
+
+    let default = candidates
+        .iter()
+        .find(|candidate| language(candidate).is_none())
+        .cloned();
+
+    for range in language_ranges(accept_language_header) {
+        if range == "*" {
+            return default.or_else(|| candidates.first().cloned());
+        }
+
+        let mut range = range.as_str();
+
+        loop {
+            let matching = candidates
+                .iter()
+                .find(|candidate| match language(candidate) {
+                    Some(tag) => tag.eq_ignore_ascii_case(range),
+                    None => false,
+                });
+
+            if let Some(candidate) = matching {
+                return Some(candidate);
+            }
+
+            // remove the last subtag and a single-character subtag that precedes it
+            range = match range.rfind('-') {
+                Some(i) => &range[..i],
+                None => break,
+            };
+
+            if range.len() >= 2 && range.as_bytes()[range.len() - 2] == b'-' {
+                range = &range[..range.len() - 2];
+            }
+        }
+    }
+
+    default
+}
+
+/// Returns the language ranges of an `Accept-Language` header ordered by quality.
+///
+/// Ranges with quality `0` or an invalid quality value are left out.
+fn language_ranges(accept_language_header: &str) -> Vec<String> {
+    let mut ranges: Vec<(String, f32)> = accept_language_header
+        .split(',')
+        .filter_map(|entry| {
+            let mut parts = entry.split(';');
+            let range = parts.next()?.trim().to_lowercase();
+
+            let mut quality = 1.0;
+
+            for parameter in parts {
+                let parameter = parameter.trim();
+
+                if let Some(value) = parameter
+                    .strip_prefix("q=")
+                    .or_else(|| parameter.strip_prefix("Q="))
+                {
+                    quality = value.trim().parse::<f32>().ok()?;
+                }
+            }
+
+            if range.is_empty() || quality <= 0.0 || quality > 1.0 {
+                None
+            } else {
+                Some((range, quality))
+            }
+        })
+        .collect();
+
+    // the sort is stable, ranges with equal quality keep their order
+    ranges.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(::std::cmp::Ordering::Equal));
+    ranges.into_iter().map(|(range, _)| range).collect()
+}
+
+/// Number of subjects that carry a label per language tag.
+///
+/// Language tags are compared case-insensitively and reported in lowercase.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LanguageCoverage {
+    subjects: BTreeSet<Node>,
+    languages: BTreeMap<String, BTreeSet<Node>>,
+}
+
+impl LanguageCoverage {
+    /// Returns the number of subjects with at least one label, with or without language tag.
+    pub fn labeled_subjects(&self) -> usize {
+        self.subjects.len()
+    }
+
+    /// Returns the language tags of the labels in lowercase and sorted.
+    pub fn languages(&self) -> Vec<&str> {
+        self.languages
+            .keys()
+            .map(|language| language.as_str())
+            .collect()
+    }
+
+    /// Returns the number of subjects with a label in the language.
+    pub fn count(&self, language: &str) -> usize {
+        self.languages
+            .get(&language.to_lowercase())
+            .map_or(0, |subjects| subjects.len())
+    }
+
+    /// Returns the share of labeled subjects that have a label in the language.
+    ///
+    /// Returns `0.0` if no subject has a label.
+    pub fn coverage(&self, language: &str) -> f64 {
+        if self.subjects.is_empty() {
+            return 0.0;
+        }
+
+        self.count(language) as f64 / self.subjects.len() as f64
+    }
+
+    /// Returns the labeled subjects without a label in the language, in order.
+    pub fn missing(&self, language: &str) -> Vec<&Node> {
+        match self.languages.get(&language.to_lowercase()) {
+            Some(translated) => self.subjects.difference(translated).collect(),
+            None => self.subjects.iter().collect(),
+        }
+    }
+}
+
+/// Reports per language tag how many subjects carry a label.
+///
+/// Labels are the literal objects of the label predicates, like `rdfs:label`. Subjects
+/// with several labels in one language are counted once.
+///
+/// # Examples
+///
+/// ```
+/// use rdf::graph::Graph;
+/// use rdf::literals;
+/// use rdf::triple::Triple;
+/// use rdf::uri::Uri;
+///
+/// let mut graph = Graph::new(None);
+///
+/// let label = graph.create_uri_node(&Uri::new("http://www.w3.org/2000/01/rdf-schema#label".to_string()));
+/// let red = graph.create_uri_node(&Uri::new("http://example.org/red".to_string()));
+/// let blue = graph.create_uri_node(&Uri::new("http://example.org/blue".to_string()));
+///
+/// let labels = vec![(&red, "red", "en"), (&red, "rot", "de"), (&blue, "blue", "en")];
+///
+/// for (subject, literal, language) in labels {
+///     let object = graph.create_literal_node_with_language(literal.to_string(), language.to_string());
+///     graph.add_triple(&Triple::new(subject, &label, &object));
+/// }
+///
+/// let coverage = literals::language_coverage(&graph, &[label]);
+///
+/// assert_eq!(coverage.languages(), vec!["de", "en"]);
+/// assert_eq!(coverage.count("en"), 2);
+/// assert_eq!(coverage.coverage("de"), 0.5);
+/// assert_eq!(coverage.missing("de"), vec![&blue]);
+/// ```
+pub fn language_coverage(graph: &Graph, label_predicates: &[Node]) -> LanguageCoverage {
+    let mut subjects = BTreeSet::new();
+    let mut languages: BTreeMap<String, BTreeSet<Node>> = BTreeMap::new();
+
+    for predicate in label_predicates {
+        for triple in graph.get_triples_with_predicate(predicate) {
+            if let Node::LiteralNode { .. } = *triple.object() {
+                subjects.insert(triple.subject().clone());
+            }
+
+            if let Some(language) = language(triple.object()) {
+                languages
+                    .entry(language.to_lowercase())
+                    .or_default()
+                    .insert(triple.subject().clone());
+            }
+        }
+    }
+
+    LanguageCoverage {
+        subjects,
+        languages,
+    }
+}
+
+/// Returns the language tag of a literal.
+fn language(node: &Node) -> Option<&str> {
+    match *node {
+        Node::LiteralNode {
+            language: Some(ref language),
+            ..
+        } => Some(language),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::literals::*;
+
+    fn literal(language: Option<&str>) -> Node {
+        Node::LiteralNode {
+            literal: "label".to_string(),
+            data_type: None,
+            language: language.map(|l| l.to_string()),
+        }
+    }
+
+    #[test]
+    fn lookup_truncates_ranges_and_skips_single_character_subtags() {
+        let labels = vec![literal(Some("zh")), literal(Some("zh-Hant"))];
+
+        assert_eq!(
+            best_language_match(&labels, "zh-Hant-CN-x-private1"),
+            Some(&labels[1])
+        );
+        assert_eq!(best_language_match(&labels, "zh-Hans-CN"), Some(&labels[0]));
+        assert_eq!(
+            best_language_match(&labels, "fr;q=abc, *"),
+            Some(&labels[0])
+        );
+        assert_eq!(best_language_match(&labels, "fr"), None);
+        assert_eq!(best_language_match(&labels, ""), None);
+    }
+
+    #[test]
+    fn language_coverage_counts_subjects_once() {
+        use crate::triple::Triple;
+        use crate::uri::Uri;
+
+        let mut graph = Graph::new(None);
+        let uri = |uri: &str| Node::UriNode {
+            uri: Uri::new(uri.to_string()),
+        };
+        let label = uri("http://www.w3.org/2000/01/rdf-schema#label");
+        let name = uri("http://xmlns.com/foaf/0.1/name");
+        let (a, b, c) = (
+            uri("http://example.org/a"),
+            uri("http://example.org/b"),
+            uri("http://example.org/c"),
+        );
+
+        graph.add_triple(&Triple::new(&a, &label, &literal(Some("en"))));
+        graph.add_triple(&Triple::new(&a, &name, &literal(Some("EN"))));
+        graph.add_triple(&Triple::new(&b, &label, &literal(None)));
+        graph.add_triple(&Triple::new(&c, &label, &uri("http://example.org/label")));
+
+        let coverage = language_coverage(&graph, &[label, name]);
+
+        assert_eq!(coverage.labeled_subjects(), 2);
+        assert_eq!(coverage.languages(), vec!["en"]);
+        assert_eq!(coverage.count("En"), 1);
+        assert_eq!(coverage.missing("en"), vec![&b]);
+        assert_eq!(coverage.missing("fr").len(), 2);
+        assert_eq!(language_coverage(&graph, &[]).coverage("en"), 0.0);
+    }
+}
@@ -0,0 +1,193 @@
+use crate::graph::Graph;
+use crate::reader::rdf_parser::RdfParser;
+use crate::Result;
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
+
+/// Number of stored triples.
+pub const TRIPLES_STORED: &str = "rdf_triples_stored";
+
+/// Number of parsed documents that contained invalid RDF.
+pub const PARSE_ERRORS: &str = "rdf_parse_errors_total";
+
+/// Time needed to parse a document.
+pub const PARSE_DURATION: &str = "rdf_parse_duration_seconds";
+
+/// Upper bounds in seconds of the buckets of duration histograms.
+const DURATION_BUCKETS: [f64; 8] = [0.001, 0.005, 0.01, 0.05, 0.1, 0.5, 1.0, 5.0];
+
+/// Trait implemented by collectors of counters, gauges and histograms.
+pub trait Metrics {
+    /// Increases the counter by the provided value.
+    fn increment_counter(&mut self, name: &str, value: u64);
+
+    /// Sets the gauge to the provided value.
+    fn set_gauge(&mut self, name: &str, value: f64);
+
+    /// Adds the duration to the histogram.
+    fn observe_duration(&mut self, name: &str, duration: Duration);
+}
+
+/// Sets the gauge of the number of triples stored in the graph.
+pub fn record_graph<M: Metrics>(metrics: &mut M, graph: &Graph) {
+    metrics.set_gauge(TRIPLES_STORED, graph.count() as f64);
+}
+
+/// Decodes the input of the parser while recording the parse duration and errors.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "ntriples")]
+/// # {
+/// use rdf::metrics::{self, PrometheusMetrics};
+/// use rdf::reader::n_triples_parser::NTriplesParser;
+///
+/// let mut metrics = PrometheusMetrics::new();
+///
+/// let mut parser = NTriplesParser::from_string("_:a <http://example.org/p> \"o\" .");
+/// let graph = metrics::record_parse(&mut metrics, &mut parser).unwrap();
+///
+/// metrics::record_graph(&mut metrics, &graph);
+///
+/// assert!(metrics.to_text().contains("rdf_triples_stored 1\n"));
+/// assert!(metrics.to_text().contains("rdf_parse_duration_seconds_count 1\n"));
+/// # }
+/// ```
+///
+/// # Failures
+///
+/// - The parser fails to decode its input.
+///
+pub fn record_parse<M: Metrics, P: RdfParser>(metrics: &mut M, parser: &mut P) -> Result<Graph> {
+    let start = Instant::now();
+    let result = parser.decode();
+
+    metrics.observe_duration(PARSE_DURATION, start.elapsed());
+
+    if result.is_err() {
+        metrics.increment_counter(PARSE_ERRORS, 1);
+    }
+
+    result
+}
+
+/// Histogram of durations with fixed buckets.
+#[derive(Debug, Default, Clone)]
+struct Histogram {
+    buckets: [u64; 8],
+    count: u64,
+    sum: f64,
+}
+
+/// Metrics collector that exports the Prometheus text format.
+#[derive(Debug, Default, Clone)]
+pub struct PrometheusMetrics {
+    counters: BTreeMap<String, u64>,
+    gauges: BTreeMap<String, f64>,
+    histograms: BTreeMap<String, Histogram>,
+}
+
+impl Metrics for PrometheusMetrics {
+    fn increment_counter(&mut self, name: &str, value: u64) {
+        *self.counters.entry(name.to_string()).or_insert(0) += value;
+    }
+
+    fn set_gauge(&mut self, name: &str, value: f64) {
+        self.gauges.insert(name.to_string(), value);
+    }
+
+    fn observe_duration(&mut self, name: &str, duration: Duration) {
+        let seconds = duration.as_secs_f64();
+        let histogram = self.histograms.entry(name.to_string()).or_default();
+
+        for (bucket, bound) in histogram.buckets.iter_mut().zip(DURATION_BUCKETS.iter()) {
+            if seconds <= *bound {
+                *bucket += 1;
+            }
+        }
+
+        histogram.count += 1;
+        histogram.sum += seconds;
+    }
+}
+
+impl PrometheusMetrics {
+    /// Constructor of `PrometheusMetrics`.
+    pub fn new() -> PrometheusMetrics {
+        PrometheusMetrics::default()
+    }
+
+    /// Returns the value of the counter, counters that were never incremented are zero.
+    pub fn counter(&self, name: &str) -> u64 {
+        self.counters.get(name).cloned().unwrap_or(0)
+    }
+
+    /// Returns the value of the gauge if it was set.
+    pub fn gauge(&self, name: &str) -> Option<f64> {
+        self.gauges.get(name).cloned()
+    }
+
+    /// Returns all metrics in the Prometheus text exposition format.
+    pub fn to_text(&self) -> String {
+        let mut output_string = "".to_string();
+
+        for (name, value) in &self.counters {
+            output_string.push_str(&format!("# TYPE {} counter\n{} {}\n", name, name, value));
+        }
+
+        for (name, value) in &self.gauges {
+            output_string.push_str(&format!("# TYPE {} gauge\n{} {}\n", name, name, value));
+        }
+
+        for (name, histogram) in &self.histograms {
+            output_string.push_str(&format!("# TYPE {} histogram\n", name));
+
+            for (bucket, bound) in histogram.buckets.iter().zip(DURATION_BUCKETS.iter()) {
+                output_string
+                    .push_str(&format!("{}_bucket{{le=\"{}\"}} {}\n", name, bound, bucket));
+            }
+
+            output_string.push_str(&format!(
+                "{}_bucket{{le=\"+Inf\"}} {}\n{}_sum {}\n{}_count {}\n",
+                name, histogram.count, name, histogram.sum, name, histogram.count
+            ));
+        }
+
+        output_string
+    }
+}
+
+#[cfg(all(test, feature = "ntriples"))]
+mod tests {
+    use crate::metrics::*;
+    use crate::reader::n_triples_parser::NTriplesParser;
+
+    #[test]
+    fn record_parse_errors() {
+        let mut metrics = PrometheusMetrics::new();
+
+        let mut parser = NTriplesParser::from_string("_:a \"p\" \"o\" .");
+        assert!(record_parse(&mut metrics, &mut parser).is_err());
+
+        assert_eq!(metrics.counter(PARSE_ERRORS), 1);
+        assert_eq!(metrics.gauge(TRIPLES_STORED), None);
+    }
+
+    #[test]
+    fn export_histogram() {
+        let mut metrics = PrometheusMetrics::new();
+
+        metrics.observe_duration("d", Duration::from_millis(20));
+        metrics.observe_duration("d", Duration::from_secs(10));
+
+        let text = metrics.to_text();
+
+        assert!(text.starts_with("# TYPE d histogram\n"));
+        assert!(text.contains("d_bucket{le=\"0.01\"} 0\n"));
+        assert!(text.contains("d_bucket{le=\"0.05\"} 1\n"));
+        assert!(text.contains("d_bucket{le=\"5\"} 1\n"));
+        assert!(text.contains("d_bucket{le=\"+Inf\"} 2\n"));
+        assert!(text.contains("d_count 2\n"));
+    }
+}
@@ -1,7 +1,7 @@
-use Result;
-use error::{Error, ErrorType};
+use crate::error::{Error, ErrorType};
+use crate::uri::Uri;
+use crate::Result;
 use std::collections::HashMap;
-use uri::Uri;
 
 /// Representation of a specific namespace.
 #[derive(Debug)]
@@ -62,7 +62,7 @@ impl Namespace {
 }
 
 /// Storage for multiple namespaces.
-#[derive(PartialEq, Debug, Default)]
+#[derive(PartialEq, Debug, Default, Clone)]
 pub struct NamespaceStore {
     /// The namespace prefix is associated with the namespace URI.
     namespaces: HashMap<String, Uri>,
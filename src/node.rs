@@ -1,7 +1,30 @@
-use uri::Uri;
+use crate::specs::xml_specs::XmlDataTypes;
+use crate::triple::Triple;
+use crate::uri::Uri;
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
 
 /// Node representation.
-#[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug)]
+///
+/// Language tags of literals are compared case-insensitively, as required by RDF 1.1, but
+/// are stored as written, so `"x"@EN-us` and `"x"@en-US` are equal and keep their case when
+/// they are written again.
+///
+/// # Examples
+///
+/// ```
+/// use rdf::node::Node;
+///
+/// let literal = |language: &str| Node::LiteralNode {
+///     literal: "x".to_string(),
+///     data_type: None,
+///     language: Some(language.to_string()),
+/// };
+///
+/// assert_eq!(literal("EN-us"), literal("en-US"));
+/// assert_ne!(literal("en"), literal("en-US"));
+/// ```
+#[derive(Clone, Debug)]
 pub enum Node {
     /// Node for representing a URI.
     UriNode { uri: Uri },
@@ -15,11 +38,204 @@ pub enum Node {
 
     /// Node for representing blanks.
     BlankNode { id: String },
+
+    /// Node for representing quoted triples of RDF-star.
+    TripleNode { triple: Box<Triple> },
+}
+
+impl Node {
+    /// Returns the node with the language tag of a literal in lowercase.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::node::Node;
+    ///
+    /// let node = Node::LiteralNode {
+    ///     literal: "x".to_string(),
+    ///     data_type: None,
+    ///     language: Some("en-US".to_string()),
+    /// };
+    ///
+    /// match node.normalized() {
+    ///     Node::LiteralNode { language, .. } => assert_eq!(language, Some("en-us".to_string())),
+    ///     _ => unreachable!(),
+    /// }
+    /// ```
+    pub fn normalized(&self) -> Node {
+        match *self {
+            Node::LiteralNode {
+                ref literal,
+                ref data_type,
+                ref language,
+            } => Node::LiteralNode {
+                literal: literal.clone(),
+                data_type: data_type.clone(),
+                language: language.as_ref().map(|l| l.to_lowercase()),
+            },
+            _ => self.clone(),
+        }
+    }
+
+    /// Creates an `xsd:hexBinary` literal of the bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::node::Node;
+    ///
+    /// let node = Node::hex_binary_literal(&[0xca, 0xfe]);
+    ///
+    /// assert_eq!(node.as_bytes(), Some(vec![0xca, 0xfe]));
+    /// ```
+    pub fn hex_binary_literal(bytes: &[u8]) -> Node {
+        Node::binary_literal(XmlDataTypes::HexBinary, bytes)
+    }
+
+    /// Creates an `xsd:base64Binary` literal of the bytes.
+    pub fn base64_binary_literal(bytes: &[u8]) -> Node {
+        Node::binary_literal(XmlDataTypes::Base64Binary, bytes)
+    }
+
+    /// Returns the decoded bytes of an `xsd:hexBinary` or `xsd:base64Binary` literal.
+    ///
+    /// Returns `None` if the node is no literal of these data types or the literal is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::node::Node;
+    /// use rdf::specs::xml_specs::XmlDataTypes;
+    ///
+    /// let node = Node::LiteralNode {
+    ///     literal: "3q2+7w==".to_string(),
+    ///     data_type: Some(XmlDataTypes::Base64Binary.to_uri()),
+    ///     language: None,
+    /// };
+    ///
+    /// assert_eq!(node.as_bytes(), Some(vec![0xde, 0xad, 0xbe, 0xef]));
+    /// ```
+    pub fn as_bytes(&self) -> Option<Vec<u8>> {
+        match *self {
+            Node::LiteralNode {
+                ref literal,
+                data_type: Some(ref data_type),
+                ..
+            } => XmlDataTypes::from_uri(data_type)?.decode_binary(literal),
+            _ => None,
+        }
+    }
+
+    /// Creates a literal of a binary data type in its canonical form.
+    fn binary_literal(data_type: XmlDataTypes, bytes: &[u8]) -> Node {
+        Node::LiteralNode {
+            literal: data_type.encode_binary(bytes).unwrap_or_default(),
+            data_type: Some(data_type.to_uri()),
+            language: None,
+        }
+    }
+
+    /// Returns the position of the variant, which orders nodes of different kinds.
+    fn rank(&self) -> u8 {
+        match *self {
+            Node::UriNode { .. } => 0,
+            Node::LiteralNode { .. } => 1,
+            Node::BlankNode { .. } => 2,
+            Node::TripleNode { .. } => 3,
+        }
+    }
+}
+
+/// Compares language tags ignoring ASCII case, without allocating lowercase copies.
+fn cmp_languages(a: &Option<String>, b: &Option<String>) -> Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) if a.eq_ignore_ascii_case(b) => Ordering::Equal,
+        (Some(a), Some(b)) => a
+            .bytes()
+            .map(|c| c.to_ascii_lowercase())
+            .cmp(b.bytes().map(|c| c.to_ascii_lowercase())),
+        _ => a.is_some().cmp(&b.is_some()),
+    }
+}
+
+/// Hashes a language tag consistently with `cmp_languages`.
+fn hash_language<H: Hasher>(language: &Option<String>, state: &mut H) {
+    match language {
+        Some(language) => {
+            state.write_u8(1);
+            language
+                .bytes()
+                .for_each(|c| state.write_u8(c.to_ascii_lowercase()));
+            state.write_usize(language.len());
+        }
+        None => state.write_u8(0),
+    }
+}
+
+impl PartialEq for Node {
+    fn eq(&self, other: &Node) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Node {}
+
+impl PartialOrd for Node {
+    fn partial_cmp(&self, other: &Node) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Node {
+    fn cmp(&self, other: &Node) -> Ordering {
+        match (self, other) {
+            (Node::UriNode { uri: a }, Node::UriNode { uri: b }) => a.cmp(b),
+            (
+                Node::LiteralNode {
+                    literal: a,
+                    data_type: a_type,
+                    language: a_language,
+                },
+                Node::LiteralNode {
+                    literal: b,
+                    data_type: b_type,
+                    language: b_language,
+                },
+            ) => a
+                .cmp(b)
+                .then_with(|| a_type.cmp(b_type))
+                .then_with(|| cmp_languages(a_language, b_language)),
+            (Node::BlankNode { id: a }, Node::BlankNode { id: b }) => a.cmp(b),
+            (Node::TripleNode { triple: a }, Node::TripleNode { triple: b }) => a.cmp(b),
+            _ => self.rank().cmp(&other.rank()),
+        }
+    }
+}
+
+impl Hash for Node {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.rank().hash(state);
+
+        match *self {
+            Node::UriNode { ref uri } => uri.hash(state),
+            Node::LiteralNode {
+                ref literal,
+                ref data_type,
+                ref language,
+            } => {
+                literal.hash(state);
+                data_type.hash(state);
+                hash_language(language, state);
+            }
+            Node::BlankNode { ref id } => id.hash(state),
+            Node::TripleNode { ref triple } => triple.hash(state),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use node::*;
+    use crate::node::*;
 
     #[test]
     fn access_literal_node() {
@@ -38,4 +254,59 @@ mod tests {
             _ => assert!(false),
         }
     }
+
+    #[test]
+    fn language_tags_are_compared_case_insensitively() {
+        use std::collections::HashSet;
+
+        let literal = |language: &str| Node::LiteralNode {
+            literal: "x".to_string(),
+            data_type: None,
+            language: Some(language.to_string()),
+        };
+
+        let mut set = HashSet::new();
+        set.insert(literal("EN-us"));
+
+        assert!(set.contains(&literal("en-US")));
+        assert!(!set.insert(literal("en-us")));
+        assert!(literal("de") < literal("EN"));
+    }
+
+    #[test]
+    fn binary_literals_round_trip() {
+        let data: Vec<u8> = (0..=255).collect();
+
+        for length in 0..6 {
+            let bytes = &data[250 - length..250];
+
+            assert_eq!(Node::hex_binary_literal(bytes).as_bytes().unwrap(), bytes);
+            assert_eq!(
+                Node::base64_binary_literal(bytes).as_bytes().unwrap(),
+                bytes
+            );
+        }
+
+        let base64 = |literal: &str| Node::LiteralNode {
+            literal: literal.to_string(),
+            data_type: Some(XmlDataTypes::Base64Binary.to_uri()),
+            language: None,
+        };
+
+        assert_eq!(
+            base64("\n 3q2+ 7w==\n").as_bytes(),
+            Some(vec![0xde, 0xad, 0xbe, 0xef])
+        );
+        assert_eq!(base64("3q2+7w=").as_bytes(), None);
+        assert_eq!(base64("3q==7w==").as_bytes(), None);
+        assert_eq!(base64("3q2+7===").as_bytes(), None);
+        assert_eq!(base64("3q2-7w==").as_bytes(), None);
+        assert_eq!(
+            Node::BlankNode {
+                id: "a".to_string()
+            }
+            .as_bytes(),
+            None
+        );
+    }
 }
@@ -0,0 +1,332 @@
+#[cfg(all(feature = "ntriples", feature = "turtle"))]
+use crate::error::{Error, ErrorType};
+use crate::graph::Graph;
+use crate::node::Node;
+#[cfg(all(feature = "ntriples", feature = "turtle"))]
+use crate::reader::n_triples_parser::NTriplesParser;
+#[cfg(all(feature = "ntriples", feature = "turtle"))]
+use crate::reader::rdf_parser::RdfParser;
+#[cfg(all(feature = "ntriples", feature = "turtle"))]
+use crate::reader::turtle_parser::TurtleParser;
+use crate::specs::owl_specs::OwlVocabulary;
+use crate::triple::Triple;
+use crate::uri::Uri;
+use crate::Result;
+#[cfg(all(feature = "ntriples", feature = "turtle"))]
+use std::collections::HashMap;
+use std::collections::{HashSet, VecDeque};
+#[cfg(all(feature = "ntriples", feature = "turtle"))]
+use std::fs::File;
+#[cfg(all(feature = "ntriples", feature = "turtle"))]
+use std::path::{Path, PathBuf};
+
+/// Trait implemented by resolvers that retrieve the documents of imported ontologies.
+///
+/// Closures taking the IRI of the document and returning the parsed graph implement this trait.
+pub trait ImportResolver {
+    /// Retrieves and parses the document that is identified by the IRI.
+    fn resolve(&mut self, iri: &Uri) -> Result<Graph>;
+}
+
+impl<F> ImportResolver for F
+where
+    F: FnMut(&Uri) -> Result<Graph>,
+{
+    fn resolve(&mut self, iri: &Uri) -> Result<Graph> {
+        self(iri)
+    }
+}
+
+/// Resolves IRIs to files on the local file system.
+///
+/// IRIs without an explicit location are interpreted as file paths.
+/// Files ending with `.nt` are parsed as N-Triples, all other files as Turtle.
+#[cfg(all(feature = "ntriples", feature = "turtle"))]
+#[derive(Debug, Default)]
+pub struct FileResolver {
+    locations: HashMap<Uri, PathBuf>,
+}
+
+#[cfg(all(feature = "ntriples", feature = "turtle"))]
+impl FileResolver {
+    /// Constructor of `FileResolver`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::ontology::FileResolver;
+    ///
+    /// let resolver = FileResolver::new();
+    /// ```
+    pub fn new() -> FileResolver {
+        FileResolver {
+            locations: HashMap::new(),
+        }
+    }
+
+    /// Maps the IRI to the file at the provided path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::ontology::FileResolver;
+    /// use rdf::uri::Uri;
+    ///
+    /// let mut resolver = FileResolver::new();
+    /// resolver.add_location(&Uri::new("http://xmlns.com/foaf/0.1/".to_string()), "vocab/foaf.ttl");
+    ///
+    /// assert_eq!(resolver.location(&Uri::new("http://xmlns.com/foaf/0.1/".to_string())).unwrap().to_str(),
+    ///            Some("vocab/foaf.ttl"));
+    /// ```
+    pub fn add_location<P: AsRef<Path>>(&mut self, iri: &Uri, path: P) {
+        self.locations
+            .insert(iri.clone(), path.as_ref().to_path_buf());
+    }
+
+    /// Returns the file path the IRI is mapped to.
+    pub fn location(&self, iri: &Uri) -> Option<&PathBuf> {
+        self.locations.get(iri)
+    }
+}
+
+#[cfg(all(feature = "ntriples", feature = "turtle"))]
+impl ImportResolver for FileResolver {
+    fn resolve(&mut self, iri: &Uri) -> Result<Graph> {
+        let path = match self.locations.get(iri) {
+            Some(path) => path.clone(),
+            None => {
+                let iri = iri.to_string();
+                PathBuf::from(iri.trim_start_matches("file://"))
+            }
+        };
+
+        parse_file(&path)
+    }
+}
+
+/// Parses the file at the provided path according to its file extension.
+///
+/// Files ending with `.nt` are parsed as N-Triples, all other files as Turtle.
+///
+/// # Failures
+///
+/// - The file cannot be opened.
+/// - The file contains invalid RDF syntax.
+///
+#[cfg(all(feature = "ntriples", feature = "turtle"))]
+pub fn parse_file(path: &Path) -> Result<Graph> {
+    let file = match File::open(path) {
+        Ok(file) => file,
+        Err(err) => {
+            return Err(Error::new(
+                ErrorType::UnresolvableIri,
+                format!("Cannot open {}.", path.display()),
+            )
+            .with_source(err))
+        }
+    };
+
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("nt") => NTriplesParser::from_reader(file).decode(),
+        _ => TurtleParser::from_reader(file).decode(),
+    }
+}
+
+/// Result of loading an ontology together with all ontologies it imports.
+#[derive(Debug)]
+pub struct ImportClosure {
+    graph: Graph,
+    imports: Graph,
+    documents: Vec<Uri>,
+}
+
+impl ImportClosure {
+    /// Returns the merged graph of all loaded documents.
+    pub fn graph(&self) -> &Graph {
+        &self.graph
+    }
+
+    /// Returns the import graph.
+    ///
+    /// It contains an `owl:imports` triple from each loaded document to every document it imports.
+    pub fn imports(&self) -> &Graph {
+        &self.imports
+    }
+
+    /// Returns the IRIs of all loaded documents in the order they were loaded.
+    pub fn documents(&self) -> &Vec<Uri> {
+        &self.documents
+    }
+
+    /// Returns the merged graph of all loaded documents and consumes the closure.
+    pub fn into_graph(self) -> Graph {
+        self.graph
+    }
+}
+
+/// Loads the document identified by the IRI and recursively all documents referenced via `owl:imports`.
+///
+/// Each document is only loaded once, so cyclic imports are resolved without looping.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "turtle")]
+/// # {
+/// use rdf::ontology;
+/// use rdf::reader::rdf_parser::RdfParser;
+/// use rdf::reader::turtle_parser::TurtleParser;
+/// use rdf::uri::Uri;
+///
+/// let mut resolver = |iri: &Uri| {
+///   let input = match iri.to_string().as_str() {
+///     "http://example.org/a" => "<http://example.org/a> <http://www.w3.org/2002/07/owl#imports> <http://example.org/b> .",
+///     _ => "<http://example.org/b> <http://www.w3.org/2002/07/owl#imports> <http://example.org/a> .",
+///   };
+///
+///   TurtleParser::from_string(input).decode()
+/// };
+///
+/// let closure = ontology::load_with_imports(&Uri::new("http://example.org/a".to_string()), &mut resolver).unwrap();
+///
+/// assert_eq!(closure.graph().count(), 2);
+/// assert_eq!(closure.imports().count(), 2);
+/// assert_eq!(closure.documents().len(), 2);
+/// # }
+/// ```
+///
+/// # Failures
+///
+/// - A document cannot be resolved or parsed.
+///
+pub fn load_with_imports<R: ImportResolver>(iri: &Uri, resolver: &mut R) -> Result<ImportClosure> {
+    let imports_predicate = Node::UriNode {
+        uri: OwlVocabulary::Imports.to_uri(),
+    };
+
+    let mut graph = Graph::new(None);
+    let mut imports = Graph::new(None);
+    let mut documents = Vec::new();
+
+    let mut visited: HashSet<Uri> = HashSet::new();
+    let mut pending: VecDeque<Uri> = VecDeque::new();
+
+    visited.insert(iri.clone());
+    pending.push_back(iri.clone());
+
+    while let Some(document_iri) = pending.pop_front() {
+        let document = resolver.resolve(&document_iri)?;
+
+        if graph.base_uri().is_none() {
+            if let Some(ref base_uri) = *document.base_uri() {
+                graph.set_base_uri(base_uri);
+            }
+        }
+
+        let document_node = Node::UriNode {
+            uri: document_iri.clone(),
+        };
+
+        for triple in document.get_triples_with_predicate(&imports_predicate) {
+            if let Node::UriNode { ref uri } = *triple.object() {
+                imports.add_triple(&Triple::new(
+                    &document_node,
+                    &imports_predicate,
+                    triple.object(),
+                ));
+
+                // already loaded or pending documents are skipped to break import cycles
+                if visited.insert(uri.clone()) {
+                    pending.push_back(uri.clone());
+                }
+            }
+        }
+
+        graph.merge(&document);
+        documents.push(document_iri);
+    }
+
+    Ok(ImportClosure {
+        graph,
+        imports,
+        documents,
+    })
+}
+
+#[cfg(all(test, feature = "ntriples", feature = "turtle"))]
+mod tests {
+    use crate::error::{Error, ErrorType};
+    use crate::graph::Graph;
+    use crate::ontology::*;
+    use crate::reader::rdf_parser::RdfParser;
+    use crate::reader::turtle_parser::TurtleParser;
+    use crate::uri::Uri;
+
+    fn resolve_example(iri: &Uri) -> Result<Graph> {
+        let input = match iri.to_string().as_str() {
+            "http://example.org/a" => {
+                "@prefix owl: <http://www.w3.org/2002/07/owl#> .
+                 <http://example.org/a> owl:imports <http://example.org/b> , <http://example.org/c> .
+                 _:x <http://example.org/p> _:y ."
+            }
+            "http://example.org/b" => {
+                "@prefix owl: <http://www.w3.org/2002/07/owl#> .
+                 <http://example.org/b> owl:imports <http://example.org/c> .
+                 _:x <http://example.org/p> _:y ."
+            }
+            "http://example.org/c" => "_:x <http://example.org/p> _:y .",
+            _ => return Err(Error::new(ErrorType::UnresolvableIri, "Unknown document.")),
+        };
+
+        TurtleParser::from_string(input).decode()
+    }
+
+    #[test]
+    fn load_diamond_imports() {
+        let closure = load_with_imports(
+            &Uri::new("http://example.org/a".to_string()),
+            &mut resolve_example,
+        )
+        .unwrap();
+
+        assert_eq!(closure.documents().len(), 3);
+        assert_eq!(closure.imports().count(), 3);
+
+        // blank nodes of the documents must not be merged
+        assert_eq!(closure.graph().count(), 6);
+        assert_eq!(
+            closure
+                .graph()
+                .triples_iter()
+                .filter(|t| t.subject() == t.object())
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn load_unresolvable_import() {
+        let mut resolver = |iri: &Uri| {
+            match iri.to_string().as_str() {
+            "http://example.org/a" => TurtleParser::from_string(
+                "<http://example.org/a> <http://www.w3.org/2002/07/owl#imports> <http://example.org/x> .",
+            ).decode(),
+            _ => Err(Error::new(ErrorType::UnresolvableIri, "Unknown document.")),
+        }
+        };
+
+        let err = load_with_imports(&Uri::new("http://example.org/a".to_string()), &mut resolver)
+            .unwrap_err();
+
+        assert!(matches!(*err.error_type(), ErrorType::UnresolvableIri));
+    }
+
+    #[test]
+    fn resolve_missing_file() {
+        let mut resolver = FileResolver::new();
+
+        assert!(resolver
+            .resolve(&Uri::new("file:///does/not/exist.ttl".to_string()))
+            .is_err());
+    }
+}
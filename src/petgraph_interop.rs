@@ -0,0 +1,98 @@
+use crate::graph::Graph;
+use crate::node::Node;
+use petgraph::graph::{DiGraph, NodeIndex};
+use std::collections::HashMap;
+
+/// Converts the graph into a directed `petgraph` multigraph.
+///
+/// URIs and blank nodes become nodes, each triple becomes an edge weighted with its predicate.
+/// Triples with literal objects are skipped. Returns the `petgraph` graph together with
+/// the index of each node.
+///
+/// # Examples
+///
+/// ```
+/// use rdf::graph::Graph;
+/// use rdf::petgraph_interop;
+/// use rdf::triple::Triple;
+/// use rdf::uri::Uri;
+///
+/// let mut graph = Graph::new(None);
+///
+/// let a = graph.create_uri_node(&Uri::new("http://example.org/a".to_string()));
+/// let b = graph.create_blank_node();
+/// let knows = graph.create_uri_node(&Uri::new("http://xmlns.com/foaf/0.1/knows".to_string()));
+/// let name = graph.create_uri_node(&Uri::new("http://xmlns.com/foaf/0.1/name".to_string()));
+/// let literal = graph.create_literal_node("A".to_string());
+///
+/// graph.add_triple(&Triple::new(&a, &knows, &b));
+/// graph.add_triple(&Triple::new(&b, &knows, &a));
+/// graph.add_triple(&Triple::new(&a, &name, &literal));
+///
+/// let (petgraph, indices) = petgraph_interop::to_petgraph(&graph);
+///
+/// assert_eq!(petgraph.node_count(), 2);
+/// assert_eq!(petgraph.edge_count(), 2);
+/// assert_eq!(petgraph[indices[&a]], a);
+/// ```
+pub fn to_petgraph(graph: &Graph) -> (DiGraph<Node, Node>, HashMap<Node, NodeIndex>) {
+    let mut petgraph = DiGraph::new();
+    let mut indices: HashMap<Node, NodeIndex> = HashMap::new();
+
+    for triple in graph.triples_iter() {
+        if let Node::LiteralNode { .. } = *triple.object() {
+            continue;
+        }
+
+        let source = node_index(&mut petgraph, &mut indices, triple.subject());
+        let target = node_index(&mut petgraph, &mut indices, triple.object());
+
+        petgraph.add_edge(source, target, triple.predicate().clone());
+    }
+
+    (petgraph, indices)
+}
+
+/// Returns the index of the node and adds it to the `petgraph` graph if it does not exist yet.
+fn node_index(
+    petgraph: &mut DiGraph<Node, Node>,
+    indices: &mut HashMap<Node, NodeIndex>,
+    node: &Node,
+) -> NodeIndex {
+    if let Some(index) = indices.get(node) {
+        return *index;
+    }
+
+    let index = petgraph.add_node(node.clone());
+    indices.insert(node.clone(), index);
+    index
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::Graph;
+    use crate::petgraph_interop::to_petgraph;
+    use crate::triple::Triple;
+    use crate::uri::Uri;
+    use petgraph::algo::kosaraju_scc;
+
+    #[test]
+    fn strongly_connected_components() {
+        let mut graph = Graph::new(None);
+
+        let p = graph.create_uri_node(&Uri::new("http://example.org/p".to_string()));
+        let a = graph.create_blank_node();
+        let b = graph.create_blank_node();
+        let c = graph.create_blank_node();
+
+        graph.add_triple(&Triple::new(&a, &p, &b));
+        graph.add_triple(&Triple::new(&b, &p, &a));
+        graph.add_triple(&Triple::new(&b, &p, &c));
+        graph.add_triple(&Triple::new(&b, &p, &c));
+
+        let (petgraph, _) = to_petgraph(&graph);
+
+        assert_eq!(petgraph.edge_count(), 3);
+        assert_eq!(kosaraju_scc(&petgraph).len(), 2);
+    }
+}
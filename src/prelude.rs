@@ -0,0 +1,54 @@
+//! Commonly used types and traits.
+//!
+//! ```
+//! # #[cfg(feature = "ntriples")]
+//! # {
+//! use rdf::prelude::*;
+//!
+//! let mut graph = rdf::graph();
+//!
+//! let subject = graph.create_uri_node(&rdf::uri("http://example.org/a"));
+//! let predicate = graph.create_uri_node(&rdf::uri("http://example.org/p"));
+//! let object = graph.create_literal_node("literal".to_string());
+//!
+//! graph.add_triple(&Triple::new(&subject, &predicate, &object));
+//!
+//! let output = NTriplesWriter::new().write_to_string(&graph).unwrap();
+//! let reparsed = NTriplesParser::from_string(output).decode().unwrap();
+//!
+//! assert_eq!(reparsed.count(), 1);
+//! # }
+//! ```
+
+pub use crate::dataset::{Dataset, Quad};
+pub use crate::error::{Error, ErrorType};
+pub use crate::graph::Graph;
+pub use crate::namespace::Namespace;
+pub use crate::node::Node;
+#[cfg(feature = "jsonld")]
+pub use crate::reader::jsonld_parser::JsonLdParser;
+#[cfg(feature = "ntriples")]
+pub use crate::reader::n_quads_parser::NQuadsParser;
+#[cfg(feature = "ntriples")]
+pub use crate::reader::n_triples_parser::NTriplesParser;
+pub use crate::reader::rdf_parser::{RdfDatasetParser, RdfParser};
+#[cfg(feature = "xml")]
+pub use crate::reader::rdf_xml_parser::RdfXmlParser;
+#[cfg(feature = "turtle")]
+pub use crate::reader::trig_parser::TriGParser;
+#[cfg(feature = "turtle")]
+pub use crate::reader::turtle_parser::TurtleParser;
+pub use crate::store::GraphStore;
+pub use crate::triple::Triple;
+pub use crate::uri::Uri;
+#[cfg(feature = "jsonld")]
+pub use crate::writer::json_ld_writer::JsonLdWriter;
+#[cfg(feature = "ntriples")]
+pub use crate::writer::n_quads_writer::NQuadsWriter;
+#[cfg(feature = "ntriples")]
+pub use crate::writer::n_triples_writer::NTriplesWriter;
+pub use crate::writer::rdf_writer::{RdfDatasetWriter, RdfWriter};
+#[cfg(feature = "turtle")]
+pub use crate::writer::trig_writer::TriGWriter;
+#[cfg(feature = "turtle")]
+pub use crate::writer::turtle_writer::TurtleWriter;
@@ -0,0 +1,662 @@
+use crate::error::{Error, ErrorType};
+use crate::graph::Graph;
+use crate::node::Node;
+use crate::specs::json_specs::JsonSpecs;
+use crate::specs::rdf_syntax_specs::RdfSyntaxDataTypes;
+use crate::table::{escape_csv, node_value};
+use crate::triple::Triple;
+use crate::uri::Uri;
+use crate::Result;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Conventions for mapping between RDF and labeled property graphs.
+///
+/// By default `rdf:type` objects become labels and all names are full IRIs.
+#[derive(Debug, Clone)]
+pub struct PropertyGraphMapping {
+    label_predicate: Uri,
+    vocabulary: Option<Uri>,
+}
+
+impl Default for PropertyGraphMapping {
+    fn default() -> PropertyGraphMapping {
+        PropertyGraphMapping::new()
+    }
+}
+
+impl PropertyGraphMapping {
+    /// Constructor of `PropertyGraphMapping`.
+    pub fn new() -> PropertyGraphMapping {
+        PropertyGraphMapping {
+            label_predicate: RdfSyntaxDataTypes::A.to_uri(),
+            vocabulary: None,
+        }
+    }
+
+    /// Returns the predicate whose objects become node labels.
+    pub fn label_predicate(&self) -> &Uri {
+        &self.label_predicate
+    }
+
+    /// Sets the predicate whose objects become node labels.
+    pub fn set_label_predicate(&mut self, label_predicate: &Uri) {
+        self.label_predicate = label_predicate.clone();
+    }
+
+    /// Returns the namespace whose IRIs are shortened to local names.
+    pub fn vocabulary(&self) -> &Option<Uri> {
+        &self.vocabulary
+    }
+
+    /// Sets the namespace whose IRIs are shortened to local names.
+    ///
+    /// Labels, edge types and property keys are expanded with this namespace when imported.
+    pub fn set_vocabulary(&mut self, vocabulary: Option<Uri>) {
+        self.vocabulary = vocabulary;
+    }
+
+    /// Returns the label, edge type or property key of an IRI.
+    fn name(&self, node: &Node) -> String {
+        let iri = node_value(node);
+
+        match self.vocabulary {
+            Some(ref vocabulary) => match iri.strip_prefix(vocabulary.to_string().as_str()) {
+                Some(local_name) if !local_name.is_empty() => local_name.to_string(),
+                _ => iri,
+            },
+            None => iri,
+        }
+    }
+
+    /// Returns the IRI of a label, edge type or property key.
+    fn iri(&self, name: &str) -> Result<Node> {
+        if name.contains(':') {
+            return Ok(Node::UriNode {
+                uri: Uri::new(name.to_string()),
+            });
+        }
+
+        match self.vocabulary {
+            Some(ref vocabulary) => Ok(Node::UriNode {
+                uri: Uri::new(vocabulary.to_string().to_owned() + name),
+            }),
+            None => Err(Error::new(
+                ErrorType::InvalidNamespace,
+                "No vocabulary to expand name: ".to_string() + name,
+            )),
+        }
+    }
+}
+
+/// A node of a labeled property graph.
+#[derive(Debug, PartialEq, Clone)]
+pub struct PropertyNode {
+    id: String,
+    labels: Vec<String>,
+    properties: BTreeMap<String, Vec<String>>,
+}
+
+impl PropertyNode {
+    /// Returns the identifier, the IRI of the resource or `_:` followed by the blank node ID.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Returns the labels of the node.
+    pub fn labels(&self) -> &[String] {
+        &self.labels
+    }
+
+    /// Returns the property values of the node by key.
+    pub fn properties(&self) -> &BTreeMap<String, Vec<String>> {
+        &self.properties
+    }
+}
+
+/// A directed edge of a labeled property graph.
+#[derive(Debug, PartialEq, Clone)]
+pub struct PropertyEdge {
+    start: String,
+    end: String,
+    edge_type: String,
+}
+
+impl PropertyEdge {
+    /// Returns the identifier of the start node.
+    pub fn start(&self) -> &str {
+        &self.start
+    }
+
+    /// Returns the identifier of the end node.
+    pub fn end(&self) -> &str {
+        &self.end
+    }
+
+    /// Returns the type of the edge.
+    pub fn edge_type(&self) -> &str {
+        &self.edge_type
+    }
+}
+
+/// Labeled property graph that can be converted from and to RDF.
+///
+/// URIs and blank nodes become nodes. Triples with literal objects become node properties,
+/// all other triples become edges. Data types and languages of literals are not kept.
+#[derive(Debug, PartialEq, Clone)]
+pub struct PropertyGraph {
+    nodes: Vec<PropertyNode>,
+    edges: Vec<PropertyEdge>,
+}
+
+impl PropertyGraph {
+    /// Converts the RDF graph into a property graph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::Graph;
+    /// use rdf::property_graph::{PropertyGraph, PropertyGraphMapping};
+    /// use rdf::specs::rdf_syntax_specs::RdfSyntaxDataTypes;
+    /// use rdf::triple::Triple;
+    /// use rdf::uri::Uri;
+    ///
+    /// let mut graph = Graph::new(None);
+    ///
+    /// let art = graph.create_uri_node(&Uri::new("http://example.org/art".to_string()));
+    /// let dan = graph.create_uri_node(&Uri::new("http://example.org/dan".to_string()));
+    /// let rdf_type = graph.create_uri_node(&RdfSyntaxDataTypes::A.to_uri());
+    /// let person = graph.create_uri_node(&Uri::new("http://xmlns.com/foaf/0.1/Person".to_string()));
+    /// let knows = graph.create_uri_node(&Uri::new("http://xmlns.com/foaf/0.1/knows".to_string()));
+    /// let name = graph.create_uri_node(&Uri::new("http://xmlns.com/foaf/0.1/name".to_string()));
+    /// let literal = graph.create_literal_node("Art".to_string());
+    ///
+    /// graph.add_triple(&Triple::new(&art, &rdf_type, &person));
+    /// graph.add_triple(&Triple::new(&art, &knows, &dan));
+    /// graph.add_triple(&Triple::new(&art, &name, &literal));
+    ///
+    /// let mut mapping = PropertyGraphMapping::new();
+    /// mapping.set_vocabulary(Some(Uri::new("http://xmlns.com/foaf/0.1/".to_string())));
+    ///
+    /// let property_graph = PropertyGraph::from_graph(&graph, &mapping);
+    /// let (nodes, edges) = property_graph.to_neo4j_csv();
+    ///
+    /// assert_eq!(nodes, "id:ID,:LABEL,name:string[]\n\
+    ///                    http://example.org/art,Person,Art\n\
+    ///                    http://example.org/dan,,\n");
+    /// assert_eq!(edges, ":START_ID,:END_ID,:TYPE\n\
+    ///                    http://example.org/art,http://example.org/dan,knows\n");
+    /// ```
+    pub fn from_graph(graph: &Graph, mapping: &PropertyGraphMapping) -> PropertyGraph {
+        let label_predicate = Node::UriNode {
+            uri: mapping.label_predicate.clone(),
+        };
+
+        let mut nodes: BTreeMap<String, PropertyNode> = BTreeMap::new();
+        let mut edges = Vec::new();
+
+        for triple in graph.triples_iter() {
+            let id = node_value(triple.subject());
+
+            match *triple.object() {
+                Node::LiteralNode { ref literal, .. } => {
+                    property_node(&mut nodes, &id)
+                        .properties
+                        .entry(mapping.name(triple.predicate()))
+                        .or_default()
+                        .push(literal.to_string());
+                }
+                ref object if *triple.predicate() == label_predicate => {
+                    property_node(&mut nodes, &id)
+                        .labels
+                        .push(mapping.name(object));
+                }
+                ref object => {
+                    property_node(&mut nodes, &id);
+                    property_node(&mut nodes, &node_value(object));
+
+                    edges.push(PropertyEdge {
+                        start: id,
+                        end: node_value(object),
+                        edge_type: mapping.name(triple.predicate()),
+                    });
+                }
+            }
+        }
+
+        PropertyGraph {
+            nodes: nodes.into_values().collect(),
+            edges,
+        }
+    }
+
+    /// Converts the property graph into RDF.
+    ///
+    /// Identifiers starting with `_:` become blank nodes, all others IRIs.
+    ///
+    /// # Failures
+    ///
+    /// - A label, edge type or property key is no IRI and no vocabulary is set.
+    ///
+    pub fn to_graph(&self, mapping: &PropertyGraphMapping) -> Result<Graph> {
+        let mut graph = Graph::new(None);
+        let label_predicate = Node::UriNode {
+            uri: mapping.label_predicate.clone(),
+        };
+
+        for node in &self.nodes {
+            let subject = id_node(&node.id);
+
+            for label in &node.labels {
+                graph.add_triple(&Triple::new(
+                    &subject,
+                    &label_predicate,
+                    &mapping.iri(label)?,
+                ));
+            }
+
+            for (key, values) in &node.properties {
+                let predicate = mapping.iri(key)?;
+
+                for value in values {
+                    let object = graph.create_literal_node(value.to_string());
+                    graph.add_triple(&Triple::new(&subject, &predicate, &object));
+                }
+            }
+        }
+
+        for edge in &self.edges {
+            graph.add_triple(&Triple::new(
+                &id_node(&edge.start),
+                &mapping.iri(&edge.edge_type)?,
+                &id_node(&edge.end),
+            ));
+        }
+
+        Ok(graph)
+    }
+
+    /// Returns the nodes of the property graph.
+    pub fn nodes(&self) -> &[PropertyNode] {
+        &self.nodes
+    }
+
+    /// Returns the edges of the property graph.
+    pub fn edges(&self) -> &[PropertyEdge] {
+        &self.edges
+    }
+
+    /// Writes the node and the relationship CSV in the format of `neo4j-admin import`.
+    ///
+    /// Labels and property values are array fields separated by `;`, a `;` or `\` inside a
+    /// value is escaped with a backslash.
+    pub fn to_neo4j_csv(&self) -> (String, String) {
+        let keys: BTreeSet<&String> = self
+            .nodes
+            .iter()
+            .flat_map(|node| node.properties.keys())
+            .collect();
+
+        let mut nodes_csv = "id:ID,:LABEL".to_string();
+
+        for key in &keys {
+            nodes_csv.push(',');
+            nodes_csv.push_str(&escape_csv(&(key.to_string() + ":string[]")));
+        }
+
+        nodes_csv.push('\n');
+
+        for node in &self.nodes {
+            nodes_csv.push_str(&escape_csv(&node.id));
+            nodes_csv.push(',');
+            nodes_csv.push_str(&escape_csv(&join_array(&node.labels)));
+
+            for key in &keys {
+                let values = node.properties.get(*key).map(|v| join_array(v));
+
+                nodes_csv.push(',');
+                nodes_csv.push_str(&escape_csv(&values.unwrap_or_default()));
+            }
+
+            nodes_csv.push('\n');
+        }
+
+        let mut edges_csv = ":START_ID,:END_ID,:TYPE\n".to_string();
+
+        for edge in &self.edges {
+            edges_csv.push_str(&escape_csv(&edge.start));
+            edges_csv.push(',');
+            edges_csv.push_str(&escape_csv(&edge.end));
+            edges_csv.push(',');
+            edges_csv.push_str(&escape_csv(&edge.edge_type));
+            edges_csv.push('\n');
+        }
+
+        (nodes_csv, edges_csv)
+    }
+
+    /// Reads a node and a relationship CSV in the format of `neo4j-admin import`.
+    ///
+    /// Array fields are split at unescaped `;`, type annotations of property columns are ignored.
+    ///
+    /// # Failures
+    ///
+    /// - A CSV has no ID, start, end or type column.
+    /// - A row has a different number of fields than the header.
+    ///
+    pub fn from_neo4j_csv(nodes_csv: &str, edges_csv: &str) -> Result<PropertyGraph> {
+        let node_rows = parse_csv(nodes_csv)?;
+        let edge_rows = parse_csv(edges_csv)?;
+
+        let mut nodes = Vec::new();
+        let mut edges = Vec::new();
+
+        if let Some((header, rows)) = node_rows.split_first() {
+            let id_column = csv_column(header, |c| c.ends_with(":ID"))?;
+            let label_column = csv_column(header, |c| c == ":LABEL").ok();
+
+            for row in rows {
+                let mut properties = BTreeMap::new();
+
+                for (column, field) in header.iter().zip(row) {
+                    if column.ends_with(":ID") || column == ":LABEL" || field.is_empty() {
+                        continue;
+                    }
+
+                    let (key, array) = match column.find(':') {
+                        Some(i) => (&column[..i], column[i..].ends_with("[]")),
+                        None => (column.as_str(), false),
+                    };
+
+                    let values = if array {
+                        split_array(field)
+                    } else {
+                        vec![field.to_string()]
+                    };
+
+                    properties.insert(key.to_string(), values);
+                }
+
+                nodes.push(PropertyNode {
+                    id: row[id_column].to_string(),
+                    labels: label_column
+                        .map(|c| split_array(&row[c]))
+                        .unwrap_or_default(),
+                    properties,
+                });
+            }
+        }
+
+        if let Some((header, rows)) = edge_rows.split_first() {
+            let start_column = csv_column(header, |c| c == ":START_ID")?;
+            let end_column = csv_column(header, |c| c == ":END_ID")?;
+            let type_column = csv_column(header, |c| c == ":TYPE")?;
+
+            for row in rows {
+                edges.push(PropertyEdge {
+                    start: row[start_column].to_string(),
+                    end: row[end_column].to_string(),
+                    edge_type: row[type_column].to_string(),
+                });
+            }
+        }
+
+        Ok(PropertyGraph { nodes, edges })
+    }
+
+    /// Writes the property graph in the adjacency list format of GraphSON 1.0, one vertex per line.
+    ///
+    /// Several labels of a node are joined with `::`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::Graph;
+    /// use rdf::property_graph::{PropertyGraph, PropertyGraphMapping};
+    /// use rdf::triple::Triple;
+    /// use rdf::uri::Uri;
+    ///
+    /// let mut graph = Graph::new(None);
+    ///
+    /// let art = graph.create_blank_node_with_id("art".to_string());
+    /// let name = graph.create_uri_node(&Uri::new("http://xmlns.com/foaf/0.1/name".to_string()));
+    /// let literal = graph.create_literal_node("Art".to_string());
+    ///
+    /// graph.add_triple(&Triple::new(&art, &name, &literal));
+    ///
+    /// let property_graph = PropertyGraph::from_graph(&graph, &PropertyGraphMapping::new());
+    ///
+    /// assert_eq!(property_graph.to_graphson(),
+    ///            "{\"id\":\"_:art\",\"label\":\"vertex\",\"outE\":{},\"properties\":\
+    ///             {\"http://xmlns.com/foaf/0.1/name\":[{\"id\":0,\"value\":\"Art\"}]}}\n");
+    /// ```
+    pub fn to_graphson(&self) -> String {
+        let mut output_string = "".to_string();
+        let mut next_id = 0;
+
+        for node in &self.nodes {
+            let label = if node.labels.is_empty() {
+                "vertex".to_string()
+            } else {
+                node.labels.join("::")
+            };
+
+            output_string.push_str("{\"id\":");
+            output_string.push_str(&JsonSpecs::escape_string(&node.id));
+            output_string.push_str(",\"label\":");
+            output_string.push_str(&JsonSpecs::escape_string(&label));
+            output_string.push_str(",\"outE\":{");
+
+            let mut edges_by_type: BTreeMap<&str, Vec<&PropertyEdge>> = BTreeMap::new();
+
+            for edge in self.edges.iter().filter(|e| e.start == node.id) {
+                edges_by_type
+                    .entry(edge.edge_type.as_str())
+                    .or_default()
+                    .push(edge);
+            }
+
+            let mut out_edges = Vec::new();
+
+            for (edge_type, edges) in edges_by_type {
+                let mut edge_objects = Vec::new();
+
+                for edge in edges {
+                    edge_objects.push(format!(
+                        "{{\"id\":{},\"inV\":{}}}",
+                        next_id,
+                        JsonSpecs::escape_string(&edge.end)
+                    ));
+                    next_id += 1;
+                }
+
+                out_edges.push(
+                    JsonSpecs::escape_string(edge_type) + ":[" + &edge_objects.join(",") + "]",
+                );
+            }
+
+            output_string.push_str(&out_edges.join(","));
+            output_string.push_str("},\"properties\":{");
+
+            let mut properties = Vec::new();
+
+            for (key, values) in &node.properties {
+                let mut value_objects = Vec::new();
+
+                for value in values {
+                    value_objects.push(format!(
+                        "{{\"id\":{},\"value\":{}}}",
+                        next_id,
+                        JsonSpecs::escape_string(value)
+                    ));
+                    next_id += 1;
+                }
+
+                properties
+                    .push(JsonSpecs::escape_string(key) + ":[" + &value_objects.join(",") + "]");
+            }
+
+            output_string.push_str(&properties.join(","));
+            output_string.push_str("}}\n");
+        }
+
+        output_string
+    }
+}
+
+/// Returns the node with the identifier and adds it if it does not exist yet.
+fn property_node<'a>(
+    nodes: &'a mut BTreeMap<String, PropertyNode>,
+    id: &str,
+) -> &'a mut PropertyNode {
+    nodes.entry(id.to_string()).or_insert_with(|| PropertyNode {
+        id: id.to_string(),
+        labels: Vec::new(),
+        properties: BTreeMap::new(),
+    })
+}
+
+/// Returns the RDF node of an identifier of the property graph.
+fn id_node(id: &str) -> Node {
+    match id.strip_prefix("_:") {
+        Some(blank_id) => Node::BlankNode {
+            id: blank_id.to_string(),
+        },
+        None => Node::UriNode {
+            uri: Uri::new(id.to_string()),
+        },
+    }
+}
+
+/// Joins the values of an array field with `;` and escapes `;` and `\` in the values.
+fn join_array(values: &[String]) -> String {
+    values
+        .iter()
+        .map(|v| v.replace('\\', "\\\\").replace(';', "\\;"))
+        .collect::<Vec<String>>()
+        .join(";")
+}
+
+/// Splits an array field at unescaped `;`, empty fields are empty arrays.
+fn split_array(field: &str) -> Vec<String> {
+    if field.is_empty() {
+        return Vec::new();
+    }
+
+    let mut values = Vec::new();
+    let mut value = "".to_string();
+    let mut chars = field.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => value.extend(chars.next()),
+            ';' => values.push(value.split_off(0)),
+            _ => value.push(c),
+        }
+    }
+
+    values.push(value);
+    values
+}
+
+/// Returns the index of the first column that matches.
+fn csv_column<F: Fn(&str) -> bool>(header: &[String], matches: F) -> Result<usize> {
+    header.iter().position(|c| matches(c)).ok_or_else(|| {
+        Error::new(
+            ErrorType::InvalidReaderInput,
+            "Missing column in header: ".to_string() + &header.join(","),
+        )
+    })
+}
+
+/// Parses CSV with quoted fields into rows of fields that all have the length of the header.
+fn parse_csv(input: &str) -> Result<Vec<Vec<String>>> {
+    let mut rows: Vec<Vec<String>> = Vec::new();
+    let mut row = Vec::new();
+    let mut field = "".to_string();
+    let mut quoted = false;
+    let mut chars = input.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => row.push(field.split_off(0)),
+            '\r' if !quoted => {}
+            '\n' if !quoted => {
+                row.push(field.split_off(0));
+                rows.push(row.split_off(0));
+            }
+            _ => field.push(c),
+        }
+    }
+
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+
+    if quoted || rows.iter().any(|r| r.len() != rows[0].len()) {
+        return Err(Error::new(ErrorType::InvalidReaderInput, "Invalid CSV."));
+    }
+
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::Graph;
+    use crate::property_graph::*;
+
+    #[test]
+    fn neo4j_csv_round_trip() {
+        let mut graph = Graph::new(None);
+
+        let art = graph.create_blank_node_with_id("art".to_string());
+        let dan = graph.create_uri_node(&Uri::new("http://example.org/dan".to_string()));
+        let rdf_type = graph.create_uri_node(&RdfSyntaxDataTypes::A.to_uri());
+        let person = graph.create_uri_node(&Uri::new("http://example.org/Person".to_string()));
+        let knows = graph.create_uri_node(&Uri::new("http://example.org/knows".to_string()));
+        let name = graph.create_uri_node(&Uri::new("http://example.org/name".to_string()));
+        let first = graph.create_literal_node("Art, \"the\"".to_string());
+        let second = graph.create_literal_node("Barstow; C:\\Art".to_string());
+
+        graph.add_triple(&Triple::new(&art, &rdf_type, &person));
+        graph.add_triple(&Triple::new(&art, &knows, &dan));
+        graph.add_triple(&Triple::new(&art, &name, &first));
+        graph.add_triple(&Triple::new(&art, &name, &second));
+        graph.add_triple(&Triple::new(&dan, &rdf_type, &person));
+
+        let mut mapping = PropertyGraphMapping::new();
+        mapping.set_vocabulary(Some(Uri::new("http://example.org/".to_string())));
+
+        let property_graph = PropertyGraph::from_graph(&graph, &mapping);
+        let (nodes, edges) = property_graph.to_neo4j_csv();
+        let imported = PropertyGraph::from_neo4j_csv(&nodes, &edges).unwrap();
+
+        assert_eq!(imported, property_graph);
+
+        let mut triples: Vec<Triple> = imported
+            .to_graph(&mapping)
+            .unwrap()
+            .triples_iter()
+            .cloned()
+            .collect();
+        let mut expected: Vec<Triple> = graph.triples_iter().cloned().collect();
+
+        triples.sort();
+        expected.sort();
+
+        assert_eq!(triples, expected);
+        assert!(imported.to_graph(&PropertyGraphMapping::new()).is_err());
+    }
+
+    #[test]
+    fn invalid_neo4j_csv() {
+        assert!(PropertyGraph::from_neo4j_csv("name\nx\n", "").is_err());
+        assert!(PropertyGraph::from_neo4j_csv("id:ID,name\nx\n", "").is_err());
+        assert!(PropertyGraph::from_neo4j_csv("id:ID\n\"x\n", "").is_err());
+    }
+}
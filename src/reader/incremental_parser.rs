@@ -0,0 +1,577 @@
+use crate::graph::Graph;
+use crate::node::Node;
+use crate::reader::rdf_parser::RdfParser;
+use crate::reader::turtle_parser::TurtleParser;
+use crate::triple::Triple;
+use crate::uri::Uri;
+use std::collections::HashMap;
+use std::ops::Range;
+
+/// Syntax error of a statement with the byte range of the statement.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Diagnostic {
+    range: Range<usize>,
+    message: String,
+}
+
+impl Diagnostic {
+    /// Returns the byte range of the erroneous statement in the document.
+    pub fn range(&self) -> &Range<usize> {
+        &self.range
+    }
+
+    /// Returns the error message.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+/// Triples that were removed from and added to a document by an edit.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct DocumentChange {
+    removed: Vec<Triple>,
+    added: Vec<Triple>,
+}
+
+impl DocumentChange {
+    /// Returns the triples that are no longer in the document.
+    pub fn removed(&self) -> &[Triple] {
+        &self.removed
+    }
+
+    /// Returns the triples that are new in the document.
+    pub fn added(&self) -> &[Triple] {
+        &self.added
+    }
+
+    /// Checks if the edit did not change the triples of the document.
+    pub fn is_empty(&self) -> bool {
+        self.removed.is_empty() && self.added.is_empty()
+    }
+}
+
+/// A statement of the document, i.e. a directive or triples terminated by `.`.
+#[derive(Debug, Clone)]
+struct Statement {
+    // unique ID that is used to label the blank nodes of `[]` and collections
+    id: usize,
+    // byte range including the whitespace and comments before the statement
+    range: Range<usize>,
+    // text of prefix and base directives
+    directive: Option<String>,
+    triples: Vec<Triple>,
+    diagnostic: Option<Diagnostic>,
+}
+
+/// Turtle document that is re-parsed statement by statement when it is edited.
+///
+/// Only the statements touched by an edit are parsed again, unless a prefix or base
+/// directive changes, in which case all following statements are parsed again as well.
+/// Blank nodes of `[]` and collections are labeled per statement, so their labels stay
+/// stable while other statements are edited.
+#[derive(Debug, Clone)]
+pub struct TurtleDocument {
+    text: String,
+    statements: Vec<Statement>,
+    next_id: usize,
+}
+
+impl TurtleDocument {
+    /// Parses a Turtle document.
+    ///
+    /// Syntax errors are reported as diagnostics of the erroneous statements, all other
+    /// statements are parsed regardless.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::incremental_parser::TurtleDocument;
+    ///
+    /// let mut document = TurtleDocument::parse("@prefix ex: <http://example.org/> .
+    ///                                           ex:a ex:p ex:b .
+    ///                                           ex:b ex:p ex:c .");
+    ///
+    /// assert_eq!(document.triples().len(), 2);
+    ///
+    /// let start = document.text().find("ex:c").unwrap();
+    /// let change = document.edit(start..start + 4, "\"c\"");
+    ///
+    /// assert_eq!(change.removed().len(), 1);
+    /// assert_eq!(change.added().len(), 1);
+    /// assert!(document.diagnostics().is_empty());
+    /// ```
+    pub fn parse(text: &str) -> TurtleDocument {
+        let mut document = TurtleDocument {
+            text: text.to_string(),
+            statements: Vec::new(),
+            next_id: 0,
+        };
+
+        let mut start = 0;
+
+        while start < text.len() {
+            let end = statement_end(text, start);
+            let statement = document.new_statement(start..end);
+            document.statements.push(statement);
+            start = end;
+        }
+
+        let count = document.statements.len();
+        document.parse_statements(0..count);
+        document
+    }
+
+    /// Returns the text of the document.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the triples of all statements that could be parsed.
+    pub fn triples(&self) -> Vec<&Triple> {
+        self.statements
+            .iter()
+            .flat_map(|statement| statement.triples.iter())
+            .collect()
+    }
+
+    /// Returns the syntax errors of the document.
+    pub fn diagnostics(&self) -> Vec<&Diagnostic> {
+        self.statements
+            .iter()
+            .filter_map(|statement| statement.diagnostic.as_ref())
+            .collect()
+    }
+
+    /// Returns the byte ranges of the statements without surrounding whitespace and comments.
+    pub fn statement_ranges(&self) -> Vec<Range<usize>> {
+        self.statements
+            .iter()
+            .map(|statement| {
+                let text = &self.text[statement.range.clone()];
+                statement.range.start + content_start(text)
+                    ..statement.range.start + text.trim_end().len()
+            })
+            .filter(|range| range.start < range.end)
+            .collect()
+    }
+
+    /// Returns the namespaces of the valid prefix directives.
+    pub fn namespaces(&self) -> HashMap<String, Uri> {
+        TurtleParser::from_string(self.header(self.statements.len()))
+            .decode()
+            .map(|graph| graph.namespaces().clone())
+            .unwrap_or_default()
+    }
+
+    /// Returns a graph with the namespaces, the base URI and the triples of the document.
+    pub fn to_graph(&self) -> Graph {
+        let mut graph = TurtleParser::from_string(self.header(self.statements.len()))
+            .decode()
+            .unwrap_or_else(|_| Graph::new(None));
+
+        for statement in &self.statements {
+            graph.add_triples(&statement.triples);
+        }
+
+        graph
+    }
+
+    /// Replaces the byte range of the text and re-parses the affected statements.
+    ///
+    /// # Panics
+    ///
+    /// - The range is out of bounds or does not lie on character boundaries.
+    ///
+    pub fn edit(&mut self, range: Range<usize>, replacement: &str) -> DocumentChange {
+        self.text.replace_range(range.clone(), replacement);
+
+        // the first statement that ends at or after the start of the edit is affected, since
+        // the edit could remove the whitespace that terminates it
+        let first = self
+            .statements
+            .iter()
+            .position(|statement| statement.range.end >= range.start)
+            .unwrap_or(self.statements.len());
+
+        let start = self
+            .statements
+            .get(first)
+            .map_or(range.start.min(self.text.len()), |statement| {
+                statement.range.start
+            });
+
+        // split the text again until a statement ends where an old statement ended after the edit
+        let edit_end = range.start + replacement.len();
+        let mut ranges = Vec::new();
+        let mut last = self.statements.len();
+        let mut position = start;
+
+        while position < self.text.len() {
+            let end = statement_end(&self.text, position);
+            ranges.push(position..end);
+            position = end;
+
+            if end >= edit_end {
+                let old_end = end + range.len() - replacement.len();
+
+                if let Some(i) = self.statements[first..]
+                    .iter()
+                    .position(|statement| statement.range.end == old_end && old_end >= range.end)
+                {
+                    last = first + i + 1;
+                    break;
+                }
+            }
+        }
+
+        let new_statements: Vec<Statement> =
+            ranges.into_iter().map(|r| self.new_statement(r)).collect();
+        let added_count = new_statements.len();
+
+        let old_statements: Vec<Statement> = self
+            .statements
+            .splice(first..last, new_statements)
+            .collect();
+
+        // shift the statements after the edit
+        for statement in &mut self.statements[first + added_count..] {
+            statement.range.start = statement.range.start + replacement.len() - range.len();
+            statement.range.end = statement.range.end + replacement.len() - range.len();
+
+            if let Some(ref mut diagnostic) = statement.diagnostic {
+                diagnostic.range.start = diagnostic.range.start + replacement.len() - range.len();
+                diagnostic.range.end = diagnostic.range.end + replacement.len() - range.len();
+            }
+        }
+
+        let old_directives: Vec<&String> = old_statements
+            .iter()
+            .filter_map(|statement| statement.directive.as_ref())
+            .collect();
+        let new_directives: Vec<&String> = self.statements[first..first + added_count]
+            .iter()
+            .filter_map(|statement| statement.directive.as_ref())
+            .collect();
+
+        // changed directives can change the meaning of all following statements
+        let reparsed_end = if old_directives != new_directives {
+            self.statements.len()
+        } else {
+            first + added_count
+        };
+
+        let mut removed: Vec<Triple> = old_statements
+            .into_iter()
+            .flat_map(|statement| statement.triples)
+            .collect();
+
+        for statement in &self.statements[first + added_count..reparsed_end] {
+            removed.extend(statement.triples.iter().cloned());
+        }
+
+        self.parse_statements(first..reparsed_end);
+
+        let added = self.statements[first..reparsed_end]
+            .iter()
+            .flat_map(|statement| statement.triples.iter().cloned())
+            .collect();
+
+        difference(removed, added)
+    }
+
+    /// Creates an unparsed statement with a new ID.
+    fn new_statement(&mut self, range: Range<usize>) -> Statement {
+        let content_start = range.start + content_start(&self.text[range.clone()]);
+        let content = &self.text[content_start..range.end];
+
+        let is_directive = starts_with_directive_keyword(content)
+            || content
+                .strip_prefix('@')
+                .is_some_and(starts_with_directive_keyword);
+        let directive = if is_directive {
+            Some(content.trim_end().to_string())
+        } else {
+            None
+        };
+
+        self.next_id += 1;
+
+        Statement {
+            id: self.next_id - 1,
+            range,
+            directive,
+            triples: Vec::new(),
+            diagnostic: None,
+        }
+    }
+
+    /// Returns the valid directives before the statement at the index.
+    fn header(&self, index: usize) -> String {
+        self.statements[..index]
+            .iter()
+            .filter(|statement| statement.directive.is_some() && statement.diagnostic.is_none())
+            .map(|statement| &self.text[statement.range.clone()])
+            .collect::<Vec<&str>>()
+            .join("\n")
+    }
+
+    /// Parses the statements at the indices.
+    fn parse_statements(&mut self, indices: Range<usize>) {
+        let mut header = self.header(indices.start);
+
+        for i in indices {
+            let range = self.statements[i].range.clone();
+            let text = &self.text[range.clone()];
+            let content_start = range.start + content_start(text);
+            let content_end = range.start + text.trim_end().len();
+
+            let mut triples = Vec::new();
+            let mut diagnostic = None;
+
+            if content_start < content_end {
+                // SPARQL-style directives do not end with '.'
+                let result = if text.trim_end().ends_with('.')
+                    || starts_with_directive_keyword(&self.text[content_start..])
+                {
+                    TurtleParser::from_string(header.clone() + "\n" + text)
+                        .decode()
+                        .map_err(|err| err.message())
+                } else {
+                    Err("Statement does not end with '.'".to_string())
+                };
+
+                match result {
+                    Ok(graph) => {
+                        let id = self.statements[i].id;
+                        triples = graph
+                            .triples_iter()
+                            .map(|triple| {
+                                Triple::new(
+                                    &label_blank_node(triple.subject(), text, id),
+                                    &label_blank_node(triple.predicate(), text, id),
+                                    &label_blank_node(triple.object(), text, id),
+                                )
+                            })
+                            .collect();
+                    }
+                    Err(message) => {
+                        diagnostic = Some(Diagnostic {
+                            range: content_start..content_end,
+                            message,
+                        });
+                    }
+                }
+            }
+
+            if self.statements[i].directive.is_some() && diagnostic.is_none() {
+                header.push('\n');
+                header.push_str(text);
+            }
+
+            let statement = &mut self.statements[i];
+            statement.triples = triples;
+            statement.diagnostic = diagnostic;
+        }
+    }
+}
+
+/// Returns the offset of the first character that is neither whitespace nor in a comment.
+fn content_start(text: &str) -> usize {
+    let mut in_comment = false;
+
+    for (i, c) in text.char_indices() {
+        match c {
+            '#' => in_comment = true,
+            '\n' | '\r' => in_comment = false,
+            _ if in_comment || c.is_whitespace() => {}
+            _ => return i,
+        }
+    }
+
+    text.len()
+}
+
+/// Checks if the text starts with `prefix` or `base` followed by whitespace, ignoring case.
+fn starts_with_directive_keyword(text: &str) -> bool {
+    ["prefix", "base"].iter().any(|keyword| {
+        text.get(..keyword.len())
+            .is_some_and(|start| start.eq_ignore_ascii_case(keyword))
+            && text[keyword.len()..].starts_with(char::is_whitespace)
+    })
+}
+
+/// Returns the end of the statement that starts at the position.
+///
+/// The statement ends after the `.` that terminates it or at the end of the text. Dots within
+/// IRIs, literals, comments, numbers and prefixed names are skipped. SPARQL-style directives
+/// end after their IRI.
+fn statement_end(text: &str, start: usize) -> usize {
+    let content = start + content_start(&text[start..]);
+
+    if starts_with_directive_keyword(&text[content..]) {
+        return text[content..]
+            .find('>')
+            .map_or(text.len(), |end| content + end + 1);
+    }
+
+    let bytes = text.as_bytes();
+    let mut i = start;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b'#' => {
+                i = text[i..].find('\n').map_or(bytes.len(), |end| i + end);
+            }
+            b'<' if bytes.get(i + 1) == Some(&b'<') => i += 2,
+            b'<' => {
+                i = text[i..].find('>').map_or(bytes.len(), |end| i + end + 1);
+            }
+            quote @ b'"' | quote @ b'\'' => {
+                let long = bytes[i..].starts_with(&[quote; 3]);
+                i += if long { 3 } else { 1 };
+
+                while i < bytes.len() {
+                    if bytes[i] == b'\\' {
+                        i += 2;
+                    } else if long && bytes[i..].starts_with(&[quote; 3]) {
+                        i += 3;
+                        break;
+                    } else if !long && (bytes[i] == quote || bytes[i] == b'\n' || bytes[i] == b'\r')
+                    {
+                        i += 1;
+                        break;
+                    } else {
+                        i += 1;
+                    }
+                }
+            }
+            b'.' => match bytes.get(i + 1) {
+                None => return i + 1,
+                Some(&next) if next == b'#' || (next as char).is_ascii_whitespace() => {
+                    return i + 1
+                }
+                _ => i += 1,
+            },
+            _ => i += 1,
+        }
+    }
+
+    bytes.len()
+}
+
+/// Labels the generated blank nodes of a statement with the ID of the statement.
+fn label_blank_node(node: &Node, text: &str, statement_id: usize) -> Node {
+    match *node {
+        Node::BlankNode { ref id }
+            if id.starts_with("auto") && !text.contains(&("_:".to_string() + id)) =>
+        {
+            Node::BlankNode {
+                id: format!("s{}_{}", statement_id, id),
+            }
+        }
+        Node::TripleNode { ref triple } => Node::TripleNode {
+            triple: Box::new(Triple::new(
+                &label_blank_node(triple.subject(), text, statement_id),
+                &label_blank_node(triple.predicate(), text, statement_id),
+                &label_blank_node(triple.object(), text, statement_id),
+            )),
+        },
+        _ => node.clone(),
+    }
+}
+
+/// Returns the triples that are only removed or only added.
+fn difference(mut removed: Vec<Triple>, mut added: Vec<Triple>) -> DocumentChange {
+    removed.sort();
+    added.sort();
+
+    let mut change = DocumentChange::default();
+    let mut removed = removed.into_iter().peekable();
+    let mut added = added.into_iter().peekable();
+
+    loop {
+        let order = match (removed.peek(), added.peek()) {
+            (Some(r), Some(a)) => r.cmp(a),
+            (Some(_), None) => ::std::cmp::Ordering::Less,
+            (None, Some(_)) => ::std::cmp::Ordering::Greater,
+            (None, None) => break,
+        };
+
+        match order {
+            ::std::cmp::Ordering::Less => change.removed.extend(removed.next()),
+            ::std::cmp::Ordering::Greater => change.added.extend(added.next()),
+            ::std::cmp::Ordering::Equal => {
+                removed.next();
+                added.next();
+            }
+        }
+    }
+
+    change
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::reader::incremental_parser::*;
+
+    #[test]
+    fn split_statements() {
+        let text =
+            "<http://a.org/s> <http://a.org/p> \"a. b\" . # c. d\n_:b <http://a.org/p> 1.5 .";
+        let end = statement_end(text, 0);
+
+        assert_eq!(&text[..end], "<http://a.org/s> <http://a.org/p> \"a. b\" .");
+        assert_eq!(statement_end(text, end), text.len());
+    }
+
+    #[test]
+    fn split_sparql_style_directives() {
+        let text = "PREFIX ex: <http://example.org/>\nBASE <http://example.org/b/> ex:a ex:p <c> .";
+        let document = TurtleDocument::parse(text);
+
+        assert_eq!(document.statement_ranges().len(), 3);
+        assert!(document.diagnostics().is_empty());
+        assert_eq!(
+            *document.triples()[0].object(),
+            Node::UriNode {
+                uri: Uri::new("http://example.org/b/c".to_string())
+            }
+        );
+    }
+
+    #[test]
+    fn edits_report_diagnostics_and_reparse_after_changed_prefixes() {
+        let mut document = TurtleDocument::parse(
+            "@prefix ex: <http://example.org/> .\nex:a ex:p [ ex:q ex:r ] .\nex:b ex:p ex:c .",
+        );
+
+        assert_eq!(document.triples().len(), 3);
+
+        // removing the dot joins the last two statements into an invalid one
+        let dot = document.text().find("] .").unwrap() + 2;
+        let change = document.edit(dot..dot + 1, "");
+
+        assert_eq!(change.removed().len(), 3);
+        assert!(change.added().is_empty());
+        assert_eq!(document.diagnostics().len(), 1);
+
+        let change = document.edit(dot..dot, ".");
+
+        assert_eq!(change.added().len(), 3);
+        assert!(document.diagnostics().is_empty());
+
+        // an unrelated edit keeps the labels of generated blank nodes
+        let c = document.text().rfind("ex:c").unwrap();
+        let change = document.edit(c..c + 4, "ex:d");
+
+        assert_eq!(change.removed().len(), 1);
+        assert_eq!(change.added().len(), 1);
+
+        let iri = document.text().find("<http://example.org/>").unwrap();
+        let change = document.edit(iri..iri + 21, "<http://example.com/>");
+
+        assert_eq!(change.removed().len(), 3);
+        assert_eq!(change.added().len(), 3);
+        assert_eq!(document.to_graph().count(), 3);
+    }
+}
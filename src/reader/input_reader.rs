@@ -1,6 +1,7 @@
-use Result;
-use error::{Error, ErrorType};
-use std::io::Read;
+use crate::error::{Error, ErrorType, Position};
+use crate::Result;
+use std::fmt;
+use std::io::{BufReader, Read};
 use std::ops::Index;
 use std::str;
 
@@ -9,8 +10,10 @@ pub struct InputReaderHelper {}
 
 impl InputReaderHelper {
     /// Returns `true` if the provided character is a whitespace.
+    ///
+    /// Only space, tab, carriage return and line feed are whitespace in N-Triples and Turtle.
     pub fn whitespace(c: char) -> bool {
-        c == '\n' || c == '\r' || c == ' '
+        c == '\n' || c == '\r' || c == ' ' || c == '\t'
     }
 
     /// Returns `true` if the provided character is a line break.
@@ -20,7 +23,15 @@ impl InputReaderHelper {
 
     /// Returns `true` if the provided character can be used to separate two nodes.
     pub fn node_delimiter(c: char) -> bool {
-        c == '\n' || c == '\r' || c == ' ' || c == '.'
+        InputReaderHelper::whitespace(c) || c == '.'
+    }
+
+    /// Returns `true` if the provided character ends a node in Turtle.
+    ///
+    /// Besides whitespace and `.`, the delimiters of collections, blank node property lists,
+    /// object and predicate lists and TriG graphs can directly follow a node.
+    pub fn turtle_node_delimiter(c: char) -> bool {
+        InputReaderHelper::node_delimiter(c) || "()[],;{}".contains(c)
     }
 
     /// Returns `true` if the provided character is a digit.
@@ -37,14 +48,12 @@ pub struct InputChars {
     input_chars: Vec<InputChar>,
 }
 
-impl ToString for InputChars {
-    fn to_string(&self) -> String {
-        let s: String = self.input_chars
-            .clone()
-            .into_iter()
-            .flat_map(|c| c)
-            .collect();
-        s
+impl fmt::Display for InputChars {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.input_chars
+            .iter()
+            .flatten()
+            .try_for_each(|c| write!(f, "{}", c))
     }
 }
 
@@ -92,8 +101,9 @@ impl InputChars {
 
 /// Reads input and transforms it to `InputChars`.
 pub struct InputReader<R: Read> {
-    input: R,
+    input: BufReader<R>,
     peeked_chars: InputChars,
+    position: Position,
 }
 
 impl<R: Read> InputReader<R> {
@@ -108,11 +118,30 @@ impl<R: Read> InputReader<R> {
     /// ```
     pub fn new(input: R) -> InputReader<R> {
         InputReader {
-            input,
+            input: BufReader::new(input),
             peeked_chars: InputChars::new(Vec::new()),
+            position: Position::default(),
         }
     }
 
+    /// Returns the position of the next character that is not consumed yet.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::input_reader::InputReader;
+    ///
+    /// let mut reader = InputReader::new("_:a\n  _:b".as_bytes());
+    /// reader.get_until(|c| c == '\n').unwrap();
+    /// reader.peek_next_char_discard_leading_spaces().unwrap();
+    ///
+    /// let position = reader.position();
+    /// assert_eq!((position.offset(), position.line(), position.column()), (6, 2, 3));
+    /// ```
+    pub fn position(&self) -> Position {
+        self.position
+    }
+
     /// Returns the next `k` characters but does not consume them.
     ///
     /// # Examples
@@ -134,8 +163,10 @@ impl<R: Read> InputReader<R> {
         if self.peeked_chars.len() >= k {
             Ok(InputChars::new(self.peeked_chars.to_vec()[0..k].to_vec()))
         } else {
+            let position = self.position;
             let next_k_chars = self.get_next_k_chars(k)?;
             self.peeked_chars = next_k_chars.clone();
+            self.position = position;
             Ok(next_k_chars)
         }
     }
@@ -158,7 +189,7 @@ impl<R: Read> InputReader<R> {
     /// - End of input reached.
     ///
     pub fn peek_next_char(&mut self) -> Result<InputChar> {
-        let peeked_char = try!(self.peek_next_k_chars(1));
+        let peeked_char = self.peek_next_k_chars(1)?;
         Ok(peeked_char.to_vec()[0])
     }
 
@@ -180,17 +211,8 @@ impl<R: Read> InputReader<R> {
     /// - End of input reached.
     ///
     pub fn peek_next_char_discard_leading_spaces(&mut self) -> Result<InputChar> {
-        match self.get_next_char_discard_leading_spaces() {
-            Ok(Some(next_char)) => {
-                if self.peeked_chars.is_empty() {
-                    self.peeked_chars.push(Some(next_char));
-                }
-
-                Ok(Some(next_char))
-            }
-            Ok(None) => Ok(None),
-            Err(err) => Err(err),
-        }
+        self.discard_leading_spaces()?;
+        self.peek_next_char()
     }
 
     /// Returns the next character of an input source.
@@ -211,6 +233,17 @@ impl<R: Read> InputReader<R> {
     /// - End of input reached.
     ///
     pub fn get_next_char(&mut self) -> Result<InputChar> {
+        let next_char = self.read_next_char()?;
+
+        if let Some(c) = next_char {
+            self.position = self.position.advance(c);
+        }
+
+        Ok(next_char)
+    }
+
+    /// Returns the next peeked character or reads the next character from the input source.
+    fn read_next_char(&mut self) -> Result<InputChar> {
         if !self.peeked_chars.is_empty() {
             return Ok(self.peeked_chars.remove(0));
         }
@@ -218,18 +251,18 @@ impl<R: Read> InputReader<R> {
         const MAX_BYTES: usize = 4;
         let mut buf = [0u8; MAX_BYTES];
 
-        let input = &mut self.input;
-        let mut bytes = input.bytes();
+        let mut bytes = (&mut self.input).bytes();
 
         for pos in 0..MAX_BYTES {
             let byte = match bytes.next() {
                 Some(Ok(b)) => b,
                 None => return Ok(None),
-                Some(Err(_)) => {
+                Some(Err(err)) => {
                     return Err(Error::new(
                         ErrorType::InvalidReaderInput,
-                        "Invalid input character.",
-                    ))
+                        "Error while reading input.",
+                    )
+                    .with_source(err))
                 }
             };
 
@@ -301,10 +334,7 @@ impl<R: Read> InputReader<R> {
     pub fn get_next_char_discard_leading_spaces(&mut self) -> Result<InputChar> {
         loop {
             match self.get_next_char() {
-                Ok(Some(' ')) => {}
-                Ok(Some('\n')) => {}
-                Ok(Some('\t')) => {}
-                Ok(Some('\r')) => {}
+                Ok(Some(c)) if InputReaderHelper::whitespace(c) => {}
                 c => return c,
             }
         }
@@ -331,10 +361,12 @@ impl<R: Read> InputReader<R> {
     /// - End of input reached.
     ///
     pub fn peek_until<F: Fn(char) -> bool>(&mut self, delimiter: F) -> Result<InputChars> {
+        let position = self.position;
         let mut chars = self.get_until(delimiter)?;
         let result = chars.clone();
         chars.append(&mut self.peeked_chars);
         self.peeked_chars = chars;
+        self.position = position;
         Ok(result)
     }
 
@@ -363,11 +395,8 @@ impl<R: Read> InputReader<R> {
         &mut self,
         delimiter: F,
     ) -> Result<InputChars> {
-        let mut chars = self.get_until_discard_leading_spaces(delimiter)?;
-        let result = chars.clone();
-        chars.append(&mut self.peeked_chars);
-        self.peeked_chars = chars;
-        Ok(result)
+        self.discard_leading_spaces()?;
+        self.peek_until(delimiter)
     }
 
     /// Returns all characters of a input source until a certain delimiter occurs.
@@ -394,9 +423,12 @@ impl<R: Read> InputReader<R> {
         let mut buf = Vec::new();
 
         loop {
+            let position = self.position;
+
             match self.get_next_char()? {
                 Some(c) if delimiter(c) => {
                     self.peeked_chars.insert(0, Some(c));
+                    self.position = position;
 
                     return Ok(InputChars::new(buf.into_iter().collect()));
                 }
@@ -435,13 +467,19 @@ impl<R: Read> InputReader<R> {
         &mut self,
         delimiter: F,
     ) -> Result<InputChars> {
-        let whitespaces = InputReaderHelper::whitespace;
+        self.discard_leading_spaces()?;
+        self.get_until(delimiter)
+    }
 
-        // consume leading whitespaces
-        while whitespaces(self.peek_next_char()?.unwrap_or('x')) {
-            let _ = self.get_next_char();
+    /// Consumes all whitespaces in front of the next character.
+    fn discard_leading_spaces(&mut self) -> Result<()> {
+        while self
+            .peek_next_char()?
+            .is_some_and(InputReaderHelper::whitespace)
+        {
+            let _ = self.get_next_char()?;
         }
 
-        self.get_until(delimiter)
+        Ok(())
     }
 }
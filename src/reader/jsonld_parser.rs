@@ -0,0 +1,1009 @@
+use crate::dataset::{Dataset, Quad};
+use crate::error::{Error, ErrorType};
+use crate::graph::Graph;
+use crate::node::Node;
+use crate::reader::parser_config::ParserConfig;
+use crate::reader::rdf_parser::{RdfDatasetParser, RdfParser};
+use crate::specs::rdf_syntax_specs::RdfSyntaxDataTypes;
+use crate::specs::xml_specs::XmlDataTypes;
+use crate::triple::Triple;
+use crate::uri::Uri;
+use crate::Result;
+use std::collections::HashMap;
+use std::io::Cursor;
+use std::io::Read;
+
+/// RDF parser to generate an RDF dataset from JSON-LD.
+///
+/// The document is expanded with its embedded contexts, supporting `@context` with terms,
+/// prefixes, `@vocab`, `@base`, `@language`, type coercion and `@list` containers, as well as
+/// `@id`, `@type`, `@value`, `@language`, `@list`, `@set` and `@graph` in the document.
+/// Remote contexts are not retrieved and `@reverse` properties are not supported.
+pub struct JsonLdParser<R: Read> {
+    input: R,
+    config: ParserConfig,
+}
+
+impl<R: Read> RdfDatasetParser for JsonLdParser<R> {
+    /// Generates an RDF dataset from a string containing JSON-LD.
+    ///
+    /// Nodes with `@graph` and `@id` are named graphs, all other nodes belong to the default graph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::node::Node;
+    /// use rdf::reader::jsonld_parser::JsonLdParser;
+    /// use rdf::reader::rdf_parser::RdfDatasetParser;
+    /// use rdf::uri::Uri;
+    ///
+    /// let input = r#"{
+    ///   "@context": {"foaf": "http://xmlns.com/foaf/0.1/", "name": "foaf:name"},
+    ///   "@id": "http://example.org/people",
+    ///   "@graph": [
+    ///     {"@id": "http://example.org/art", "@type": "foaf:Person", "name": "Art Barstow"}
+    ///   ]
+    /// }"#;
+    ///
+    /// let dataset = JsonLdParser::from_string(input).decode_dataset().unwrap();
+    /// let people = Node::UriNode { uri: Uri::new("http://example.org/people".to_string()) };
+    ///
+    /// assert!(dataset.default_graph().is_empty());
+    /// assert_eq!(dataset.named_graph(&people).unwrap().count(), 2);
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - Input that is not valid JSON.
+    /// - Invalid JSON-LD, for example a context that is not an object.
+    /// - Remote contexts.
+    ///
+    fn decode_dataset(&mut self) -> Result<Dataset> {
+        let mut input = String::new();
+
+        if let Err(err) = self.input.read_to_string(&mut input) {
+            return Err(Error::new(
+                ErrorType::InvalidByteEncoding,
+                "JSON-LD input is not valid UTF-8.",
+            )
+            .with_source(err));
+        }
+
+        let document = JsonReader::new(&input).read_document()?;
+
+        let mut expansion = Expansion {
+            dataset: Dataset::new(),
+            blank_nodes: Graph::new(None),
+        };
+
+        expansion.read_top_level(&document, &ActiveContext::default())?;
+        self.config.check_dataset(&expansion.dataset)?;
+
+        Ok(expansion.dataset)
+    }
+}
+
+impl<R: Read> RdfParser for JsonLdParser<R> {
+    /// Generates an RDF graph with the triples of all graphs of the JSON-LD document.
+    ///
+    /// The graph names are dropped, use `decode_dataset` to keep them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::jsonld_parser::JsonLdParser;
+    /// use rdf::reader::rdf_parser::RdfParser;
+    ///
+    /// let input = r#"{
+    ///   "@context": {"@vocab": "http://schema.org/"},
+    ///   "@type": "Book",
+    ///   "name": "Moby-Dick",
+    ///   "author": {"name": "Herman Melville"}
+    /// }"#;
+    ///
+    /// assert_eq!(JsonLdParser::from_string(input).decode().unwrap().count(), 4);
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - Invalid input that does not conform with the JSON-LD standard.
+    ///
+    fn decode(&mut self) -> Result<Graph> {
+        let dataset = self.decode_dataset()?;
+        let mut graph = dataset.default_graph().clone();
+
+        for named_graph in dataset.named_graphs().values() {
+            graph.add_triples(&named_graph.triples_iter().cloned().collect::<Vec<_>>());
+        }
+
+        Ok(graph)
+    }
+}
+
+impl JsonLdParser<Cursor<Vec<u8>>> {
+    /// Constructor of `JsonLdParser` from input string.
+    pub fn from_string<S>(input: S) -> JsonLdParser<Cursor<Vec<u8>>>
+    where
+        S: Into<String>,
+    {
+        JsonLdParser::from_reader(Cursor::new(input.into().into_bytes()))
+    }
+}
+
+impl<R: Read> JsonLdParser<R> {
+    /// Constructor of `JsonLdParser` from input reader.
+    pub fn from_reader(input: R) -> JsonLdParser<R> {
+        JsonLdParser {
+            input,
+            config: ParserConfig::new(),
+        }
+    }
+
+    /// Sets the configuration of how strictly the input is parsed.
+    pub fn with_config(mut self, config: ParserConfig) -> JsonLdParser<R> {
+        self.config = config;
+        self
+    }
+}
+
+/// Returns an error for invalid JSON-LD.
+fn invalid(message: &str) -> Error {
+    Error::new(ErrorType::InvalidReaderInput, message.to_string())
+}
+
+/// Definition of a term in the active context.
+#[derive(Debug, Clone, Default)]
+struct TermDefinition {
+    /// IRI, compact IRI or term the term expands to, `None` uses the vocabulary.
+    id: Option<String>,
+    type_coercion: Option<String>,
+    /// Language of string values, `Some(None)` if strings have no language.
+    language: Option<Option<String>>,
+    is_list: bool,
+}
+
+/// Context that is used to expand the terms of a node object.
+#[derive(Debug, Clone, Default)]
+struct ActiveContext {
+    terms: HashMap<String, TermDefinition>,
+    vocab: Option<String>,
+    base: Option<String>,
+    language: Option<String>,
+}
+
+impl ActiveContext {
+    /// Returns the context updated with a local `@context` value.
+    fn with_context(&self, value: &JsonValue) -> Result<ActiveContext> {
+        match *value {
+            JsonValue::Null => Ok(ActiveContext {
+                base: self.base.clone(),
+                ..ActiveContext::default()
+            }),
+            JsonValue::Array(ref contexts) => {
+                let mut context = self.clone();
+                for local in contexts {
+                    context = context.with_context(local)?;
+                }
+                Ok(context)
+            }
+            JsonValue::String(_) => Err(Error::new(
+                ErrorType::UnresolvableIri,
+                "Remote JSON-LD contexts are not supported.",
+            )),
+            JsonValue::Object(ref members) => {
+                let mut context = self.clone();
+
+                for (key, value) in members {
+                    match (key.as_str(), value) {
+                        ("@vocab", &JsonValue::Null) => context.vocab = None,
+                        ("@vocab", JsonValue::String(vocab)) => {
+                            context.vocab = Some(context.expand_iri(vocab, true))
+                        }
+                        ("@base", &JsonValue::Null) => context.base = None,
+                        ("@base", JsonValue::String(base)) => {
+                            context.base = Some(context.expand_iri(base, false))
+                        }
+                        ("@language", &JsonValue::Null) => context.language = None,
+                        ("@language", JsonValue::String(language)) => {
+                            context.language = Some(language.clone())
+                        }
+                        ("@version", _) | ("@protected", _) | ("@propagate", _) => {}
+                        (keyword, _) if keyword.starts_with('@') => {
+                            return Err(invalid(
+                                &("Invalid JSON-LD context entry ".to_string() + keyword),
+                            ))
+                        }
+                        (term, &JsonValue::Null) => {
+                            context.terms.remove(term);
+                        }
+                        (term, definition) => {
+                            let definition = TermDefinition::from_json(definition)?;
+                            context.terms.insert(term.to_string(), definition);
+                        }
+                    }
+                }
+
+                Ok(context)
+            }
+            _ => Err(invalid("JSON-LD context is not an object.")),
+        }
+    }
+
+    /// Expands a term, compact IRI or relative IRI.
+    ///
+    /// Terms and the vocabulary are only used for vocabulary-relative values, like properties
+    /// and types, other values are resolved against the base IRI.
+    fn expand_iri(&self, value: &str, vocab: bool) -> String {
+        self.expand_iri_with_depth(value, vocab, 0)
+    }
+
+    /// Expands the IRI and stops following term definitions after some steps to avoid cycles.
+    fn expand_iri_with_depth(&self, value: &str, vocab: bool, depth: usize) -> String {
+        if value.starts_with('@') || depth > 10 {
+            return value.to_string();
+        }
+
+        if vocab {
+            if let Some(definition) = self.terms.get(value) {
+                return match definition.id {
+                    Some(ref id) if id != value => self.expand_iri_with_depth(id, true, depth + 1),
+                    _ => self.vocab.clone().unwrap_or_default() + value,
+                };
+            }
+        }
+
+        if let Some(i) = value.find(':') {
+            let (prefix, suffix) = (&value[..i], &value[i + 1..]);
+
+            if prefix == "_" || suffix.starts_with("//") {
+                return value.to_string();
+            }
+
+            if let Some(&TermDefinition {
+                id: Some(ref id), ..
+            }) = self.terms.get(prefix)
+            {
+                return self.expand_iri_with_depth(id, true, depth + 1) + suffix;
+            }
+
+            return value.to_string();
+        }
+
+        match (vocab, &self.vocab, &self.base) {
+            (true, Some(vocab), _) => vocab.clone() + value,
+            (false, _, Some(base)) => Uri::new(base.clone()).resolve(value).to_string().clone(),
+            _ => value.to_string(),
+        }
+    }
+
+    /// Returns a URI or blank node for an expanded IRI, relative IRIs are not nodes.
+    fn node(&self, value: &str, vocab: bool) -> Option<Node> {
+        let iri = self.expand_iri(value, vocab);
+
+        if let Some(id) = iri.strip_prefix("_:") {
+            return Some(Node::BlankNode { id: id.to_string() });
+        }
+
+        if iri.contains(':') {
+            return Some(Node::UriNode { uri: Uri::new(iri) });
+        }
+
+        None
+    }
+}
+
+impl TermDefinition {
+    /// Reads a term definition, either an IRI or an object with `@id`, `@type`,
+    /// `@container` and `@language`.
+    fn from_json(value: &JsonValue) -> Result<TermDefinition> {
+        let members = match *value {
+            JsonValue::String(ref id) => {
+                return Ok(TermDefinition {
+                    id: Some(id.clone()),
+                    ..TermDefinition::default()
+                })
+            }
+            JsonValue::Object(ref members) => members,
+            _ => return Err(invalid("Invalid JSON-LD term definition.")),
+        };
+
+        let mut definition = TermDefinition::default();
+
+        for (key, value) in members {
+            match (key.as_str(), value) {
+                ("@id", JsonValue::String(id)) => definition.id = Some(id.clone()),
+                ("@type", JsonValue::String(type_coercion)) => {
+                    definition.type_coercion = Some(type_coercion.clone())
+                }
+                ("@language", JsonValue::String(language)) => {
+                    definition.language = Some(Some(language.clone()))
+                }
+                ("@language", &JsonValue::Null) => definition.language = Some(None),
+                ("@container", JsonValue::String(container)) => {
+                    definition.is_list = container == "@list"
+                }
+                ("@container", JsonValue::Array(containers)) => {
+                    definition.is_list = containers
+                        .iter()
+                        .any(|c| *c == JsonValue::String("@list".to_string()))
+                }
+                _ => {}
+            }
+        }
+
+        Ok(definition)
+    }
+}
+
+/// State of the conversion of a JSON-LD document to a dataset.
+struct Expansion {
+    dataset: Dataset,
+    /// Creates the IDs of blank nodes without `@id`.
+    blank_nodes: Graph,
+}
+
+impl Expansion {
+    /// Reads the top-level value of the document, an object without other properties than
+    /// `@context` and `@graph` only contains the nodes of the default graph.
+    fn read_top_level(&mut self, document: &JsonValue, context: &ActiveContext) -> Result<()> {
+        if let JsonValue::Object(ref members) = *document {
+            let only_graph = members.iter().any(|(key, _)| key == "@graph")
+                && members
+                    .iter()
+                    .all(|(key, _)| key == "@context" || key == "@graph");
+
+            if only_graph {
+                let context = match document.member("@context") {
+                    Some(local) => context.with_context(local)?,
+                    None => context.clone(),
+                };
+
+                if let Some(graph) = document.member("@graph") {
+                    return self.read_nodes(graph, &context, None);
+                }
+            }
+        }
+
+        self.read_nodes(document, context, None)
+    }
+
+    /// Reads a node object or an array of node objects into the graph.
+    fn read_nodes(
+        &mut self,
+        value: &JsonValue,
+        context: &ActiveContext,
+        graph_name: Option<&Node>,
+    ) -> Result<()> {
+        match *value {
+            JsonValue::Array(ref values) => {
+                for value in values {
+                    self.read_nodes(value, context, graph_name)?;
+                }
+                Ok(())
+            }
+            JsonValue::Object(_) => self.read_node(value, context, graph_name).map(|_| ()),
+            _ => Err(invalid("Expected JSON-LD node object.")),
+        }
+    }
+
+    /// Adds the triple to the graph.
+    fn add(&mut self, graph_name: Option<&Node>, subject: &Node, predicate: &Node, object: &Node) {
+        let triple = Triple::new(subject, predicate, object);
+        self.dataset.add_quad(&Quad::new(&triple, graph_name));
+    }
+
+    /// Reads a node object, adds its triples to the graph and returns the node.
+    fn read_node(
+        &mut self,
+        object: &JsonValue,
+        parent_context: &ActiveContext,
+        graph_name: Option<&Node>,
+    ) -> Result<Node> {
+        let context = match object.member("@context") {
+            Some(local) => parent_context.with_context(local)?,
+            None => parent_context.clone(),
+        };
+
+        let subject = match object.member("@id") {
+            Some(JsonValue::String(id)) => match context.node(id, false) {
+                Some(node) => node,
+                None => return Err(invalid(&("Invalid JSON-LD node @id ".to_string() + id))),
+            },
+            Some(_) => return Err(invalid("JSON-LD @id is not a string.")),
+            None => self.blank_nodes.create_blank_node(),
+        };
+
+        let members = match *object {
+            JsonValue::Object(ref members) => members,
+            _ => return Err(invalid("Expected JSON-LD node object.")),
+        };
+
+        for (key, value) in members {
+            match key.as_str() {
+                "@type" => {
+                    let rdf_type = Node::UriNode {
+                        uri: RdfSyntaxDataTypes::A.to_uri(),
+                    };
+
+                    for type_value in value.as_array() {
+                        let class = match *type_value {
+                            JsonValue::String(ref class) => context.node(class, true),
+                            _ => return Err(invalid("JSON-LD @type is not a string.")),
+                        };
+
+                        if let Some(class) = class {
+                            self.add(graph_name, &subject, &rdf_type, &class);
+                        }
+                    }
+                }
+                "@graph" => self.read_nodes(value, &context, Some(&subject))?,
+                "@reverse" => {
+                    return Err(Error::new(
+                        ErrorType::UnsupportedOperation,
+                        "JSON-LD @reverse properties are not supported.",
+                    ))
+                }
+                keyword if keyword.starts_with('@') => {}
+                property => {
+                    let predicate = match context.node(property, true) {
+                        Some(node @ Node::UriNode { .. }) => node,
+                        _ => continue, // properties that do not expand to IRIs are dropped
+                    };
+
+                    let definition = context.terms.get(property).cloned().unwrap_or_default();
+
+                    let objects = if definition.is_list {
+                        match *value {
+                            JsonValue::Object(_) => {
+                                self.read_values(value, &definition, &context, graph_name)?
+                            }
+                            _ => {
+                                let items =
+                                    self.read_values(value, &definition, &context, graph_name)?;
+                                vec![self.add_list(&items, graph_name)]
+                            }
+                        }
+                    } else {
+                        self.read_values(value, &definition, &context, graph_name)?
+                    };
+
+                    for object in objects {
+                        self.add(graph_name, &subject, &predicate, &object);
+                    }
+                }
+            }
+        }
+
+        Ok(subject)
+    }
+
+    /// Reads the values of a property.
+    fn read_values(
+        &mut self,
+        value: &JsonValue,
+        definition: &TermDefinition,
+        context: &ActiveContext,
+        graph_name: Option<&Node>,
+    ) -> Result<Vec<Node>> {
+        let mut nodes = Vec::new();
+
+        for value in value.as_array() {
+            match *value {
+                JsonValue::Null => {}
+                JsonValue::Array(_) => {
+                    return Err(invalid("Nested JSON-LD arrays are only allowed in lists."))
+                }
+                JsonValue::Object(_) if value.member("@value").is_some() => {
+                    if let Some(literal) = self.read_value_object(value, context)? {
+                        nodes.push(literal);
+                    }
+                }
+                JsonValue::Object(_) if value.member("@list").is_some() => {
+                    let list = value.member("@list").unwrap_or(&JsonValue::Null);
+                    let items = self.read_values(list, definition, context, graph_name)?;
+                    nodes.push(self.add_list(&items, graph_name));
+                }
+                JsonValue::Object(_) if value.member("@set").is_some() => {
+                    let set = value.member("@set").unwrap_or(&JsonValue::Null);
+                    nodes.append(&mut self.read_values(set, definition, context, graph_name)?);
+                }
+                JsonValue::Object(_) => nodes.push(self.read_node(value, context, graph_name)?),
+                JsonValue::String(ref string) => match definition.type_coercion.as_deref() {
+                    Some("@id") => nodes.extend(context.node(string, false)),
+                    Some("@vocab") => nodes.extend(context.node(string, true)),
+                    Some(data_type) => nodes.push(Node::LiteralNode {
+                        literal: string.clone(),
+                        data_type: Some(Uri::new(context.expand_iri(data_type, true))),
+                        language: None,
+                    }),
+                    None => nodes.push(Node::LiteralNode {
+                        literal: string.clone(),
+                        data_type: None,
+                        language: match definition.language {
+                            Some(ref language) => language.clone(),
+                            None => context.language.clone(),
+                        },
+                    }),
+                },
+                JsonValue::Bool(_) | JsonValue::Number(_) => nodes.push(native_literal(value)),
+            }
+        }
+
+        Ok(nodes)
+    }
+
+    /// Reads a value object with `@value` and optional `@type` or `@language`.
+    fn read_value_object(
+        &mut self,
+        value: &JsonValue,
+        context: &ActiveContext,
+    ) -> Result<Option<Node>> {
+        let literal = match value.member("@value") {
+            Some(&JsonValue::Null) | None => return Ok(None),
+            Some(JsonValue::String(literal)) => literal.clone(),
+            Some(native) => match native_literal(native) {
+                Node::LiteralNode {
+                    literal, data_type, ..
+                } if value.member("@type").is_none() => {
+                    return Ok(Some(Node::LiteralNode {
+                        literal,
+                        data_type,
+                        language: None,
+                    }))
+                }
+                Node::LiteralNode { literal, .. } => literal,
+                _ => return Err(invalid("Invalid JSON-LD @value.")),
+            },
+        };
+
+        let data_type = match value.member("@type") {
+            Some(JsonValue::String(data_type)) => {
+                Some(Uri::new(context.expand_iri(data_type, true)))
+            }
+            Some(_) => return Err(invalid("JSON-LD @type of a value is not a string.")),
+            None => None,
+        };
+
+        let language = match value.member("@language") {
+            Some(JsonValue::String(language)) if data_type.is_none() => Some(language.clone()),
+            Some(_) => return Err(invalid("Invalid JSON-LD @language of a value.")),
+            None => None,
+        };
+
+        Ok(Some(Node::LiteralNode {
+            literal,
+            data_type,
+            language,
+        }))
+    }
+
+    /// Adds the `rdf:first` and `rdf:rest` triples of a list and returns its head.
+    fn add_list(&mut self, items: &[Node], graph_name: Option<&Node>) -> Node {
+        let nil = Node::UriNode {
+            uri: RdfSyntaxDataTypes::ListNil.to_uri(),
+        };
+        let first = Node::UriNode {
+            uri: RdfSyntaxDataTypes::ListFirst.to_uri(),
+        };
+        let rest = Node::UriNode {
+            uri: RdfSyntaxDataTypes::ListRest.to_uri(),
+        };
+
+        let cells: Vec<Node> = items
+            .iter()
+            .map(|_| self.blank_nodes.create_blank_node())
+            .collect();
+
+        for (index, item) in items.iter().enumerate() {
+            let next = cells.get(index + 1).unwrap_or(&nil).clone();
+            self.add(graph_name, &cells[index], &first, item);
+            self.add(graph_name, &cells[index], &rest, &next);
+        }
+
+        cells.first().cloned().unwrap_or(nil)
+    }
+}
+
+/// Returns the literal of a JSON boolean or number.
+fn native_literal(value: &JsonValue) -> Node {
+    let (literal, data_type) = match *value {
+        JsonValue::Bool(boolean) => (boolean.to_string(), XmlDataTypes::Boolean),
+        JsonValue::Number(ref number) if number.contains(['.', 'e', 'E']) => {
+            (number.clone(), XmlDataTypes::Double)
+        }
+        JsonValue::Number(ref number) => (number.clone(), XmlDataTypes::Integer),
+        _ => (String::new(), XmlDataTypes::String),
+    };
+
+    Node::LiteralNode {
+        literal,
+        data_type: Some(data_type.to_uri()),
+        language: None,
+    }
+}
+
+/// Value of a JSON document, the members of objects keep their order.
+#[derive(Debug, Clone, PartialEq)]
+enum JsonValue {
+    Null,
+    Bool(bool),
+    Number(String),
+    String(String),
+    Array(Vec<JsonValue>),
+    Object(Vec<(String, JsonValue)>),
+}
+
+impl JsonValue {
+    /// Returns the value of the member of an object.
+    fn member(&self, key: &str) -> Option<&JsonValue> {
+        match *self {
+            JsonValue::Object(ref members) => members
+                .iter()
+                .find(|(member, _)| member == key)
+                .map(|(_, value)| value),
+            _ => None,
+        }
+    }
+
+    /// Returns the elements of an array or the value itself.
+    fn as_array(&self) -> Vec<&JsonValue> {
+        match *self {
+            JsonValue::Array(ref values) => values.iter().collect(),
+            ref value => vec![value],
+        }
+    }
+}
+
+/// Reads a JSON document.
+struct JsonReader {
+    chars: Vec<char>,
+    position: usize,
+}
+
+impl JsonReader {
+    /// Constructor of `JsonReader`.
+    fn new(input: &str) -> JsonReader {
+        JsonReader {
+            chars: input.chars().collect(),
+            position: 0,
+        }
+    }
+
+    /// Reads the value of the document, only whitespace may follow it.
+    fn read_document(&mut self) -> Result<JsonValue> {
+        let value = self.read_value()?;
+
+        self.skip_whitespace();
+        if self.position < self.chars.len() {
+            return Err(invalid("Unexpected content after the JSON value."));
+        }
+
+        Ok(value)
+    }
+
+    /// Consumes whitespace.
+    fn skip_whitespace(&mut self) {
+        while self
+            .chars
+            .get(self.position)
+            .is_some_and(|c| c.is_whitespace())
+        {
+            self.position += 1;
+        }
+    }
+
+    /// Returns the next character that is not whitespace without consuming it.
+    fn peek(&mut self) -> Option<char> {
+        self.skip_whitespace();
+        self.chars.get(self.position).cloned()
+    }
+
+    /// Consumes the expected character.
+    fn expect(&mut self, expected: char) -> Result<()> {
+        if self.peek() != Some(expected) {
+            return Err(invalid(&format!("Expected '{}' in JSON input.", expected)));
+        }
+
+        self.position += 1;
+        Ok(())
+    }
+
+    /// Consumes the keyword if the input starts with it.
+    fn consume_keyword(&mut self, keyword: &str) -> bool {
+        let end = self.position + keyword.len();
+
+        if end <= self.chars.len()
+            && self.chars[self.position..end]
+                .iter()
+                .cloned()
+                .eq(keyword.chars())
+        {
+            self.position = end;
+            return true;
+        }
+
+        false
+    }
+
+    /// Reads a JSON value.
+    fn read_value(&mut self) -> Result<JsonValue> {
+        match self.peek() {
+            Some('{') => self.read_object(),
+            Some('[') => self.read_array(),
+            Some('"') => Ok(JsonValue::String(self.read_string()?)),
+            Some(c) if c == '-' || c.is_ascii_digit() => self.read_number(),
+            Some(_) if self.consume_keyword("true") => Ok(JsonValue::Bool(true)),
+            Some(_) if self.consume_keyword("false") => Ok(JsonValue::Bool(false)),
+            Some(_) if self.consume_keyword("null") => Ok(JsonValue::Null),
+            Some(_) => Err(invalid("Invalid JSON value.")),
+            None => Err(Error::new(
+                ErrorType::InvalidReaderInput,
+                "Unexpected end of JSON input.",
+            )),
+        }
+    }
+
+    /// Reads a JSON object.
+    fn read_object(&mut self) -> Result<JsonValue> {
+        self.expect('{')?;
+        let mut members = Vec::new();
+
+        if self.peek() == Some('}') {
+            self.position += 1;
+            return Ok(JsonValue::Object(members));
+        }
+
+        loop {
+            if self.peek() != Some('"') {
+                return Err(invalid("JSON object key is not a string."));
+            }
+
+            let key = self.read_string()?;
+            self.expect(':')?;
+            members.push((key, self.read_value()?));
+
+            match self.peek() {
+                Some(',') => self.position += 1,
+                Some('}') => {
+                    self.position += 1;
+                    return Ok(JsonValue::Object(members));
+                }
+                _ => return Err(invalid("Expected ',' or '}' in JSON object.")),
+            }
+        }
+    }
+
+    /// Reads a JSON array.
+    fn read_array(&mut self) -> Result<JsonValue> {
+        self.expect('[')?;
+        let mut values = Vec::new();
+
+        if self.peek() == Some(']') {
+            self.position += 1;
+            return Ok(JsonValue::Array(values));
+        }
+
+        loop {
+            values.push(self.read_value()?);
+
+            match self.peek() {
+                Some(',') => self.position += 1,
+                Some(']') => {
+                    self.position += 1;
+                    return Ok(JsonValue::Array(values));
+                }
+                _ => return Err(invalid("Expected ',' or ']' in JSON array.")),
+            }
+        }
+    }
+
+    /// Reads a JSON number and keeps its lexical form.
+    fn read_number(&mut self) -> Result<JsonValue> {
+        let start = self.position;
+
+        while self
+            .chars
+            .get(self.position)
+            .is_some_and(|&c| c.is_ascii_digit() || "+-.eE".contains(c))
+        {
+            self.position += 1;
+        }
+
+        let number: String = self.chars[start..self.position].iter().collect();
+
+        if number.parse::<f64>().is_err() {
+            return Err(invalid(&("Invalid JSON number ".to_string() + &number)));
+        }
+
+        Ok(JsonValue::Number(number))
+    }
+
+    /// Reads a JSON string and replaces its escape sequences.
+    fn read_string(&mut self) -> Result<String> {
+        self.expect('"')?;
+        let mut string = String::new();
+
+        loop {
+            let c = match self.chars.get(self.position) {
+                Some(&c) => c,
+                None => return Err(invalid("Unterminated JSON string.")),
+            };
+            self.position += 1;
+
+            match c {
+                '"' => return Ok(string),
+                '\\' => {
+                    let escaped = self.chars.get(self.position).cloned();
+                    self.position += 1;
+
+                    match escaped {
+                        Some('"') => string.push('"'),
+                        Some('\\') => string.push('\\'),
+                        Some('/') => string.push('/'),
+                        Some('b') => string.push('\u{8}'),
+                        Some('f') => string.push('\u{c}'),
+                        Some('n') => string.push('\n'),
+                        Some('r') => string.push('\r'),
+                        Some('t') => string.push('\t'),
+                        Some('u') => string.push(self.read_unicode_escape()?),
+                        _ => return Err(invalid("Invalid escape sequence in JSON string.")),
+                    }
+                }
+                c => string.push(c),
+            }
+        }
+    }
+
+    /// Reads the hex digits of a `\u` escape sequence, including surrogate pairs.
+    fn read_unicode_escape(&mut self) -> Result<char> {
+        let high = self.read_hex()?;
+
+        if (0xD800..0xDC00).contains(&high) && self.consume_keyword("\\u") {
+            let low = self.read_hex()?;
+            let code = 0x10000 + ((high - 0xD800) << 10) + (low.wrapping_sub(0xDC00) & 0x3FF);
+
+            if let Some(c) = std::char::from_u32(code) {
+                return Ok(c);
+            }
+        }
+
+        std::char::from_u32(high).ok_or_else(|| invalid("Invalid unicode escape in JSON string."))
+    }
+
+    /// Reads four hex digits.
+    fn read_hex(&mut self) -> Result<u32> {
+        let end = self.position + 4;
+
+        let digits: String = match self.chars.get(self.position..end) {
+            Some(digits) => digits.iter().collect(),
+            None => return Err(invalid("Invalid unicode escape in JSON string.")),
+        };
+        self.position = end;
+
+        u32::from_str_radix(&digits, 16)
+            .map_err(|_| invalid("Invalid unicode escape in JSON string."))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::node::Node;
+    use crate::reader::jsonld_parser::JsonLdParser;
+    use crate::reader::rdf_parser::{RdfDatasetParser, RdfParser};
+    use crate::specs::xml_specs::XmlDataTypes;
+    use crate::uri::Uri;
+
+    #[test]
+    fn read_json_ld_with_context_lists_and_value_objects() {
+        let input = r#"{
+          "@context": [
+            {"ex": "http://example.org/", "@base": "http://example.org/data/"},
+            {
+              "@language": "en",
+              "title": "ex:title",
+              "label": {"@id": "ex:label", "@language": null},
+              "steps": {"@id": "ex:steps", "@container": "@list"},
+              "next": {"@id": "ex:next", "@type": "@id"},
+              "effort": {"@id": "ex:effort", "@type": "ex:hours"}
+            }
+          ],
+          "@id": "task",
+          "@type": ["ex:Task"],
+          "title": "Write \"docs\" é",
+          "label": "w",
+          "effort": "3",
+          "ex:done": false,
+          "ex:size": 2.5,
+          "next": "_:review",
+          "steps": ["draft", {"@value": "check", "@language": "de"}, 1],
+          "ex:owner": {"@id": "http://example.org/alice", "ex:name": {"@value": "Alice", "@type": "ex:Name"}},
+          "unmapped": "dropped"
+        }"#;
+
+        let graph = JsonLdParser::from_string(input).decode().unwrap();
+        let uri = |uri: &str| Node::UriNode {
+            uri: Uri::new(uri.to_string()),
+        };
+        let literal =
+            |literal: &str, data_type: Option<Uri>, language: Option<&str>| Node::LiteralNode {
+                literal: literal.to_string(),
+                data_type,
+                language: language.map(|l| l.to_string()),
+            };
+        let task = uri("http://example.org/data/task");
+        let objects = |predicate: &str| -> Vec<Node> {
+            graph
+                .get_triples_with_subject_and_predicate(&task, &uri(predicate))
+                .into_iter()
+                .map(|t| t.object().clone())
+                .collect()
+        };
+
+        assert_eq!(graph.count(), 16);
+        assert_eq!(
+            objects("http://example.org/title"),
+            vec![literal("Write \"docs\" é", None, Some("en"))]
+        );
+        assert_eq!(
+            objects("http://example.org/label"),
+            vec![literal("w", None, None)]
+        );
+        assert_eq!(
+            objects("http://example.org/effort"),
+            vec![literal(
+                "3",
+                Some(Uri::new("http://example.org/hours".to_string())),
+                None
+            )]
+        );
+        assert_eq!(
+            objects("http://example.org/size"),
+            vec![literal("2.5", Some(XmlDataTypes::Double.to_uri()), None)]
+        );
+        assert_eq!(
+            objects("http://example.org/next"),
+            vec![Node::BlankNode {
+                id: "review".to_string()
+            }]
+        );
+        assert!(graph.contains_matching(None, None, Some(&literal("check", None, Some("de")))));
+        assert!(graph.contains_matching(
+            Some(&uri("http://example.org/alice")),
+            None,
+            Some(&literal(
+                "Alice",
+                Some(Uri::new("http://example.org/Name".to_string())),
+                None
+            ))
+        ));
+    }
+
+    #[test]
+    fn named_graphs_and_invalid_documents() {
+        let input = r#"{
+          "@context": {"@vocab": "http://example.org/"},
+          "@graph": [
+            {"@id": "http://example.org/g", "@graph": {"@id": "http://example.org/a", "p": "x"}},
+            {"@id": "http://example.org/b", "p": "y"}
+          ]
+        }"#;
+
+        let dataset = JsonLdParser::from_string(input).decode_dataset().unwrap();
+        let g = Node::UriNode {
+            uri: Uri::new("http://example.org/g".to_string()),
+        };
+
+        assert_eq!(dataset.default_graph().count(), 1);
+        assert_eq!(dataset.named_graph(&g).unwrap().count(), 1);
+
+        for input in &[
+            "{\"a\": }",
+            "[{\"@id\": \"_:a\"}] x",
+            "{\"@context\": \"http://schema.org/\"}",
+            "{\"@context\": 1}",
+            "{\"@id\": 1}",
+        ] {
+            assert!(JsonLdParser::from_string(*input).decode_dataset().is_err());
+        }
+    }
+}
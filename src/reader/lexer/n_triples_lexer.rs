@@ -1,14 +1,17 @@
-use Result;
-use error::{Error, ErrorType};
-use reader::input_reader::InputReader;
-use reader::lexer::rdf_lexer::RdfLexer;
-use reader::lexer::token::Token;
+use crate::error::{Error, ErrorType, Position};
+use crate::reader::input_reader::{InputReader, InputReaderHelper};
+use crate::reader::lexer::rdf_lexer::RdfLexer;
+use crate::reader::lexer::token::Token;
+use crate::specs::rdf_syntax_specs::RdfSyntaxSpecs;
+use crate::Result;
 use std::io::Read;
 
 /// Produces tokens from NTriples input.
 pub struct NTriplesLexer<R: Read> {
     input_reader: InputReader<R>,
     peeked_token: Option<Token>,
+    position: Position,
+    statement_end: bool,
 }
 
 impl<R: Read> RdfLexer<R> for NTriplesLexer<R> {
@@ -28,6 +31,8 @@ impl<R: Read> RdfLexer<R> for NTriplesLexer<R> {
         NTriplesLexer {
             input_reader: InputReader::new(input),
             peeked_token: None,
+            position: Position::default(),
+            statement_end: false,
         }
     }
 
@@ -55,26 +60,14 @@ impl<R: Read> RdfLexer<R> for NTriplesLexer<R> {
     /// - Input that does not conform to the NTriples standard.
     ///
     fn get_next_token(&mut self) -> Result<Token> {
-        if let Some(token) = self.peeked_token.clone() {
-            self.peeked_token = None;
-            return Ok(token);
-        }
+        // first return the peeked token
+        let token = match self.peeked_token.take() {
+            Some(token) => Ok(token),
+            None => self.read_next_token(),
+        };
 
-        match self.input_reader.peek_next_char_discard_leading_spaces()? {
-            Some('#') => self.get_comment(),
-            Some('"') => self.get_literal(),
-            Some('<') => self.get_uri(),
-            Some('_') => self.get_blank_node(),
-            Some('.') => {
-                self.consume_next_char(); // consume '.'
-                Ok(Token::TripleDelimiter)
-            }
-            None => Ok(Token::EndOfInput),
-            Some(c) => Err(Error::new(
-                ErrorType::InvalidReaderInput,
-                "Invalid NTriples input: ".to_string() + &c.to_string(),
-            )),
-        }
+        self.statement_end = matches!(token, Ok(Token::TripleDelimiter));
+        token
     }
 
     /// Determines the next token without consuming it.
@@ -105,26 +98,93 @@ impl<R: Read> RdfLexer<R> for NTriplesLexer<R> {
         match self.peeked_token.clone() {
             Some(token) => Ok(token),
             None => {
-                let next = self.get_next_token()?;
+                let next = self
+                    .read_next_token()
+                    .inspect_err(|_| self.statement_end = false)?;
                 self.peeked_token = Some(next.clone());
                 Ok(next)
             }
         }
     }
+
+    /// Returns the position where the last read or peeked token starts.
+    fn position(&self) -> Position {
+        self.position
+    }
 }
 
 impl<R: Read> NTriplesLexer<R> {
+    /// Skips the input up to and including the '.' that ends the current statement.
+    ///
+    /// Nothing is skipped if the last read token was '.'. Invalid input is skipped character
+    /// by character, so parsing can continue with the next statement after an error.
+    pub(crate) fn skip_statement(&mut self) {
+        while !self.statement_end {
+            match self.get_next_token() {
+                Ok(Token::EndOfInput) => return,
+                Ok(_) => {}
+                Err(err) => match *err.error_type() {
+                    ErrorType::EndOfInput(_) => return,
+                    _ => self.consume_next_char(),
+                },
+            }
+        }
+    }
+
+    /// Reads the next token from the input.
+    fn read_next_token(&mut self) -> Result<Token> {
+        let next_char = self.input_reader.peek_next_char_discard_leading_spaces()?;
+        self.position = self.input_reader.position();
+
+        match next_char {
+            Some('#') => self.get_comment(),
+            Some('"') => self.get_literal(),
+            Some('<') => {
+                // '<<' starts a quoted triple, otherwise it is a URI
+                if let Ok(token) = self.get_two_char_delimiter("<<", Token::QuotedTripleStart) {
+                    return Ok(token);
+                }
+                self.get_uri()
+            }
+            Some('>') => self.get_two_char_delimiter(">>", Token::QuotedTripleEnd),
+            Some('_') => self.get_blank_node(),
+            Some('.') => {
+                self.consume_next_char(); // consume '.'
+                Ok(Token::TripleDelimiter)
+            }
+            None => Ok(Token::EndOfInput),
+            Some(c) => Err(Error::new(
+                ErrorType::InvalidReaderInput,
+                "Invalid NTriples input: ".to_string() + &c.to_string(),
+            )),
+        }
+    }
+
     /// Consumes the next character of the input reader.
     fn consume_next_char(&mut self) {
         let _ = self.input_reader.get_next_char();
     }
 
+    /// Parses a delimiter that consists of two characters, like '<<' of quoted triples.
+    fn get_two_char_delimiter(&mut self, delimiter: &str, token: Token) -> Result<Token> {
+        if self.input_reader.peek_next_k_chars(2)?.to_string() == delimiter {
+            let _ = self.input_reader.get_next_k_chars(2); // consume delimiter
+            Ok(token)
+        } else {
+            Err(Error::new(
+                ErrorType::InvalidReaderInput,
+                "Invalid NTriples input, expected: ".to_string() + delimiter,
+            ))
+        }
+    }
+
     /// Parses the comment from the input and returns it as token.
     fn get_comment(&mut self) -> Result<Token> {
         self.consume_next_char(); // consume '#'
 
-        match self.input_reader
-            .get_until_discard_leading_spaces(|c| c == '\n' || c == '\r')
+        match self
+            .input_reader
+            .get_until_discard_leading_spaces(InputReaderHelper::line_break)
         {
             Ok(chars) => {
                 self.consume_next_char(); // consume comment delimiter
@@ -142,8 +202,9 @@ impl<R: Read> NTriplesLexer<R> {
 
     /// Parses the language specification from the input and returns it as token.
     fn get_language_specification(&mut self) -> Result<String> {
-        match self.input_reader
-            .get_until(|c| c == '\n' || c == '\r' || c == ' ' || c == '.')
+        match self
+            .input_reader
+            .get_until(InputReaderHelper::node_delimiter)
         {
             Ok(chars) => Ok(chars.to_string()),
             Err(err) => match *err.error_type() {
@@ -159,8 +220,30 @@ impl<R: Read> NTriplesLexer<R> {
     /// Parses a literal from the input and returns it as token.
     fn get_literal(&mut self) -> Result<Token> {
         self.consume_next_char(); // consume '"'
-        let literal = self.input_reader.get_until(|c| c == '"')?.to_string();
-        self.consume_next_char(); // consume '"'
+        let mut literal = "".to_string();
+
+        loop {
+            literal.push_str(
+                &self
+                    .input_reader
+                    .get_until(|c| c == '"' || c == '\\')?
+                    .to_string(),
+            );
+
+            // the escaped character never ends the literal, escapes are decoded at the end
+            match self.input_reader.get_next_char()? {
+                Some('\\') => {
+                    literal.push('\\');
+
+                    if let Some(c) = self.input_reader.get_next_char()? {
+                        literal.push(c);
+                    }
+                }
+                _ => break, // consumed '"'
+            }
+        }
+
+        let literal = RdfSyntaxSpecs::unescape_literal(&literal)?;
 
         match self.input_reader.peek_next_char()? {
             Some('@') => {
@@ -195,10 +278,7 @@ impl<R: Read> NTriplesLexer<R> {
                     )),
                 }
             }
-            _ => {
-                self.consume_next_char(); // consume '"'
-                Ok(Token::Literal(literal))
-            }
+            _ => Ok(Token::Literal(literal)),
         }
     }
 
@@ -207,7 +287,9 @@ impl<R: Read> NTriplesLexer<R> {
         self.consume_next_char(); // consume '<'
         let chars = self.input_reader.get_until(|c| c == '>')?;
         self.consume_next_char(); // consume '>'
-        Ok(Token::Uri(chars.to_string()))
+        Ok(Token::Uri(RdfSyntaxSpecs::unescape_iri(
+            &chars.to_string(),
+        )?))
     }
 
     /// Parses a blank node ID from the input and returns it as token.
@@ -232,8 +314,9 @@ impl<R: Read> NTriplesLexer<R> {
             }
         }
 
-        match self.input_reader
-            .get_until(|c| c == '\n' || c == '\r' || c == ' ' || c == '.')
+        match self
+            .input_reader
+            .get_until(|c| InputReaderHelper::node_delimiter(c) || c == '<' || c == '"')
         {
             Ok(chars) => Ok(Token::BlankNode(chars.to_string())),
             Err(err) => match *err.error_type() {
@@ -249,9 +332,9 @@ impl<R: Read> NTriplesLexer<R> {
 
 #[cfg(test)]
 mod tests {
-    use reader::lexer::n_triples_lexer::NTriplesLexer;
-    use reader::lexer::rdf_lexer::RdfLexer;
-    use reader::lexer::token::Token;
+    use crate::reader::lexer::n_triples_lexer::NTriplesLexer;
+    use crate::reader::lexer::rdf_lexer::RdfLexer;
+    use crate::reader::lexer::token::Token;
 
     #[test]
     fn test_n_triples_parse_comment() {
@@ -281,6 +364,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_n_triples_parse_escaped_literal() {
+        let input = r#""say \"hi\"\r\n\\ \u0041" <example.org/\U00000041>"#.as_bytes();
+
+        let mut lexer = NTriplesLexer::new(input);
+
+        assert_eq!(
+            lexer.get_next_token().unwrap(),
+            Token::Literal("say \"hi\"\r\n\\ A".to_string())
+        );
+        assert_eq!(
+            lexer.get_next_token().unwrap(),
+            Token::Uri("example.org/A".to_string())
+        );
+    }
+
     #[test]
     fn test_n_triples_parse_uri() {
         let input = "<example.org/a>".as_bytes();
@@ -342,4 +441,26 @@ mod tests {
         );
         assert_eq!(lexer.get_next_token().unwrap(), Token::TripleDelimiter);
     }
+
+    #[test]
+    fn test_n_triples_parse_quoted_triple() {
+        let input = "<< _:a <example.org/b> <example.org/c> >>".as_bytes();
+
+        let mut lexer = NTriplesLexer::new(input);
+
+        assert_eq!(lexer.get_next_token().unwrap(), Token::QuotedTripleStart);
+        assert_eq!(
+            lexer.get_next_token().unwrap(),
+            Token::BlankNode("a".to_string())
+        );
+        assert_eq!(
+            lexer.get_next_token().unwrap(),
+            Token::Uri("example.org/b".to_string())
+        );
+        assert_eq!(
+            lexer.get_next_token().unwrap(),
+            Token::Uri("example.org/c".to_string())
+        );
+        assert_eq!(lexer.get_next_token().unwrap(), Token::QuotedTripleEnd);
+    }
 }
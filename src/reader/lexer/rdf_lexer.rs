@@ -1,5 +1,6 @@
-use Result;
-use reader::lexer::token::Token;
+use crate::error::Position;
+use crate::reader::lexer::token::Token;
+use crate::Result;
 use std::io::Read;
 
 /// Trait implemented by RDF lexer.
@@ -12,4 +13,7 @@ pub trait RdfLexer<R: Read> {
 
     // Determines the next token without consuming it.
     fn peek_next_token(&mut self) -> Result<Token>;
+
+    /// Returns the position where the last read or peeked token starts.
+    fn position(&self) -> Position;
 }
@@ -11,6 +11,8 @@ pub enum Token {
     TripleDelimiter,
     PrefixDirective(String, String),
     BaseDirective(String),
+    SparqlPrefixDirective(String, String), // 'PREFIX' without '@' and trailing '.'
+    SparqlBaseDirective(String),           // 'BASE' without '@' and trailing '.'
     QName(String, String),
     Prefix(String),
     KeywordA,                // 'a'
@@ -20,5 +22,12 @@ pub enum Token {
     CollectionEnd,           // e.g. for Turtle syntax -> )
     UnlabeledBlankNodeStart, // e.g. for Turtle syntax -> [
     UnlabeledBlankNodeEnd,   // e.g. for Turtle syntax -> ]
+    QuotedTripleStart,       // e.g. for Turtle syntax -> <<
+    QuotedTripleEnd,         // e.g. for Turtle syntax -> >>
+    AnnotationStart,         // e.g. for Turtle syntax -> {|
+    AnnotationEnd,           // e.g. for Turtle syntax -> |}
+    GraphStart,              // e.g. for TriG syntax -> {
+    GraphEnd,                // e.g. for TriG syntax -> }
+    KeywordGraph,            // 'GRAPH'
     EndOfInput,
 }
@@ -1,16 +1,19 @@
-use Result;
-use error::{Error, ErrorType};
-use reader::input_reader::{InputReader, InputReaderHelper};
-use reader::lexer::rdf_lexer::RdfLexer;
-use reader::lexer::token::Token;
-use specs::turtle_specs::TurtleSpecs;
-use specs::xml_specs::XmlDataTypes;
+use crate::error::{Error, ErrorType, Position};
+use crate::reader::input_reader::{InputReader, InputReaderHelper};
+use crate::reader::lexer::rdf_lexer::RdfLexer;
+use crate::reader::lexer::token::Token;
+use crate::specs::rdf_syntax_specs::RdfSyntaxSpecs;
+use crate::specs::turtle_specs::TurtleSpecs;
+use crate::specs::xml_specs::XmlDataTypes;
+use crate::Result;
 use std::io::Read;
 
 /// Produces tokens from Turtle syntax input.
 pub struct TurtleLexer<R: Read> {
     input_reader: InputReader<R>,
     peeked_token: Option<Token>,
+    position: Position,
+    statement_end: bool,
 }
 
 impl<R: Read> RdfLexer<R> for TurtleLexer<R> {
@@ -30,6 +33,8 @@ impl<R: Read> RdfLexer<R> for TurtleLexer<R> {
         TurtleLexer {
             input_reader: InputReader::new(input),
             peeked_token: None,
+            position: Position::default(),
+            statement_end: false,
         }
     }
 
@@ -57,24 +62,120 @@ impl<R: Read> RdfLexer<R> for TurtleLexer<R> {
     /// - Input that does not conform to the Turtle syntax standard.
     ///
     fn get_next_token(&mut self) -> Result<Token> {
-        // first read peeked characters
-        if let Some(token) = self.peeked_token.clone() {
-            self.peeked_token = None;
-            return Ok(token);
+        // first return the peeked token
+        let token = match self.peeked_token.take() {
+            Some(token) => Ok(token),
+            None => self.read_next_token(),
+        };
+
+        self.statement_end = matches!(token, Ok(Token::TripleDelimiter));
+        token
+    }
+
+    /// Determines the next token without consuming the input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::lexer::rdf_lexer::RdfLexer;
+    /// use rdf::reader::lexer::turtle_lexer::TurtleLexer;
+    /// use rdf::reader::lexer::token::Token;
+    ///
+    /// let input = "_:auto <example.org/b> \"test\" .".as_bytes();
+    ///
+    /// let mut lexer = TurtleLexer::new(input);
+    ///
+    /// assert_eq!(lexer.peek_next_token().unwrap(), Token::BlankNode("auto".to_string()));
+    /// assert_eq!(lexer.peek_next_token().unwrap(), Token::BlankNode("auto".to_string()));
+    /// assert_eq!(lexer.get_next_token().unwrap(), Token::BlankNode("auto".to_string()));
+    /// assert_eq!(lexer.get_next_token().unwrap(), Token::Uri("example.org/b".to_string()));
+    /// ```
+    ///
+    ///  # Failures
+    ///
+    /// - End of input reached.
+    /// - Invalid input that does not conform with NTriples standard.
+    ///
+    fn peek_next_token(&mut self) -> Result<Token> {
+        match self.peeked_token.clone() {
+            Some(token) => Ok(token),
+            None => {
+                let next = self
+                    .read_next_token()
+                    .inspect_err(|_| self.statement_end = false)?;
+                self.peeked_token = Some(next.clone());
+                Ok(next)
+            }
         }
+    }
+
+    /// Returns the position where the last read or peeked token starts.
+    fn position(&self) -> Position {
+        self.position
+    }
+}
 
-        match try!(self.input_reader.peek_next_char_discard_leading_spaces()) {
+impl<R: Read> TurtleLexer<R> {
+    /// Skips the input up to and including the '.' that ends the current statement.
+    ///
+    /// Nothing is skipped if the last read token was '.'. Invalid input is skipped character
+    /// by character, so parsing can continue with the next statement after an error.
+    pub(crate) fn skip_statement(&mut self) {
+        while !self.statement_end {
+            match self.get_next_token() {
+                Ok(Token::EndOfInput) => return,
+                Ok(_) => {}
+                Err(err) => match *err.error_type() {
+                    ErrorType::EndOfInput(_) => return,
+                    _ => self.consume_next_char(),
+                },
+            }
+        }
+    }
+
+    /// Reads the next token from the input.
+    fn read_next_token(&mut self) -> Result<Token> {
+        let next_char = self.input_reader.peek_next_char_discard_leading_spaces()?;
+        self.position = self.input_reader.position();
+
+        match next_char {
             Some('#') => return self.get_comment(),
             Some('@') => {
                 self.consume_next_char(); // consume '@'
-                return self.get_base_or_prefix();
+                return self.get_base_or_prefix(false);
             }
             Some('"') | Some('\'') => return self.get_literal(),
-            Some('<') => return self.get_uri(),
+            Some('<') => {
+                // '<<' starts a quoted triple, otherwise it is a URI
+                if let Ok(token) = self.get_two_char_delimiter("<<", Token::QuotedTripleStart) {
+                    return Ok(token);
+                }
+                return self.get_uri();
+            }
+            Some('>') => return self.get_two_char_delimiter(">>", Token::QuotedTripleEnd),
+            Some('{') => {
+                // '{|' starts an annotation, otherwise it is the start of a TriG graph
+                if let Ok(token) = self.get_two_char_delimiter("{|", Token::AnnotationStart) {
+                    return Ok(token);
+                }
+                self.consume_next_char(); // consume '{'
+                return Ok(Token::GraphStart);
+            }
+            Some('}') => {
+                self.consume_next_char(); // consume '}'
+                return Ok(Token::GraphEnd);
+            }
+            Some('|') => return self.get_two_char_delimiter("|}", Token::AnnotationEnd),
             Some('_') => return self.get_blank_node(),
             Some('.') => {
-                // try to parse a decimal, if there is an error then it is a triple delimiter
-                return self.get_numeric().or_else(|_| Ok(Token::TripleDelimiter));
+                // a '.' followed by a digit starts a decimal, otherwise it is a triple delimiter
+                if self.input_reader.peek_next_k_chars(2)?[1].is_some_and(InputReaderHelper::digit)
+                {
+                    return self.get_numeric();
+                }
+
+                self.consume_next_char(); // consume '.'
+                return Ok(Token::TripleDelimiter);
             }
             Some(',') => {
                 self.consume_next_char(); // consume ','
@@ -100,9 +201,16 @@ impl<R: Read> RdfLexer<R> for TurtleLexer<R> {
                 self.consume_next_char(); // consume ']'
                 return Ok(Token::UnlabeledBlankNodeEnd);
             }
-            Some('P') | Some('B') => {
-                // try parsing PREFIX or BASE
-                if let Ok(token) = self.get_base_or_prefix() {
+            Some('P') | Some('B') | Some('p') | Some('b') => {
+                // try parsing the case-insensitive SPARQL-style PREFIX or BASE
+                if let Ok(token) = self.get_base_or_prefix(true) {
+                    return Ok(token);
+                }
+                // continue, because it could still be a QName
+            }
+            Some('G') | Some('g') => {
+                // try parsing the 'GRAPH' keyword of TriG
+                if let Ok(token) = self.get_graph_keyword() {
                     return Ok(token);
                 }
                 // continue, because it could still be a QName
@@ -130,55 +238,31 @@ impl<R: Read> RdfLexer<R> for TurtleLexer<R> {
         self.get_qname()
     }
 
-    /// Determines the next token without consuming the input.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use rdf::reader::lexer::rdf_lexer::RdfLexer;
-    /// use rdf::reader::lexer::turtle_lexer::TurtleLexer;
-    /// use rdf::reader::lexer::token::Token;
-    ///
-    /// let input = "_:auto <example.org/b> \"test\" .".as_bytes();
-    ///
-    /// let mut lexer = TurtleLexer::new(input);
-    ///
-    /// assert_eq!(lexer.peek_next_token().unwrap(), Token::BlankNode("auto".to_string()));
-    /// assert_eq!(lexer.peek_next_token().unwrap(), Token::BlankNode("auto".to_string()));
-    /// assert_eq!(lexer.get_next_token().unwrap(), Token::BlankNode("auto".to_string()));
-    /// assert_eq!(lexer.get_next_token().unwrap(), Token::Uri("example.org/b".to_string()));
-    /// ```
-    ///
-    ///  # Failures
-    ///
-    /// - End of input reached.
-    /// - Invalid input that does not conform with NTriples standard.
-    ///
-    fn peek_next_token(&mut self) -> Result<Token> {
-        match self.peeked_token.clone() {
-            Some(token) => Ok(token),
-            None => match self.get_next_token() {
-                Ok(next) => {
-                    self.peeked_token = Some(next.clone());
-                    Ok(next)
-                }
-                Err(err) => Err(err),
-            },
-        }
-    }
-}
-
-impl<R: Read> TurtleLexer<R> {
     /// Consumes the next character of the input reader.
     fn consume_next_char(&mut self) {
         let _ = self.input_reader.get_next_char();
     }
 
+    /// Parses a delimiter that consists of two characters, like '<<' of quoted triples.
+    fn get_two_char_delimiter(&mut self, delimiter: &str, token: Token) -> Result<Token> {
+        if self.input_reader.peek_next_k_chars(2)?.to_string() == delimiter {
+            let _ = self.input_reader.get_next_k_chars(2); // consume delimiter
+            Ok(token)
+        } else {
+            Err(Error::new(
+                ErrorType::InvalidReaderInput,
+                "Invalid Turtle input, expected: ".to_string() + delimiter,
+            ))
+        }
+    }
+
     /// Parses the base or prefix definition.
-    fn get_base_or_prefix(&mut self) -> Result<Token> {
+    ///
+    /// SPARQL-style directives are case-insensitive and do not end with '.'.
+    fn get_base_or_prefix(&mut self, sparql: bool) -> Result<Token> {
         match self.input_reader.peek_next_char()? {
-            Some('b') | Some('B') => self.get_base_directive(),
-            Some('p') | Some('P') => self.get_prefix_directive(),
+            Some('b') | Some('B') => self.get_base_directive(sparql),
+            Some('p') | Some('P') => self.get_prefix_directive(sparql),
             None | Some(_) => Err(Error::new(
                 ErrorType::InvalidReaderInput,
                 "Invalid input while trying to parse base or prefix definition.",
@@ -187,10 +271,12 @@ impl<R: Read> TurtleLexer<R> {
     }
 
     /// Parses the base directive.
-    fn get_base_directive(&mut self) -> Result<Token> {
-        let base_directive = self.input_reader.peek_next_k_chars(5)?;
+    fn get_base_directive(&mut self, sparql: bool) -> Result<Token> {
+        let base_directive = self.input_reader.peek_next_k_chars(5)?.to_string();
 
-        if base_directive.to_string().to_lowercase() != "base " {
+        if !is_directive_keyword(&base_directive, "base", sparql)
+            || !base_directive.ends_with(InputReaderHelper::whitespace)
+        {
             return Err(Error::new(
                 ErrorType::InvalidReaderInput,
                 "Invalid URI for Turtle base directive.",
@@ -200,6 +286,7 @@ impl<R: Read> TurtleLexer<R> {
         let _ = self.input_reader.get_until(|c| c == '<'); // consume 'base'
 
         match self.get_uri()? {
+            Token::Uri(base_uri) if sparql => Ok(Token::SparqlBaseDirective(base_uri)),
             Token::Uri(base_uri) => Ok(Token::BaseDirective(base_uri)),
             _ => Err(Error::new(
                 ErrorType::InvalidReaderInput,
@@ -209,27 +296,30 @@ impl<R: Read> TurtleLexer<R> {
     }
 
     /// Parses the prefix directive.
-    fn get_prefix_directive(&mut self) -> Result<Token> {
-        let prefix_directive = self.input_reader.peek_next_k_chars(7)?;
+    fn get_prefix_directive(&mut self, sparql: bool) -> Result<Token> {
+        let prefix_directive = self.input_reader.peek_next_k_chars(7)?.to_string();
 
-        if prefix_directive.to_string().to_lowercase() != "prefix " {
+        if !is_directive_keyword(&prefix_directive, "prefix", sparql)
+            || !prefix_directive.ends_with(InputReaderHelper::whitespace)
+        {
             return Err(Error::new(
                 ErrorType::InvalidReaderInput,
                 "Invalid URI for Turtle base directive.",
             ));
         }
 
-        let _ = self.input_reader.get_until(|c| c == ' '); // consume 'prefix'
+        let _ = self.input_reader.get_until(InputReaderHelper::whitespace); // consume 'prefix'
 
-        // get prefix name including ':'
-        let mut name = self.input_reader
+        // get prefix name without ':'
+        let name = self
+            .input_reader
             .get_until_discard_leading_spaces(|c| c == ':')?
             .to_string();
-        name.push(':');
 
         let _ = self.input_reader.get_until(|c| c == '<'); // consume characters until URI begin
 
         match self.get_uri()? {
+            Token::Uri(prefix_uri) if sparql => Ok(Token::SparqlPrefixDirective(name, prefix_uri)),
             Token::Uri(prefix_uri) => Ok(Token::PrefixDirective(name, prefix_uri)),
             _ => Err(Error::new(
                 ErrorType::InvalidReaderInput,
@@ -242,7 +332,8 @@ impl<R: Read> TurtleLexer<R> {
     fn get_comment(&mut self) -> Result<Token> {
         self.consume_next_char(); // consume '#'
 
-        match self.input_reader
+        match self
+            .input_reader
             .get_until_discard_leading_spaces(InputReaderHelper::line_break)
         {
             Ok(chars) => {
@@ -260,55 +351,56 @@ impl<R: Read> TurtleLexer<R> {
     }
 
     /// Parses integer, decimals and doubles.
+    ///
+    /// The literal gets the data type of the matching numeric production of the Turtle grammar,
+    /// e.g. `42` is an `xsd:integer`, `3.14` an `xsd:decimal` and `1.2e6` an `xsd:double`.
     fn get_numeric(&mut self) -> Result<Token> {
-        let numeric = self.input_reader
-            .get_until_discard_leading_spaces(InputReaderHelper::node_delimiter)?;
+        let mut numeric = self
+            .input_reader
+            .get_until_discard_leading_spaces(InputReaderHelper::turtle_node_delimiter)?;
 
-        // check if delimiter was '.' and if it is part of a decimal or if it is a delimiter
-        if self.input_reader.get_next_char()? == Some('.') {
-            let mut complete_numeric = numeric.clone();
+        // check if the delimiter is a '.' that is part of a decimal, other delimiters like ')'
+        // or a '.' ending the triple are tokens on their own and are not consumed
+        let next_chars = self.input_reader.peek_next_k_chars(2)?;
 
-            if let Ok(mut input_chars) = self.input_reader
-                .peek_until(InputReaderHelper::node_delimiter)
-            {
-                complete_numeric.push(Some('.'));
-                complete_numeric.append(&mut input_chars);
-
-                if TurtleSpecs::is_double_literal(&complete_numeric.to_string()) {
-                    let _ = self.input_reader
-                        .get_until_discard_leading_spaces(InputReaderHelper::node_delimiter)?; // consume
-                    return Ok(Token::LiteralWithUrlDatatype(
-                        complete_numeric.to_string(),
-                        XmlDataTypes::Double.to_string(),
-                    ));
-                }
-            }
+        if next_chars[0] == Some('.')
+            && next_chars[1].is_some_and(|c| InputReaderHelper::digit(c) || c == 'e' || c == 'E')
+        {
+            self.consume_next_char(); // consume '.'
+            let mut fraction = self
+                .input_reader
+                .get_until(InputReaderHelper::turtle_node_delimiter)?;
+
+            numeric.push(Some('.'));
+            numeric.append(&mut fraction);
         }
 
-        if TurtleSpecs::is_integer_literal(&numeric.to_string()) {
-            Ok(Token::LiteralWithUrlDatatype(
-                numeric.to_string(),
-                XmlDataTypes::Integer.to_string(),
-            ))
-        } else if TurtleSpecs::is_double_literal(&numeric.to_string()) {
-            Ok(Token::LiteralWithUrlDatatype(
-                numeric.to_string(),
-                XmlDataTypes::Double.to_string(),
-            ))
-        } else {
-            Err(Error::new(
+        let numeric = numeric.to_string();
+
+        [
+            XmlDataTypes::Integer,
+            XmlDataTypes::Decimal,
+            XmlDataTypes::Double,
+        ]
+        .iter()
+        .find(|data_type| TurtleSpecs::is_numeric_shorthand(&numeric, &Some(data_type.to_uri())))
+        .map(|data_type| Token::LiteralWithUrlDatatype(numeric.clone(), data_type.to_string()))
+        .ok_or_else(|| {
+            Error::new(
                 ErrorType::InvalidReaderInput,
-                "Invalid Turtle input for numeric literal.",
-            ))
-        }
+                "Invalid Turtle input for numeric literal: ".to_string() + &numeric,
+            )
+        })
     }
 
     /// Parses a boolean value and returns it as token.
     fn get_boolean_literal(&mut self) -> Result<Token> {
-        let boolean = self.input_reader
-            .peek_until_discard_leading_spaces(InputReaderHelper::node_delimiter)?;
+        let boolean = self
+            .input_reader
+            .peek_until_discard_leading_spaces(InputReaderHelper::turtle_node_delimiter)?;
 
         if TurtleSpecs::is_boolean_literal(&boolean.to_string()) {
+            let _ = self.input_reader.get_next_k_chars(boolean.len()); // consume 'true' or 'false'
             Ok(Token::LiteralWithUrlDatatype(
                 boolean.to_string(),
                 XmlDataTypes::Boolean.to_string(),
@@ -323,10 +415,12 @@ impl<R: Read> TurtleLexer<R> {
 
     /// Parses the 'a' keyword.
     fn get_a_keyword(&mut self) -> Result<Token> {
-        let a = self.input_reader
-            .peek_until_discard_leading_spaces(InputReaderHelper::node_delimiter)?;
+        let a = self
+            .input_reader
+            .peek_until_discard_leading_spaces(InputReaderHelper::turtle_node_delimiter)?;
 
         if a.len() == 1 && a[0] == Some('a') {
+            self.consume_next_char(); // consume 'a'
             Ok(Token::KeywordA)
         } else {
             Err(Error::new(
@@ -336,10 +430,28 @@ impl<R: Read> TurtleLexer<R> {
         }
     }
 
+    /// Parses the 'GRAPH' keyword of TriG, which is case-insensitive.
+    fn get_graph_keyword(&mut self) -> Result<Token> {
+        let keyword = self
+            .input_reader
+            .peek_until_discard_leading_spaces(InputReaderHelper::turtle_node_delimiter)?;
+
+        if keyword.to_string().to_lowercase() == "graph" {
+            let _ = self.input_reader.get_next_k_chars(5); // consume 'GRAPH'
+            Ok(Token::KeywordGraph)
+        } else {
+            Err(Error::new(
+                ErrorType::InvalidReaderInput,
+                "Invalid TriG input for keyword 'GRAPH'.",
+            ))
+        }
+    }
+
     /// Parses the language specification from the input and returns it as token.
     fn get_language_specification(&mut self) -> Result<String> {
-        match self.input_reader
-            .get_until(InputReaderHelper::node_delimiter)
+        match self
+            .input_reader
+            .get_until(InputReaderHelper::turtle_node_delimiter)
         {
             Ok(chars) => Ok(chars.to_string()),
             Err(err) => match *err.error_type() {
@@ -367,34 +479,45 @@ impl<R: Read> TurtleLexer<R> {
             let _ = self.input_reader.get_next_k_chars(2); // consume
         }
 
-        let mut found_literal_end = false;
+        let delimiter = literal_delimiter.unwrap_or('"');
         let mut literal = "".to_string();
 
-        while !found_literal_end {
-            literal.push_str(&self.input_reader
-                .get_until(|c| c == literal_delimiter.unwrap())?
-                .to_string());
-
-            if is_multiline {
-                // if not escaped check if the literal is complete
-                let potential_literal_delimiters = self.input_reader.peek_next_k_chars(2)?.to_vec();
+        loop {
+            literal.push_str(
+                &self
+                    .input_reader
+                    .get_until(|c| c == delimiter || c == '\\')?
+                    .to_string(),
+            );
 
-                if potential_literal_delimiters[0] == literal_delimiter
-                    && potential_literal_delimiters[1] == literal_delimiter
-                {
-                    self.consume_next_char();
-                    self.consume_next_char();
+            if self.input_reader.get_next_char()? == Some('\\') {
+                // the escaped character never ends the literal, escapes are decoded at the end
+                literal.push('\\');
 
-                    found_literal_end = true;
-                } else {
-                    literal.push_str(&self.input_reader.get_next_k_chars(1)?.to_string());
+                if let Some(c) = self.input_reader.get_next_char()? {
+                    literal.push(c);
                 }
-            } else {
-                found_literal_end = true;
+                continue;
+            }
+
+            if !is_multiline {
+                break;
             }
+
+            // long literals end with the first three quotes, single and double quotes are content
+            let potential_literal_delimiters = self.input_reader.peek_next_k_chars(2)?;
+
+            if potential_literal_delimiters[0] == literal_delimiter
+                && potential_literal_delimiters[1] == literal_delimiter
+            {
+                let _ = self.input_reader.get_next_k_chars(2); // consume
+                break;
+            }
+
+            literal.push(delimiter);
         }
 
-        self.consume_next_char(); // consume '"'
+        let literal = RdfSyntaxSpecs::unescape_literal(&literal)?;
 
         match self.input_reader.peek_next_char()? {
             Some('@') => {
@@ -443,7 +566,7 @@ impl<R: Read> TurtleLexer<R> {
         self.consume_next_char(); // consume '<'
         let chars = self.input_reader.get_until(|c| c == '>')?.to_string();
         self.consume_next_char(); // consume '>'
-        Ok(Token::Uri(chars))
+        Ok(Token::Uri(RdfSyntaxSpecs::unescape_iri(&chars)?))
     }
 
     /// Parses a blank node ID from the input and returns it as token.
@@ -468,8 +591,9 @@ impl<R: Read> TurtleLexer<R> {
             }
         }
 
-        match self.input_reader
-            .get_until(InputReaderHelper::node_delimiter)
+        match self
+            .input_reader
+            .get_until(InputReaderHelper::turtle_node_delimiter)
         {
             Ok(chars) => Ok(Token::BlankNode(chars.to_string())),
             Err(err) => match *err.error_type() {
@@ -484,31 +608,73 @@ impl<R: Read> TurtleLexer<R> {
 
     /// Parses a QName.
     fn get_qname(&mut self) -> Result<Token> {
-        let mut prefix = self.input_reader.get_until(|c| c == ':')?.to_string();
-        prefix.push(':'); // ':' is part of prefix name
+        let prefix = self.input_reader.get_until(|c| c == ':')?.to_string();
         self.consume_next_char(); // consume ':'
 
-        match self.input_reader
-            .get_until(InputReaderHelper::node_delimiter)
-        {
-            Ok(chars) => Ok(Token::QName(prefix, chars.to_string())),
-            Err(err) => match *err.error_type() {
-                ErrorType::EndOfInput(ref chars) => Ok(Token::QName(prefix, chars.to_string())),
-                _ => Err(Error::new(
-                    ErrorType::InvalidReaderInput,
-                    "Invalid input for Turtle lexer while parsing QName.",
-                )),
-            },
+        let local_name = self.get_local_name()?;
+
+        Ok(Token::QName(prefix, local_name))
+    }
+
+    /// Parses the local part of a QName and decodes its escaped characters, like `\-`.
+    ///
+    /// A '.' is part of the local name if another character of the name follows it.
+    fn get_local_name(&mut self) -> Result<String> {
+        let mut local_name = String::new();
+
+        loop {
+            match self.input_reader.peek_next_char()? {
+                Some('\\') => {
+                    self.consume_next_char(); // consume '\'
+
+                    match self.input_reader.get_next_char()? {
+                        Some(c) if TurtleSpecs::is_local_name_escape(c) => local_name.push(c),
+                        _ => {
+                            return Err(Error::new(
+                                ErrorType::InvalidReaderInput,
+                                "Invalid escape sequence in Turtle QName.",
+                            ))
+                        }
+                    }
+                }
+                Some('.') => match self.input_reader.peek_next_k_chars(2)?[1] {
+                    Some(c) if !InputReaderHelper::turtle_node_delimiter(c) => {
+                        self.consume_next_char(); // consume '.'
+                        local_name.push('.');
+                    }
+                    _ => break,
+                },
+                Some(c) if !InputReaderHelper::turtle_node_delimiter(c) => {
+                    self.consume_next_char();
+                    local_name.push(c);
+                }
+                _ => break,
+            }
         }
+
+        Ok(local_name)
+    }
+}
+
+/// Checks if the input starts with the keyword of a directive.
+///
+/// Keywords of SPARQL-style directives are case-insensitive, those starting with '@' are not.
+fn is_directive_keyword(input: &str, keyword: &str, sparql: bool) -> bool {
+    let start = &input[..keyword.len().min(input.len())];
+
+    if sparql {
+        start.eq_ignore_ascii_case(keyword)
+    } else {
+        start == keyword
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use reader::lexer::rdf_lexer::RdfLexer;
-    use reader::lexer::token::Token;
-    use reader::lexer::turtle_lexer::TurtleLexer;
-    use specs::xml_specs::XmlDataTypes;
+    use crate::reader::lexer::rdf_lexer::RdfLexer;
+    use crate::reader::lexer::token::Token;
+    use crate::reader::lexer::turtle_lexer::TurtleLexer;
+    use crate::specs::xml_specs::XmlDataTypes;
 
     #[test]
     fn parse_base_directive() {
@@ -525,15 +691,18 @@ mod tests {
 
     #[test]
     fn parse_sparql_base_directive() {
-        let input = "BASE <http://example.org/> .".as_bytes();
+        let input = "BASE <http://example.org/> base <a>".as_bytes();
 
         let mut lexer = TurtleLexer::new(input);
 
         assert_eq!(
             lexer.get_next_token().unwrap(),
-            Token::BaseDirective("http://example.org/".to_string())
+            Token::SparqlBaseDirective("http://example.org/".to_string())
+        );
+        assert_eq!(
+            lexer.get_next_token().unwrap(),
+            Token::SparqlBaseDirective("a".to_string())
         );
-        assert_eq!(lexer.get_next_token().unwrap(), Token::TripleDelimiter);
     }
 
     #[test]
@@ -544,28 +713,35 @@ mod tests {
 
         assert_eq!(
             lexer.get_next_token().unwrap(),
-            Token::PrefixDirective(
-                "foaf:".to_string(),
-                "http://xmlns.com/foaf/0.1/".to_string()
-            )
+            Token::PrefixDirective("foaf".to_string(), "http://xmlns.com/foaf/0.1/".to_string())
         );
         assert_eq!(lexer.get_next_token().unwrap(), Token::TripleDelimiter);
     }
 
     #[test]
     fn parse_sparql_prefix_directive() {
-        let input = "PREFIX foaf: <http://xmlns.com/foaf/0.1/> .".as_bytes();
+        let input = "PREFIX foaf: <http://xmlns.com/foaf/0.1/>\nprefix:a prefix:b".as_bytes();
 
         let mut lexer = TurtleLexer::new(input);
 
         assert_eq!(
             lexer.get_next_token().unwrap(),
-            Token::PrefixDirective(
-                "foaf:".to_string(),
+            Token::SparqlPrefixDirective(
+                "foaf".to_string(),
                 "http://xmlns.com/foaf/0.1/".to_string()
             )
         );
-        assert_eq!(lexer.get_next_token().unwrap(), Token::TripleDelimiter);
+        assert_eq!(
+            lexer.get_next_token().unwrap(),
+            Token::QName("prefix".to_string(), "a".to_string())
+        );
+    }
+
+    #[test]
+    fn parse_invalid_directive_keyword() {
+        let input = "@PREFIX foaf: <http://xmlns.com/foaf/0.1/> .".as_bytes();
+
+        assert!(TurtleLexer::new(input).get_next_token().is_err());
     }
 
     #[test]
@@ -641,15 +817,15 @@ mod tests {
 
         assert_eq!(
             lexer.get_next_token().unwrap(),
-            Token::QName("abc:".to_string(), "def:ghij".to_string())
+            Token::QName("abc".to_string(), "def:ghij".to_string())
         );
         assert_eq!(
             lexer.get_next_token().unwrap(),
-            Token::QName("gggg:".to_string(), "gggg".to_string())
+            Token::QName("gggg".to_string(), "gggg".to_string())
         );
         assert_eq!(
             lexer.get_next_token().unwrap(),
-            Token::QName("abc:".to_string(), "dd".to_string())
+            Token::QName("abc".to_string(), "dd".to_string())
         );
     }
 
@@ -675,7 +851,7 @@ mod tests {
             lexer.get_next_token().unwrap(),
             Token::LiteralWithQNameDatatype(
                 "a".to_string(),
-                "ex:".to_string(),
+                "ex".to_string(),
                 "abc:asdf".to_string()
             )
         );
@@ -707,9 +883,82 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_long_literals() {
+        let input = r#""""first line
+second "quoted" ""line"""
+                       '''it's ''done''' """""" """a \"""b""""#;
+        let mut lexer = TurtleLexer::new(input.as_bytes());
+
+        assert_eq!(
+            lexer.get_next_token().unwrap(),
+            Token::Literal("first line\nsecond \"quoted\" \"\"line".to_string())
+        );
+        assert_eq!(
+            lexer.get_next_token().unwrap(),
+            Token::Literal("it's ''done".to_string())
+        );
+        assert_eq!(
+            lexer.get_next_token().unwrap(),
+            Token::Literal("".to_string())
+        );
+        assert_eq!(
+            lexer.get_next_token().unwrap(),
+            Token::Literal("a \"\"\"b".to_string())
+        );
+    }
+
+    #[test]
+    fn parse_escape_sequences() {
+        let input = r#""a\tb\n\"c\\" 'it\'s' "\u00E9\U0001F600" <http://example.org/\u00E9> "\x""#;
+        let mut lexer = TurtleLexer::new(input.as_bytes());
+
+        assert_eq!(
+            lexer.get_next_token().unwrap(),
+            Token::Literal("a\tb\n\"c\\".to_string())
+        );
+        assert_eq!(
+            lexer.get_next_token().unwrap(),
+            Token::Literal("it's".to_string())
+        );
+        assert_eq!(
+            lexer.get_next_token().unwrap(),
+            Token::Literal("\u{e9}\u{1f600}".to_string())
+        );
+        assert_eq!(
+            lexer.get_next_token().unwrap(),
+            Token::Uri("http://example.org/\u{e9}".to_string())
+        );
+        assert!(lexer.get_next_token().is_err());
+    }
+
+    #[test]
+    fn parse_single_quoted_literals() {
+        let input = r#"'a "b"' 'c'@en '' '''x
+y'''"#;
+        let mut lexer = TurtleLexer::new(input.as_bytes());
+
+        assert_eq!(
+            lexer.get_next_token().unwrap(),
+            Token::Literal("a \"b\"".to_string())
+        );
+        assert_eq!(
+            lexer.get_next_token().unwrap(),
+            Token::LiteralWithLanguageSpecification("c".to_string(), "en".to_string())
+        );
+        assert_eq!(
+            lexer.get_next_token().unwrap(),
+            Token::Literal("".to_string())
+        );
+        assert_eq!(
+            lexer.get_next_token().unwrap(),
+            Token::Literal("x\ny".to_string())
+        );
+    }
+
     #[test]
     fn parse_numeric_literals() {
-        let input = "4 1.2 -5.123 -.123 .123 5e10 .".as_bytes();
+        let input = "4 1.2 -5.123 -.123 .123 5e10 +1.2E-6 1.e3 .".as_bytes();
         let mut lexer = TurtleLexer::new(input);
 
         assert_eq!(
@@ -718,23 +967,128 @@ mod tests {
         );
         assert_eq!(
             lexer.get_next_token().unwrap(),
-            Token::LiteralWithUrlDatatype("1.2".to_string(), XmlDataTypes::Double.to_string())
+            Token::LiteralWithUrlDatatype("1.2".to_string(), XmlDataTypes::Decimal.to_string())
         );
         assert_eq!(
             lexer.get_next_token().unwrap(),
-            Token::LiteralWithUrlDatatype("-5.123".to_string(), XmlDataTypes::Double.to_string())
+            Token::LiteralWithUrlDatatype("-5.123".to_string(), XmlDataTypes::Decimal.to_string())
         );
         assert_eq!(
             lexer.get_next_token().unwrap(),
-            Token::LiteralWithUrlDatatype("-.123".to_string(), XmlDataTypes::Double.to_string())
+            Token::LiteralWithUrlDatatype("-.123".to_string(), XmlDataTypes::Decimal.to_string())
         );
         assert_eq!(
             lexer.get_next_token().unwrap(),
-            Token::LiteralWithUrlDatatype(".123".to_string(), XmlDataTypes::Double.to_string())
+            Token::LiteralWithUrlDatatype(".123".to_string(), XmlDataTypes::Decimal.to_string())
         );
         assert_eq!(
             lexer.get_next_token().unwrap(),
             Token::LiteralWithUrlDatatype("5e10".to_string(), XmlDataTypes::Double.to_string())
         );
+        assert_eq!(
+            lexer.get_next_token().unwrap(),
+            Token::LiteralWithUrlDatatype("+1.2E-6".to_string(), XmlDataTypes::Double.to_string())
+        );
+        assert_eq!(
+            lexer.get_next_token().unwrap(),
+            Token::LiteralWithUrlDatatype("1.e3".to_string(), XmlDataTypes::Double.to_string())
+        );
+        assert_eq!(lexer.get_next_token().unwrap(), Token::TripleDelimiter);
+    }
+
+    #[test]
+    fn parse_numeric_literals_in_collection() {
+        let input = "(1 2.5)".as_bytes();
+        let mut lexer = TurtleLexer::new(input);
+
+        assert_eq!(lexer.get_next_token().unwrap(), Token::CollectionStart);
+        assert_eq!(
+            lexer.get_next_token().unwrap(),
+            Token::LiteralWithUrlDatatype("1".to_string(), XmlDataTypes::Integer.to_string())
+        );
+        assert_eq!(
+            lexer.get_next_token().unwrap(),
+            Token::LiteralWithUrlDatatype("2.5".to_string(), XmlDataTypes::Decimal.to_string())
+        );
+        assert_eq!(lexer.get_next_token().unwrap(), Token::CollectionEnd);
+    }
+
+    #[test]
+    fn parse_boolean_literals() {
+        let input = "true, false) truth:x .".as_bytes();
+        let mut lexer = TurtleLexer::new(input);
+
+        assert_eq!(
+            lexer.get_next_token().unwrap(),
+            Token::LiteralWithUrlDatatype("true".to_string(), XmlDataTypes::Boolean.to_string())
+        );
+        assert_eq!(lexer.get_next_token().unwrap(), Token::ObjectListDelimiter);
+        assert_eq!(
+            lexer.get_next_token().unwrap(),
+            Token::LiteralWithUrlDatatype("false".to_string(), XmlDataTypes::Boolean.to_string())
+        );
+        assert_eq!(lexer.get_next_token().unwrap(), Token::CollectionEnd);
+        assert_eq!(
+            lexer.get_next_token().unwrap(),
+            Token::QName("truth".to_string(), "x".to_string())
+        );
+    }
+
+    #[test]
+    fn parse_a_keyword() {
+        let input = "_:s a ab:c ; a:b a ; a[ ] .".as_bytes();
+        let mut lexer = TurtleLexer::new(input);
+
+        assert_eq!(
+            lexer.get_next_token().unwrap(),
+            Token::BlankNode("s".to_string())
+        );
+        assert_eq!(lexer.get_next_token().unwrap(), Token::KeywordA);
+        assert_eq!(
+            lexer.get_next_token().unwrap(),
+            Token::QName("ab".to_string(), "c".to_string())
+        );
+        assert_eq!(
+            lexer.get_next_token().unwrap(),
+            Token::PredicateListDelimiter
+        );
+        assert_eq!(
+            lexer.get_next_token().unwrap(),
+            Token::QName("a".to_string(), "b".to_string())
+        );
+        assert_eq!(lexer.get_next_token().unwrap(), Token::KeywordA);
+        assert_eq!(
+            lexer.get_next_token().unwrap(),
+            Token::PredicateListDelimiter
+        );
+        assert_eq!(lexer.get_next_token().unwrap(), Token::KeywordA);
+        assert_eq!(
+            lexer.get_next_token().unwrap(),
+            Token::UnlabeledBlankNodeStart
+        );
+    }
+
+    #[test]
+    fn parse_quoted_triple_with_annotation() {
+        let input = "<< <http://example.org/s> <http://example.org/p> _:o >> {| |} .".as_bytes();
+        let mut lexer = TurtleLexer::new(input);
+
+        assert_eq!(lexer.get_next_token().unwrap(), Token::QuotedTripleStart);
+        assert_eq!(
+            lexer.get_next_token().unwrap(),
+            Token::Uri("http://example.org/s".to_string())
+        );
+        assert_eq!(
+            lexer.get_next_token().unwrap(),
+            Token::Uri("http://example.org/p".to_string())
+        );
+        assert_eq!(
+            lexer.get_next_token().unwrap(),
+            Token::BlankNode("o".to_string())
+        );
+        assert_eq!(lexer.get_next_token().unwrap(), Token::QuotedTripleEnd);
+        assert_eq!(lexer.get_next_token().unwrap(), Token::AnnotationStart);
+        assert_eq!(lexer.get_next_token().unwrap(), Token::AnnotationEnd);
+        assert_eq!(lexer.get_next_token().unwrap(), Token::TripleDelimiter);
     }
 }
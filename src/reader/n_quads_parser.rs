@@ -0,0 +1,198 @@
+use crate::dataset::{Dataset, Quad};
+use crate::graph::Graph;
+use crate::reader::n_triples_parser::NTriplesParser;
+use crate::reader::parser_config::ParserConfig;
+use crate::reader::rdf_parser::{RdfDatasetParser, RdfParser};
+use crate::Result;
+use std::io::Cursor;
+use std::io::Read;
+
+/// RDF parser to generate an RDF dataset from N-Quads syntax.
+///
+/// N-Quads extends N-Triples by an optional graph label after the object of each triple,
+/// triples without graph label belong to the default graph.
+pub struct NQuadsParser<R: Read> {
+    parser: NTriplesParser<R>,
+}
+
+impl<R: Read> RdfDatasetParser for NQuadsParser<R> {
+    /// Generates an RDF dataset from a string containing N-Quads syntax.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::node::Node;
+    /// use rdf::reader::n_quads_parser::NQuadsParser;
+    /// use rdf::reader::rdf_parser::RdfDatasetParser;
+    /// use rdf::uri::Uri;
+    ///
+    /// let input = "_:art <http://xmlns.com/foaf/0.1/name> \"Art Barstow\" <http://example.org/people> .
+    ///              _:art <http://xmlns.com/foaf/0.1/knows> _:dan .";
+    ///
+    /// let dataset = NQuadsParser::from_string(input).decode_dataset().unwrap();
+    /// let people = Node::UriNode { uri: Uri::new("http://example.org/people".to_string()) };
+    ///
+    /// assert_eq!(dataset.default_graph().count(), 1);
+    /// assert_eq!(dataset.named_graph(&people).unwrap().count(), 1);
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - Invalid input that does not conform with the N-Quads standard.
+    ///
+    fn decode_dataset(&mut self) -> Result<Dataset> {
+        let mut dataset = Dataset::new();
+
+        while let Some(quad) = self.next_quad()? {
+            dataset.add_quad(&quad);
+        }
+
+        Ok(dataset)
+    }
+}
+
+impl<R: Read> RdfParser for NQuadsParser<R> {
+    /// Generates an RDF graph with the triples of all graphs of the N-Quads input.
+    ///
+    /// The graph labels are dropped, use `decode_dataset` to keep them.
+    ///
+    /// # Failures
+    ///
+    /// - Invalid input that does not conform with the N-Quads standard.
+    ///
+    fn decode(&mut self) -> Result<Graph> {
+        let mut graph = Graph::new(None);
+
+        while let Some(quad) = self.next_quad()? {
+            graph.add_triple(quad.triple());
+        }
+
+        Ok(graph)
+    }
+}
+
+impl NQuadsParser<Cursor<Vec<u8>>> {
+    /// Constructor of `NQuadsParser` from input string.
+    pub fn from_string<S>(input: S) -> NQuadsParser<Cursor<Vec<u8>>>
+    where
+        S: Into<String>,
+    {
+        NQuadsParser::from_reader(Cursor::new(input.into().into_bytes()))
+    }
+}
+
+impl<R: Read> NQuadsParser<R> {
+    /// Constructor of `NQuadsParser` from input reader.
+    pub fn from_reader(input: R) -> NQuadsParser<R> {
+        NQuadsParser {
+            parser: NTriplesParser::from_reader(input),
+        }
+    }
+
+    /// Sets the configuration of how strictly the input is parsed.
+    pub fn with_config(self, config: ParserConfig) -> NQuadsParser<R> {
+        NQuadsParser {
+            parser: self.parser.with_config(config),
+        }
+    }
+
+    /// Returns an iterator that parses the quads one by one while the input is read.
+    ///
+    /// The iterator stops after the first error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::n_quads_parser::NQuadsParser;
+    ///
+    /// let input = "_:s <http://example.org/p> _:o _:g .
+    ///              _:s <http://example.org/p> _:o .";
+    ///
+    /// let graph_names: Vec<_> = NQuadsParser::from_string(input)
+    ///     .quads()
+    ///     .map(|quad| quad.unwrap().graph_name().is_some())
+    ///     .collect();
+    ///
+    /// assert_eq!(graph_names, vec![true, false]);
+    /// ```
+    pub fn quads(self) -> Quads<R> {
+        Quads {
+            parser: self,
+            failed: false,
+        }
+    }
+
+    /// Reads the next quad, returns `None` at the end of the input.
+    fn next_quad(&mut self) -> Result<Option<Quad>> {
+        Ok(self
+            .parser
+            .next_statement(true)?
+            .map(|(triple, graph_name)| Quad::new(&triple, graph_name.as_ref())))
+    }
+}
+
+/// Iterator over quads that are parsed while the input is read.
+pub struct Quads<R: Read> {
+    parser: NQuadsParser<R>,
+    failed: bool,
+}
+
+impl<R: Read> Iterator for Quads<R> {
+    type Item = Result<Quad>;
+
+    fn next(&mut self) -> Option<Result<Quad>> {
+        if self.failed {
+            return None;
+        }
+
+        match self.parser.next_quad() {
+            Ok(quad) => quad.map(Ok),
+            Err(err) => {
+                self.failed = true;
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::node::Node;
+    use crate::reader::n_quads_parser::NQuadsParser;
+    use crate::reader::n_triples_parser::NTriplesParser;
+    use crate::reader::rdf_parser::{RdfDatasetParser, RdfParser};
+
+    #[test]
+    fn read_n_quads_with_blank_graph_labels_and_comments() {
+        let input = "# people
+                     <http://example.org/a> <http://example.org/p> \"a\"@en <http://example.org/g1> .
+                     <http://example.org/a> <http://example.org/p> \"b\"^^<http://example.org/t> _:g2 .
+                     << _:a <http://example.org/p> _:b >> <http://example.org/q> _:c _:g2 .
+                     <http://example.org/a> <http://example.org/p> <http://example.org/b> .";
+
+        let dataset = NQuadsParser::from_string(input).decode_dataset().unwrap();
+        let g2 = Node::BlankNode {
+            id: "g2".to_string(),
+        };
+
+        assert_eq!(dataset.count(), 4);
+        assert_eq!(dataset.graph_names().len(), 2);
+        assert_eq!(dataset.named_graph(&g2).unwrap().count(), 2);
+        assert_eq!(
+            NQuadsParser::from_string(input).decode().unwrap().count(),
+            4
+        );
+    }
+
+    #[test]
+    fn graph_labels_are_rejected_in_n_triples() {
+        let input = "_:s <http://example.org/p> _:o _:g .";
+
+        assert!(NTriplesParser::from_string(input).decode().is_err());
+        assert!(
+            NQuadsParser::from_string("_:s <http://example.org/p> _:o \"g\" .")
+                .decode_dataset()
+                .is_err()
+        );
+    }
+}
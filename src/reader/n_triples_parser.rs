@@ -1,19 +1,23 @@
-use Result;
-use error::{Error, ErrorType};
-use graph::Graph;
-use node::Node;
-use reader::lexer::n_triples_lexer::NTriplesLexer;
-use reader::lexer::rdf_lexer::RdfLexer;
-use reader::lexer::token::Token;
-use reader::rdf_parser::RdfParser;
+use crate::error::{Error, ErrorType};
+use crate::graph::Graph;
+use crate::node::Node;
+use crate::reader::lexer::n_triples_lexer::NTriplesLexer;
+use crate::reader::lexer::rdf_lexer::RdfLexer;
+use crate::reader::lexer::token::Token;
+use crate::reader::parser_config::ParserConfig;
+use crate::reader::rdf_parser::{RdfParser, TripleSink};
+use crate::triple::Triple;
+use crate::uri::Uri;
+use crate::Result;
 use std::io::Cursor;
 use std::io::Read;
-use triple::Triple;
-use uri::Uri;
+use std::sync::mpsc::{sync_channel, Receiver};
+use std::thread::{self, JoinHandle};
 
 /// RDF parser to generate an RDF graph from N-Triples syntax.
 pub struct NTriplesParser<R: Read> {
     lexer: NTriplesLexer<R>,
+    config: ParserConfig,
 }
 
 impl<R: Read> RdfParser for NTriplesParser<R> {
@@ -44,33 +48,16 @@ impl<R: Read> RdfParser for NTriplesParser<R> {
     /// - Invalid node type for triple segment.
     ///
     fn decode(&mut self) -> Result<Graph> {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("decode", format = "N-Triples").entered();
+
         let mut graph = Graph::new(None);
+        self.parse_into(&mut graph)?;
 
-        loop {
-            match self.lexer.peek_next_token()? {
-                Token::Comment(_) => {
-                    // ignore comments
-                    let _ = self.lexer.get_next_token();
-                    continue;
-                }
-                Token::EndOfInput => return Ok(graph),
-                _ => {}
-            }
+        #[cfg(feature = "tracing")]
+        tracing::debug!(triples = graph.count(), "Decoded N-Triples");
 
-            match self.read_triple() {
-                Ok(triple) => graph.add_triple(&triple),
-                Err(err) => match *err.error_type() {
-                    ErrorType::EndOfInput(_) => return Ok(graph),
-                    _ => {
-                        println!("Error: {}", err.to_string());
-                        return Err(Error::new(
-                            ErrorType::InvalidReaderInput,
-                            "Error while parsing NTriples syntax.",
-                        ));
-                    }
-                },
-            }
-        }
+        Ok(graph)
     }
 }
 
@@ -113,26 +100,230 @@ impl<R: Read> NTriplesParser<R> {
     pub fn from_reader(input: R) -> NTriplesParser<R> {
         NTriplesParser {
             lexer: NTriplesLexer::new(input),
+            config: ParserConfig::new(),
+        }
+    }
+
+    /// Sets the configuration of how strictly the input is parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::n_triples_parser::NTriplesParser;
+    /// use rdf::reader::parser_config::ParserConfig;
+    /// use rdf::reader::rdf_parser::RdfParser;
+    ///
+    /// let input = "_:art <http://xmlns.com/foaf/0.1/name> \"Art Barstow\"";
+    ///
+    /// let mut reader = NTriplesParser::from_string(input).with_config(ParserConfig::lenient());
+    ///
+    /// assert_eq!(reader.decode().unwrap().count(), 1);
+    /// ```
+    pub fn with_config(mut self, config: ParserConfig) -> NTriplesParser<R> {
+        self.config = config;
+        self
+    }
+
+    /// Returns an iterator that parses the triples one by one while the input is read.
+    ///
+    /// Unlike `decode`, the triples are not collected in a graph, so inputs of any size can
+    /// be processed. The iterator stops after the first error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::n_triples_parser::NTriplesParser;
+    ///
+    /// let input = "_:art <http://xmlns.com/foaf/0.1/name> \"Art Barstow\" .
+    ///              _:art <http://xmlns.com/foaf/0.1/knows> _:dan .
+    ///              _:dan \"invalid\" _:art .
+    ///              _:dan <http://xmlns.com/foaf/0.1/knows> _:art .";
+    ///
+    /// let triples: Vec<_> = NTriplesParser::from_string(input).triples().collect();
+    ///
+    /// assert_eq!(triples.len(), 3);
+    /// assert!(triples[2].is_err());
+    /// ```
+    pub fn triples(self) -> Triples<R> {
+        Triples {
+            parser: self,
+            failed: false,
+        }
+    }
+
+    /// Generates an RDF graph from the valid statements and returns the errors of all others.
+    ///
+    /// Instead of stopping at the first error, the rest of an invalid statement up to the next
+    /// '.' is skipped and parsing continues with the following statement.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::n_triples_parser::NTriplesParser;
+    ///
+    /// let input = "_:art <http://xmlns.com/foaf/0.1/name> \"Art Barstow\" .
+    ///              _:art \"invalid\" _:dan .
+    ///              _:art <http://xmlns.com/foaf/0.1/knows> .
+    ///              _:dan <http://xmlns.com/foaf/0.1/knows> _:art .";
+    ///
+    /// let (graph, errors) = NTriplesParser::from_string(input).decode_tolerant();
+    ///
+    /// assert_eq!(graph.count(), 2);
+    /// assert_eq!(errors.len(), 2);
+    /// ```
+    pub fn decode_tolerant(&mut self) -> (Graph, Vec<Error>) {
+        let mut graph = Graph::new(None);
+        let mut errors = Vec::new();
+
+        loop {
+            match self.next_triple() {
+                Ok(Some(triple)) => graph.add_triple(&triple),
+                Ok(None) => break,
+                Err(err) => {
+                    errors.push(err);
+                    self.lexer.skip_statement();
+                }
+            }
+        }
+
+        (graph, errors)
+    }
+
+    /// Reads the triples of the input and pushes them into the sink without building a graph.
+    ///
+    /// Triples that were pushed before an error remain in the sink.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::node::Node;
+    /// use rdf::reader::n_triples_parser::NTriplesParser;
+    /// use rdf::triple::Triple;
+    ///
+    /// let input = "_:a <http://example.org/age> \"42\" .
+    ///              _:a <http://example.org/knows> _:b .
+    ///              _:a <http://example.org/name> \"Alice\" .";
+    ///
+    /// let mut literals = Vec::new();
+    /// NTriplesParser::from_string(input)
+    ///     .parse_into(&mut |triple: Triple| {
+    ///         if let Node::LiteralNode { .. } = *triple.object() {
+    ///             literals.push(triple);
+    ///         }
+    ///     })
+    ///     .unwrap();
+    ///
+    /// assert_eq!(literals.len(), 2);
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - Invalid input that does not conform with NTriples standard.
+    ///
+    pub fn parse_into<T: TripleSink>(&mut self, sink: &mut T) -> Result<()> {
+        while let Some(triple) = self.next_triple()? {
+            sink.triple(triple);
+        }
+
+        Ok(())
+    }
+
+    /// Reads the next triple and skips comments, returns `None` at the end of the input.
+    fn next_triple(&mut self) -> Result<Option<Triple>> {
+        Ok(self.next_statement(false)?.map(|(triple, _)| triple))
+    }
+
+    /// Reads the next triple and, if `graph_label` is set, its optional graph label as used
+    /// by N-Quads. Skips comments and returns `None` at the end of the input.
+    pub(crate) fn next_statement(
+        &mut self,
+        graph_label: bool,
+    ) -> Result<Option<(Triple, Option<Node>)>> {
+        loop {
+            match self.lexer.peek_next_token() {
+                Ok(Token::Comment(_)) => {
+                    // ignore comments
+                    let _ = self.lexer.get_next_token();
+                    continue;
+                }
+                Ok(Token::EndOfInput) => return Ok(None),
+                Ok(_) => {}
+                Err(err) => return Err(self.statement_error(err)),
+            }
+
+            let statement = self.read_statement(graph_label).and_then(|statement| {
+                self.config.check_triple(&statement.0)?;
+                if let Some(ref graph_name) = statement.1 {
+                    self.config.check_node(graph_name)?;
+                }
+                Ok(statement)
+            });
+
+            return match statement {
+                Ok(statement) => Ok(Some(statement)),
+                Err(err) => Err(self.statement_error(err)),
+            };
         }
     }
 
-    /// Creates a triple from the parsed tokens.
-    fn read_triple(&mut self) -> Result<Triple> {
+    /// Adds the position to an error of a statement.
+    ///
+    /// The end of the input is only valid between statements, inside a statement it is
+    /// reported as invalid input caused by the lexer error.
+    fn statement_error(&self, err: Error) -> Error {
+        let err = match *err.error_type() {
+            ErrorType::EndOfInput(_) => Error::new(
+                ErrorType::InvalidReaderInput,
+                "Unexpected end of input inside N-Triples statement.",
+            )
+            .with_source(err),
+            _ => err,
+        };
+
+        #[cfg(feature = "tracing")]
+        tracing::warn!(error = %err, "Error while parsing N-Triples syntax");
+
+        match err.position() {
+            Some(_) => err,
+            None => err.with_position(self.lexer.position()),
+        }
+    }
+
+    /// Creates a triple and its optional graph label from the parsed tokens.
+    fn read_statement(&mut self, graph_label: bool) -> Result<(Triple, Option<Node>)> {
         let subject = self.read_subject()?;
         let predicate = self.read_predicate()?;
         let object = self.read_object()?;
 
+        let graph_name = if graph_label {
+            match self.lexer.peek_next_token()? {
+                Token::Uri(uri) => {
+                    let _ = self.lexer.get_next_token();
+                    Some(Node::UriNode { uri: Uri::new(uri) })
+                }
+                Token::BlankNode(id) => {
+                    let _ = self.lexer.get_next_token();
+                    Some(Node::BlankNode { id })
+                }
+                _ => None,
+            }
+        } else {
+            None
+        };
+
         match self.lexer.get_next_token() {
             Ok(Token::TripleDelimiter) => {}
-            _ => {
-                return Err(Error::new(
+            Ok(Token::EndOfInput) if self.config.allow_missing_final_dot() => {}
+            token => {
+                return Err(unexpected_token(
+                    token,
                     ErrorType::InvalidReaderInput,
-                    "Expected triple delimiter.",
+                    "Expected triple delimiter",
                 ))
             }
         }
 
-        Ok(Triple::new(&subject, &predicate, &object))
+        Ok((Triple::new(&subject, &predicate, &object), graph_name))
     }
 
     /// Get the next token and check if it is a valid subject and create a new subject node.
@@ -140,9 +331,11 @@ impl<R: Read> NTriplesParser<R> {
         match self.lexer.get_next_token() {
             Ok(Token::BlankNode(id)) => Ok(Node::BlankNode { id }),
             Ok(Token::Uri(uri)) => Ok(Node::UriNode { uri: Uri::new(uri) }),
-            _ => Err(Error::new(
+            Ok(Token::QuotedTripleStart) => self.read_quoted_triple(),
+            token => Err(unexpected_token(
+                token,
                 ErrorType::InvalidToken,
-                "Invalid token for NTriples subject.",
+                "Invalid token for NTriples subject",
             )),
         }
     }
@@ -151,45 +344,193 @@ impl<R: Read> NTriplesParser<R> {
     fn read_predicate(&mut self) -> Result<Node> {
         match self.lexer.get_next_token() {
             Ok(Token::Uri(uri)) => Ok(Node::UriNode { uri: Uri::new(uri) }),
-            _ => Err(Error::new(
+            token => Err(unexpected_token(
+                token,
                 ErrorType::InvalidToken,
-                "Invalid token for NTriples predicate.",
+                "Invalid token for NTriples predicate",
             )),
         }
     }
 
     /// Get the next token and check if it is a valid object and create a new object node.
     fn read_object(&mut self) -> Result<Node> {
-        match self.lexer.get_next_token()? {
-            Token::BlankNode(id) => Ok(Node::BlankNode { id }),
-            Token::Uri(uri) => Ok(Node::UriNode { uri: Uri::new(uri) }),
-            Token::LiteralWithLanguageSpecification(literal, lang) => Ok(Node::LiteralNode {
+        match self.lexer.get_next_token() {
+            Ok(Token::BlankNode(id)) => Ok(Node::BlankNode { id }),
+            Ok(Token::Uri(uri)) => Ok(Node::UriNode { uri: Uri::new(uri) }),
+            Ok(Token::LiteralWithLanguageSpecification(literal, lang)) => Ok(Node::LiteralNode {
                 literal,
                 data_type: None,
                 language: Some(lang),
             }),
-            Token::LiteralWithUrlDatatype(literal, datatype) => Ok(Node::LiteralNode {
+            Ok(Token::LiteralWithUrlDatatype(literal, datatype)) => Ok(Node::LiteralNode {
                 literal,
                 data_type: Some(Uri::new(datatype)),
                 language: None,
             }),
-            Token::Literal(literal) => Ok(Node::LiteralNode {
+            Ok(Token::Literal(literal)) => Ok(Node::LiteralNode {
                 literal,
                 data_type: None,
                 language: None,
             }),
-            _ => Err(Error::new(
+            Ok(Token::QuotedTripleStart) => self.read_quoted_triple(),
+            token => Err(unexpected_token(
+                token,
                 ErrorType::InvalidToken,
-                "Invalid token for NTriples object.",
+                "Invalid token for NTriples object",
+            )),
+        }
+    }
+
+    /// Reads a quoted triple and returns it as node.
+    fn read_quoted_triple(&mut self) -> Result<Node> {
+        let subject = self.read_subject()?;
+        let predicate = self.read_predicate()?;
+        let object = self.read_object()?;
+
+        match self.lexer.get_next_token() {
+            Ok(Token::QuotedTripleEnd) => Ok(Node::TripleNode {
+                triple: Box::new(Triple::new(&subject, &predicate, &object)),
+            }),
+            token => Err(unexpected_token(
+                token,
+                ErrorType::InvalidToken,
+                "Quoted triple does not end with '>>'",
             )),
         }
     }
 }
 
+/// Returns the error of the lexer or, if a token was read, an error naming the token.
+///
+/// The position of the token is added when the error leaves `next_statement`.
+fn unexpected_token(token: Result<Token>, error_type: ErrorType, message: &str) -> Error {
+    match token {
+        Ok(token) => Error::new(error_type, format!("{}, found {:?}.", message, token)),
+        Err(err) => err,
+    }
+}
+
+impl<R: Read + Send + 'static> NTriplesParser<R> {
+    /// Parses the input on a separate thread and returns an iterator over the parsed triples.
+    ///
+    /// The parsing thread buffers at most `capacity` triples and waits until the consumer
+    /// catches up, so reading and lexing the input overlaps with processing the triples.
+    /// Parsing stops after the first error, which is the last item of the iterator, or when
+    /// the iterator is dropped. If the parsing thread panics, an error is the last item.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::n_triples_parser::NTriplesParser;
+    ///
+    /// let input = "_:art <http://xmlns.com/foaf/0.1/name> \"Art Barstow\" .
+    ///              _:art <http://xmlns.com/foaf/0.1/knows> _:dan .";
+    ///
+    /// let triples = NTriplesParser::from_string(input).spawn(16);
+    ///
+    /// assert_eq!(triples.map(|t| t.unwrap()).count(), 2);
+    /// ```
+    pub fn spawn(self, capacity: usize) -> ParsedTriples {
+        let (sender, receiver) = sync_channel(capacity);
+        let mut parser = self;
+
+        let handle = thread::spawn(move || loop {
+            let message = match parser.next_triple() {
+                Ok(Some(triple)) => Ok(triple),
+                Ok(None) => return,
+                Err(err) => Err(err),
+            };
+
+            let is_error = message.is_err();
+
+            // stop if the consumer is gone
+            if sender.send(message).is_err() || is_error {
+                return;
+            }
+        });
+
+        ParsedTriples {
+            receiver,
+            handle: Some(handle),
+        }
+    }
+
+    /// Generates an RDF graph while the input is parsed on a separate thread.
+    ///
+    /// Lexing and parsing run on one thread and the graph is built on the calling thread,
+    /// `capacity` is the number of triples that can be buffered in between.
+    ///
+    /// # Failures
+    ///
+    /// - Invalid input that does not conform with NTriples standard.
+    ///
+    pub fn decode_pipelined(self, capacity: usize) -> Result<Graph> {
+        let mut graph = Graph::new(None);
+
+        for triple in self.spawn(capacity) {
+            graph.add_triple(&triple?);
+        }
+
+        Ok(graph)
+    }
+}
+
+/// Iterator over triples that are parsed while the input is read.
+pub struct Triples<R: Read> {
+    parser: NTriplesParser<R>,
+    failed: bool,
+}
+
+impl<R: Read> Iterator for Triples<R> {
+    type Item = Result<Triple>;
+
+    fn next(&mut self) -> Option<Result<Triple>> {
+        if self.failed {
+            return None;
+        }
+
+        match self.parser.next_triple() {
+            Ok(triple) => triple.map(Ok),
+            Err(err) => {
+                self.failed = true;
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+/// Iterator over triples that are parsed on a separate thread.
+pub struct ParsedTriples {
+    receiver: Receiver<Result<Triple>>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl Iterator for ParsedTriples {
+    type Item = Result<Triple>;
+
+    fn next(&mut self) -> Option<Result<Triple>> {
+        match self.receiver.recv() {
+            Ok(message) => Some(message),
+            // the channel is closed once the parsing thread has finished or panicked
+            Err(_) => match self.handle.take()?.join() {
+                Ok(()) => None,
+                Err(_) => Some(Err(Error::new(
+                    ErrorType::InvalidReaderInput,
+                    "Parsing thread panicked.",
+                ))),
+            },
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use reader::n_triples_parser::NTriplesParser;
-    use reader::rdf_parser::RdfParser;
+    use crate::error::ErrorType;
+    use crate::node::Node;
+    use crate::reader::n_triples_parser::NTriplesParser;
+    use crate::reader::rdf_parser::RdfParser;
+    use crate::writer::n_triples_writer::NTriplesWriter;
+    use crate::writer::rdf_writer::RdfWriter;
 
     #[test]
     fn test_read_n_triples_from_string() {
@@ -208,4 +549,226 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_read_n_triples_with_quoted_triples_from_string() {
+        let input = "<< _:a <http://example.org/p> \"o\"@en >> <http://example.org/source> << _:b <http://example.org/p> _:c >> .
+                 _:a <http://example.org/p> \"o\"@en .";
+
+        let graph = NTriplesParser::from_string(input).decode().unwrap();
+
+        assert_eq!(graph.count(), 2);
+        assert_eq!(
+            graph
+                .triples_iter()
+                .filter(|t| matches!(*t.object(), Node::TripleNode { .. }))
+                .count(),
+            1
+        );
+
+        let writer = NTriplesWriter::new();
+        let output = writer.write_to_string(&graph).unwrap();
+        let reparsed = NTriplesParser::from_string(output).decode().unwrap();
+
+        assert_eq!(reparsed.count(), 2);
+        assert!(graph
+            .triples_iter()
+            .all(|t| reparsed.triples_iter().any(|r| r == t)));
+    }
+
+    #[test]
+    fn read_n_triples_with_minimal_whitespace_and_line_endings() {
+        let input = "<http://example/s><http://example/p><http://example/o>.\r\n\
+                     <http://example/s><http://example/p>\"Alice\".\r\
+                     _:s<http://example/p>\"o\"@en.# comment\r\
+                     _:s\t<http://example/p>\t_:o\t.\n";
+
+        let graph = NTriplesParser::from_string(input).decode().unwrap();
+
+        assert_eq!(graph.count(), 4);
+        assert_eq!(
+            graph
+                .get_triples_with_subject(&Node::BlankNode {
+                    id: "s".to_string()
+                })
+                .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn triples_are_streamed_from_reader() {
+        use std::cell::Cell;
+        use std::io::{self, Read};
+        use std::rc::Rc;
+
+        // generates the triples of the input on demand
+        struct Generator {
+            remaining: Rc<Cell<usize>>,
+            line: Vec<u8>,
+        }
+
+        impl Read for Generator {
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                if self.line.is_empty() && self.remaining.get() > 0 {
+                    self.remaining.set(self.remaining.get() - 1);
+                    self.line = format!(
+                        "_:s <http://example.org/p> \"{}\" .\n",
+                        self.remaining.get()
+                    )
+                    .into_bytes();
+                }
+
+                let count = self.line.len().min(buf.len());
+                buf[..count].copy_from_slice(&self.line[..count]);
+                self.line.drain(..count);
+                Ok(count)
+            }
+        }
+
+        let remaining = Rc::new(Cell::new(100000));
+
+        let mut triples = NTriplesParser::from_reader(Generator {
+            remaining: remaining.clone(),
+            line: Vec::new(),
+        })
+        .triples();
+
+        assert!(triples.next().unwrap().is_ok());
+        assert!(remaining.get() > 99000);
+        assert_eq!(triples.filter(|t| t.is_ok()).count(), 99999);
+    }
+
+    #[test]
+    fn decode_pipelined_stops_at_first_error() {
+        let mut input = String::new();
+
+        for i in 0..1000 {
+            input.push_str(&format!("_:s <http://example.org/p> \"{}\" .\n", i));
+        }
+
+        let graph = NTriplesParser::from_string(input.clone())
+            .decode_pipelined(8)
+            .unwrap();
+        assert_eq!(graph.count(), 1000);
+
+        input.push_str("_:s \"p\" \"o\" .\n_:s <http://example.org/p> \"last\" .\n");
+
+        let triples: Vec<_> = NTriplesParser::from_string(input.clone())
+            .spawn(8)
+            .collect();
+        assert_eq!(triples.len(), 1001);
+
+        let err = triples[1000].as_ref().unwrap_err();
+        assert!(matches!(*err.error_type(), ErrorType::InvalidToken));
+        assert_eq!(err.position().unwrap().line(), 1001);
+        assert!(NTriplesParser::from_string(input)
+            .decode_pipelined(8)
+            .is_err());
+    }
+
+    #[test]
+    fn spawn_reports_panic_of_parsing_thread() {
+        use std::io::{self, Read};
+
+        struct Failing {
+            line: Option<&'static [u8]>,
+        }
+
+        impl Read for Failing {
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                let line = self.line.take().expect("input failed");
+
+                buf[..line.len()].copy_from_slice(line);
+                Ok(line.len())
+            }
+        }
+
+        let triples: Vec<_> = NTriplesParser::from_reader(Failing {
+            line: Some(b"_:s <http://example.org/p> _:o .\n"),
+        })
+        .spawn(8)
+        .collect();
+
+        assert_eq!(triples.len(), 2);
+        assert!(triples[0].is_ok());
+        assert!(matches!(
+            *triples[1].as_ref().unwrap_err().error_type(),
+            ErrorType::InvalidReaderInput
+        ));
+    }
+
+    #[test]
+    fn report_position_of_invalid_statement() {
+        let input = "_:a <http://example.org/p> _:b .
+                     _:a <http://example.org/p> \"c\"@en .
+                     _:a _:b _:c .";
+
+        let err = NTriplesParser::from_string(input).decode().unwrap_err();
+        let position = err.position().unwrap();
+
+        assert_eq!(position.line(), 3);
+        assert_eq!(position.column(), 26);
+        assert!(err.to_string().ends_with("(at line 3, column 26)"));
+    }
+
+    #[test]
+    fn errors_keep_their_cause() {
+        let err = NTriplesParser::from_string("_:a _:b _:c .")
+            .decode()
+            .unwrap_err();
+
+        assert!(matches!(*err.error_type(), ErrorType::InvalidToken));
+        assert_eq!(
+            err.message(),
+            "Invalid token for NTriples predicate, found BlankNode(\"b\")."
+        );
+
+        let err = NTriplesParser::from_string("_:a <http://example.org/p> !c .")
+            .decode()
+            .unwrap_err();
+
+        assert!(matches!(*err.error_type(), ErrorType::InvalidReaderInput));
+        assert_eq!(err.position().unwrap().column(), 28);
+    }
+
+    #[test]
+    fn report_truncated_last_statement() {
+        let inputs = [
+            "<http://example.org/a> <http://example.org/b> <http://example.org/c> .
+             <http://example.org/a> <http://example.org/b> \"x",
+            "<http://example.org/a> <http://example.org/b> <http://example.org/c> .
+             <http://example.org/a> <http://example.org/b> <http://example.org/c",
+        ];
+
+        for input in &inputs {
+            let err = NTriplesParser::from_string(*input).decode().unwrap_err();
+
+            assert!(matches!(*err.error_type(), ErrorType::InvalidReaderInput));
+            assert_eq!(err.position().unwrap().line(), 2);
+            assert!(std::error::Error::source(&err).is_some());
+
+            let triples: Vec<_> = NTriplesParser::from_string(*input).triples().collect();
+            assert_eq!(triples.len(), 2);
+            assert!(triples[1].is_err());
+
+            let (graph, errors) = NTriplesParser::from_string(*input).decode_tolerant();
+            assert_eq!(graph.count(), 1);
+            assert_eq!(errors.len(), 1);
+        }
+    }
+
+    #[test]
+    fn decode_tolerant_skips_invalid_characters() {
+        let input = "_:a <http://example.org/p> !x? .
+                     _:a <http://example.org/p> _:b .
+                     _:b <http://example.org/p> ";
+
+        let (graph, errors) = NTriplesParser::from_string(input).decode_tolerant();
+
+        assert_eq!(graph.count(), 1);
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0].position().unwrap().line(), 1);
+        assert_eq!(errors[1].position().unwrap().line(), 3);
+    }
 }
@@ -0,0 +1,330 @@
+#[cfg(any(feature = "jsonld", feature = "turtle"))]
+use crate::dataset::Dataset;
+#[cfg(any(feature = "jsonld", feature = "turtle", feature = "xml"))]
+use crate::graph::Graph;
+#[cfg(feature = "xml")]
+use crate::reader::rdf_xml_parser;
+#[cfg(feature = "xml")]
+use crate::specs::rdf_syntax_specs::RdfSyntaxDataTypes;
+#[cfg(any(
+    feature = "jsonld",
+    feature = "ntriples",
+    feature = "turtle",
+    feature = "xml"
+))]
+use crate::{
+    error::{Error, ErrorType},
+    node::Node,
+    specs::rdf_syntax_specs::RdfSyntaxSpecs,
+    triple::Triple,
+    uri::Uri,
+    Result,
+};
+
+/// Configuration of how strictly parsers follow the syntax specifications.
+///
+/// By default parsers behave as in earlier versions: IRIs and language tags are accepted as
+/// they are written, but each statement has to end with '.'. Use `ParserConfig::strict` to
+/// reject all input that does not conform with the specifications and `ParserConfig::lenient`
+/// to recover from common mistakes.
+#[derive(Debug, Clone)]
+pub struct ParserConfig {
+    allow_invalid_iris: bool,
+    allow_missing_final_dot: bool,
+    allow_invalid_language_tags: bool,
+    validate_xml_literals: bool,
+}
+
+impl Default for ParserConfig {
+    fn default() -> ParserConfig {
+        ParserConfig::new()
+    }
+}
+
+impl ParserConfig {
+    /// Constructor of `ParserConfig`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::parser_config::ParserConfig;
+    ///
+    /// let mut config = ParserConfig::new();
+    /// config.set_allow_missing_final_dot(true);
+    /// config.set_allow_invalid_language_tags(false);
+    /// ```
+    pub fn new() -> ParserConfig {
+        ParserConfig {
+            allow_invalid_iris: true,
+            allow_missing_final_dot: false,
+            allow_invalid_language_tags: true,
+            validate_xml_literals: false,
+        }
+    }
+
+    /// Returns a configuration that only accepts input conforming with the specifications.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "ntriples")]
+    /// # {
+    /// use rdf::reader::n_triples_parser::NTriplesParser;
+    /// use rdf::reader::parser_config::ParserConfig;
+    /// use rdf::reader::rdf_parser::RdfParser;
+    ///
+    /// let input = "<http://example.org/a b> <http://example.org/c> \"d\"@en_GB .";
+    ///
+    /// assert!(NTriplesParser::from_string(input).decode().is_ok());
+    /// assert!(NTriplesParser::from_string(input)
+    ///     .with_config(ParserConfig::strict())
+    ///     .decode()
+    ///     .is_err());
+    /// # }
+    /// ```
+    pub fn strict() -> ParserConfig {
+        ParserConfig {
+            allow_invalid_iris: false,
+            allow_missing_final_dot: false,
+            allow_invalid_language_tags: false,
+            validate_xml_literals: true,
+        }
+    }
+
+    /// Returns a configuration that recovers from all supported deviations from the specifications.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "turtle")]
+    /// # {
+    /// use rdf::reader::parser_config::ParserConfig;
+    /// use rdf::reader::rdf_parser::RdfParser;
+    /// use rdf::reader::turtle_parser::TurtleParser;
+    ///
+    /// let input = "<http://example.org/a> <http://example.org/b> \"c\"";
+    ///
+    /// assert!(TurtleParser::from_string(input).decode().is_err());
+    ///
+    /// let graph = TurtleParser::from_string(input)
+    ///     .with_config(ParserConfig::lenient())
+    ///     .decode()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(graph.count(), 1);
+    /// # }
+    /// ```
+    pub fn lenient() -> ParserConfig {
+        ParserConfig {
+            allow_invalid_iris: true,
+            allow_missing_final_dot: true,
+            allow_invalid_language_tags: true,
+            validate_xml_literals: false,
+        }
+    }
+
+    /// Returns if IRIs with spaces or other characters that are not allowed in IRIs are accepted.
+    pub fn allow_invalid_iris(&self) -> bool {
+        self.allow_invalid_iris
+    }
+
+    /// Sets if IRIs with spaces or other characters that are not allowed in IRIs are accepted.
+    ///
+    /// Accepted IRIs are kept as they are written.
+    pub fn set_allow_invalid_iris(&mut self, allow_invalid_iris: bool) {
+        self.allow_invalid_iris = allow_invalid_iris;
+    }
+
+    /// Returns if the last statement of the input may end without '.'.
+    pub fn allow_missing_final_dot(&self) -> bool {
+        self.allow_missing_final_dot
+    }
+
+    /// Sets if the last statement of the input may end without '.'.
+    ///
+    /// Formats without statement delimiters, like RDF/XML, ignore this option.
+    pub fn set_allow_missing_final_dot(&mut self, allow_missing_final_dot: bool) {
+        self.allow_missing_final_dot = allow_missing_final_dot;
+    }
+
+    /// Returns if language tags that are not well-formed according to BCP 47 are accepted.
+    pub fn allow_invalid_language_tags(&self) -> bool {
+        self.allow_invalid_language_tags
+    }
+
+    /// Sets if language tags that are not well-formed according to BCP 47 are accepted.
+    pub fn set_allow_invalid_language_tags(&mut self, allow_invalid_language_tags: bool) {
+        self.allow_invalid_language_tags = allow_invalid_language_tags;
+    }
+
+    /// Returns if `rdf:XMLLiteral` values are checked to be well-formed XML content.
+    pub fn validate_xml_literals(&self) -> bool {
+        self.validate_xml_literals
+    }
+
+    /// Sets if `rdf:XMLLiteral` values are checked to be well-formed XML content.
+    ///
+    /// The values are kept as they are written. Validation requires the `xml` feature,
+    /// without it this option is ignored. `rdf:HTML` values are never validated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "ntriples")]
+    /// # {
+    /// use rdf::reader::n_triples_parser::NTriplesParser;
+    /// use rdf::reader::parser_config::ParserConfig;
+    /// use rdf::reader::rdf_parser::RdfParser;
+    ///
+    /// let input = "_:a <http://example.org/note> \"<b>bold\"^^<http://www.w3.org/1999/02/22-rdf-syntax-ns#XMLLiteral> .";
+    ///
+    /// let mut config = ParserConfig::new();
+    /// config.set_validate_xml_literals(true);
+    ///
+    /// assert!(NTriplesParser::from_string(input).decode().is_ok());
+    /// assert!(NTriplesParser::from_string(input).with_config(config).decode().is_err());
+    /// # }
+    /// ```
+    pub fn set_validate_xml_literals(&mut self, validate_xml_literals: bool) {
+        self.validate_xml_literals = validate_xml_literals;
+    }
+
+    /// Checks the IRIs, language tags and XML literals of a node.
+    #[cfg(any(
+        feature = "jsonld",
+        feature = "ntriples",
+        feature = "turtle",
+        feature = "xml"
+    ))]
+    pub(crate) fn check_node(&self, node: &Node) -> Result<()> {
+        match *node {
+            Node::UriNode { ref uri } => self.check_iri(uri.to_string()),
+            Node::LiteralNode {
+                ref literal,
+                ref data_type,
+                ref language,
+            } => {
+                if let Some(data_type) = data_type {
+                    self.check_iri(data_type.to_string())?;
+                    self.check_xml_literal(literal, data_type)?;
+                }
+
+                match language {
+                    Some(language)
+                        if !self.allow_invalid_language_tags
+                            && !RdfSyntaxSpecs::is_language_tag(language) =>
+                    {
+                        Err(Error::new(
+                            ErrorType::InvalidReaderInput,
+                            "Invalid language tag: ".to_string() + language,
+                        ))
+                    }
+                    _ => Ok(()),
+                }
+            }
+            Node::TripleNode { ref triple } => self.check_triple(triple),
+            Node::BlankNode { .. } => Ok(()),
+        }
+    }
+
+    /// Checks the IRIs and language tags of all nodes of a triple.
+    #[cfg(any(
+        feature = "jsonld",
+        feature = "ntriples",
+        feature = "turtle",
+        feature = "xml"
+    ))]
+    pub(crate) fn check_triple(&self, triple: &Triple) -> Result<()> {
+        self.check_node(triple.subject())?;
+        self.check_node(triple.predicate())?;
+        self.check_node(triple.object())
+    }
+
+    /// Checks the IRIs and language tags of all triples of a graph.
+    #[cfg(any(feature = "jsonld", feature = "turtle", feature = "xml"))]
+    pub(crate) fn check_graph(&self, graph: &Graph) -> Result<()> {
+        graph
+            .triples_iter()
+            .try_for_each(|triple| self.check_triple(triple))
+    }
+
+    /// Checks the IRIs and language tags of all graphs and graph names of a dataset.
+    #[cfg(any(feature = "jsonld", feature = "turtle"))]
+    pub(crate) fn check_dataset(&self, dataset: &Dataset) -> Result<()> {
+        self.check_graph(dataset.default_graph())?;
+
+        for (name, graph) in dataset.named_graphs() {
+            self.check_node(name)?;
+            self.check_graph(graph)?;
+        }
+
+        Ok(())
+    }
+
+    /// Checks if the value of an `rdf:XMLLiteral` is well-formed, if enabled.
+    #[cfg(feature = "xml")]
+    fn check_xml_literal(&self, literal: &str, data_type: &Uri) -> Result<()> {
+        if self.validate_xml_literals && *data_type == RdfSyntaxDataTypes::XmlLiteral.to_uri() {
+            rdf_xml_parser::canonicalize_xml_literal(literal).map_err(|err| {
+                Error::new(
+                    ErrorType::InvalidLiteral,
+                    "Invalid XML literal: ".to_string() + literal,
+                )
+                .with_source(err)
+            })?;
+        }
+
+        Ok(())
+    }
+
+    /// XML literals cannot be validated without the `xml` feature.
+    #[cfg(all(
+        not(feature = "xml"),
+        any(feature = "jsonld", feature = "ntriples", feature = "turtle")
+    ))]
+    fn check_xml_literal(&self, _literal: &str, _data_type: &Uri) -> Result<()> {
+        Ok(())
+    }
+
+    /// Checks if an IRI is accepted.
+    #[cfg(any(
+        feature = "jsonld",
+        feature = "ntriples",
+        feature = "turtle",
+        feature = "xml"
+    ))]
+    fn check_iri(&self, iri: &str) -> Result<()> {
+        if self.allow_invalid_iris || RdfSyntaxSpecs::is_iri(iri) {
+            Ok(())
+        } else {
+            Err(Error::new(
+                ErrorType::InvalidReaderInput,
+                "Invalid IRI: ".to_string() + iri,
+            ))
+        }
+    }
+}
+
+#[cfg(all(test, feature = "ntriples"))]
+mod tests {
+    use crate::reader::n_triples_parser::NTriplesParser;
+    use crate::reader::parser_config::ParserConfig;
+    use crate::reader::rdf_parser::RdfParser;
+
+    #[test]
+    fn check_language_tags_of_quoted_triples() {
+        let input = "<< <http://example.org/a> <http://example.org/b> \"c\"@en_GB >> <http://example.org/d> \"e\"@en-GB .";
+
+        let mut config = ParserConfig::new();
+        assert!(NTriplesParser::from_string(input)
+            .with_config(config.clone())
+            .decode()
+            .is_ok());
+
+        config.set_allow_invalid_language_tags(false);
+        assert!(NTriplesParser::from_string(input)
+            .with_config(config)
+            .decode()
+            .is_err());
+    }
+}
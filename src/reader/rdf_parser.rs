@@ -1,5 +1,10 @@
-use Result;
-use graph::Graph;
+use crate::dataset::Dataset;
+use crate::graph::Graph;
+use crate::namespace::Namespace;
+use crate::triple::Triple;
+use crate::uri::Uri;
+use crate::Result;
+use std::iter;
 
 /// Trait implemented by RDF parsers to generate a RDF graph from RDF syntax.
 pub trait RdfParser {
@@ -7,3 +12,85 @@ pub trait RdfParser {
     /// Returns an error if invalid RDF input is provided.
     fn decode(&mut self) -> Result<Graph>;
 }
+
+/// Trait implemented by parsers of RDF syntaxes with named graphs to generate an RDF dataset.
+pub trait RdfDatasetParser {
+    /// Generates an RDF dataset from a provided RDF syntax.
+    /// Returns an error if invalid RDF input is provided.
+    fn decode_dataset(&mut self) -> Result<Dataset>;
+}
+
+/// Receiver of the triples and directives that parsers read, one at a time.
+///
+/// Parsers push into a sink with `parse_into`, so triples can be counted, filtered or
+/// forwarded without building a graph. `Graph` is a sink that keeps everything, closures
+/// taking a `Triple` are sinks that ignore prefixes and base URIs.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "turtle")]
+/// # {
+/// use rdf::reader::rdf_parser::TripleSink;
+/// use rdf::reader::turtle_parser::TurtleParser;
+/// use rdf::triple::Triple;
+/// use rdf::uri::Uri;
+///
+/// #[derive(Default)]
+/// struct Statistics {
+///     triples: usize,
+///     prefixes: Vec<String>,
+/// }
+///
+/// impl TripleSink for Statistics {
+///     fn triple(&mut self, _triple: Triple) {
+///         self.triples += 1;
+///     }
+///
+///     fn prefix(&mut self, prefix: &str, _uri: &Uri) {
+///         self.prefixes.push(prefix.to_string());
+///     }
+/// }
+///
+/// let input = "@prefix ex: <http://example.org/> .
+///              ex:a ex:b ex:c , ex:d .";
+///
+/// let mut statistics = Statistics::default();
+/// TurtleParser::from_string(input).parse_into(&mut statistics).unwrap();
+///
+/// assert_eq!(statistics.triples, 2);
+/// assert_eq!(statistics.prefixes, vec!["ex"]);
+/// # }
+/// ```
+pub trait TripleSink {
+    /// Receives a triple.
+    fn triple(&mut self, triple: Triple);
+
+    /// Receives a prefix declaration without the trailing ':', ignored by default.
+    fn prefix(&mut self, _prefix: &str, _uri: &Uri) {}
+
+    /// Receives a base URI declaration, ignored by default.
+    ///
+    /// The URI is already resolved against the previous base URI.
+    fn base(&mut self, _uri: &Uri) {}
+}
+
+impl TripleSink for Graph {
+    fn triple(&mut self, triple: Triple) {
+        self.insert_batch(iter::once(triple));
+    }
+
+    fn prefix(&mut self, prefix: &str, uri: &Uri) {
+        self.add_namespace(&Namespace::new(prefix.to_string(), uri.clone()));
+    }
+
+    fn base(&mut self, uri: &Uri) {
+        self.set_base_uri(uri);
+    }
+}
+
+impl<F: FnMut(Triple)> TripleSink for F {
+    fn triple(&mut self, triple: Triple) {
+        self(triple)
+    }
+}
@@ -0,0 +1,1125 @@
+use crate::error::{Error, ErrorType};
+use crate::graph::Graph;
+use crate::namespace::Namespace;
+use crate::node::Node;
+use crate::reader::parser_config::ParserConfig;
+use crate::reader::rdf_parser::RdfParser;
+use crate::specs::rdf_syntax_specs::RdfSyntaxDataTypes;
+use crate::triple::Triple;
+use crate::uri::Uri;
+use crate::Result;
+use std::collections::{BTreeMap, HashMap};
+use std::io::Cursor;
+use std::io::Read;
+
+const RDF_NAMESPACE: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#";
+const XML_NAMESPACE: &str = "http://www.w3.org/XML/1998/namespace";
+
+/// RDF parser to generate an RDF graph from RDF/XML syntax.
+///
+/// Supports node elements (`rdf:Description` and typed node elements), property elements
+/// and attributes, `rdf:about`, `rdf:ID`, `rdf:nodeID`, `rdf:resource`, `rdf:datatype`,
+/// `rdf:li`, `xml:lang`, `xml:base` and `rdf:parseType` with `Resource`, `Literal` and
+/// `Collection`. The reification of statements with `rdf:ID` on property elements is not
+/// supported, the ID is ignored. Entities declared in the document type declaration are not
+/// expanded.
+pub struct RdfXmlParser<R: Read> {
+    input: R,
+    config: ParserConfig,
+}
+
+impl<R: Read> RdfParser for RdfXmlParser<R> {
+    /// Generates an RDF graph from a string containing RDF/XML syntax.
+    ///
+    /// The namespaces declared on the root element are added to the graph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::rdf_parser::RdfParser;
+    /// use rdf::reader::rdf_xml_parser::RdfXmlParser;
+    ///
+    /// let input = r#"<?xml version="1.0"?>
+    ///   <rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#"
+    ///            xmlns:foaf="http://xmlns.com/foaf/0.1/">
+    ///     <foaf:Person rdf:about="http://example.org/art">
+    ///       <foaf:name xml:lang="en">Art Barstow</foaf:name>
+    ///       <foaf:knows rdf:resource="http://example.org/dan"/>
+    ///     </foaf:Person>
+    ///   </rdf:RDF>"#;
+    ///
+    /// let graph = RdfXmlParser::from_string(input).decode().unwrap();
+    ///
+    /// assert_eq!(graph.count(), 3);
+    /// assert_eq!(graph.namespaces().len(), 2);
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - Input that is not well-formed XML.
+    /// - Invalid input that does not conform with the RDF/XML standard.
+    ///
+    fn decode(&mut self) -> Result<Graph> {
+        let mut input = String::new();
+
+        if let Err(err) = self.input.read_to_string(&mut input) {
+            return Err(Error::new(
+                ErrorType::InvalidByteEncoding,
+                "RDF/XML input is not valid UTF-8.",
+            )
+            .with_source(err));
+        }
+
+        let (root, namespaces) = XmlReader::new(&input).read_document()?;
+
+        let mut graph = Graph::new(None);
+        for (prefix, uri) in namespaces {
+            graph.add_namespace(&Namespace::new(prefix, Uri::new(uri)));
+        }
+
+        let scope = Scope {
+            base: None,
+            language: None,
+        }
+        .enter(&root);
+
+        if root.is(RDF_NAMESPACE, "RDF") {
+            if let Some(ref base) = scope.base {
+                graph.set_base_uri(&Uri::new(base.clone()));
+            }
+
+            for child in root.child_elements()? {
+                read_node_element(&mut graph, child, &scope)?;
+            }
+        } else {
+            read_node_element(&mut graph, &root, &scope)?;
+        }
+
+        self.config.check_graph(&graph)?;
+
+        Ok(graph)
+    }
+}
+
+impl RdfXmlParser<Cursor<Vec<u8>>> {
+    /// Constructor of `RdfXmlParser` from input string.
+    pub fn from_string<S>(input: S) -> RdfXmlParser<Cursor<Vec<u8>>>
+    where
+        S: Into<String>,
+    {
+        RdfXmlParser::from_reader(Cursor::new(input.into().into_bytes()))
+    }
+}
+
+impl<R: Read> RdfXmlParser<R> {
+    /// Constructor of `RdfXmlParser` from input reader.
+    pub fn from_reader(input: R) -> RdfXmlParser<R> {
+        RdfXmlParser {
+            input,
+            config: ParserConfig::new(),
+        }
+    }
+
+    /// Sets the configuration of how strictly the input is parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::parser_config::ParserConfig;
+    /// use rdf::reader::rdf_parser::RdfParser;
+    /// use rdf::reader::rdf_xml_parser::RdfXmlParser;
+    ///
+    /// let input = r#"<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#"
+    ///                         xmlns:foaf="http://xmlns.com/foaf/0.1/">
+    ///     <foaf:Person rdf:about="http://example.org/art">
+    ///       <foaf:name xml:lang="en_GB">Art Barstow</foaf:name>
+    ///     </foaf:Person>
+    ///   </rdf:RDF>"#;
+    ///
+    /// assert!(RdfXmlParser::from_string(input).decode().is_ok());
+    /// assert!(RdfXmlParser::from_string(input)
+    ///     .with_config(ParserConfig::strict())
+    ///     .decode()
+    ///     .is_err());
+    /// ```
+    pub fn with_config(mut self, config: ParserConfig) -> RdfXmlParser<R> {
+        self.config = config;
+        self
+    }
+}
+
+/// Base URI and language that are in scope of an element.
+#[derive(Debug, Clone)]
+struct Scope {
+    base: Option<String>,
+    language: Option<String>,
+}
+
+impl Scope {
+    /// Returns the scope inside of the element, which can change it with `xml:base` and `xml:lang`.
+    fn enter(&self, element: &XmlElement) -> Scope {
+        let mut scope = self.clone();
+
+        if let Some(base) = element.attribute(XML_NAMESPACE, "base") {
+            scope.base = Some(resolve(&self.base, base));
+        }
+
+        if let Some(language) = element.attribute(XML_NAMESPACE, "lang") {
+            scope.language = if language.is_empty() {
+                None
+            } else {
+                Some(language.to_string())
+            };
+        }
+
+        scope
+    }
+}
+
+/// Reads a node element, adds its triples to the graph and returns its subject.
+fn read_node_element(graph: &mut Graph, element: &XmlElement, parent: &Scope) -> Result<Node> {
+    let scope = parent.enter(element);
+
+    let subject = if let Some(about) = element.attribute(RDF_NAMESPACE, "about") {
+        uri_node(&resolve(&scope.base, about))
+    } else if let Some(id) = element.attribute(RDF_NAMESPACE, "ID") {
+        uri_node(&resolve(&scope.base, &("#".to_string() + id)))
+    } else if let Some(id) = element.attribute(RDF_NAMESPACE, "nodeID") {
+        Node::BlankNode { id: id.to_string() }
+    } else {
+        graph.create_blank_node()
+    };
+
+    if !element.is(RDF_NAMESPACE, "Description") {
+        graph.add_triple(&Triple::new(
+            &subject,
+            &uri_node(&RdfSyntaxDataTypes::A.to_string()),
+            &uri_node(&element.iri()?),
+        ));
+    }
+
+    read_property_attributes(graph, &subject, element, &scope);
+    read_property_elements(graph, &subject, element, &scope)?;
+
+    Ok(subject)
+}
+
+/// Adds a triple for each attribute of the element that is not part of the RDF/XML syntax.
+fn read_property_attributes(
+    graph: &mut Graph,
+    subject: &Node,
+    element: &XmlElement,
+    scope: &Scope,
+) {
+    for attribute in element.attributes.iter().filter(|a| a.is_property()) {
+        let object = if attribute.is(RDF_NAMESPACE, "type") {
+            uri_node(&resolve(&scope.base, &attribute.value))
+        } else {
+            Node::LiteralNode {
+                literal: attribute.value.clone(),
+                data_type: None,
+                language: scope.language.clone(),
+            }
+        };
+
+        graph.add_triple(&Triple::new(subject, &uri_node(&attribute.iri()), &object));
+    }
+}
+
+/// Reads the child elements of the element as property elements of the subject.
+fn read_property_elements(
+    graph: &mut Graph,
+    subject: &Node,
+    element: &XmlElement,
+    scope: &Scope,
+) -> Result<()> {
+    let mut list_index = 0;
+
+    for child in element.child_elements()? {
+        let predicate = if child.is(RDF_NAMESPACE, "li") {
+            list_index += 1;
+            uri_node(&(RDF_NAMESPACE.to_string() + "_" + &list_index.to_string()))
+        } else {
+            uri_node(&child.iri()?)
+        };
+
+        let object = read_property_element(graph, child, scope)?;
+        graph.add_triple(&Triple::new(subject, &predicate, &object));
+    }
+
+    Ok(())
+}
+
+/// Reads the object of a property element and adds the triples of nested nodes to the graph.
+fn read_property_element(graph: &mut Graph, element: &XmlElement, parent: &Scope) -> Result<Node> {
+    let scope = parent.enter(element);
+
+    match element.attribute(RDF_NAMESPACE, "parseType") {
+        Some("Resource") => {
+            let object = graph.create_blank_node();
+            read_property_elements(graph, &object, element, &scope)?;
+            return Ok(object);
+        }
+        Some("Collection") => {
+            let mut items = Vec::new();
+            for child in element.child_elements()? {
+                items.push(read_node_element(graph, child, &scope)?);
+            }
+            return Ok(add_collection(graph, &items));
+        }
+        Some(_) => {
+            return Ok(Node::LiteralNode {
+                literal: element.canonical_inner_xml(&BTreeMap::new()),
+                data_type: Some(RdfSyntaxDataTypes::XmlLiteral.to_uri()),
+                language: None,
+            });
+        }
+        None => {}
+    }
+
+    let has_elements = element
+        .children
+        .iter()
+        .any(|child| matches!(*child, XmlContent::Element(_)));
+    let children = if has_elements {
+        element.child_elements()?
+    } else {
+        Vec::new()
+    };
+
+    if children.len() > 1 {
+        return Err(Error::new(
+            ErrorType::InvalidReaderInput,
+            "RDF/XML property element contains more than one node element.",
+        ));
+    }
+
+    if let Some(child) = children.first() {
+        return read_node_element(graph, child, &scope);
+    }
+
+    let has_property_attributes = element.attributes.iter().any(|a| a.is_property());
+
+    if let Some(resource) = element.attribute(RDF_NAMESPACE, "resource") {
+        let object = uri_node(&resolve(&scope.base, resource));
+        read_property_attributes(graph, &object, element, &scope);
+        Ok(object)
+    } else if let Some(id) = element.attribute(RDF_NAMESPACE, "nodeID") {
+        let object = Node::BlankNode { id: id.to_string() };
+        read_property_attributes(graph, &object, element, &scope);
+        Ok(object)
+    } else if has_property_attributes {
+        let object = graph.create_blank_node();
+        read_property_attributes(graph, &object, element, &scope);
+        Ok(object)
+    } else if let Some(data_type) = element.attribute(RDF_NAMESPACE, "datatype") {
+        Ok(Node::LiteralNode {
+            literal: element.text(),
+            data_type: Some(Uri::new(resolve(&scope.base, data_type))),
+            language: None,
+        })
+    } else {
+        Ok(Node::LiteralNode {
+            literal: element.text(),
+            data_type: None,
+            language: scope.language,
+        })
+    }
+}
+
+/// Adds the `rdf:first` and `rdf:rest` triples of a collection and returns its head.
+fn add_collection(graph: &mut Graph, items: &[Node]) -> Node {
+    let nil = uri_node(&RdfSyntaxDataTypes::ListNil.to_string());
+    let first = uri_node(&RdfSyntaxDataTypes::ListFirst.to_string());
+    let rest = uri_node(&RdfSyntaxDataTypes::ListRest.to_string());
+
+    let cells: Vec<Node> = items.iter().map(|_| graph.create_blank_node()).collect();
+
+    for (index, item) in items.iter().enumerate() {
+        let next = cells.get(index + 1).unwrap_or(&nil);
+        graph.add_triple(&Triple::new(&cells[index], &first, item));
+        graph.add_triple(&Triple::new(&cells[index], &rest, next));
+    }
+
+    cells.first().cloned().unwrap_or(nil)
+}
+
+/// Returns a URI node for the IRI.
+fn uri_node(iri: &str) -> Node {
+    Node::UriNode {
+        uri: Uri::new(iri.to_string()),
+    }
+}
+
+/// Resolves a reference against the base URI, references stay unchanged without base URI.
+fn resolve(base: &Option<String>, reference: &str) -> String {
+    match *base {
+        Some(ref base) => Uri::new(base.clone())
+            .resolve(reference)
+            .to_string()
+            .clone(),
+        None => reference.to_string(),
+    }
+}
+
+/// Attribute of an XML element with resolved namespace.
+#[derive(Debug, Clone)]
+struct XmlAttribute {
+    name: String,
+    namespace: String,
+    local_name: String,
+    value: String,
+}
+
+impl XmlAttribute {
+    /// Checks if the attribute has the namespace and local name.
+    fn is(&self, namespace: &str, local_name: &str) -> bool {
+        self.namespace == namespace && self.local_name == local_name
+    }
+
+    /// Returns the IRI of the attribute name.
+    fn iri(&self) -> String {
+        self.namespace.clone() + &self.local_name
+    }
+
+    /// Checks if the attribute is a property attribute and not part of the RDF/XML syntax.
+    fn is_property(&self) -> bool {
+        let syntax_terms = [
+            "about",
+            "ID",
+            "nodeID",
+            "resource",
+            "parseType",
+            "datatype",
+            "aboutEach",
+            "aboutEachPrefix",
+            "bagID",
+        ];
+
+        if self.namespace == RDF_NAMESPACE {
+            return !syntax_terms.contains(&self.local_name.as_str());
+        }
+
+        !self.namespace.is_empty() && self.namespace != XML_NAMESPACE
+    }
+}
+
+/// Content of an XML element.
+#[derive(Debug, Clone)]
+enum XmlContent {
+    Element(XmlElement),
+    Text(String),
+}
+
+/// Element of an XML document with resolved namespaces.
+#[derive(Debug, Clone)]
+struct XmlElement {
+    name: String,
+    namespace: String,
+    local_name: String,
+    attributes: Vec<XmlAttribute>,
+    children: Vec<XmlContent>,
+}
+
+impl XmlElement {
+    /// Checks if the element has the namespace and local name.
+    fn is(&self, namespace: &str, local_name: &str) -> bool {
+        self.namespace == namespace && self.local_name == local_name
+    }
+
+    /// Returns the IRI of the element name.
+    ///
+    /// # Failures
+    ///
+    /// - The element does not belong to a namespace.
+    ///
+    fn iri(&self) -> Result<String> {
+        if self.namespace.is_empty() {
+            return Err(Error::new(
+                ErrorType::InvalidNamespace,
+                "RDF/XML element without namespace: ".to_string() + &self.name,
+            ));
+        }
+
+        Ok(self.namespace.clone() + &self.local_name)
+    }
+
+    /// Returns the value of the attribute with the namespace and local name.
+    fn attribute(&self, namespace: &str, local_name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|a| a.is(namespace, local_name))
+            .map(|a| a.value.as_str())
+    }
+
+    /// Returns the child elements, text between them must be whitespace.
+    fn child_elements(&self) -> Result<Vec<&XmlElement>> {
+        let mut elements = Vec::new();
+
+        for child in &self.children {
+            match *child {
+                XmlContent::Element(ref element) => elements.push(element),
+                XmlContent::Text(ref text) if text.trim().is_empty() => {}
+                XmlContent::Text(_) => {
+                    return Err(Error::new(
+                        ErrorType::InvalidReaderInput,
+                        "Unexpected text in RDF/XML element ".to_string() + &self.name,
+                    ))
+                }
+            }
+        }
+
+        Ok(elements)
+    }
+
+    /// Returns the text content of the element.
+    fn text(&self) -> String {
+        self.children
+            .iter()
+            .map(|child| match *child {
+                XmlContent::Element(ref element) => element.text(),
+                XmlContent::Text(ref text) => text.clone(),
+            })
+            .collect()
+    }
+
+    /// Returns the content of the element as exclusive canonical XML.
+    ///
+    /// Each element declares the namespaces that it and its attributes use, unless an
+    /// ancestor in the output already declares them, as specified by Exclusive XML
+    /// Canonicalization. `rendered` contains the namespaces declared by the ancestors.
+    fn canonical_inner_xml(&self, rendered: &BTreeMap<String, String>) -> String {
+        self.children
+            .iter()
+            .map(|child| match *child {
+                XmlContent::Element(ref element) => element.canonical_xml(rendered),
+                XmlContent::Text(ref text) => escape_canonical_text(text),
+            })
+            .collect()
+    }
+
+    /// Returns the element as exclusive canonical XML.
+    fn canonical_xml(&self, rendered: &BTreeMap<String, String>) -> String {
+        let mut used = vec![(prefix(&self.name), &self.namespace)];
+        used.extend(
+            self.attributes
+                .iter()
+                .filter(|a| a.name.contains(':'))
+                .map(|a| (prefix(&a.name), &a.namespace)),
+        );
+
+        let mut rendered = rendered.clone();
+        let mut declarations = BTreeMap::new();
+        for (prefix, namespace) in used {
+            let declared = rendered.get(prefix).map_or("", |n| n.as_str());
+
+            if prefix != "xml" && declared != namespace.as_str() {
+                declarations.insert(prefix.to_string(), namespace.clone());
+                rendered.insert(prefix.to_string(), namespace.clone());
+            }
+        }
+
+        let mut xml = "<".to_string() + &self.name;
+
+        for (prefix, namespace) in declarations {
+            let name = match prefix.as_str() {
+                "" => "xmlns".to_string(),
+                prefix => "xmlns:".to_string() + prefix,
+            };
+            xml.push_str(&format!(
+                " {}=\"{}\"",
+                name,
+                escape_canonical_attribute(&namespace)
+            ));
+        }
+
+        let mut attributes: Vec<&XmlAttribute> = self.attributes.iter().collect();
+        attributes
+            .sort_by(|a, b| (&a.namespace, &a.local_name).cmp(&(&b.namespace, &b.local_name)));
+
+        for attribute in attributes {
+            xml.push_str(&format!(
+                " {}=\"{}\"",
+                attribute.name,
+                escape_canonical_attribute(&attribute.value)
+            ));
+        }
+
+        xml + ">" + &self.canonical_inner_xml(&rendered) + "</" + &self.name + ">"
+    }
+}
+
+/// Canonicalizes an `rdf:XMLLiteral` value according to Exclusive XML Canonicalization.
+///
+/// The value is a fragment of XML content. It has to be well-formed and to declare all
+/// namespace prefixes it uses. Namespace declarations are moved to the elements that use
+/// them, attributes are sorted and empty elements are written with end tags, so equal
+/// XML content has the same lexical form.
+///
+/// # Examples
+///
+/// ```
+/// use rdf::reader::rdf_xml_parser;
+///
+/// let literal = "<p xmlns:ex=\"http://example.org/\"><ex:b ex:c='d' a='&amp;'/></p>";
+///
+/// assert_eq!(
+///     rdf_xml_parser::canonicalize_xml_literal(literal).unwrap(),
+///     "<p><ex:b xmlns:ex=\"http://example.org/\" a=\"&amp;\" ex:c=\"d\"></ex:b></p>"
+/// );
+/// assert!(rdf_xml_parser::canonicalize_xml_literal("<p>text").is_err());
+/// ```
+///
+/// # Failures
+///
+/// - The value is not well-formed XML content.
+/// - The value uses undeclared namespace prefixes.
+///
+pub fn canonicalize_xml_literal(literal: &str) -> Result<String> {
+    let document = "<literal>".to_string() + literal + "</literal>";
+    let (element, _) = XmlReader::new(&document).read_document()?;
+
+    Ok(element.canonical_inner_xml(&BTreeMap::new()))
+}
+
+/// Returns the prefix of a qualified name, or an empty string if it has no prefix.
+fn prefix(name: &str) -> &str {
+    name.find(':').map_or("", |i| &name[..i])
+}
+
+/// Escapes text as required by canonical XML.
+fn escape_canonical_text(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('\r', "&#xD;")
+}
+
+/// Escapes attribute values as required by canonical XML.
+fn escape_canonical_attribute(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('"', "&quot;")
+        .replace('\t', "&#x9;")
+        .replace('\n', "&#xA;")
+        .replace('\r', "&#xD;")
+}
+
+/// Reads an XML document into a tree of elements.
+///
+/// Comments, processing instructions and the document type declaration are skipped,
+/// CDATA sections are read as text.
+struct XmlReader {
+    chars: Vec<char>,
+    position: usize,
+}
+
+impl XmlReader {
+    /// Constructor of `XmlReader`.
+    fn new(input: &str) -> XmlReader {
+        XmlReader {
+            chars: input.chars().collect(),
+            position: 0,
+        }
+    }
+
+    /// Reads the root element and returns it with the namespaces it declares.
+    fn read_document(&mut self) -> Result<(XmlElement, Vec<(String, String)>)> {
+        self.skip_misc()?;
+
+        let mut scope = HashMap::new();
+        scope.insert("xml".to_string(), XML_NAMESPACE.to_string());
+
+        let mut namespaces = Vec::new();
+        let root = self.read_element(&scope, &mut namespaces)?;
+
+        self.skip_misc()?;
+        if self.position < self.chars.len() {
+            return Err(self.error("Unexpected content after the XML root element."));
+        }
+
+        Ok((root, namespaces))
+    }
+
+    /// Returns an error for invalid XML input.
+    fn error(&self, message: &str) -> Error {
+        Error::new(ErrorType::InvalidReaderInput, message.to_string())
+    }
+
+    /// Checks if the remaining input starts with the string.
+    fn starts_with(&self, prefix: &str) -> bool {
+        prefix
+            .chars()
+            .enumerate()
+            .all(|(i, c)| self.chars.get(self.position + i) == Some(&c))
+    }
+
+    /// Consumes the input until after the delimiter and returns the characters before it.
+    fn read_until(&mut self, delimiter: &str) -> Result<String> {
+        let start = self.position;
+
+        while self.position < self.chars.len() {
+            if self.starts_with(delimiter) {
+                let content = self.chars[start..self.position].iter().collect();
+                self.position += delimiter.chars().count();
+                return Ok(content);
+            }
+            self.position += 1;
+        }
+
+        Err(Error::new(
+            ErrorType::InvalidReaderInput,
+            "Missing '".to_string() + delimiter + "' in XML input.",
+        ))
+    }
+
+    /// Consumes whitespace.
+    fn skip_whitespace(&mut self) {
+        while self
+            .chars
+            .get(self.position)
+            .is_some_and(|c| c.is_whitespace())
+        {
+            self.position += 1;
+        }
+    }
+
+    /// Skips whitespace, comments, processing instructions and document type declarations.
+    fn skip_misc(&mut self) -> Result<()> {
+        loop {
+            self.skip_whitespace();
+
+            if self.starts_with("<!--") {
+                self.read_until("-->")?;
+            } else if self.starts_with("<?") {
+                self.read_until("?>")?;
+            } else if self.starts_with("<!DOCTYPE") {
+                self.skip_doctype()?;
+            } else {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Skips a document type declaration including its internal subset.
+    fn skip_doctype(&mut self) -> Result<()> {
+        let mut depth = 0;
+
+        while let Some(&c) = self.chars.get(self.position) {
+            self.position += 1;
+            match c {
+                '[' => depth += 1,
+                ']' => depth -= 1,
+                '>' if depth == 0 => return Ok(()),
+                _ => {}
+            }
+        }
+
+        Err(self.error("Unterminated XML document type declaration."))
+    }
+
+    /// Reads a name of an element or attribute.
+    fn read_name(&mut self) -> Result<String> {
+        let start = self.position;
+
+        while self
+            .chars
+            .get(self.position)
+            .is_some_and(|&c| !c.is_whitespace() && !"/>=".contains(c))
+        {
+            self.position += 1;
+        }
+
+        if start == self.position {
+            return Err(self.error("Missing XML name."));
+        }
+
+        Ok(self.chars[start..self.position].iter().collect())
+    }
+
+    /// Reads an element and its content, namespace declarations are added to the scope.
+    fn read_element(
+        &mut self,
+        parent_scope: &HashMap<String, String>,
+        namespaces: &mut Vec<(String, String)>,
+    ) -> Result<XmlElement> {
+        self.position += 1; // consume '<'
+        let name = self.read_name()?;
+
+        let mut raw_attributes = Vec::new();
+        let is_empty = loop {
+            self.skip_whitespace();
+
+            if self.starts_with("/>") {
+                self.position += 2;
+                break true;
+            } else if self.starts_with(">") {
+                self.position += 1;
+                break false;
+            }
+
+            let attribute_name = self.read_name()?;
+            self.skip_whitespace();
+            if !self.starts_with("=") {
+                return Err(self.error("Missing '=' after XML attribute name."));
+            }
+            self.position += 1;
+            self.skip_whitespace();
+
+            let quote = match self.chars.get(self.position) {
+                Some(&c) if c == '"' || c == '\'' => c,
+                _ => return Err(self.error("XML attribute value is not quoted.")),
+            };
+            self.position += 1;
+            let value = unescape_xml(&self.read_until(&quote.to_string())?)?;
+
+            raw_attributes.push((attribute_name, value));
+        };
+
+        let mut scope = parent_scope.clone();
+        for (attribute_name, value) in &raw_attributes {
+            let prefix = if attribute_name == "xmlns" {
+                ""
+            } else if let Some(prefix) = attribute_name.strip_prefix("xmlns:") {
+                prefix
+            } else {
+                continue;
+            };
+
+            scope.insert(prefix.to_string(), value.clone());
+            if !prefix.is_empty() {
+                namespaces.push((prefix.to_string(), value.clone()));
+            }
+        }
+
+        let (namespace, local_name) = self.resolve_name(&scope, &name, true)?;
+
+        let mut attributes = Vec::new();
+        for (attribute_name, value) in raw_attributes {
+            if attribute_name == "xmlns" || attribute_name.starts_with("xmlns:") {
+                continue;
+            }
+
+            let (namespace, local_name) = self.resolve_name(&scope, &attribute_name, false)?;
+            attributes.push(XmlAttribute {
+                name: attribute_name,
+                namespace,
+                local_name,
+                value,
+            });
+        }
+
+        let mut element = XmlElement {
+            name,
+            namespace,
+            local_name,
+            attributes,
+            children: Vec::new(),
+        };
+
+        if !is_empty {
+            self.read_content(&mut element, &scope)?;
+        }
+
+        Ok(element)
+    }
+
+    /// Reads the content of the element until its end tag.
+    fn read_content(
+        &mut self,
+        element: &mut XmlElement,
+        scope: &HashMap<String, String>,
+    ) -> Result<()> {
+        loop {
+            if self.position >= self.chars.len() {
+                return Err(self.error("Missing XML end tag."));
+            }
+
+            if self.starts_with("</") {
+                self.position += 2;
+                let name = self.read_name()?;
+                self.skip_whitespace();
+
+                if name != element.name || !self.starts_with(">") {
+                    return Err(Error::new(
+                        ErrorType::InvalidReaderInput,
+                        "XML end tag does not match ".to_string() + &element.name,
+                    ));
+                }
+
+                self.position += 1;
+                return Ok(());
+            } else if self.starts_with("<!--") {
+                self.read_until("-->")?;
+            } else if self.starts_with("<![CDATA[") {
+                self.position += 9;
+                let text = self.read_until("]]>")?;
+                push_text(&mut element.children, text);
+            } else if self.starts_with("<?") {
+                self.read_until("?>")?;
+            } else if self.starts_with("<") {
+                let child = self.read_element(scope, &mut Vec::new())?;
+                element.children.push(XmlContent::Element(child));
+            } else {
+                let start = self.position;
+                while self.position < self.chars.len() && !self.starts_with("<") {
+                    self.position += 1;
+                }
+
+                let text: String = self.chars[start..self.position].iter().collect();
+                push_text(&mut element.children, unescape_xml(&text)?);
+            }
+        }
+    }
+
+    /// Splits a qualified name into namespace and local name.
+    ///
+    /// Unprefixed attributes, and unprefixed elements without default namespace, do not
+    /// belong to a namespace.
+    fn resolve_name(
+        &self,
+        scope: &HashMap<String, String>,
+        name: &str,
+        is_element: bool,
+    ) -> Result<(String, String)> {
+        let (prefix, local_name) = match name.find(':') {
+            Some(i) => (&name[..i], &name[i + 1..]),
+            None if is_element => ("", name),
+            None => return Ok((String::new(), name.to_string())),
+        };
+
+        match scope.get(prefix) {
+            Some(namespace) => Ok((namespace.clone(), local_name.to_string())),
+            None if prefix.is_empty() => Ok((String::new(), local_name.to_string())),
+            None => Err(Error::new(
+                ErrorType::InvalidNamespace,
+                "Undeclared XML namespace prefix in ".to_string() + name,
+            )),
+        }
+    }
+}
+
+/// Appends text to the content, adjacent text is merged.
+fn push_text(children: &mut Vec<XmlContent>, text: String) {
+    if let Some(&mut XmlContent::Text(ref mut previous)) = children.last_mut() {
+        previous.push_str(&text);
+        return;
+    }
+
+    children.push(XmlContent::Text(text));
+}
+
+/// Replaces the predefined entities and character references of XML.
+fn unescape_xml(text: &str) -> Result<String> {
+    let mut unescaped = String::new();
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        unescaped.push_str(&rest[..start]);
+        rest = &rest[start + 1..];
+
+        let end = match rest.find(';') {
+            Some(end) => end,
+            None => {
+                return Err(Error::new(
+                    ErrorType::InvalidReaderInput,
+                    "Unterminated XML entity reference.",
+                ))
+            }
+        };
+
+        let character = match &rest[..end] {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            reference if reference.starts_with("#x") => u32::from_str_radix(&reference[2..], 16)
+                .ok()
+                .and_then(std::char::from_u32),
+            reference if reference.starts_with('#') => reference[1..]
+                .parse::<u32>()
+                .ok()
+                .and_then(std::char::from_u32),
+            _ => None,
+        };
+
+        match character {
+            Some(c) => unescaped.push(c),
+            None => {
+                return Err(Error::new(
+                    ErrorType::InvalidReaderInput,
+                    "Unknown XML entity reference &".to_string() + &rest[..end] + ";",
+                ))
+            }
+        }
+
+        rest = &rest[end + 1..];
+    }
+
+    unescaped.push_str(rest);
+    Ok(unescaped)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::node::Node;
+    #[cfg(feature = "turtle")]
+    use crate::reader::parser_config::ParserConfig;
+    use crate::reader::rdf_parser::RdfParser;
+    use crate::reader::rdf_xml_parser::RdfXmlParser;
+    #[cfg(feature = "turtle")]
+    use crate::reader::turtle_parser::TurtleParser;
+    use crate::specs::rdf_syntax_specs::RdfSyntaxDataTypes;
+    use crate::specs::xml_specs::XmlDataTypes;
+    use crate::uri::Uri;
+    #[cfg(feature = "turtle")]
+    use crate::writer::rdf_writer::RdfWriter;
+    #[cfg(feature = "turtle")]
+    use crate::writer::turtle_writer::TurtleWriter;
+
+    #[test]
+    fn read_rdf_xml_with_nested_nodes_and_parse_types() {
+        let input = r##"<?xml version="1.0" encoding="utf-8"?>
+            <!DOCTYPE rdf:RDF [ <!ENTITY xsd "http://www.w3.org/2001/XMLSchema#"> ]>
+            <rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#"
+                     xmlns:ex="http://example.org/terms#"
+                     xml:base="http://example.org/data/">
+              <!-- a typed node with nested nodes -->
+              <ex:Task rdf:ID="write" ex:title="Write &amp; publish" xml:lang="en">
+                <ex:effort rdf:datatype="http://www.w3.org/2001/XMLSchema#integer">3</ex:effort>
+                <ex:owner>
+                  <rdf:Description rdf:about="people/alice" ex:name="Alice"/>
+                </ex:owner>
+                <ex:address rdf:parseType="Resource">
+                  <ex:city xml:lang="">Berlin</ex:city>
+                </ex:address>
+                <ex:steps rdf:parseType="Collection">
+                  <rdf:Description rdf:about="#draft"/>
+                  <rdf:Description rdf:nodeID="review"/>
+                </ex:steps>
+                <ex:note rdf:parseType="Literal"><b>bold</b> text</ex:note>
+              </ex:Task>
+              <rdf:Bag rdf:about="http://example.org/bag">
+                <rdf:li rdf:resource="a"/>
+                <rdf:li><![CDATA[<b>]]></rdf:li>
+              </rdf:Bag>
+            </rdf:RDF>"##;
+
+        let graph = RdfXmlParser::from_string(input).decode().unwrap();
+        let uri = |uri: &str| Node::UriNode {
+            uri: Uri::new(uri.to_string()),
+        };
+        let literal =
+            |literal: &str, data_type: Option<Uri>, language: Option<&str>| Node::LiteralNode {
+                literal: literal.to_string(),
+                data_type,
+                language: language.map(|l| l.to_string()),
+            };
+        let task = uri("http://example.org/data/#write");
+
+        assert_eq!(graph.count(), 16);
+        assert_eq!(
+            graph.base_uri(),
+            &Some(Uri::new("http://example.org/data/".to_string()))
+        );
+
+        let objects = |subject: &Node, predicate: &str| -> Vec<Node> {
+            graph
+                .get_triples_with_subject_and_predicate(subject, &uri(predicate))
+                .into_iter()
+                .map(|t| t.object().clone())
+                .collect()
+        };
+
+        assert_eq!(
+            objects(&task, "http://example.org/terms#title"),
+            vec![literal("Write & publish", None, Some("en"))]
+        );
+        assert_eq!(
+            objects(&task, "http://example.org/terms#effort"),
+            vec![literal("3", Some(XmlDataTypes::Integer.to_uri()), None)]
+        );
+        assert_eq!(
+            objects(&task, "http://example.org/terms#owner"),
+            vec![uri("http://example.org/data/people/alice")]
+        );
+        assert_eq!(
+            objects(&task, "http://example.org/terms#note"),
+            vec![literal(
+                "<b>bold</b> text",
+                Some(RdfSyntaxDataTypes::XmlLiteral.to_uri()),
+                None
+            )]
+        );
+        assert_eq!(
+            objects(
+                &uri("http://example.org/bag"),
+                "http://www.w3.org/1999/02/22-rdf-syntax-ns#_2"
+            ),
+            vec![literal("<b>", None, None)]
+        );
+        assert!(graph.contains_matching(
+            None,
+            Some(&uri("http://example.org/terms#city")),
+            Some(&literal("Berlin", None, None))
+        ));
+        assert!(graph.contains_matching(
+            None,
+            Some(&uri(&RdfSyntaxDataTypes::ListFirst.to_string())),
+            Some(&Node::BlankNode {
+                id: "review".to_string()
+            })
+        ));
+    }
+
+    #[test]
+    fn malformed_rdf_xml_is_rejected() {
+        let rdf = "xmlns:rdf=\"http://www.w3.org/1999/02/22-rdf-syntax-ns#\"";
+
+        for input in &[
+            format!("<rdf:RDF {}><rdf:Description></rdf:RDF>", rdf),
+            format!("<rdf:RDF {}><ex:Task/></rdf:RDF>", rdf),
+            format!("<rdf:RDF {}>text</rdf:RDF>", rdf),
+            format!("<rdf:RDF {}><Task/></rdf:RDF>", rdf),
+            format!("<rdf:RDF {}><rdf:Description ex=unquoted/></rdf:RDF>", rdf),
+        ] {
+            assert!(RdfXmlParser::from_string(input.as_str()).decode().is_err());
+        }
+    }
+
+    #[test]
+    fn xml_literals_are_canonicalized_and_survive_round_trips() {
+        let input = r#"<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#"
+                     xmlns:ex="http://example.org/terms#"
+                     xmlns="http://www.w3.org/1999/xhtml">
+              <rdf:Description rdf:about="http://example.org/a">
+                <ex:comment rdf:parseType="Literal"><p class='x' ex:y="1 &lt; 2">A <ex:em/></p></ex:comment>
+              </rdf:Description>
+            </rdf:RDF>"#;
+
+        let graph = RdfXmlParser::from_string(input).decode().unwrap();
+        let triple = graph.triples_iter().next().unwrap();
+        let expected = Node::LiteralNode {
+            literal: "<p xmlns=\"http://www.w3.org/1999/xhtml\" xmlns:ex=\"http://example.org/terms#\" class=\"x\" ex:y=\"1 &lt; 2\">A <ex:em></ex:em></p>".to_string(),
+            data_type: Some(RdfSyntaxDataTypes::XmlLiteral.to_uri()),
+            language: None,
+        };
+
+        assert_eq!(*triple.object(), expected);
+
+        #[cfg(feature = "turtle")]
+        {
+            let turtle = TurtleWriter::new(graph.namespaces())
+                .write_to_string(&graph)
+                .unwrap();
+            let reparsed = TurtleParser::from_string(turtle)
+                .with_config(ParserConfig::strict())
+                .decode()
+                .unwrap();
+
+            assert_eq!(*reparsed.triples_iter().next().unwrap().object(), expected);
+        }
+    }
+}
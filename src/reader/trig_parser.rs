@@ -0,0 +1,314 @@
+use crate::dataset::Dataset;
+use crate::error::{Error, ErrorType};
+use crate::graph::Graph;
+use crate::namespace::Namespace;
+use crate::node::Node;
+use crate::reader::lexer::token::Token;
+use crate::reader::parser_config::ParserConfig;
+use crate::reader::rdf_parser::{RdfDatasetParser, RdfParser};
+use crate::reader::turtle_parser::TurtleParser;
+use crate::triple::{Triple, TripleStore};
+use crate::Result;
+use std::io::Cursor;
+use std::io::Read;
+use std::mem;
+
+/// RDF parser to generate an RDF dataset from TriG syntax.
+///
+/// TriG extends Turtle by graph blocks `GRAPH <name> { ... }`, the keyword `GRAPH` is optional.
+/// Triples outside of graph blocks and in blocks without name belong to the default graph.
+pub struct TriGParser<R: Read> {
+    parser: TurtleParser<R>,
+}
+
+impl<R: Read> RdfDatasetParser for TriGParser<R> {
+    /// Generates an RDF dataset from a string containing TriG syntax.
+    ///
+    /// Prefixes and the base URI are added to the default graph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::node::Node;
+    /// use rdf::reader::rdf_parser::RdfDatasetParser;
+    /// use rdf::reader::trig_parser::TriGParser;
+    /// use rdf::uri::Uri;
+    ///
+    /// let input = "@prefix ex: <http://example.org/> .
+    ///
+    ///              ex:alice ex:knows ex:bob .
+    ///
+    ///              GRAPH ex:people {
+    ///                ex:alice ex:name \"Alice\" .
+    ///                ex:bob ex:name \"Bob\"
+    ///              }";
+    ///
+    /// let dataset = TriGParser::from_string(input).decode_dataset().unwrap();
+    /// let people = Node::UriNode { uri: Uri::new("http://example.org/people".to_string()) };
+    ///
+    /// assert_eq!(dataset.default_graph().count(), 1);
+    /// assert_eq!(dataset.named_graph(&people).unwrap().count(), 2);
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - Invalid input that does not conform with the TriG standard.
+    ///
+    fn decode_dataset(&mut self) -> Result<Dataset> {
+        let mut dataset = Dataset::new();
+
+        // keeps prefixes, base URI and blank node IDs across all graphs
+        let mut context = Graph::new(None);
+
+        self.read_statements(&mut context, &mut dataset)
+            .map_err(|err| err.with_position(self.parser.position()))?;
+
+        self.parser.config().check_dataset(&dataset)?;
+
+        let default_graph = dataset.default_graph_mut();
+
+        if let Some(ref base_uri) = *context.base_uri() {
+            default_graph.set_base_uri(base_uri);
+        }
+        for (prefix, uri) in context.namespaces() {
+            default_graph.add_namespace(&Namespace::new(prefix.clone(), uri.clone()));
+        }
+
+        Ok(dataset)
+    }
+}
+
+impl<R: Read> RdfParser for TriGParser<R> {
+    /// Generates an RDF graph with the triples of all graphs of the TriG input.
+    ///
+    /// The graph names are dropped, use `decode_dataset` to keep them.
+    ///
+    /// # Failures
+    ///
+    /// - Invalid input that does not conform with the TriG standard.
+    ///
+    fn decode(&mut self) -> Result<Graph> {
+        let dataset = self.decode_dataset()?;
+        let mut graph = dataset.default_graph().clone();
+
+        for named_graph in dataset.named_graphs().values() {
+            graph.add_triples(&named_graph.triples_iter().cloned().collect::<Vec<_>>());
+        }
+
+        Ok(graph)
+    }
+}
+
+impl TriGParser<Cursor<Vec<u8>>> {
+    /// Constructor of `TriGParser` from input string.
+    pub fn from_string<S>(input: S) -> TriGParser<Cursor<Vec<u8>>>
+    where
+        S: Into<String>,
+    {
+        TriGParser::from_reader(Cursor::new(input.into().into_bytes()))
+    }
+}
+
+impl<R: Read> TriGParser<R> {
+    /// Constructor of `TriGParser` from input reader.
+    pub fn from_reader(input: R) -> TriGParser<R> {
+        TriGParser {
+            parser: TurtleParser::from_reader(input),
+        }
+    }
+
+    /// Sets the configuration of how strictly the input is parsed.
+    pub fn with_config(self, config: ParserConfig) -> TriGParser<R> {
+        TriGParser {
+            parser: self.parser.with_config(config),
+        }
+    }
+
+    /// Reads all directives, triples and graphs of the input into the dataset.
+    fn read_statements(&mut self, context: &mut Graph, dataset: &mut Dataset) -> Result<()> {
+        loop {
+            match self.parser.peek_token() {
+                Ok(Token::Comment(_)) => {
+                    let _ = self.parser.next_token();
+                    continue;
+                }
+                Ok(Token::EndOfInput) => break,
+                Ok(Token::BaseDirective(_)) | Ok(Token::SparqlBaseDirective(_)) => {
+                    let base_uri = self.parser.read_base_directive(context)?;
+                    context.set_base_uri(&base_uri);
+                }
+                Ok(Token::PrefixDirective(_, _)) | Ok(Token::SparqlPrefixDirective(_, _)) => {
+                    let namespace = self.parser.read_prefix_directive(context)?;
+                    context.add_namespace(&namespace);
+                }
+                Ok(Token::GraphStart) => {
+                    let _ = self.parser.next_token(); // consume '{'
+                    self.read_graph(None, context, dataset)?;
+                }
+                Ok(Token::KeywordGraph) => {
+                    let _ = self.parser.next_token(); // consume 'GRAPH'
+                    let name = self.read_graph_name(context)?;
+
+                    match self.parser.next_token()? {
+                        Token::GraphStart => self.read_graph(Some(&name), context, dataset)?,
+                        _ => {
+                            return Err(Error::new(
+                                ErrorType::InvalidToken,
+                                "TriG graph name is not followed by '{'.",
+                            ))
+                        }
+                    }
+                }
+                Ok(Token::Uri(_)) | Ok(Token::BlankNode(_)) | Ok(Token::QName(_, _)) => {
+                    // either the name of a graph or the subject of triples in the default graph
+                    let node = self.parser.read_subject(context)?;
+
+                    if self.parser.peek_token()? == Token::GraphStart {
+                        let _ = self.parser.next_token(); // consume '{'
+                        self.read_graph(Some(&node), context, dataset)?;
+                    } else {
+                        let triples = self.parser.read_predicate_object_list(
+                            &node,
+                            Token::TripleDelimiter,
+                            context,
+                        )?;
+                        add_triples(dataset.default_graph_mut(), context, &triples);
+                    }
+                }
+                Ok(Token::CollectionStart)
+                | Ok(Token::UnlabeledBlankNodeStart)
+                | Ok(Token::QuotedTripleStart) => {
+                    let triples = self.parser.read_triples(context)?;
+                    add_triples(dataset.default_graph_mut(), context, &triples);
+                }
+                Err(err) => match *err.error_type() {
+                    ErrorType::EndOfInput(_) => break,
+                    _ => {
+                        return Err(Error::new(
+                            ErrorType::InvalidReaderInput,
+                            "Error while parsing TriG syntax.",
+                        )
+                        .with_source(err))
+                    }
+                },
+                Ok(_) => {
+                    return Err(Error::new(
+                        ErrorType::InvalidToken,
+                        "Invalid token while parsing TriG syntax.",
+                    ))
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Reads the name of a graph after the `GRAPH` keyword.
+    fn read_graph_name(&mut self, context: &mut Graph) -> Result<Node> {
+        match self.parser.peek_token()? {
+            Token::Uri(_) | Token::BlankNode(_) | Token::QName(_, _) => {
+                self.parser.read_subject(context)
+            }
+            _ => Err(Error::new(
+                ErrorType::InvalidToken,
+                "Invalid token for TriG graph name.",
+            )),
+        }
+    }
+
+    /// Reads the triples of a graph block until '}' and adds them to the graph of the dataset.
+    fn read_graph(
+        &mut self,
+        name: Option<&Node>,
+        context: &mut Graph,
+        dataset: &mut Dataset,
+    ) -> Result<()> {
+        loop {
+            match self.parser.peek_token()? {
+                Token::GraphEnd => {
+                    let _ = self.parser.next_token(); // consume '}'
+                    break;
+                }
+                Token::Comment(_) => {
+                    let _ = self.parser.next_token();
+                }
+                Token::Uri(_)
+                | Token::BlankNode(_)
+                | Token::QName(_, _)
+                | Token::CollectionStart
+                | Token::UnlabeledBlankNodeStart
+                | Token::QuotedTripleStart => {
+                    let triples = self.parser.read_triples(context)?;
+                    let graph = match name {
+                        Some(name) => dataset.named_graph_mut(name),
+                        None => dataset.default_graph_mut(),
+                    };
+                    add_triples(graph, context, &triples);
+                }
+                _ => {
+                    return Err(Error::new(
+                        ErrorType::InvalidToken,
+                        "Invalid token while parsing TriG graph.",
+                    ))
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Adds the triples and the nested triples that the Turtle parser added to the context.
+fn add_triples(graph: &mut Graph, context: &mut Graph, triples: &[Triple]) {
+    let nested = mem::replace(context.store_mut(), TripleStore::new()).into_vec();
+
+    graph.add_triples(&nested);
+    graph.add_triples(triples);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::node::Node;
+    use crate::reader::rdf_parser::{RdfDatasetParser, RdfParser};
+    use crate::reader::trig_parser::TriGParser;
+    use crate::reader::turtle_parser::TurtleParser;
+
+    #[test]
+    fn read_trig_with_default_and_named_graphs() {
+        let input = "@prefix ex: <http://example.org/> .
+                     # default graph
+                     { ex:a ex:p ( ex:b ex:c ) . }
+                     ex:a ex:q [ ex:r ex:s ] .
+                     _:g { ex:a ex:p ex:d }
+                     graph <http://example.org/g> {
+                       ex:a ex:p \"e\" .
+                       # comment in graph
+                       ex:a ex:p ex:f .
+                     }
+                     GRAPH _:g { ex:a ex:p [ ex:r ex:t ] . }";
+
+        let dataset = TriGParser::from_string(input).decode_dataset().unwrap();
+        let blank = Node::BlankNode {
+            id: "g".to_string(),
+        };
+
+        assert_eq!(dataset.default_graph().count(), 7);
+        assert_eq!(dataset.default_graph().namespaces().len(), 1);
+        assert_eq!(dataset.graph_names().len(), 2);
+        assert_eq!(dataset.named_graph(&blank).unwrap().count(), 3);
+        assert_eq!(TriGParser::from_string(input).decode().unwrap().count(), 12);
+    }
+
+    #[test]
+    fn graph_blocks_are_rejected_in_turtle() {
+        let input = "<http://example.org/g> { <http://example.org/a> <http://example.org/p> _:b }";
+
+        assert!(TurtleParser::from_string(input).decode().is_err());
+        assert!(TriGParser::from_string("GRAPH { }")
+            .decode_dataset()
+            .is_err());
+        assert!(TriGParser::from_string("GRAPH _:g { _:a _:p _:b .")
+            .decode_dataset()
+            .is_err());
+    }
+}
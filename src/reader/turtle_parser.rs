@@ -1,21 +1,23 @@
-use Result;
-use error::{Error, ErrorType};
-use graph::Graph;
-use namespace::Namespace;
-use node::Node;
-use reader::lexer::rdf_lexer::RdfLexer;
-use reader::lexer::token::Token;
-use reader::lexer::turtle_lexer::TurtleLexer;
-use reader::rdf_parser::RdfParser;
-use specs::rdf_syntax_specs::RdfSyntaxDataTypes;
+use crate::error::{Error, ErrorType, Position};
+use crate::graph::Graph;
+use crate::namespace::Namespace;
+use crate::node::Node;
+use crate::reader::lexer::rdf_lexer::RdfLexer;
+use crate::reader::lexer::token::Token;
+use crate::reader::lexer::turtle_lexer::TurtleLexer;
+use crate::reader::parser_config::ParserConfig;
+use crate::reader::rdf_parser::{RdfParser, TripleSink};
+use crate::specs::rdf_syntax_specs::RdfSyntaxDataTypes;
+use crate::triple::Triple;
+use crate::uri::Uri;
+use crate::Result;
 use std::io::Cursor;
 use std::io::Read;
-use triple::Triple;
-use uri::Uri;
 
 /// RDF parser to generate an RDF graph from Turtle syntax.
 pub struct TurtleParser<R: Read> {
     lexer: TurtleLexer<R>,
+    config: ParserConfig,
 }
 
 impl<R: Read> RdfParser for TurtleParser<R> {
@@ -46,48 +48,20 @@ impl<R: Read> RdfParser for TurtleParser<R> {
     /// - Invalid node type for triple segment.
     ///
     fn decode(&mut self) -> Result<Graph> {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("decode", format = "Turtle").entered();
+
         let mut graph = Graph::new(None);
 
-        loop {
-            match self.lexer.peek_next_token() {
-                Ok(Token::Comment(_)) => {
-                    let _ = self.lexer.get_next_token();
-                    continue;
-                }
-                Ok(Token::EndOfInput) => return Ok(graph),
-                Ok(Token::BaseDirective(_)) => {
-                    let base_uri = self.read_base_directive()?;
-                    graph.set_base_uri(&base_uri);
-                }
-                Ok(Token::PrefixDirective(_, _)) => {
-                    let namespace = self.read_prefix_directive()?;
-                    graph.add_namespace(&namespace);
-                }
-                Ok(Token::Uri(_))
-                | Ok(Token::BlankNode(_))
-                | Ok(Token::QName(_, _))
-                | Ok(Token::CollectionStart)
-                | Ok(Token::UnlabeledBlankNodeStart) => {
-                    let triples = self.read_triples(&mut graph)?;
-                    graph.add_triples(&triples);
-                }
-                Err(err) => match *err.error_type() {
-                    ErrorType::EndOfInput(_) => return Ok(graph),
-                    _ => {
-                        return Err(Error::new(
-                            ErrorType::InvalidReaderInput,
-                            "Error while parsing Turtle syntax.",
-                        ))
-                    }
-                },
-                Ok(_) => {
-                    return Err(Error::new(
-                        ErrorType::InvalidToken,
-                        "Invalid token while parsing Turtle syntax.",
-                    ))
-                }
-            }
-        }
+        self.read_statements(&mut graph)
+            .map_err(|err| err.with_position(self.lexer.position()))?;
+
+        self.config.check_graph(&graph)?;
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(triples = graph.count(), "Decoded Turtle");
+
+        Ok(graph)
     }
 }
 
@@ -130,19 +104,256 @@ impl<R: Read> TurtleParser<R> {
     pub fn from_reader(input: R) -> TurtleParser<R> {
         TurtleParser {
             lexer: TurtleLexer::new(input),
+            config: ParserConfig::new(),
         }
     }
 
-    /// Parses prefix directives and returns the created namespace.
-    fn read_base_directive(&mut self) -> Result<Uri> {
+    /// Sets the configuration of how strictly the input is parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::parser_config::ParserConfig;
+    /// use rdf::reader::rdf_parser::RdfParser;
+    /// use rdf::reader::turtle_parser::TurtleParser;
+    ///
+    /// let input = "@prefix ex: <http://example.org/> .
+    ///              ex:a ex:b \"c\"@en_US .";
+    ///
+    /// let mut reader = TurtleParser::from_string(input).with_config(ParserConfig::strict());
+    ///
+    /// assert!(reader.decode().is_err());
+    /// ```
+    pub fn with_config(mut self, config: ParserConfig) -> TurtleParser<R> {
+        self.config = config;
+        self
+    }
+
+    /// Generates an RDF graph from the valid statements and returns the errors of all others.
+    ///
+    /// Instead of stopping at the first error, the rest of an invalid statement up to the next
+    /// '.' is skipped and parsing continues with the following statement. None of the triples
+    /// of an invalid statement, including those of nested blank nodes and collections, are
+    /// added to the graph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::reader::turtle_parser::TurtleParser;
+    ///
+    /// let input = "@prefix ex: <http://example.org/> .
+    ///              ex:a ex:b ex:c .
+    ///              ex:a ex:b .
+    ///              ex:a ex:d ] ex:g .
+    ///              ex:a ex:e ex:f .";
+    ///
+    /// let (graph, errors) = TurtleParser::from_string(input).decode_tolerant();
+    ///
+    /// assert_eq!(graph.count(), 2);
+    /// assert_eq!(errors.len(), 2);
+    /// assert_eq!(errors[0].position().unwrap().line(), 3);
+    /// ```
+    pub fn decode_tolerant(&mut self) -> (Graph, Vec<Error>) {
+        // each statement is read into the graph on its own and only kept once it is complete
+        let mut graph = Graph::new(None);
+        let mut triples = Vec::new();
+        let mut errors = Vec::new();
+
+        loop {
+            match self.read_statement(&mut graph) {
+                Ok(has_more) => {
+                    for triple in graph.take_triples() {
+                        match self.config.check_triple(&triple) {
+                            Ok(()) => triples.push(triple),
+                            Err(err) => errors.push(err),
+                        }
+                    }
+
+                    if !has_more {
+                        break;
+                    }
+                }
+                Err(err) => {
+                    errors.push(err.with_position(self.lexer.position()));
+                    self.lexer.skip_statement();
+                    let _ = graph.take_triples();
+                }
+            }
+        }
+
+        graph.add_triples(&triples);
+
+        (graph, errors)
+    }
+
+    /// Reads the input statement by statement and pushes the directives and triples into the
+    /// sink without building a graph.
+    ///
+    /// Only the triples of the current statement are kept in memory. Triples that were pushed
+    /// before an error remain in the sink.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::node::Node;
+    /// use rdf::reader::turtle_parser::TurtleParser;
+    /// use rdf::triple::Triple;
+    /// use rdf::uri::Uri;
+    ///
+    /// let input = "@prefix ex: <http://example.org/> .
+    ///              ex:a ex:knows ex:b , ex:c ;
+    ///                   ex:name \"A\" .";
+    ///
+    /// let knows = Node::UriNode { uri: Uri::new("http://example.org/knows".to_string()) };
+    /// let mut acquaintances = Vec::new();
+    ///
+    /// TurtleParser::from_string(input)
+    ///     .parse_into(&mut |triple: Triple| {
+    ///         if *triple.predicate() == knows {
+    ///             acquaintances.push(triple.object().clone());
+    ///         }
+    ///     })
+    ///     .unwrap();
+    ///
+    /// assert_eq!(acquaintances.len(), 2);
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - Invalid input that does not conform with Turtle standard.
+    /// - Triples that are not accepted by the parser configuration.
+    ///
+    pub fn parse_into<T: TripleSink>(&mut self, sink: &mut T) -> Result<()> {
+        // keeps the prefixes, base URI and blank node IDs that statements depend on
+        let mut context = Graph::new(None);
+
+        loop {
+            let statement = match self.lexer.peek_next_token() {
+                Ok(Token::BaseDirective(_)) | Ok(Token::SparqlBaseDirective(_)) => {
+                    self.read_base_directive(&context).map(|base_uri| {
+                        context.set_base_uri(&base_uri);
+                        sink.base(&base_uri);
+                        true
+                    })
+                }
+                Ok(Token::PrefixDirective(_, _)) | Ok(Token::SparqlPrefixDirective(_, _)) => {
+                    self.read_prefix_directive(&context).map(|namespace| {
+                        context.add_namespace(&namespace);
+                        sink.prefix(namespace.prefix(), namespace.uri());
+                        true
+                    })
+                }
+                _ => self.read_statement(&mut context),
+            };
+
+            let has_more = statement.map_err(|err| err.with_position(self.lexer.position()))?;
+
+            for triple in context.take_triples() {
+                self.config.check_triple(&triple)?;
+                sink.triple(triple);
+            }
+
+            if !has_more {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Returns the configuration of how strictly the input is parsed.
+    pub(crate) fn config(&self) -> &ParserConfig {
+        &self.config
+    }
+
+    /// Returns the position where the last read or peeked token starts.
+    pub(crate) fn position(&self) -> Position {
+        self.lexer.position()
+    }
+
+    /// Reads all directives and triples of the input into the graph.
+    fn read_statements(&mut self, graph: &mut Graph) -> Result<()> {
+        while self.read_statement(graph)? {}
+
+        Ok(())
+    }
+
+    /// Reads the next directive or triples into the graph, returns `false` at the end of the input.
+    fn read_statement(&mut self, graph: &mut Graph) -> Result<bool> {
+        loop {
+            match self.lexer.peek_next_token() {
+                Ok(Token::Comment(_)) => {
+                    let _ = self.lexer.get_next_token();
+                    continue;
+                }
+                Ok(Token::EndOfInput) => return Ok(false),
+                Ok(Token::BaseDirective(_)) | Ok(Token::SparqlBaseDirective(_)) => {
+                    let base_uri = self.read_base_directive(graph)?;
+                    graph.set_base_uri(&base_uri);
+                }
+                Ok(Token::PrefixDirective(_, _)) | Ok(Token::SparqlPrefixDirective(_, _)) => {
+                    let namespace = self.read_prefix_directive(graph)?;
+                    graph.add_namespace(&namespace);
+                }
+                Ok(Token::Uri(_))
+                | Ok(Token::BlankNode(_))
+                | Ok(Token::QName(_, _))
+                | Ok(Token::CollectionStart)
+                | Ok(Token::UnlabeledBlankNodeStart)
+                | Ok(Token::QuotedTripleStart) => {
+                    let triples = self.read_triples(graph)?;
+                    graph.add_triples(&triples);
+                }
+                Err(err) => match *err.error_type() {
+                    ErrorType::EndOfInput(_) => return Ok(false),
+                    _ => {
+                        #[cfg(feature = "tracing")]
+                        tracing::warn!(error = %err, "Error while parsing Turtle syntax");
+
+                        return Err(Error::new(
+                            ErrorType::InvalidReaderInput,
+                            "Error while parsing Turtle syntax.",
+                        )
+                        .with_source(err));
+                    }
+                },
+                Ok(_) => {
+                    return Err(Error::new(
+                        ErrorType::InvalidToken,
+                        "Invalid token while parsing Turtle syntax.",
+                    ))
+                }
+            }
+
+            return Ok(true);
+        }
+    }
+
+    /// Returns the next token without consuming it.
+    pub(crate) fn peek_token(&mut self) -> Result<Token> {
+        self.lexer.peek_next_token()
+    }
+
+    /// Returns the next token and consumes it.
+    pub(crate) fn next_token(&mut self) -> Result<Token> {
+        self.lexer.get_next_token()
+    }
+
+    /// Parses base directives and returns the base URI.
+    ///
+    /// Only `@base` directives end with '.', SPARQL-style `BASE` directives do not. A relative
+    /// base URI is resolved against the current base URI of the graph.
+    pub(crate) fn read_base_directive(&mut self, graph: &Graph) -> Result<Uri> {
         match self.lexer.get_next_token()? {
             Token::BaseDirective(uri) => match self.lexer.get_next_token()? {
-                Token::TripleDelimiter => Ok(Uri::new(uri)),
+                Token::TripleDelimiter => Ok(resolve_uri(graph, uri)),
+                Token::EndOfInput if self.config.allow_missing_final_dot() => {
+                    Ok(resolve_uri(graph, uri))
+                }
                 _ => Err(Error::new(
                     ErrorType::InvalidReaderInput,
                     "Turtle base directive does not end with '.'",
                 )),
             },
+            Token::SparqlBaseDirective(uri) => Ok(resolve_uri(graph, uri)),
             _ => Err(Error::new(
                 ErrorType::InvalidReaderInput,
                 "Invalid input for Turtle base directive.",
@@ -151,15 +362,23 @@ impl<R: Read> TurtleParser<R> {
     }
 
     /// Parses prefix directives and returns the created namespace.
-    fn read_prefix_directive(&mut self) -> Result<Namespace> {
+    ///
+    /// Only `@prefix` directives end with '.', SPARQL-style `PREFIX` directives do not.
+    pub(crate) fn read_prefix_directive(&mut self, graph: &Graph) -> Result<Namespace> {
         match self.lexer.get_next_token()? {
             Token::PrefixDirective(prefix, uri) => match self.lexer.get_next_token()? {
-                Token::TripleDelimiter => Ok(Namespace::new(prefix, Uri::new(uri))),
+                Token::TripleDelimiter => Ok(Namespace::new(prefix, resolve_uri(graph, uri))),
+                Token::EndOfInput if self.config.allow_missing_final_dot() => {
+                    Ok(Namespace::new(prefix, resolve_uri(graph, uri)))
+                }
                 _ => Err(Error::new(
                     ErrorType::InvalidReaderInput,
                     "Turtle prefix directive does not end with '.'",
                 )),
             },
+            Token::SparqlPrefixDirective(prefix, uri) => {
+                Ok(Namespace::new(prefix, resolve_uri(graph, uri)))
+            }
             _ => Err(Error::new(
                 ErrorType::InvalidReaderInput,
                 "Invalid input for Turtle prefix.",
@@ -168,24 +387,39 @@ impl<R: Read> TurtleParser<R> {
     }
 
     /// Creates a triple from the parsed tokens.
-    fn read_triples(&mut self, graph: &mut Graph) -> Result<Vec<Triple>> {
+    pub(crate) fn read_triples(&mut self, graph: &mut Graph) -> Result<Vec<Triple>> {
+        let is_blank_node_property_list =
+            self.lexer.peek_next_token()? == Token::UnlabeledBlankNodeStart;
         let subject = self.read_subject(graph)?;
 
-        self.read_predicate_object_list(&subject, graph)
+        // a blank node property list can form a statement on its own, like `[ foaf:name "Bob" ] .`
+        if is_blank_node_property_list {
+            match self.lexer.peek_next_token()? {
+                Token::TripleDelimiter => {
+                    let _ = self.lexer.get_next_token()?; // consume the token indicating the triple end '.'
+                    return Ok(Vec::new());
+                }
+                Token::EndOfInput if self.config.allow_missing_final_dot() => return Ok(Vec::new()),
+                _ => {}
+            }
+        }
+
+        self.read_predicate_object_list(&subject, Token::TripleDelimiter, graph)
     }
 
     /// Get the next token and check if it is a valid subject and create a new subject node.
-    fn read_subject(&mut self, graph: &mut Graph) -> Result<Node> {
+    pub(crate) fn read_subject(&mut self, graph: &mut Graph) -> Result<Node> {
         match self.lexer.get_next_token()? {
             Token::BlankNode(id) => Ok(Node::BlankNode { id }),
-            Token::QName(prefix, path) => {
-                let mut uri = graph.get_namespace_uri_by_prefix(&prefix)?.to_owned();
-                uri.append_resource_path(&path.replace(":", "/")); // adjust the QName path to URI path
-                Ok(Node::UriNode { uri })
-            }
-            Token::Uri(uri) => Ok(Node::UriNode { uri: Uri::new(uri) }),
+            Token::QName(prefix, local_name) => Ok(Node::UriNode {
+                uri: expand_qname(graph, &prefix, &local_name)?,
+            }),
+            Token::Uri(uri) => Ok(Node::UriNode {
+                uri: resolve_uri(graph, uri),
+            }),
             Token::CollectionStart => self.read_collection(graph),
             Token::UnlabeledBlankNodeStart => self.read_unlabeled_blank_node(graph),
+            Token::QuotedTripleStart => self.read_quoted_triple(graph),
             _ => Err(Error::new(
                 ErrorType::InvalidToken,
                 "Invalid token for Turtle subject.",
@@ -194,27 +428,57 @@ impl<R: Read> TurtleParser<R> {
     }
 
     /// Reads a list or a single pair of predicate and object nodes.
-    fn read_predicate_object_list(
+    ///
+    /// The list has to be closed by `end`, which is '.' for statements, ']' for blank node
+    /// property lists and '|}' for annotations.
+    pub(crate) fn read_predicate_object_list(
         &mut self,
         subject: &Node,
+        end: Token,
         graph: &mut Graph,
     ) -> Result<Vec<Triple>> {
         let mut triples: Vec<Triple> = Vec::new();
 
         let (predicate, object) = self.read_predicate_with_object(graph)?;
-        triples.push(Triple::new(subject, &predicate, &object));
+        let triple = Triple::new(subject, &predicate, &object);
+        let mut annotation = self.read_annotation(&triple, graph)?;
+        triples.push(triple);
+        triples.append(&mut annotation);
 
         loop {
+            let is_statement = end == Token::TripleDelimiter;
+
+            // the last triple of a TriG graph does not need to end with '.'
+            if is_statement && self.lexer.peek_next_token()? == Token::GraphEnd {
+                break;
+            }
+
             match self.lexer.get_next_token()? {
-                Token::TripleDelimiter => break,
-                Token::UnlabeledBlankNodeEnd => break,
+                ref token if *token == end => break,
+                Token::EndOfInput if is_statement && self.config.allow_missing_final_dot() => break,
                 Token::PredicateListDelimiter => {
+                    // the predicate object list may end with ';' or contain repeated ';'
+                    match self.lexer.peek_next_token()? {
+                        Token::TripleDelimiter
+                        | Token::UnlabeledBlankNodeEnd
+                        | Token::AnnotationEnd
+                        | Token::GraphEnd
+                        | Token::PredicateListDelimiter => continue,
+                        _ => {}
+                    }
+
                     let (predicate, object) = self.read_predicate_with_object(graph)?;
-                    triples.push(Triple::new(subject, &predicate, &object));
+                    let triple = Triple::new(subject, &predicate, &object);
+                    let mut annotation = self.read_annotation(&triple, graph)?;
+                    triples.push(triple);
+                    triples.append(&mut annotation);
                 }
                 Token::ObjectListDelimiter => {
                     let object = self.read_object(graph)?;
-                    triples.push(Triple::new(subject, &predicate, &object));
+                    let triple = Triple::new(subject, &predicate, &object);
+                    let mut annotation = self.read_annotation(&triple, graph)?;
+                    triples.push(triple);
+                    triples.append(&mut annotation);
                 }
                 _ => {
                     return Err(Error::new(
@@ -232,15 +496,15 @@ impl<R: Read> TurtleParser<R> {
     fn read_predicate_with_object(&mut self, graph: &mut Graph) -> Result<(Node, Node)> {
         // read the predicate
         let predicate = match self.lexer.get_next_token()? {
-            Token::Uri(uri) => Node::UriNode { uri: Uri::new(uri) },
+            Token::Uri(uri) => Node::UriNode {
+                uri: resolve_uri(graph, uri),
+            },
             Token::KeywordA => Node::UriNode {
                 uri: RdfSyntaxDataTypes::A.to_uri(),
             },
-            Token::QName(prefix, path) => {
-                let mut uri = graph.get_namespace_uri_by_prefix(&prefix)?.to_owned();
-                uri.append_resource_path(&path.replace(":", "/")); // adjust the QName path to URI path
-                Node::UriNode { uri }
-            }
+            Token::QName(prefix, local_name) => Node::UriNode {
+                uri: expand_qname(graph, &prefix, &local_name)?,
+            },
             Token::BlankNode(id) => Node::BlankNode { id },
             _ => {
                 return Err(Error::new(
@@ -260,12 +524,12 @@ impl<R: Read> TurtleParser<R> {
     fn read_object(&mut self, graph: &mut Graph) -> Result<Node> {
         match self.lexer.get_next_token()? {
             Token::BlankNode(id) => Ok(Node::BlankNode { id }),
-            Token::Uri(uri) => Ok(Node::UriNode { uri: Uri::new(uri) }),
-            Token::QName(prefix, path) => {
-                let mut uri = graph.get_namespace_uri_by_prefix(&prefix)?.to_owned();
-                uri.append_resource_path(&path.replace(":", "/")); // adjust the QName path to URI path
-                Ok(Node::UriNode { uri })
-            }
+            Token::Uri(uri) => Ok(Node::UriNode {
+                uri: resolve_uri(graph, uri),
+            }),
+            Token::QName(prefix, local_name) => Ok(Node::UriNode {
+                uri: expand_qname(graph, &prefix, &local_name)?,
+            }),
             Token::LiteralWithLanguageSpecification(literal, lang) => Ok(Node::LiteralNode {
                 literal,
                 data_type: None,
@@ -273,7 +537,12 @@ impl<R: Read> TurtleParser<R> {
             }),
             Token::LiteralWithUrlDatatype(literal, datatype) => Ok(Node::LiteralNode {
                 literal,
-                data_type: Some(Uri::new(datatype)),
+                data_type: Some(resolve_uri(graph, datatype)),
+                language: None,
+            }),
+            Token::LiteralWithQNameDatatype(literal, prefix, local_name) => Ok(Node::LiteralNode {
+                literal,
+                data_type: Some(expand_qname(graph, &prefix, &local_name)?),
                 language: None,
             }),
             Token::Literal(literal) => Ok(Node::LiteralNode {
@@ -283,6 +552,7 @@ impl<R: Read> TurtleParser<R> {
             }),
             Token::CollectionStart => self.read_collection(graph),
             Token::UnlabeledBlankNodeStart => self.read_unlabeled_blank_node(graph),
+            Token::QuotedTripleStart => self.read_quoted_triple(graph),
             _ => Err(Error::new(
                 ErrorType::InvalidToken,
                 "Invalid token for Turtle object.",
@@ -290,6 +560,38 @@ impl<R: Read> TurtleParser<R> {
         }
     }
 
+    /// Reads the annotation `{| ... |}` of a triple if there is one.
+    ///
+    /// Returns the annotation triples which have the quoted triple as subject.
+    fn read_annotation(&mut self, triple: &Triple, graph: &mut Graph) -> Result<Vec<Triple>> {
+        if self.lexer.peek_next_token()? != Token::AnnotationStart {
+            return Ok(Vec::new());
+        }
+
+        let _ = self.lexer.get_next_token()?; // consume the token indicating the annotation start '{|'
+
+        let subject = graph.create_triple_node(triple);
+        self.read_predicate_object_list(&subject, Token::AnnotationEnd, graph)
+    }
+
+    /// Reads a quoted triple and returns it as node.
+    ///
+    /// The quoted triple itself is not added to the graph.
+    fn read_quoted_triple(&mut self, graph: &mut Graph) -> Result<Node> {
+        let subject = self.read_subject(graph)?;
+        let (predicate, object) = self.read_predicate_with_object(graph)?;
+
+        match self.lexer.get_next_token()? {
+            Token::QuotedTripleEnd => {
+                Ok(graph.create_triple_node(&Triple::new(&subject, &predicate, &object)))
+            }
+            _ => Err(Error::new(
+                ErrorType::InvalidToken,
+                "Quoted triple does not end with '>>'.",
+            )),
+        }
+    }
+
     /// Reads a unlabeled blank node.
     ///
     /// Returns the subject node and add all other nested nodes to the graph.
@@ -299,7 +601,8 @@ impl<R: Read> TurtleParser<R> {
         if self.lexer.peek_next_token()? == Token::UnlabeledBlankNodeEnd {
             let _ = self.lexer.get_next_token()?; // consume the token indicating the node end ']'
         } else {
-            let triples = self.read_predicate_object_list(&subject, graph)?;
+            let triples =
+                self.read_predicate_object_list(&subject, Token::UnlabeledBlankNodeEnd, graph)?;
             graph.add_triples(&triples);
         }
 
@@ -369,11 +672,32 @@ impl<R: Read> TurtleParser<R> {
     }
 }
 
+/// Expands a QName by concatenating the namespace URI of the prefix and the local name.
+fn expand_qname(graph: &Graph, prefix: &str, local_name: &str) -> Result<Uri> {
+    let namespace = graph.get_namespace_uri_by_prefix(prefix)?;
+
+    Ok(Uri::new(namespace.to_string().to_owned() + local_name))
+}
+
+/// Resolves a possibly relative IRI against the base URI of the graph.
+fn resolve_uri(graph: &Graph, uri: String) -> Uri {
+    match *graph.base_uri() {
+        Some(ref base) => base.resolve(&uri),
+        None => Uri::new(uri),
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use reader::rdf_parser::RdfParser;
-    use reader::turtle_parser::TurtleParser;
-    use uri::Uri;
+    use crate::graph::Graph;
+    use crate::node::Node;
+    use crate::reader::parser_config::ParserConfig;
+    use crate::reader::rdf_parser::RdfParser;
+    use crate::reader::turtle_parser::TurtleParser;
+    use crate::specs::rdf_syntax_specs::RdfSyntaxDataTypes;
+    use crate::specs::xml_specs::XmlDataTypes;
+    use crate::triple::Triple;
+    use crate::uri::Uri;
 
     #[test]
     fn test_read_n_triples_as_turtle_from_string() {
@@ -410,6 +734,7 @@ mod tests {
             Ok(graph) => {
                 assert_eq!(graph.count(), 4);
                 assert_eq!(graph.namespaces().len(), 2);
+                assert!(graph.get_namespace_uri_by_prefix("foaf").is_ok());
                 assert_eq!(
                     graph.base_uri(),
                     &Some(Uri::new("http://example.org/".to_string()))
@@ -473,7 +798,7 @@ mod tests {
 
     #[test]
     fn test_parsing_turtle_sparql_base_uri() {
-        let input = "BASE <http://example/> .";
+        let input = "BASE <http://example/>";
         let mut reader = TurtleParser::from_string(input.to_string());
 
         match reader.decode() {
@@ -504,7 +829,7 @@ mod tests {
 
     #[test]
     fn test_parsing_turtle_sparql_prefix() {
-        let input = "PREFIX p: <http://p.example/> .";
+        let input = "PREFIX p: <http://p.example/>";
         let mut reader = TurtleParser::from_string(input.to_string());
 
         match reader.decode() {
@@ -516,6 +841,25 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parsing_mixed_directive_styles() {
+        let input = "PREFIX ex: <http://example.org/>
+                     base <http://example.org/data/>
+                     @prefix foaf: <http://xmlns.com/foaf/0.1/> .
+                     ex:a foaf:knows <b> .";
+
+        let graph = TurtleParser::from_string(input).decode().unwrap();
+
+        assert_eq!(graph.count(), 1);
+        assert_eq!(graph.namespaces().len(), 2);
+        assert!(TurtleParser::from_string("PREFIX p: <http://p.example/> .")
+            .decode()
+            .is_err());
+        assert!(TurtleParser::from_string("@prefix p: <http://p.example/>")
+            .decode()
+            .is_err());
+    }
+
     #[test]
     fn test_read_turtle_with_empty_prefix_from_string() {
         let input = "@prefix : <http://example/> .
@@ -577,6 +921,29 @@ mod tests {
         }
     }
 
+    #[test]
+    fn read_collection_of_literals_from_string() {
+        let input = "@prefix ex: <http://example.org/> .
+            ex:s ex:p (1 \"two\" ex:three).";
+
+        let graph = TurtleParser::from_string(input).decode().unwrap();
+
+        let first = Node::UriNode {
+            uri: RdfSyntaxDataTypes::ListFirst.to_uri(),
+        };
+        let rest = Node::UriNode {
+            uri: RdfSyntaxDataTypes::ListRest.to_uri(),
+        };
+        let nil = Node::UriNode {
+            uri: RdfSyntaxDataTypes::ListNil.to_uri(),
+        };
+
+        assert_eq!(graph.count(), 7);
+        assert_eq!(graph.get_triples_with_predicate(&first).len(), 3);
+        assert_eq!(graph.get_triples_with_predicate(&rest).len(), 3);
+        assert_eq!(graph.get_triples_with_object(&nil).len(), 1);
+    }
+
     #[test]
     fn read_empty_unlabeled_node_from_string() {
         let input = "[ ] _:b [ ] .";
@@ -610,4 +977,362 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn read_blank_node_property_lists_from_string() {
+        let input = "@prefix foaf: <http://xmlns.com/foaf/0.1/> .
+            [ foaf:name \"Bob\" ; foaf:age 23 ] .
+            <http://example.org/a> foaf:knows [ foaf:name \"Carol\" ; ] ;
+                                   foaf:knows [ foaf:knows [ foaf:name \"Dave\" ] ] .";
+
+        let graph = TurtleParser::from_string(input).decode().unwrap();
+
+        let name = Node::UriNode {
+            uri: Uri::new("http://xmlns.com/foaf/0.1/name".to_string()),
+        };
+
+        assert_eq!(graph.count(), 7);
+        assert_eq!(graph.get_triples_with_predicate(&name).len(), 3);
+    }
+
+    #[test]
+    fn read_a_keyword_as_rdf_type_from_string() {
+        let input = "@prefix ex: <http://example.org/> .
+            ex:s a ex:Person ;
+                 ex:knows [ a ex:Person ] .";
+
+        let graph = TurtleParser::from_string(input).decode().unwrap();
+
+        let rdf_type = Node::UriNode {
+            uri: RdfSyntaxDataTypes::A.to_uri(),
+        };
+
+        assert_eq!(graph.count(), 3);
+        assert_eq!(graph.get_triples_with_predicate(&rdf_type).len(), 2);
+    }
+
+    #[test]
+    fn read_numeric_literals_from_string() {
+        let input = "@prefix ex: <http://example.org/> .
+            ex:s ex:p 42 , -3.14 , 1.2e6 .";
+
+        let graph = TurtleParser::from_string(input).decode().unwrap();
+
+        let has_literal = |literal: &str, data_type: XmlDataTypes| {
+            graph.triples_iter().any(|triple| {
+                *triple.object()
+                    == Node::LiteralNode {
+                        literal: literal.to_string(),
+                        data_type: Some(data_type.to_uri()),
+                        language: None,
+                    }
+            })
+        };
+
+        assert_eq!(graph.count(), 3);
+        assert!(has_literal("42", XmlDataTypes::Integer));
+        assert!(has_literal("-3.14", XmlDataTypes::Decimal));
+        assert!(has_literal("1.2e6", XmlDataTypes::Double));
+    }
+
+    #[test]
+    fn read_literals_with_prefixed_data_types_from_string() {
+        let input = "@prefix ex: <http://example.org/> .
+            @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+            ex:s ex:p \"5\"^^xsd:int , \"x\"^^ex:type .";
+
+        let graph = TurtleParser::from_string(input).decode().unwrap();
+
+        let literal = |literal: &str, data_type: &str| Node::LiteralNode {
+            literal: literal.to_string(),
+            data_type: Some(Uri::new(data_type.to_string())),
+            language: None,
+        };
+
+        let int = XmlDataTypes::Int.to_string();
+
+        assert_eq!(graph.count(), 2);
+        assert_eq!(graph.get_triples_with_object(&literal("5", &int)).len(), 1);
+        assert_eq!(
+            graph
+                .get_triples_with_object(&literal("x", "http://example.org/type"))
+                .len(),
+            1
+        );
+
+        let undeclared = "<http://example.org/s> <http://example.org/p> \"5\"^^xsd:int .";
+        assert!(TurtleParser::from_string(undeclared).decode().is_err());
+    }
+
+    #[test]
+    fn read_boolean_literals_from_string() {
+        let input = "@prefix ex: <http://example.org/> .
+            ex:s ex:p true ;
+                 ex:q false.";
+
+        let graph = TurtleParser::from_string(input).decode().unwrap();
+
+        let boolean = |literal: &str| Node::LiteralNode {
+            literal: literal.to_string(),
+            data_type: Some(XmlDataTypes::Boolean.to_uri()),
+            language: None,
+        };
+
+        assert_eq!(graph.count(), 2);
+        assert_eq!(graph.get_triples_with_object(&boolean("true")).len(), 1);
+        assert_eq!(graph.get_triples_with_object(&boolean("false")).len(), 1);
+    }
+
+    #[test]
+    fn read_single_quoted_literals_from_string() {
+        let input = "@prefix ex: <http://example.org/> .
+            ex:s ex:p 'say \"hi\"' , 'hallo'@de , '''multi
+line''' .";
+
+        let graph = TurtleParser::from_string(input).decode().unwrap();
+
+        let literal = |literal: &str, language: Option<&str>| Node::LiteralNode {
+            literal: literal.to_string(),
+            data_type: None,
+            language: language.map(|language| language.to_string()),
+        };
+
+        assert_eq!(graph.count(), 3);
+        assert_eq!(
+            graph
+                .get_triples_with_object(&literal("say \"hi\"", None))
+                .len(),
+            1
+        );
+        assert_eq!(
+            graph
+                .get_triples_with_object(&literal("hallo", Some("de")))
+                .len(),
+            1
+        );
+        assert_eq!(
+            graph
+                .get_triples_with_object(&literal("multi\nline", None))
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn read_annotations_from_string() {
+        let input = "@prefix ex: <http://example.org/> .
+            ex:s ex:p ex:o {| ex:certainty 0.9 ; ex:source ex:a , ex:b |} ;
+                 ex:q ex:r {| ex:source << ex:x ex:y ex:z >> |} .";
+
+        let graph = TurtleParser::from_string(input).decode().unwrap();
+
+        let uri = |name: &str| Node::UriNode {
+            uri: Uri::new("http://example.org/".to_string() + name),
+        };
+        let quoted = |p: &str, o: &str| Node::TripleNode {
+            triple: Box::new(Triple::new(&uri("s"), &uri(p), &uri(o))),
+        };
+
+        assert_eq!(graph.count(), 6);
+        assert_eq!(graph.get_triples_with_subject(&quoted("p", "o")).len(), 3);
+        assert_eq!(graph.get_triples_with_subject(&quoted("q", "r")).len(), 1);
+    }
+
+    #[test]
+    fn decode_tolerant_drops_nested_triples_of_invalid_statements() {
+        let input = "@prefix ex: <http://example.org/> .
+            ex:a ex:b (ex:c ex:d) ex:zzz .
+            ex:a ex:b [ ex:c ex:d ] , nope:x .
+            ex:a ex:b ex:c .";
+
+        let (graph, errors) = TurtleParser::from_string(input).decode_tolerant();
+
+        assert_eq!(graph.count(), 1);
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0].position().unwrap().line(), 2);
+        assert_eq!(errors[1].position().unwrap().line(), 3);
+        assert!(graph.get_namespace_uri_by_prefix("ex").is_ok());
+    }
+
+    #[test]
+    fn reject_misplaced_closing_delimiters() {
+        let inputs = [
+            "<http://example.org/s> <http://example.org/p> <http://example.org/o> |}",
+            "<http://example.org/s> <http://example.org/p> <http://example.org/o> ]",
+            "[ <http://example.org/p> <http://example.org/o> .",
+            "<http://example.org/s> <http://example.org/p> <http://example.org/o> \
+             {| <http://example.org/p> <http://example.org/o> .",
+        ];
+
+        for input in &inputs {
+            assert!(
+                TurtleParser::from_string(*input).decode().is_err(),
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn read_quoted_triple_as_subject_from_string() {
+        let input = "<< _:a <http://example.org/p> \"o\" >> <http://example.org/certainty> 0.9 .";
+
+        let graph = TurtleParser::from_string(input).decode().unwrap();
+        let triple = graph.triples_iter().next().unwrap();
+
+        match *triple.subject() {
+            Node::TripleNode { ref triple } => {
+                assert_eq!(
+                    *triple.subject(),
+                    Node::BlankNode {
+                        id: "a".to_string()
+                    }
+                )
+            }
+            _ => panic!("subject is not a quoted triple"),
+        }
+    }
+
+    #[test]
+    fn read_tabs_and_carriage_returns_from_string() {
+        let input = "@prefix\tex:\t<http://example.org/> .\r\n\
+                     # comment\r\
+                     ex:a\tex:p\tex:b ;\r\
+                     \tex:q \"\"\"line\r\nbreak\"\"\" .\r";
+
+        let graph = TurtleParser::from_string(input).decode().unwrap();
+
+        assert_eq!(graph.count(), 2);
+        assert!(graph.triples_iter().any(|t| *t.object()
+            == Node::LiteralNode {
+                literal: "line\r\nbreak".to_string(),
+                data_type: None,
+                language: None,
+            }));
+    }
+
+    #[test]
+    fn read_unterminated_quoted_triple_from_string() {
+        let input = "<< _:a <http://example.org/p> _:b <http://example.org/certainty> 0.9 .";
+
+        assert!(TurtleParser::from_string(input).decode().is_err());
+    }
+
+    #[test]
+    fn resolve_relative_uris_against_base_uri() {
+        let input = "@base <http://example.org/data/people> .
+                     @prefix ex: <../terms#> .
+                     <#me> ex:knows <friends/alice> .
+                     @base <other/> .
+                     <bob> ex:age \"42\"^^<../types#int> .";
+
+        let graph = TurtleParser::from_string(input).decode().unwrap();
+        let uri = |uri: &str| Node::UriNode {
+            uri: Uri::new(uri.to_string()),
+        };
+
+        assert!(graph.triples_iter().any(|t| *t
+            == Triple::new(
+                &uri("http://example.org/data/people#me"),
+                &uri("http://example.org/terms#knows"),
+                &uri("http://example.org/data/friends/alice"),
+            )));
+        assert!(graph.triples_iter().any(|t| *t.subject()
+            == uri("http://example.org/data/other/bob")
+            && *t.object()
+                == Node::LiteralNode {
+                    literal: "42".to_string(),
+                    data_type: Some(Uri::new("http://example.org/data/types#int".to_string())),
+                    language: None,
+                }));
+    }
+
+    #[test]
+    fn expand_qnames_by_concatenation() {
+        let input = "@prefix foaf: <http://xmlns.com/foaf/0.1/> .
+                     @prefix ex: <http://example.org/data#> .
+                     ex:v1.2 foaf:name ex:a:b, ex:c\\/d\\., ex:e.
+                     ex: ex:p \"1\"^^ex:type.";
+
+        let graph = TurtleParser::from_string(input).decode().unwrap();
+        let uri = |uri: &str| Node::UriNode {
+            uri: Uri::new(uri.to_string()),
+        };
+        let subject = uri("http://example.org/data#v1.2");
+        let name = uri("http://xmlns.com/foaf/0.1/name");
+
+        assert_eq!(graph.count(), 4);
+        assert!(graph
+            .triples_iter()
+            .any(|t| *t == Triple::new(&subject, &name, &uri("http://example.org/data#a:b"))));
+        assert!(graph
+            .triples_iter()
+            .any(|t| *t == Triple::new(&subject, &name, &uri("http://example.org/data#c/d."))));
+        assert!(graph
+            .triples_iter()
+            .any(|t| *t == Triple::new(&subject, &name, &uri("http://example.org/data#e"))));
+        assert!(graph
+            .triples_iter()
+            .any(|t| *t.subject() == uri("http://example.org/data#")
+                && *t.object()
+                    == Node::LiteralNode {
+                        literal: "1".to_string(),
+                        data_type: Some(Uri::new("http://example.org/data#type".to_string())),
+                        language: None,
+                    }));
+    }
+
+    #[test]
+    fn read_missing_final_dot_from_string() {
+        let input = "@prefix ex: <http://example.org/> .
+                     ex:a ex:b ex:c ;
+                          ex:d [ ex:e \"f\" ]";
+
+        assert!(TurtleParser::from_string(input).decode().is_err());
+
+        let mut config = ParserConfig::new();
+        config.set_allow_missing_final_dot(true);
+        let graph = TurtleParser::from_string(input)
+            .with_config(config)
+            .decode()
+            .unwrap();
+
+        assert_eq!(graph.count(), 3);
+    }
+
+    #[test]
+    fn parse_into_graph_sink_matches_decode() {
+        let input = "@base <http://example.org/> .
+                     @prefix ex: <terms#> .
+                     <a> ex:b [ ex:c ( 1 2 ) ] .
+                     PREFIX foaf: <http://xmlns.com/foaf/0.1/>
+                     <a> foaf:knows _:x , [ foaf:name \"B\" ] .";
+
+        let decoded = TurtleParser::from_string(input).decode().unwrap();
+
+        let mut graph = Graph::new(None);
+        TurtleParser::from_string(input)
+            .parse_into(&mut graph)
+            .unwrap();
+
+        let mut expected: Vec<&Triple> = decoded.triples_iter().collect();
+        let mut triples: Vec<&Triple> = graph.triples_iter().collect();
+        expected.sort();
+        triples.sort();
+
+        assert_eq!(triples, expected);
+        assert_eq!(graph.namespaces(), decoded.namespaces());
+        assert_eq!(
+            graph.base_uri(),
+            &Some(Uri::new("http://example.org/".to_string()))
+        );
+
+        let mut count = 0;
+        let err = TurtleParser::from_string("<a> <b> <c> . <a> <b> .")
+            .parse_into(&mut |_| count += 1)
+            .unwrap_err();
+
+        assert_eq!(count, 1);
+        assert_eq!(err.position().unwrap().column(), 23);
+    }
 }
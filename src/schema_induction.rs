@@ -0,0 +1,561 @@
+use crate::graph::Graph;
+use crate::node::Node;
+use crate::specs::rdf_syntax_specs::RdfSyntaxDataTypes;
+use crate::specs::rdfs_specs::RdfsVocabulary;
+use crate::specs::shacl_specs::ShaclVocabulary;
+use crate::specs::xml_specs::XmlDataTypes;
+use crate::triple::Triple;
+use crate::uri::Uri;
+use std::collections::BTreeMap;
+
+/// Type of the values of a property.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
+pub enum ValueType {
+    /// IRIs.
+    Iri,
+    /// Blank nodes.
+    BlankNode,
+    /// Literals of the data type, plain literals are `xsd:string` and literals with language
+    /// are `rdf:langString`.
+    Literal(Uri),
+    /// Quoted triples.
+    Triple,
+}
+
+impl ValueType {
+    /// Returns the value type of a node.
+    pub fn of(node: &Node) -> ValueType {
+        match *node {
+            Node::UriNode { .. } => ValueType::Iri,
+            Node::BlankNode { .. } => ValueType::BlankNode,
+            Node::LiteralNode {
+                data_type: Some(ref data_type),
+                ..
+            } => ValueType::Literal(data_type.clone()),
+            Node::LiteralNode {
+                language: Some(_), ..
+            } => ValueType::Literal(RdfSyntaxDataTypes::LangString.to_uri()),
+            Node::LiteralNode { .. } => ValueType::Literal(XmlDataTypes::String.to_uri()),
+            Node::TripleNode { .. } => ValueType::Triple,
+        }
+    }
+}
+
+/// Usage of a property by the sampled instances of a class.
+#[derive(Debug, PartialEq, Clone)]
+pub struct PropertySummary {
+    predicate: Node,
+    instance_count: usize,
+    value_count: usize,
+    min_count: usize,
+    max_count: usize,
+    value_types: BTreeMap<ValueType, usize>,
+}
+
+impl PropertySummary {
+    /// Returns the predicate of the property.
+    pub fn predicate(&self) -> &Node {
+        &self.predicate
+    }
+
+    /// Returns the number of sampled instances with at least one value.
+    pub fn instance_count(&self) -> usize {
+        self.instance_count
+    }
+
+    /// Returns the number of values of all sampled instances.
+    pub fn value_count(&self) -> usize {
+        self.value_count
+    }
+
+    /// Returns the smallest number of values of a sampled instance, `0` if an instance has none.
+    pub fn min_count(&self) -> usize {
+        self.min_count
+    }
+
+    /// Returns the largest number of values of a sampled instance.
+    pub fn max_count(&self) -> usize {
+        self.max_count
+    }
+
+    /// Returns the number of values of each value type.
+    pub fn value_types(&self) -> &BTreeMap<ValueType, usize> {
+        &self.value_types
+    }
+
+    /// Returns the value type if all values have the same type.
+    pub fn single_value_type(&self) -> Option<&ValueType> {
+        match self.value_types.len() {
+            1 => self.value_types.keys().next(),
+            _ => None,
+        }
+    }
+}
+
+/// Summary of the instances of a class.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ClassSummary {
+    class: Option<Node>,
+    instance_count: usize,
+    sample_count: usize,
+    properties: Vec<PropertySummary>,
+}
+
+impl ClassSummary {
+    /// Returns the class, `None` summarizes the subjects without `rdf:type`.
+    pub fn class(&self) -> &Option<Node> {
+        &self.class
+    }
+
+    /// Returns the number of instances of the class.
+    pub fn instance_count(&self) -> usize {
+        self.instance_count
+    }
+
+    /// Returns the number of instances the summary is based on.
+    pub fn sample_count(&self) -> usize {
+        self.sample_count
+    }
+
+    /// Returns the properties of the sampled instances, ordered by predicate.
+    pub fn properties(&self) -> &[PropertySummary] {
+        &self.properties
+    }
+
+    /// Returns the summary of a property.
+    pub fn property(&self, predicate: &Node) -> Option<&PropertySummary> {
+        self.properties
+            .iter()
+            .find(|property| property.predicate == *predicate)
+    }
+}
+
+/// Schema inferred from the instance data of a graph.
+#[derive(Debug, PartialEq, Clone)]
+pub struct InducedSchema {
+    classes: Vec<ClassSummary>,
+}
+
+impl InducedSchema {
+    /// Returns the class summaries, ordered by class with the untyped subjects first.
+    pub fn classes(&self) -> &[ClassSummary] {
+        &self.classes
+    }
+
+    /// Returns the summary of a class.
+    pub fn class(&self, class: &Node) -> Option<&ClassSummary> {
+        self.classes
+            .iter()
+            .find(|summary| summary.class.as_ref() == Some(class))
+    }
+
+    /// Returns an RDFS and SHACL sketch of the schema.
+    ///
+    /// Each class is an `rdfs:Class` with a node shape that targets it. The node shape has a
+    /// property shape with the observed cardinalities for each property, and the data type or
+    /// node kind if all values share it. Properties used by a single class get it as
+    /// `rdfs:domain`, properties with a single literal data type get it as `rdfs:range`.
+    /// Subjects without class are left out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "turtle")]
+    /// # {
+    /// use rdf::reader::rdf_parser::RdfParser;
+    /// use rdf::reader::turtle_parser::TurtleParser;
+    /// use rdf::shacl;
+    /// use rdf::specs::shacl_specs::ShaclVocabulary;
+    ///
+    /// let input = "@prefix ex: <http://example.org/> .
+    ///              @prefix rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#> .
+    ///
+    ///              ex:art rdf:type ex:Person ; ex:name \"Art\" .
+    ///              ex:dan rdf:type ex:Person ; ex:name \"Dan\" ; ex:knows ex:art .";
+    ///
+    /// let sketch = TurtleParser::from_string(input).decode().unwrap().induce_schema().to_graph();
+    /// let target_class = sketch.create_uri_node(&ShaclVocabulary::TargetClass.to_uri());
+    /// let shape = sketch.get_triples_with_predicate(&target_class)[0].subject().clone();
+    ///
+    /// let form = shacl::to_form_model(&sketch, &shape).unwrap();
+    ///
+    /// assert_eq!(form.fields().len(), 2);
+    /// assert!(form.fields().iter().any(|field| field.is_required() && field.max_count() == Some(1)));
+    /// # }
+    /// ```
+    pub fn to_graph(&self) -> Graph {
+        let mut graph = Graph::new(None);
+
+        let uri_node = |uri: Uri| Node::UriNode { uri };
+        let rdf_type = uri_node(RdfSyntaxDataTypes::A.to_uri());
+        let integer = |count: usize| Node::LiteralNode {
+            literal: count.to_string(),
+            data_type: Some(XmlDataTypes::Integer.to_uri()),
+            language: None,
+        };
+
+        // classes and value types of each property over all classes
+        let mut domains: BTreeMap<&Node, Vec<&Node>> = BTreeMap::new();
+        let mut ranges: BTreeMap<&Node, Vec<&ValueType>> = BTreeMap::new();
+
+        for summary in &self.classes {
+            let class = match summary.class {
+                Some(ref class) => class,
+                None => continue,
+            };
+
+            let shape = graph.create_blank_node();
+
+            add_unique(
+                &mut graph,
+                class,
+                &rdf_type,
+                &uri_node(RdfsVocabulary::Class.to_uri()),
+            );
+            add_unique(
+                &mut graph,
+                &shape,
+                &rdf_type,
+                &uri_node(ShaclVocabulary::NodeShape.to_uri()),
+            );
+            add_unique(
+                &mut graph,
+                &shape,
+                &uri_node(ShaclVocabulary::TargetClass.to_uri()),
+                class,
+            );
+
+            for property in &summary.properties {
+                let property_shape = graph.create_blank_node();
+
+                add_unique(
+                    &mut graph,
+                    &shape,
+                    &uri_node(ShaclVocabulary::Property.to_uri()),
+                    &property_shape,
+                );
+                add_unique(
+                    &mut graph,
+                    &property_shape,
+                    &uri_node(ShaclVocabulary::Path.to_uri()),
+                    &property.predicate,
+                );
+
+                if property.min_count > 0 {
+                    add_unique(
+                        &mut graph,
+                        &property_shape,
+                        &uri_node(ShaclVocabulary::MinCount.to_uri()),
+                        &integer(property.min_count),
+                    );
+                }
+
+                add_unique(
+                    &mut graph,
+                    &property_shape,
+                    &uri_node(ShaclVocabulary::MaxCount.to_uri()),
+                    &integer(property.max_count),
+                );
+
+                match property.single_value_type() {
+                    Some(ValueType::Literal(data_type)) => add_unique(
+                        &mut graph,
+                        &property_shape,
+                        &uri_node(ShaclVocabulary::Datatype.to_uri()),
+                        &uri_node(data_type.clone()),
+                    ),
+                    Some(ValueType::Iri) => add_unique(
+                        &mut graph,
+                        &property_shape,
+                        &uri_node(ShaclVocabulary::NodeKind.to_uri()),
+                        &uri_node(ShaclVocabulary::Iri.to_uri()),
+                    ),
+                    Some(ValueType::BlankNode) => add_unique(
+                        &mut graph,
+                        &property_shape,
+                        &uri_node(ShaclVocabulary::NodeKind.to_uri()),
+                        &uri_node(ShaclVocabulary::BlankNode.to_uri()),
+                    ),
+                    _ => {}
+                }
+
+                domains.entry(&property.predicate).or_default().push(class);
+                ranges
+                    .entry(&property.predicate)
+                    .or_default()
+                    .extend(property.value_types.keys());
+            }
+        }
+
+        for (predicate, classes) in domains {
+            add_unique(
+                &mut graph,
+                predicate,
+                &rdf_type,
+                &uri_node(RdfSyntaxDataTypes::Property.to_uri()),
+            );
+
+            if let [class] = classes[..] {
+                add_unique(
+                    &mut graph,
+                    predicate,
+                    &uri_node(RdfsVocabulary::Domain.to_uri()),
+                    class,
+                );
+            }
+
+            let mut value_types = ranges.remove(predicate).unwrap_or_default();
+            value_types.sort();
+            value_types.dedup();
+
+            if let [ValueType::Literal(data_type)] = value_types[..] {
+                add_unique(
+                    &mut graph,
+                    predicate,
+                    &uri_node(RdfsVocabulary::Range.to_uri()),
+                    &uri_node(data_type.clone()),
+                );
+            }
+        }
+
+        graph
+    }
+}
+
+/// Adds the triple unless the graph already contains it.
+fn add_unique(graph: &mut Graph, subject: &Node, predicate: &Node, object: &Node) {
+    let triple = Triple::new(subject, predicate, object);
+
+    if !graph.contains(&triple) {
+        graph.add_triple(&triple);
+    }
+}
+
+/// Infers the classes and the usage of properties by their instances.
+///
+/// Subjects with several classes are instances of each class. Without sample size all
+/// instances are summarized, otherwise at most `sample_size` instances per class that are
+/// spread evenly over the instances in node order.
+pub fn induce_schema(graph: &Graph, sample_size: Option<usize>) -> InducedSchema {
+    let rdf_type = Node::UriNode {
+        uri: RdfSyntaxDataTypes::A.to_uri(),
+    };
+
+    let mut subjects: BTreeMap<&Node, Vec<&Triple>> = BTreeMap::new();
+
+    for triple in graph.triples_iter() {
+        subjects.entry(triple.subject()).or_default().push(triple);
+    }
+
+    let mut instances: BTreeMap<Option<&Node>, Vec<&Node>> = BTreeMap::new();
+
+    for (subject, triples) in &subjects {
+        let classes: Vec<&Node> = triples
+            .iter()
+            .filter(|triple| *triple.predicate() == rdf_type)
+            .map(|triple| triple.object())
+            .filter(|class| ValueType::of(class) == ValueType::Iri)
+            .collect();
+
+        if classes.is_empty() {
+            instances.entry(None).or_default().push(subject);
+        }
+
+        for class in classes {
+            instances.entry(Some(class)).or_default().push(subject);
+        }
+    }
+
+    let mut classes = Vec::new();
+
+    for (class, instances) in instances {
+        let sample: Vec<&Node> = match sample_size {
+            Some(sample_size) if sample_size < instances.len() => (0..sample_size)
+                .map(|i| instances[i * instances.len() / sample_size])
+                .collect(),
+            _ => instances.clone(),
+        };
+
+        let mut properties: BTreeMap<&Node, PropertySummary> = BTreeMap::new();
+
+        for instance in &sample {
+            let mut counts: BTreeMap<&Node, usize> = BTreeMap::new();
+
+            for triple in &subjects[instance] {
+                if *triple.predicate() == rdf_type {
+                    continue;
+                }
+
+                *counts.entry(triple.predicate()).or_insert(0) += 1;
+
+                let property =
+                    properties
+                        .entry(triple.predicate())
+                        .or_insert_with(|| PropertySummary {
+                            predicate: triple.predicate().clone(),
+                            instance_count: 0,
+                            value_count: 0,
+                            min_count: usize::MAX,
+                            max_count: 0,
+                            value_types: BTreeMap::new(),
+                        });
+
+                property.value_count += 1;
+                *property
+                    .value_types
+                    .entry(ValueType::of(triple.object()))
+                    .or_insert(0) += 1;
+            }
+
+            for (predicate, count) in counts {
+                let property = properties.get_mut(predicate).unwrap();
+
+                property.instance_count += 1;
+                property.min_count = property.min_count.min(count);
+                property.max_count = property.max_count.max(count);
+            }
+        }
+
+        for property in properties.values_mut() {
+            // instances without values of the property
+            if property.instance_count < sample.len() {
+                property.min_count = 0;
+            }
+        }
+
+        classes.push(ClassSummary {
+            class: class.cloned(),
+            instance_count: instances.len(),
+            sample_count: sample.len(),
+            properties: properties.into_values().collect(),
+        });
+    }
+
+    InducedSchema { classes }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::Graph;
+    use crate::node::Node;
+    use crate::schema_induction::ValueType;
+    use crate::specs::rdf_syntax_specs::RdfSyntaxDataTypes;
+    use crate::specs::rdfs_specs::RdfsVocabulary;
+    use crate::specs::xml_specs::XmlDataTypes;
+    use crate::triple::Triple;
+    use crate::uri::Uri;
+
+    fn uri(uri: &str) -> Node {
+        Node::UriNode {
+            uri: Uri::new("http://example.org/".to_string() + uri),
+        }
+    }
+
+    fn people() -> Graph {
+        let mut graph = Graph::new(None);
+        let rdf_type = Node::UriNode {
+            uri: RdfSyntaxDataTypes::A.to_uri(),
+        };
+
+        for i in 0..10 {
+            let person = uri(&format!("p{}", i));
+            graph.add_triple(&Triple::new(&person, &rdf_type, &uri("Person")));
+            graph.add_triple(&Triple::new(
+                &person,
+                &uri("age"),
+                &graph.create_literal_node_with_data_type(
+                    i.to_string(),
+                    &XmlDataTypes::Integer.to_uri(),
+                ),
+            ));
+
+            // every other person has two names, the others have none
+            if i % 2 == 0 {
+                for language in &["en", "de"] {
+                    graph.add_triple(&Triple::new(
+                        &person,
+                        &uri("name"),
+                        &graph.create_literal_node_with_language(
+                            "n".to_string(),
+                            language.to_string(),
+                        ),
+                    ));
+                }
+            }
+        }
+
+        graph.add_triple(&Triple::new(&uri("writer"), &rdf_type, &uri("Author")));
+        graph.add_triple(&Triple::new(&uri("writer"), &uri("wrote"), &uri("book")));
+        graph.add_triple(&Triple::new(
+            &uri("writer"),
+            &uri("name"),
+            &graph.create_literal_node("W".to_string()),
+        ));
+        graph.add_triple(&Triple::new(
+            &uri("book"),
+            &uri("title"),
+            &graph.create_literal_node("B".to_string()),
+        ));
+
+        graph
+    }
+
+    #[test]
+    fn induce_classes_properties_and_cardinalities() {
+        let schema = people().induce_schema();
+
+        assert_eq!(schema.classes().len(), 3);
+        assert_eq!(schema.classes()[0].class(), &None);
+        assert_eq!(schema.classes()[0].instance_count(), 1);
+
+        let person = schema.class(&uri("Person")).unwrap();
+        let name = person.property(&uri("name")).unwrap();
+        let age = person.property(&uri("age")).unwrap();
+
+        assert_eq!(person.instance_count(), 10);
+        assert_eq!(person.properties().len(), 2);
+        assert_eq!(
+            (name.instance_count(), name.min_count(), name.max_count()),
+            (5, 0, 2)
+        );
+        assert_eq!(
+            name.single_value_type(),
+            Some(&ValueType::Literal(RdfSyntaxDataTypes::LangString.to_uri()))
+        );
+        assert_eq!(
+            (age.min_count(), age.max_count(), age.value_count()),
+            (1, 1, 10)
+        );
+
+        let sampled = people().induce_schema_from_sample(4);
+        let person = sampled.class(&uri("Person")).unwrap();
+
+        assert_eq!((person.instance_count(), person.sample_count()), (10, 4));
+        assert_eq!(person.property(&uri("age")).unwrap().value_count(), 4);
+    }
+
+    #[test]
+    fn sketch_graph_has_domains_ranges_and_shapes() {
+        let sketch = people().induce_schema().to_graph();
+        let domain = Node::UriNode {
+            uri: RdfsVocabulary::Domain.to_uri(),
+        };
+        let range = Node::UriNode {
+            uri: RdfsVocabulary::Range.to_uri(),
+        };
+
+        // names are used by persons and authors
+        assert!(!sketch.contains_matching(Some(&uri("name")), Some(&domain), None));
+        assert!(!sketch.contains_matching(Some(&uri("name")), Some(&range), None));
+        assert!(sketch.contains(&Triple::new(&uri("age"), &domain, &uri("Person"))));
+        assert!(sketch.contains(&Triple::new(&uri("wrote"), &domain, &uri("Author"))));
+        assert!(sketch.contains(&Triple::new(
+            &uri("age"),
+            &range,
+            &Node::UriNode {
+                uri: XmlDataTypes::Integer.to_uri()
+            }
+        )));
+        assert!(!sketch.contains_matching(Some(&uri("title")), None, None));
+    }
+}
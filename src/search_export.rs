@@ -0,0 +1,166 @@
+use crate::describe::{self, DescribeOptions};
+use crate::graph::Graph;
+use crate::node::Node;
+use crate::specs::json_specs::JsonSpecs;
+use crate::specs::rdf_syntax_specs::RdfSyntaxDataTypes;
+use crate::uri::Uri;
+use std::collections::BTreeSet;
+
+/// Options for exporting the subjects of a graph as Elasticsearch or OpenSearch bulk requests.
+#[derive(Debug, Clone)]
+pub struct BulkExportOptions {
+    index: String,
+    classes: Vec<Uri>,
+    describe_options: DescribeOptions,
+}
+
+impl BulkExportOptions {
+    /// Constructor of `BulkExportOptions` for documents of the index.
+    ///
+    /// By default all subjects with a type are exported and the documents are described with
+    /// the default `DescribeOptions`.
+    pub fn new(index: &str) -> BulkExportOptions {
+        BulkExportOptions {
+            index: index.to_string(),
+            classes: Vec::new(),
+            describe_options: DescribeOptions::new(),
+        }
+    }
+
+    /// Returns the name of the index.
+    pub fn index(&self) -> &str {
+        &self.index
+    }
+
+    /// Sets the name of the index.
+    pub fn set_index(&mut self, index: &str) {
+        self.index = index.to_string();
+    }
+
+    /// Returns the classes whose instances are exported, all typed subjects if empty.
+    pub fn classes(&self) -> &Vec<Uri> {
+        &self.classes
+    }
+
+    /// Adds a class whose instances are exported.
+    pub fn add_class(&mut self, class: &Uri) {
+        self.classes.push(class.clone());
+    }
+
+    /// Returns the options for writing the documents.
+    pub fn describe_options(&self) -> &DescribeOptions {
+        &self.describe_options
+    }
+
+    /// Sets the options for writing the documents, including the mapping of predicates to
+    /// field names.
+    pub fn set_describe_options(&mut self, describe_options: DescribeOptions) {
+        self.describe_options = describe_options;
+    }
+}
+
+/// Writes the instances of the selected classes as NDJSON for the bulk API of Elasticsearch
+/// and OpenSearch.
+///
+/// Each instance is written as an `index` action with the IRI as `_id`, followed by its
+/// description as written by `describe_as_json`. Instances are sorted by IRI, blank nodes are
+/// skipped since they have no stable identifier.
+pub fn export_bulk(graph: &Graph, options: &BulkExportOptions) -> String {
+    let rdf_type = Node::UriNode {
+        uri: RdfSyntaxDataTypes::A.to_uri(),
+    };
+
+    let mut subjects = BTreeSet::new();
+
+    for triple in graph.get_triples_with_predicate(&rdf_type) {
+        let is_selected = options.classes.is_empty()
+            || matches!(*triple.object(), Node::UriNode { ref uri } if options.classes.contains(uri));
+
+        if let Node::UriNode { ref uri } = *triple.subject() {
+            if is_selected {
+                subjects.insert((uri, triple.subject()));
+            }
+        }
+    }
+
+    let mut output_string = String::new();
+
+    for (uri, subject) in subjects {
+        output_string.push_str(&format!(
+            "{{\"index\": {{\"_index\": {}, \"_id\": {}}}}}\n",
+            JsonSpecs::escape_string(&options.index),
+            JsonSpecs::escape_string(uri.to_string())
+        ));
+        output_string.push_str(&describe::describe_as_json(
+            graph,
+            subject,
+            &options.describe_options,
+        ));
+        output_string.push('\n');
+    }
+
+    output_string
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::describe::DescribeOptions;
+    use crate::graph::Graph;
+    use crate::search_export::BulkExportOptions;
+    use crate::specs::rdf_syntax_specs::RdfSyntaxDataTypes;
+    use crate::triple::Triple;
+    use crate::uri::Uri;
+
+    #[test]
+    fn export_instances_of_classes_with_field_mapping() {
+        let mut graph = Graph::new(None);
+        let uri = |uri: &str| Uri::new("http://example.org/".to_string() + uri);
+
+        let rdf_type = graph.create_uri_node(&RdfSyntaxDataTypes::A.to_uri());
+        let book = graph.create_uri_node(&uri("Book"));
+        let person = graph.create_uri_node(&uri("Person"));
+        let title = graph.create_uri_node(&uri("title"));
+        let by = graph.create_uri_node(&uri("by"));
+        let trial = graph.create_uri_node(&uri("trial"));
+        let castle = graph.create_uri_node(&uri("castle"));
+        let kafka = graph.create_uri_node(&uri("kafka"));
+        let anonymous = graph.create_blank_node();
+
+        graph.add_triple(&Triple::new(&trial, &rdf_type, &book));
+        graph.add_triple(&Triple::new(&castle, &rdf_type, &book));
+        graph.add_triple(&Triple::new(&kafka, &rdf_type, &person));
+        graph.add_triple(&Triple::new(&anonymous, &rdf_type, &book));
+        graph.add_triple(&Triple::new(
+            &trial,
+            &title,
+            &graph.create_literal_node("The \"Trial\"".to_string()),
+        ));
+        graph.add_triple(&Triple::new(&trial, &by, &kafka));
+        graph.add_triple(&Triple::new(&castle, &by, &kafka));
+
+        let mut describe_options = DescribeOptions::new();
+        describe_options.set_field_name(&uri("title"), "name");
+        describe_options.set_field_name(&uri("by"), "author");
+        describe_options.set_mapped_fields_only(true);
+
+        let mut options = BulkExportOptions::new("books");
+        options.add_class(&uri("Book"));
+        options.set_describe_options(describe_options);
+
+        assert_eq!(
+            graph.export_bulk(&options),
+            "{\"index\": {\"_index\": \"books\", \"_id\": \"http://example.org/castle\"}}\n\
+             {\"@id\": \"http://example.org/castle\", \"author\": \"http://example.org/kafka\"}\n\
+             {\"index\": {\"_index\": \"books\", \"_id\": \"http://example.org/trial\"}}\n\
+             {\"@id\": \"http://example.org/trial\", \"author\": \"http://example.org/kafka\", \
+               \"name\": \"The \\\"Trial\\\"\"}\n"
+        );
+
+        let output = graph.export_bulk(&BulkExportOptions::new("all"));
+
+        assert_eq!(output.lines().count(), 6);
+        assert!(output.contains(
+            "\"http://www.w3.org/1999/02/22-rdf-syntax-ns#type\": \"http://example.org/Person\""
+        ));
+    }
+}
@@ -0,0 +1,428 @@
+use crate::error::{Error, ErrorType};
+use crate::graph::Graph;
+use crate::node::Node;
+use crate::specs::rdf_syntax_specs::RdfSyntaxDataTypes;
+use crate::specs::rdfs_specs::RdfsVocabulary;
+use crate::specs::shacl_specs::ShaclVocabulary;
+use crate::uri::Uri;
+use crate::Result;
+use std::cmp::Ordering;
+
+/// A field of a form that edits the values of one property.
+#[derive(Debug, PartialEq, Clone)]
+pub struct FormField {
+    path: Node,
+    label: Option<String>,
+    description: Option<String>,
+    data_type: Option<Uri>,
+    class: Option<Node>,
+    min_count: usize,
+    max_count: Option<usize>,
+    options: Vec<Node>,
+    order: Option<f64>,
+}
+
+impl FormField {
+    /// Returns the predicate edited by the field.
+    pub fn path(&self) -> &Node {
+        &self.path
+    }
+
+    /// Returns the `sh:name` of the property shape or the `rdfs:label` of the predicate.
+    pub fn label(&self) -> &Option<String> {
+        &self.label
+    }
+
+    /// Returns the `sh:description` of the property shape.
+    pub fn description(&self) -> &Option<String> {
+        &self.description
+    }
+
+    /// Returns the data type of literal values.
+    pub fn data_type(&self) -> &Option<Uri> {
+        &self.data_type
+    }
+
+    /// Returns the class of resource values.
+    pub fn class(&self) -> &Option<Node> {
+        &self.class
+    }
+
+    /// Returns the minimum number of values.
+    pub fn min_count(&self) -> usize {
+        self.min_count
+    }
+
+    /// Returns the maximum number of values, `None` if unbounded.
+    pub fn max_count(&self) -> Option<usize> {
+        self.max_count
+    }
+
+    /// Returns the allowed values listed with `sh:in`, empty if any value is allowed.
+    pub fn options(&self) -> &[Node] {
+        &self.options
+    }
+
+    /// Checks if at least one value is required.
+    pub fn is_required(&self) -> bool {
+        self.min_count > 0
+    }
+}
+
+/// Descriptor of a form generated from a SHACL node shape.
+#[derive(Debug, PartialEq, Clone)]
+pub struct FormModel {
+    shape: Node,
+    label: Option<String>,
+    target_class: Option<Node>,
+    fields: Vec<FormField>,
+}
+
+impl FormModel {
+    /// Returns the node shape the form was generated from.
+    pub fn shape(&self) -> &Node {
+        &self.shape
+    }
+
+    /// Returns the `rdfs:label` of the node shape.
+    pub fn label(&self) -> &Option<String> {
+        &self.label
+    }
+
+    /// Returns the class of the resources edited with the form.
+    pub fn target_class(&self) -> &Option<Node> {
+        &self.target_class
+    }
+
+    /// Returns the fields ordered by `sh:order`, fields without order come last.
+    pub fn fields(&self) -> &[FormField] {
+        &self.fields
+    }
+}
+
+/// Kinds of constraints that can be violated, named after the SHACL constraint components.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum ConstraintComponent {
+    /// Fewer values than required (`sh:minCount`).
+    MinCount,
+
+    /// More values than allowed (`sh:maxCount`).
+    MaxCount,
+
+    /// A value is not a literal of the data type (`sh:datatype`).
+    Datatype,
+
+    /// A value is not an instance of the class (`sh:class`).
+    Class,
+
+    /// A value is not one of the allowed values (`sh:in`).
+    In,
+}
+
+/// A violation of a constraint by a focus node.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ValidationResult {
+    focus_node: Node,
+    path: Node,
+    value: Option<Node>,
+    component: ConstraintComponent,
+    message: String,
+}
+
+impl ValidationResult {
+    /// Constructor of `ValidationResult`.
+    pub fn new(
+        focus_node: &Node,
+        path: &Node,
+        value: Option<&Node>,
+        component: ConstraintComponent,
+        message: String,
+    ) -> ValidationResult {
+        ValidationResult {
+            focus_node: focus_node.clone(),
+            path: path.clone(),
+            value: value.cloned(),
+            component,
+            message,
+        }
+    }
+
+    /// Returns the node that violates the constraint.
+    pub fn focus_node(&self) -> &Node {
+        &self.focus_node
+    }
+
+    /// Returns the property of the constraint.
+    pub fn path(&self) -> &Node {
+        &self.path
+    }
+
+    /// Returns the value that violates the constraint, if the violation concerns a single value.
+    pub fn value(&self) -> &Option<Node> {
+        &self.value
+    }
+
+    /// Returns the kind of the violated constraint.
+    pub fn component(&self) -> ConstraintComponent {
+        self.component
+    }
+
+    /// Returns a human-readable description of the violation.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+/// Outcome of validating a graph.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct ValidationReport {
+    results: Vec<ValidationResult>,
+}
+
+impl ValidationReport {
+    /// Constructor of `ValidationReport`.
+    pub fn new(results: Vec<ValidationResult>) -> ValidationReport {
+        ValidationReport { results }
+    }
+
+    /// Checks if the graph conforms, i.e. no constraint is violated.
+    pub fn conforms(&self) -> bool {
+        self.results.is_empty()
+    }
+
+    /// Returns the violations.
+    pub fn results(&self) -> &[ValidationResult] {
+        &self.results
+    }
+
+    /// Adds the violations of another report.
+    pub fn merge(&mut self, other: ValidationReport) {
+        self.results.extend(other.results);
+    }
+}
+
+/// Generates the form model of a node shape of the shapes graph.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "turtle")]
+/// # {
+/// use rdf::reader::rdf_parser::RdfParser;
+/// use rdf::reader::turtle_parser::TurtleParser;
+/// use rdf::shacl;
+///
+/// let input = "@prefix sh: <http://www.w3.org/ns/shacl#> .
+///              @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+///              @prefix ex: <http://example.org/> .
+///
+///              ex:PersonShape sh:targetClass ex:Person ;
+///                  sh:property [ sh:path ex:name ; sh:name \"Name\" ; sh:datatype xsd:string ; sh:minCount 1 ; sh:maxCount 1 ; sh:order 1 ] ;
+///                  sh:property [ sh:path ex:knows ; sh:class ex:Person ; sh:order 2 ] .";
+///
+/// let shapes = TurtleParser::from_string(input.to_string()).decode().unwrap();
+/// let shape = shapes.create_uri_node(&rdf::uri::Uri::new("http://example.org/PersonShape".to_string()));
+///
+/// let form = shacl::to_form_model(&shapes, &shape).unwrap();
+///
+/// assert_eq!(form.fields().len(), 2);
+/// assert_eq!(form.fields()[0].label(), &Some("Name".to_string()));
+/// assert!(form.fields()[0].is_required());
+/// assert_eq!(form.fields()[1].max_count(), None);
+/// # }
+/// ```
+///
+/// # Failures
+///
+/// - A property shape has no `sh:path`.
+/// - A count or order is not a number.
+/// - A `sh:in` value is not a well-formed RDF list.
+///
+pub fn to_form_model(shapes: &Graph, shape: &Node) -> Result<FormModel> {
+    let mut fields = Vec::new();
+
+    for property in objects(shapes, shape, &ShaclVocabulary::Property.to_uri()) {
+        fields.push(to_form_field(shapes, property)?);
+    }
+
+    fields.sort_by(|a, b| match (a.order, b.order) {
+        (Some(a_order), Some(b_order)) => a_order
+            .partial_cmp(&b_order)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| a.path.cmp(&b.path)),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => a.path.cmp(&b.path),
+    });
+
+    Ok(FormModel {
+        shape: shape.clone(),
+        label: literal(shapes, shape, &RdfsVocabulary::Label.to_uri()),
+        target_class: objects(shapes, shape, &ShaclVocabulary::TargetClass.to_uri())
+            .into_iter()
+            .next()
+            .cloned(),
+        fields,
+    })
+}
+
+/// Generates the form field of a property shape.
+fn to_form_field(shapes: &Graph, property: &Node) -> Result<FormField> {
+    let path = match objects(shapes, property, &ShaclVocabulary::Path.to_uri()).first() {
+        Some(path) => (*path).clone(),
+        None => {
+            return Err(Error::new(
+                ErrorType::InvalidShape,
+                "Property shape without sh:path.",
+            ))
+        }
+    };
+
+    let label = literal(shapes, property, &ShaclVocabulary::Name.to_uri())
+        .or_else(|| literal(shapes, &path, &RdfsVocabulary::Label.to_uri()));
+
+    let data_type = objects(shapes, property, &ShaclVocabulary::Datatype.to_uri())
+        .into_iter()
+        .filter_map(|object| match *object {
+            Node::UriNode { ref uri } => Some(uri.clone()),
+            _ => None,
+        })
+        .next();
+
+    let options = match objects(shapes, property, &ShaclVocabulary::In.to_uri()).first() {
+        Some(list) => read_list(shapes, list)?,
+        None => Vec::new(),
+    };
+
+    Ok(FormField {
+        label,
+        description: literal(shapes, property, &ShaclVocabulary::Description.to_uri()),
+        data_type,
+        class: objects(shapes, property, &ShaclVocabulary::Class.to_uri())
+            .into_iter()
+            .next()
+            .cloned(),
+        min_count: number(shapes, property, &ShaclVocabulary::MinCount.to_uri())?.unwrap_or(0),
+        max_count: number(shapes, property, &ShaclVocabulary::MaxCount.to_uri())?,
+        options,
+        order: number(shapes, property, &ShaclVocabulary::Order.to_uri())?,
+        path,
+    })
+}
+
+/// Returns the ordered objects of the subject and predicate.
+fn objects<'a>(shapes: &'a Graph, subject: &Node, predicate: &Uri) -> Vec<&'a Node> {
+    let predicate = Node::UriNode {
+        uri: predicate.clone(),
+    };
+
+    let mut objects: Vec<&Node> = shapes
+        .get_triples_with_subject_and_predicate(subject, &predicate)
+        .into_iter()
+        .map(|t| t.object())
+        .collect();
+    objects.sort();
+    objects
+}
+
+/// Returns the first literal object of the subject and predicate.
+fn literal(shapes: &Graph, subject: &Node, predicate: &Uri) -> Option<String> {
+    objects(shapes, subject, predicate)
+        .into_iter()
+        .filter_map(|object| match *object {
+            Node::LiteralNode { ref literal, .. } => Some(literal.to_string()),
+            _ => None,
+        })
+        .next()
+}
+
+/// Parses the first literal object of the subject and predicate as number.
+fn number<T: ::std::str::FromStr>(
+    shapes: &Graph,
+    subject: &Node,
+    predicate: &Uri,
+) -> Result<Option<T>> {
+    match literal(shapes, subject, predicate) {
+        Some(literal) => match literal.parse::<T>() {
+            Ok(number) => Ok(Some(number)),
+            Err(_) => Err(Error::new(
+                ErrorType::InvalidShape,
+                "Invalid number for ".to_string() + predicate.to_string() + ": " + &literal,
+            )),
+        },
+        None => Ok(None),
+    }
+}
+
+/// Returns the members of an RDF list.
+fn read_list(shapes: &Graph, head: &Node) -> Result<Vec<Node>> {
+    let nil = Node::UriNode {
+        uri: RdfSyntaxDataTypes::ListNil.to_uri(),
+    };
+
+    let mut members = Vec::new();
+    let mut current = head;
+
+    while *current != nil {
+        let first = objects(shapes, current, &RdfSyntaxDataTypes::ListFirst.to_uri());
+        let rest = objects(shapes, current, &RdfSyntaxDataTypes::ListRest.to_uri());
+
+        // a list cannot have more members than the graph has triples, otherwise it is cyclic
+        if first.len() != 1 || rest.len() != 1 || members.len() > shapes.count() {
+            return Err(Error::new(ErrorType::InvalidShape, "Invalid RDF list."));
+        }
+
+        members.push(first[0].clone());
+        current = rest[0];
+    }
+
+    Ok(members)
+}
+
+#[cfg(all(test, feature = "turtle"))]
+mod tests {
+    use crate::reader::rdf_parser::RdfParser;
+    use crate::reader::turtle_parser::TurtleParser;
+    use crate::shacl::*;
+
+    #[test]
+    fn form_field_with_options_and_label_of_path() {
+        let input = "@prefix sh: <http://www.w3.org/ns/shacl#> .
+                     @prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+                     @prefix ex: <http://example.org/> .
+
+                     ex:status rdfs:label \"Status\" .
+                     ex:TaskShape rdfs:label \"Task\" ;
+                         sh:property [ sh:path ex:status ; sh:in ( ex:Open ex:Done ) ] .";
+
+        let shapes = TurtleParser::from_string(input.to_string())
+            .decode()
+            .unwrap();
+        let shape = shapes.create_uri_node(&Uri::new("http://example.org/TaskShape".to_string()));
+
+        let form = to_form_model(&shapes, &shape).unwrap();
+        let field = &form.fields()[0];
+
+        assert_eq!(form.label(), &Some("Task".to_string()));
+        assert_eq!(field.label(), &Some("Status".to_string()));
+        assert_eq!(field.options().len(), 2);
+        assert!(!field.is_required());
+    }
+
+    #[test]
+    fn invalid_property_shapes() {
+        let inputs = vec![
+            "<http://example.org/S> <http://www.w3.org/ns/shacl#property> [ <http://www.w3.org/ns/shacl#minCount> 1 ] .",
+            "<http://example.org/S> <http://www.w3.org/ns/shacl#property> [ <http://www.w3.org/ns/shacl#path> <http://example.org/p> ; <http://www.w3.org/ns/shacl#maxCount> \"many\" ] .",
+        ];
+
+        for input in inputs {
+            let shapes = TurtleParser::from_string(input.to_string())
+                .decode()
+                .unwrap();
+            let shape = shapes.create_uri_node(&Uri::new("http://example.org/S".to_string()));
+
+            assert!(to_form_model(&shapes, &shape).is_err());
+        }
+    }
+}
@@ -0,0 +1,689 @@
+use crate::graph::Graph;
+use crate::node::Node;
+use crate::specs::xml_specs::XmlDataTypes;
+use crate::store::GraphStore;
+use crate::temporal::DateTime;
+use crate::triple::Triple;
+use crate::Result;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+/// Solutions of a SPARQL `SELECT` query, one map from variable names to nodes per solution.
+pub type Solutions = Vec<HashMap<String, Node>>;
+
+/// Sort direction of an `ORDER BY` condition.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum OrderDirection {
+    /// Smallest values first, like `ASC(?x)`.
+    Ascending,
+
+    /// Largest values first, like `DESC(?x)`.
+    Descending,
+}
+
+/// Condition of a SPARQL `ORDER BY` clause that sorts solutions by the node bound to a variable.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct OrderCondition {
+    variable: String,
+    direction: OrderDirection,
+}
+
+impl OrderCondition {
+    /// Creates a condition that sorts by the variable in ascending order.
+    pub fn ascending<S: Into<String>>(variable: S) -> OrderCondition {
+        OrderCondition {
+            variable: variable.into(),
+            direction: OrderDirection::Ascending,
+        }
+    }
+
+    /// Creates a condition that sorts by the variable in descending order.
+    pub fn descending<S: Into<String>>(variable: S) -> OrderCondition {
+        OrderCondition {
+            variable: variable.into(),
+            direction: OrderDirection::Descending,
+        }
+    }
+
+    /// Returns the name of the variable without leading `?`.
+    pub fn variable(&self) -> &str {
+        &self.variable
+    }
+
+    /// Returns the sort direction.
+    pub fn direction(&self) -> OrderDirection {
+        self.direction
+    }
+}
+
+/// Compares two possibly unbound nodes in the order SPARQL uses for `ORDER BY`.
+///
+/// Unbound variables come first, followed by blank nodes, IRIs, literals and quoted triples.
+/// Numeric literals are ordered by their value and come before `xsd:dateTime` literals, which
+/// are ordered chronologically, followed by all other literals. Nodes that compare equal by
+/// value, like `"1"^^xsd:integer` and `"01"^^xsd:integer`, are ordered by their lexical form,
+/// so the order is total and the same for every call.
+///
+/// # Examples
+///
+/// ```
+/// use rdf::node::Node;
+/// use rdf::solutions;
+/// use rdf::specs::xml_specs::XmlDataTypes;
+/// use std::cmp::Ordering;
+///
+/// let integer = |value: &str| Node::LiteralNode {
+///     literal: value.to_string(),
+///     data_type: Some(XmlDataTypes::Integer.to_uri()),
+///     language: None,
+/// };
+/// let blank = Node::BlankNode { id: "b".to_string() };
+///
+/// assert_eq!(solutions::compare_terms(Some(&integer("9")), Some(&integer("10"))), Ordering::Less);
+/// assert_eq!(solutions::compare_terms(Some(&blank), Some(&integer("9"))), Ordering::Less);
+/// assert_eq!(solutions::compare_terms(None, Some(&blank)), Ordering::Less);
+/// ```
+pub fn compare_terms(a: Option<&Node>, b: Option<&Node>) -> Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) => {
+            let (a_rank, a_key) = term_key(a);
+            let (b_rank, b_key) = term_key(b);
+
+            a_rank
+                .cmp(&b_rank)
+                .then_with(|| match (a_key, b_key) {
+                    (Some(TermKey::Numeric(a)), Some(TermKey::Numeric(b))) => a.total_cmp(&b),
+                    (Some(TermKey::DateTime(a)), Some(TermKey::DateTime(b))) => a.cmp(&b),
+                    _ => Ordering::Equal,
+                })
+                .then_with(|| a.cmp(b))
+        }
+        (a, b) => a.is_some().cmp(&b.is_some()),
+    }
+}
+
+/// Sorts the solutions by the conditions, the first condition takes precedence.
+///
+/// The sort is stable, so solutions that are equal for all conditions keep their order.
+/// Combined with `slice`, this allows paginating through the solutions of a query.
+///
+/// # Examples
+///
+/// ```
+/// use rdf::node::Node;
+/// use rdf::solutions::{self, OrderCondition};
+/// use std::collections::HashMap;
+///
+/// let solution = |name: &str| {
+///     let mut solution = HashMap::new();
+///     solution.insert("name".to_string(), Node::LiteralNode {
+///         literal: name.to_string(),
+///         data_type: None,
+///         language: None,
+///     });
+///     solution
+/// };
+///
+/// let mut results = vec![solution("Bob"), HashMap::new(), solution("Alice")];
+/// solutions::order_by(&mut results, &[OrderCondition::descending("name")]);
+///
+/// assert_eq!(results[0], solution("Bob"));
+/// assert_eq!(results[1], solution("Alice"));
+/// assert!(results[2].is_empty());
+/// ```
+pub fn order_by(solutions: &mut Solutions, conditions: &[OrderCondition]) {
+    solutions.sort_by(|a, b| {
+        conditions
+            .iter()
+            .map(|condition| {
+                let ordering =
+                    compare_terms(a.get(condition.variable()), b.get(condition.variable()));
+
+                match condition.direction() {
+                    OrderDirection::Ascending => ordering,
+                    OrderDirection::Descending => ordering.reverse(),
+                }
+            })
+            .find(|ordering| *ordering != Ordering::Equal)
+            .unwrap_or(Ordering::Equal)
+    });
+}
+
+/// Removes duplicate solutions like `SELECT DISTINCT`, the first occurrence is kept.
+///
+/// # Examples
+///
+/// ```
+/// use rdf::node::Node;
+/// use rdf::solutions;
+/// use std::collections::HashMap;
+///
+/// let mut solution = HashMap::new();
+/// solution.insert("s".to_string(), Node::BlankNode { id: "a".to_string() });
+///
+/// let results = vec![solution.clone(), HashMap::new(), solution];
+///
+/// assert_eq!(solutions::distinct(results).len(), 2);
+/// ```
+pub fn distinct(solutions: Solutions) -> Solutions {
+    let mut seen = HashSet::new();
+
+    solutions
+        .into_iter()
+        .filter(|solution| seen.insert(solution_key(solution)))
+        .collect()
+}
+
+/// Removes duplicates of consecutive solutions like `SELECT REDUCED`.
+///
+/// `REDUCED` permits but does not require the elimination of duplicates, removing only
+/// consecutive ones avoids keeping all solutions in memory. Applied after `order_by` on all
+/// variables, the result is the same as of `distinct`.
+pub fn reduced(mut solutions: Solutions) -> Solutions {
+    solutions.dedup();
+    solutions
+}
+
+/// Returns the solutions of a page like `OFFSET` and `LIMIT`, `None` does not limit the count.
+///
+/// # Examples
+///
+/// ```
+/// use rdf::solutions;
+/// use std::collections::HashMap;
+///
+/// let results = vec![HashMap::new(); 5];
+///
+/// assert_eq!(solutions::slice(results.clone(), 2, Some(2)).len(), 2);
+/// assert_eq!(solutions::slice(results.clone(), 4, Some(2)).len(), 1);
+/// assert_eq!(solutions::slice(results, 1, None).len(), 4);
+/// ```
+pub fn slice(solutions: Solutions, offset: usize, limit: Option<usize>) -> Solutions {
+    solutions
+        .into_iter()
+        .skip(offset)
+        .take(limit.unwrap_or(usize::MAX))
+        .collect()
+}
+
+/// Node or variable of a triple pattern.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum PatternTerm {
+    /// Matches only the node.
+    Node(Node),
+
+    /// Matches any node and binds it to the variable, like `?x`.
+    Variable(String),
+}
+
+impl PatternTerm {
+    /// Creates a variable term.
+    pub fn variable<S: Into<String>>(name: S) -> PatternTerm {
+        PatternTerm::Variable(name.into())
+    }
+
+    /// Returns the node of the term under the bindings, `None` for unbound variables.
+    fn resolve<'a>(&'a self, bindings: &'a HashMap<String, Node>) -> Option<&'a Node> {
+        match *self {
+            PatternTerm::Node(ref node) => Some(node),
+            PatternTerm::Variable(ref variable) => bindings.get(variable),
+        }
+    }
+
+    /// Binds the variable of the term to the node, fails if it is bound to another node.
+    fn bind(&self, node: &Node, bindings: &mut HashMap<String, Node>) -> bool {
+        match *self {
+            PatternTerm::Node(ref term) => term == node,
+            PatternTerm::Variable(ref variable) => match bindings.get(variable) {
+                Some(bound) => bound == node,
+                None => {
+                    bindings.insert(variable.clone(), node.clone());
+                    true
+                }
+            },
+        }
+    }
+}
+
+/// Triple pattern of a basic graph pattern, like `?s foaf:knows ?o`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct TriplePattern {
+    subject: PatternTerm,
+    predicate: PatternTerm,
+    object: PatternTerm,
+}
+
+impl TriplePattern {
+    /// Constructor of `TriplePattern`.
+    pub fn new(subject: PatternTerm, predicate: PatternTerm, object: PatternTerm) -> TriplePattern {
+        TriplePattern {
+            subject,
+            predicate,
+            object,
+        }
+    }
+
+    /// Returns the subject of the pattern.
+    pub fn subject(&self) -> &PatternTerm {
+        &self.subject
+    }
+
+    /// Returns the predicate of the pattern.
+    pub fn predicate(&self) -> &PatternTerm {
+        &self.predicate
+    }
+
+    /// Returns the object of the pattern.
+    pub fn object(&self) -> &PatternTerm {
+        &self.object
+    }
+
+    /// Returns the bindings extended by the nodes of the triple, if the triple matches.
+    fn bind(
+        &self,
+        triple: &Triple,
+        bindings: &HashMap<String, Node>,
+    ) -> Option<HashMap<String, Node>> {
+        let mut bindings = bindings.clone();
+
+        let matches = self.subject.bind(triple.subject(), &mut bindings)
+            && self.predicate.bind(triple.predicate(), &mut bindings)
+            && self.object.bind(triple.object(), &mut bindings);
+
+        if matches {
+            Some(bindings)
+        } else {
+            None
+        }
+    }
+}
+
+/// Matches of a triple pattern under the bindings of the patterns before it.
+struct Frame {
+    bindings: HashMap<String, Node>,
+    matches: Vec<Triple>,
+    next: usize,
+}
+
+/// Iterator over the solutions of a basic graph pattern, see `select`.
+pub struct SolutionIter<'a, S: GraphStore> {
+    graph: &'a Graph<S>,
+    patterns: Vec<TriplePattern>,
+    frames: Vec<Frame>,
+    started: bool,
+    done: bool,
+}
+
+impl<'a, S: GraphStore> SolutionIter<'a, S> {
+    /// Queries the store for the matches of the pattern at `level` under the bindings.
+    fn frame(&self, level: usize, bindings: HashMap<String, Node>) -> Result<Frame> {
+        let pattern = &self.patterns[level];
+        let matches = self.graph.triples_matching(
+            pattern.subject.resolve(&bindings),
+            pattern.predicate.resolve(&bindings),
+            pattern.object.resolve(&bindings),
+        )?;
+
+        Ok(Frame {
+            bindings,
+            matches,
+            next: 0,
+        })
+    }
+}
+
+impl<'a, S: GraphStore> Iterator for SolutionIter<'a, S> {
+    type Item = Result<HashMap<String, Node>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        if !self.started {
+            self.started = true;
+
+            if self.patterns.is_empty() {
+                self.done = true;
+                return Some(Ok(HashMap::new()));
+            }
+
+            match self.frame(0, HashMap::new()) {
+                Ok(frame) => self.frames.push(frame),
+                Err(err) => {
+                    self.done = true;
+                    return Some(Err(err));
+                }
+            }
+        }
+
+        loop {
+            let level = self.frames.len().checked_sub(1)?;
+            let frame = self.frames.last_mut()?;
+
+            if frame.next == frame.matches.len() {
+                self.frames.pop();
+                continue;
+            }
+
+            frame.next += 1;
+
+            let bindings =
+                match self.patterns[level].bind(&frame.matches[frame.next - 1], &frame.bindings) {
+                    Some(bindings) => bindings,
+                    None => continue,
+                };
+
+            if level + 1 == self.patterns.len() {
+                return Some(Ok(bindings));
+            }
+
+            match self.frame(level + 1, bindings) {
+                Ok(frame) => self.frames.push(frame),
+                Err(err) => {
+                    self.done = true;
+                    return Some(Err(err));
+                }
+            }
+        }
+    }
+}
+
+/// Evaluates a basic graph pattern against the graph and yields its solutions lazily.
+///
+/// The patterns are joined from first to last: for each match of a pattern the next pattern is
+/// queried with the variables bound so far. Only the matches of one pattern per level are held
+/// at a time, so the complete result set is never materialized and a consumer that stops
+/// pulling solutions stops the evaluation. `OFFSET` and `LIMIT` are applied lazily with
+/// `Iterator::skip` and `Iterator::take`, `DISTINCT` with `distinct_iter`. `order_by` needs
+/// all solutions and has to collect them first.
+///
+/// An error of the store ends the iteration after it is returned.
+///
+/// # Examples
+///
+/// ```
+/// use rdf::graph::Graph;
+/// use rdf::solutions::{self, PatternTerm, TriplePattern};
+/// use rdf::triple::Triple;
+/// use rdf::uri::Uri;
+///
+/// let mut graph = Graph::new(None);
+///
+/// let knows = graph.create_uri_node(&Uri::new("http://xmlns.com/foaf/0.1/knows".to_string()));
+/// let a = graph.create_blank_node();
+/// let b = graph.create_blank_node();
+/// let c = graph.create_blank_node();
+///
+/// graph.add_triple(&Triple::new(&a, &knows, &b));
+/// graph.add_triple(&Triple::new(&b, &knows, &c));
+///
+/// // SELECT * WHERE { ?x foaf:knows ?y . ?y foaf:knows ?z }
+/// let patterns = [
+///     TriplePattern::new(PatternTerm::variable("x"), PatternTerm::Node(knows.clone()), PatternTerm::variable("y")),
+///     TriplePattern::new(PatternTerm::variable("y"), PatternTerm::Node(knows), PatternTerm::variable("z")),
+/// ];
+///
+/// let mut results = solutions::select(&graph, &patterns);
+/// let solution = results.next().unwrap().unwrap();
+///
+/// assert_eq!(solution["x"], a);
+/// assert_eq!(solution["z"], c);
+/// assert!(results.next().is_none());
+/// ```
+pub fn select<'a, S: GraphStore>(
+    graph: &'a Graph<S>,
+    patterns: &[TriplePattern],
+) -> SolutionIter<'a, S> {
+    SolutionIter {
+        graph,
+        patterns: patterns.to_vec(),
+        frames: Vec::new(),
+        started: false,
+        done: false,
+    }
+}
+
+/// Removes duplicate solutions like `SELECT DISTINCT` while the solutions are pulled.
+///
+/// Unlike `distinct`, the solutions are not collected, only the distinct solutions seen so far
+/// are kept. Errors are passed through.
+pub fn distinct_iter<I>(solutions: I) -> impl Iterator<Item = Result<HashMap<String, Node>>>
+where
+    I: IntoIterator<Item = Result<HashMap<String, Node>>>,
+{
+    let mut seen = HashSet::new();
+
+    solutions
+        .into_iter()
+        .filter(move |solution| match *solution {
+            Ok(ref solution) => seen.insert(solution_key(solution)),
+            Err(_) => true,
+        })
+}
+
+/// Value of a literal that is compared instead of its lexical form.
+enum TermKey {
+    Numeric(f64),
+    DateTime(DateTime),
+}
+
+/// Returns the rank of a node in the `ORDER BY` order and the value of typed literals.
+fn term_key(node: &Node) -> (u8, Option<TermKey>) {
+    match *node {
+        Node::BlankNode { .. } => (0, None),
+        Node::UriNode { .. } => (1, None),
+        Node::LiteralNode {
+            ref literal,
+            data_type: Some(ref data_type),
+            ..
+        } => match XmlDataTypes::from_uri(data_type) {
+            Some(
+                XmlDataTypes::Integer
+                | XmlDataTypes::Decimal
+                | XmlDataTypes::Double
+                | XmlDataTypes::Long
+                | XmlDataTypes::UnsignedLong
+                | XmlDataTypes::Int,
+            ) => match literal.trim().parse::<f64>() {
+                Ok(value) => (2, Some(TermKey::Numeric(value))),
+                Err(_) => (4, None),
+            },
+            Some(XmlDataTypes::DateTime) => match DateTime::parse(literal) {
+                Ok(value) => (3, Some(TermKey::DateTime(value))),
+                Err(_) => (4, None),
+            },
+            _ => (4, None),
+        },
+        Node::LiteralNode { .. } => (4, None),
+        Node::TripleNode { .. } => (5, None),
+    }
+}
+
+/// Returns the bindings of a solution sorted by variable, so equal solutions have equal keys.
+fn solution_key(solution: &HashMap<String, Node>) -> BTreeMap<String, Node> {
+    solution
+        .iter()
+        .map(|(variable, node)| (variable.clone(), node.clone()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::{Error, ErrorType};
+    use crate::graph::Graph;
+    use crate::node::Node;
+    use crate::solutions::{self, OrderCondition, PatternTerm, TriplePattern};
+    use crate::specs::xml_specs::XmlDataTypes;
+    use crate::store::GraphStore;
+    use crate::triple::{Triple, TripleStore};
+    use crate::uri::Uri;
+    use crate::Result;
+    use std::cell::Cell;
+    use std::collections::HashMap;
+
+    /// Store that counts its pattern queries and fails for queries with the failing node.
+    struct CountingStore {
+        triples: TripleStore,
+        queries: Cell<usize>,
+        failing: Option<Node>,
+    }
+
+    impl GraphStore for CountingStore {
+        fn insert(&mut self, triple: &Triple) -> Result<()> {
+            self.triples.insert(triple)
+        }
+
+        fn remove(&mut self, triple: &Triple) -> Result<()> {
+            self.triples.remove(triple)
+        }
+
+        fn matching(
+            &self,
+            subject: Option<&Node>,
+            predicate: Option<&Node>,
+            object: Option<&Node>,
+        ) -> Result<Vec<Triple>> {
+            self.queries.set(self.queries.get() + 1);
+
+            if self.failing.is_some() && subject == self.failing.as_ref() {
+                return Err(Error::new(
+                    ErrorType::UnsupportedOperation,
+                    "The store cannot be queried.",
+                ));
+            }
+
+            self.triples.matching(subject, predicate, object)
+        }
+
+        fn len(&self) -> Result<usize> {
+            self.triples.len()
+        }
+    }
+
+    fn literal(value: &str, data_type: XmlDataTypes) -> Node {
+        Node::LiteralNode {
+            literal: value.to_string(),
+            data_type: Some(data_type.to_uri()),
+            language: None,
+        }
+    }
+
+    #[test]
+    fn order_solutions_by_term_order_and_value() {
+        let nodes = [
+            Node::LiteralNode {
+                literal: "b".to_string(),
+                data_type: None,
+                language: None,
+            },
+            literal("2020-01-01T00:00:00Z", XmlDataTypes::DateTime),
+            literal("10", XmlDataTypes::Integer),
+            literal("2019-12-31T23:00:00-02:00", XmlDataTypes::DateTime),
+            Node::UriNode {
+                uri: Uri::new("http://example.org/a".to_string()),
+            },
+            literal("9.5", XmlDataTypes::Decimal),
+            Node::BlankNode {
+                id: "b0".to_string(),
+            },
+            literal("1e1", XmlDataTypes::Double),
+        ];
+
+        let mut results: Vec<_> = nodes
+            .iter()
+            .map(|node| {
+                let mut solution = HashMap::new();
+                solution.insert("x".to_string(), node.clone());
+                solution
+            })
+            .collect();
+        results.push(HashMap::new());
+
+        solutions::order_by(&mut results, &[OrderCondition::ascending("x")]);
+
+        let ordered: Vec<_> = results.iter().map(|solution| solution.get("x")).collect();
+        assert_eq!(
+            ordered,
+            vec![
+                None,
+                Some(&nodes[6]),
+                Some(&nodes[4]),
+                Some(&nodes[5]),
+                Some(&nodes[2]),
+                Some(&nodes[7]),
+                Some(&nodes[1]),
+                Some(&nodes[3]),
+                Some(&nodes[0]),
+            ]
+        );
+
+        let page = solutions::slice(results, 2, Some(3));
+        assert_eq!(page[0].get("x"), Some(&nodes[4]));
+        assert_eq!(page.len(), 3);
+    }
+
+    #[test]
+    fn select_yields_solutions_lazily() {
+        let node = |id: &str| Node::BlankNode { id: id.to_string() };
+        let store = CountingStore {
+            triples: TripleStore::new(),
+            queries: Cell::new(0),
+            failing: Some(node("d")),
+        };
+        let mut graph = Graph::with_store(None, store);
+
+        for (subject, object) in [("a", "b"), ("a", "c"), ("b", "b"), ("c", "d"), ("d", "a")] {
+            graph
+                .try_add_triple(&Triple::new(&node(subject), &node("p"), &node(object)))
+                .unwrap();
+        }
+
+        let p = PatternTerm::Node(node("p"));
+
+        // ?x p ?x
+        let loops = [TriplePattern::new(
+            PatternTerm::variable("x"),
+            p.clone(),
+            PatternTerm::variable("x"),
+        )];
+        let results: Vec<_> = solutions::select(&graph, &loops)
+            .collect::<Result<_>>()
+            .unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0]["x"], node("b"));
+
+        // ?x p ?y . ?y p ?z
+        let paths = [
+            TriplePattern::new(
+                PatternTerm::variable("x"),
+                p.clone(),
+                PatternTerm::variable("y"),
+            ),
+            TriplePattern::new(PatternTerm::variable("y"), p, PatternTerm::variable("z")),
+        ];
+
+        graph.store().queries.set(0);
+        let first = solutions::select(&graph, &paths).next().unwrap().unwrap();
+        assert_eq!(first["x"], node("a"));
+        assert_eq!(first["z"], node("b"));
+        assert_eq!(graph.store().queries.get(), 2);
+
+        // the failing query for ?y = d ends the iteration after the solutions before it
+        let results: Vec<_> = solutions::select(&graph, &paths).collect();
+        assert_eq!(results.len(), 4);
+        assert!(results[..3].iter().all(|result| result.is_ok()));
+        assert!(results[3].is_err());
+
+        let ys: Vec<_> =
+            solutions::distinct_iter(solutions::select(&graph, &paths).take(3).map(|result| {
+                result.map(|mut solution| {
+                    solution.retain(|variable, _| variable == "y");
+                    solution
+                })
+            }))
+            .collect::<Result<_>>()
+            .unwrap();
+        assert_eq!(ys.len(), 2);
+    }
+}
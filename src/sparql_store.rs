@@ -0,0 +1,259 @@
+use crate::error::{Error, ErrorType};
+use crate::node::Node;
+use crate::store::GraphStore;
+use crate::triple::Triple;
+use crate::writer::formatter::n_triples_formatter::NTriplesFormatter;
+use crate::writer::formatter::rdf_formatter::RdfFormatter;
+use crate::Result;
+use std::collections::HashMap;
+
+pub use crate::solutions::Solutions;
+
+/// Client of a remote SPARQL endpoint.
+///
+/// Implementations send the query over HTTP (or any other protocol) and decode the result,
+/// so `SparqlStore` does not depend on a particular HTTP client.
+pub trait SparqlEndpoint {
+    /// Evaluates the `SELECT` query and returns its solutions.
+    ///
+    /// # Failures
+    ///
+    /// - The endpoint cannot be reached or rejects the query.
+    ///
+    fn select(&self, query: &str) -> Result<Solutions>;
+}
+
+/// Read-only graph store that translates pattern queries into SPARQL `SELECT` queries
+/// against a remote endpoint.
+///
+/// # Examples
+///
+/// ```
+/// use rdf::Result;
+/// use rdf::graph::Graph;
+/// use rdf::node::Node;
+/// use rdf::sparql_store::{Solutions, SparqlEndpoint, SparqlStore};
+/// use rdf::uri::Uri;
+/// use std::collections::HashMap;
+///
+/// struct Endpoint;
+///
+/// impl SparqlEndpoint for Endpoint {
+///     fn select(&self, query: &str) -> Result<Solutions> {
+///         assert_eq!(query, "SELECT DISTINCT ?o WHERE { <http://www.wikidata.org/entity/Q42> \
+///                            <http://www.w3.org/2000/01/rdf-schema#label> ?o }");
+///
+///         let mut solution = HashMap::new();
+///         solution.insert("o".to_string(), Node::LiteralNode {
+///             literal: "Douglas Adams".to_string(),
+///             data_type: None,
+///             language: Some("en".to_string()),
+///         });
+///
+///         Ok(vec![solution])
+///     }
+/// }
+///
+/// let graph = Graph::with_store(None, SparqlStore::new(Endpoint));
+///
+/// let subject = graph.create_uri_node(&Uri::new("http://www.wikidata.org/entity/Q42".to_string()));
+/// let label = graph.create_uri_node(&Uri::new("http://www.w3.org/2000/01/rdf-schema#label".to_string()));
+///
+/// assert_eq!(graph.triples_matching(Some(&subject), Some(&label), None).unwrap().len(), 1);
+/// ```
+#[derive(Debug)]
+pub struct SparqlStore<E: SparqlEndpoint> {
+    endpoint: E,
+}
+
+impl<E: SparqlEndpoint> SparqlStore<E> {
+    /// Constructor of `SparqlStore`.
+    pub fn new(endpoint: E) -> SparqlStore<E> {
+        SparqlStore { endpoint }
+    }
+
+    /// Returns the endpoint that is queried.
+    pub fn endpoint(&self) -> &E {
+        &self.endpoint
+    }
+
+    /// Returns the `SELECT` query for the pattern, `None` matches any node.
+    ///
+    /// # Failures
+    ///
+    /// - The pattern contains a blank node, which would act as variable in the query.
+    ///
+    pub fn select_query(
+        &self,
+        subject: Option<&Node>,
+        predicate: Option<&Node>,
+        object: Option<&Node>,
+    ) -> Result<String> {
+        let formatter = NTriplesFormatter::new();
+        let mut variables = Vec::new();
+        let mut terms = Vec::new();
+
+        for (node, variable) in [(subject, "?s"), (predicate, "?p"), (object, "?o")].iter() {
+            match *node {
+                Some(&Node::BlankNode { .. }) => {
+                    return Err(Error::new(
+                        ErrorType::UnsupportedOperation,
+                        "Blank nodes of a remote store cannot be matched.",
+                    ))
+                }
+                Some(node) => terms.push(formatter.format_node(node)),
+                None => {
+                    variables.push(*variable);
+                    terms.push(variable.to_string());
+                }
+            }
+        }
+
+        let projection = if variables.is_empty() {
+            "*".to_string()
+        } else {
+            "DISTINCT ".to_string() + &variables.join(" ")
+        };
+
+        Ok(format!(
+            "SELECT {} WHERE {{ {} }}",
+            projection,
+            terms.join(" ")
+        ))
+    }
+}
+
+/// Returns the node of the pattern or the node bound to the variable.
+fn bound_node(
+    pattern: Option<&Node>,
+    solution: &HashMap<String, Node>,
+    variable: &str,
+) -> Result<Node> {
+    match pattern {
+        Some(node) => Ok(node.clone()),
+        None => solution.get(variable).cloned().ok_or_else(|| {
+            Error::new(
+                ErrorType::InvalidQuery,
+                format!("SPARQL endpoint did not bind the variable ?{}.", variable),
+            )
+        }),
+    }
+}
+
+impl<E: SparqlEndpoint> GraphStore for SparqlStore<E> {
+    fn insert(&mut self, _triple: &Triple) -> Result<()> {
+        Err(Error::new(
+            ErrorType::UnsupportedOperation,
+            "Triples cannot be added to a SPARQL endpoint.",
+        ))
+    }
+
+    fn remove(&mut self, _triple: &Triple) -> Result<()> {
+        Err(Error::new(
+            ErrorType::UnsupportedOperation,
+            "Triples cannot be removed from a SPARQL endpoint.",
+        ))
+    }
+
+    fn matching(
+        &self,
+        subject: Option<&Node>,
+        predicate: Option<&Node>,
+        object: Option<&Node>,
+    ) -> Result<Vec<Triple>> {
+        let query = self.select_query(subject, predicate, object)?;
+
+        self.endpoint
+            .select(&query)?
+            .iter()
+            .map(|solution| {
+                Ok(Triple::new(
+                    &bound_node(subject, solution, "s")?,
+                    &bound_node(predicate, solution, "p")?,
+                    &bound_node(object, solution, "o")?,
+                ))
+            })
+            .collect()
+    }
+
+    fn len(&self) -> Result<usize> {
+        let solutions = self
+            .endpoint
+            .select("SELECT (COUNT(*) AS ?count) WHERE { ?s ?p ?o }")?;
+
+        match solutions.first().and_then(|solution| solution.get("count")) {
+            Some(Node::LiteralNode { literal, .. }) => literal.parse().map_err(|_| {
+                Error::new(
+                    ErrorType::InvalidQuery,
+                    format!("SPARQL endpoint returned the invalid count {}.", literal),
+                )
+            }),
+            _ => Err(Error::new(
+                ErrorType::InvalidQuery,
+                "SPARQL endpoint did not return a count.",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::node::Node;
+    use crate::sparql_store::*;
+    use crate::uri::Uri;
+    use std::cell::RefCell;
+
+    struct Endpoint {
+        queries: RefCell<Vec<String>>,
+    }
+
+    impl SparqlEndpoint for Endpoint {
+        fn select(&self, query: &str) -> Result<Solutions> {
+            self.queries.borrow_mut().push(query.to_string());
+
+            let mut solution = HashMap::new();
+            solution.insert(
+                "count".to_string(),
+                Node::LiteralNode {
+                    literal: "42".to_string(),
+                    data_type: None,
+                    language: None,
+                },
+            );
+
+            Ok(vec![solution])
+        }
+    }
+
+    #[test]
+    fn queries_are_built_from_patterns() {
+        let mut store = SparqlStore::new(Endpoint {
+            queries: RefCell::new(Vec::new()),
+        });
+
+        let uri = Node::UriNode {
+            uri: Uri::new("http://example.org/a".to_string()),
+        };
+        let blank = Node::BlankNode {
+            id: "b".to_string(),
+        };
+
+        assert_eq!(store.len().unwrap(), 42);
+        assert!(store.matching(Some(&uri), None, None).is_err());
+        assert!(store.matching(Some(&blank), None, None).is_err());
+        assert!(store.insert(&Triple::new(&uri, &uri, &uri)).is_err());
+        assert_eq!(
+            store.matching(Some(&uri), Some(&uri), Some(&uri)).unwrap(),
+            vec![Triple::new(&uri, &uri, &uri)]
+        );
+
+        assert_eq!(
+            *store.endpoint().queries.borrow(),
+            vec![
+                "SELECT (COUNT(*) AS ?count) WHERE { ?s ?p ?o }",
+                "SELECT DISTINCT ?p ?o WHERE { <http://example.org/a> ?p ?o }",
+                "SELECT * WHERE { <http://example.org/a> <http://example.org/a> <http://example.org/a> }",
+            ]
+        );
+    }
+}
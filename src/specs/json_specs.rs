@@ -0,0 +1,32 @@
+/// Contains specifications for writing JSON.
+pub struct JsonSpecs {}
+
+impl JsonSpecs {
+    /// Returns the value as quoted JSON string with escaped special characters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::specs::json_specs::JsonSpecs;
+    ///
+    /// assert_eq!(JsonSpecs::escape_string("a \"b\"\n"), "\"a \\\"b\\\"\\n\"".to_string());
+    /// ```
+    pub fn escape_string(value: &str) -> String {
+        let mut output_string = "\"".to_string();
+
+        for c in value.chars() {
+            match c {
+                '"' => output_string.push_str("\\\""),
+                '\\' => output_string.push_str("\\\\"),
+                '\n' => output_string.push_str("\\n"),
+                '\r' => output_string.push_str("\\r"),
+                '\t' => output_string.push_str("\\t"),
+                c if (c as u32) < 0x20 => output_string.push_str(&format!("\\u{:04x}", c as u32)),
+                c => output_string.push(c),
+            }
+        }
+
+        output_string.push('"');
+        output_string
+    }
+}
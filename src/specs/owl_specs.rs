@@ -0,0 +1,38 @@
+use crate::uri::Uri;
+use std::fmt;
+
+/// OWL vocabulary.
+pub enum OwlVocabulary {
+    Ontology,
+    Imports,
+    SameAs,
+    InverseOf,
+    EquivalentClass,
+    EquivalentProperty,
+    SymmetricProperty,
+    TransitiveProperty,
+}
+
+impl OwlVocabulary {
+    /// Returns a specific term as URI.
+    pub fn to_uri(&self) -> Uri {
+        Uri::new(self.to_string())
+    }
+}
+
+impl fmt::Display for OwlVocabulary {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let schema_name = "http://www.w3.org/2002/07/owl#";
+
+        match *self {
+            OwlVocabulary::Ontology => write!(f, "{}Ontology", schema_name),
+            OwlVocabulary::Imports => write!(f, "{}imports", schema_name),
+            OwlVocabulary::SameAs => write!(f, "{}sameAs", schema_name),
+            OwlVocabulary::InverseOf => write!(f, "{}inverseOf", schema_name),
+            OwlVocabulary::EquivalentClass => write!(f, "{}equivalentClass", schema_name),
+            OwlVocabulary::EquivalentProperty => write!(f, "{}equivalentProperty", schema_name),
+            OwlVocabulary::SymmetricProperty => write!(f, "{}SymmetricProperty", schema_name),
+            OwlVocabulary::TransitiveProperty => write!(f, "{}TransitiveProperty", schema_name),
+        }
+    }
+}
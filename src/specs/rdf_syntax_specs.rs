@@ -1,4 +1,7 @@
-use uri::Uri;
+use crate::error::{Error, ErrorType};
+use crate::uri::Uri;
+use crate::Result;
+use std::str::Chars;
 
 /// RDF Schema data types and vocabulary.
 pub enum RdfSyntaxDataTypes {
@@ -6,6 +9,10 @@ pub enum RdfSyntaxDataTypes {
     ListFirst,
     ListRest,
     ListNil,
+    LangString,
+    XmlLiteral,
+    Html,
+    Property,
 }
 
 impl RdfSyntaxDataTypes {
@@ -23,6 +30,10 @@ impl RdfSyntaxDataTypes {
             RdfSyntaxDataTypes::ListFirst => schema_name + "first",
             RdfSyntaxDataTypes::ListRest => schema_name + "rest",
             RdfSyntaxDataTypes::ListNil => schema_name + "nil",
+            RdfSyntaxDataTypes::LangString => schema_name + "langString",
+            RdfSyntaxDataTypes::XmlLiteral => schema_name + "XMLLiteral",
+            RdfSyntaxDataTypes::Html => schema_name + "HTML",
+            RdfSyntaxDataTypes::Property => schema_name + "Property",
         }
     }
 }
@@ -31,21 +42,214 @@ impl RdfSyntaxDataTypes {
 pub struct RdfSyntaxSpecs {}
 
 impl RdfSyntaxSpecs {
-    /// Contains characters that need to be escaped when written.
-    fn characters_to_be_escaped() -> Vec<char> {
-        vec!['\'', '"', '\\']
-    }
-
-    /// Replaces all characters with their escaped counterparts.
+    /// Replaces all characters that cannot occur in a quoted literal with their escape sequences.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::specs::rdf_syntax_specs::RdfSyntaxSpecs;
+    ///
+    /// assert_eq!(RdfSyntaxSpecs::escape_literal("say \"hi\"\n"), "say \\\"hi\\\"\\n");
+    /// ```
     pub fn escape_literal(literal: &str) -> String {
-        let escaped_literal = literal.to_string();
+        let mut escaped_literal = String::with_capacity(literal.len());
 
-        for c in RdfSyntaxSpecs::characters_to_be_escaped() {
-            let mut escaped_char = "\\".to_string();
-            escaped_char.push(c);
-            escaped_literal.replace(c, &escaped_char);
+        for c in literal.chars() {
+            match c {
+                '"' => escaped_literal.push_str("\\\""),
+                '\\' => escaped_literal.push_str("\\\\"),
+                '\n' => escaped_literal.push_str("\\n"),
+                '\r' => escaped_literal.push_str("\\r"),
+                '\t' => escaped_literal.push_str("\\t"),
+                '\u{8}' => escaped_literal.push_str("\\b"),
+                '\u{c}' => escaped_literal.push_str("\\f"),
+                c => escaped_literal.push(c),
+            }
         }
 
         escaped_literal
     }
+
+    /// Decodes the escape sequences (`\n`, `\"`, `\u00E9`, ...) of a literal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::specs::rdf_syntax_specs::RdfSyntaxSpecs;
+    ///
+    /// assert_eq!(RdfSyntaxSpecs::unescape_literal("caf\\u00E9\\t\\\"\\U0001F600\\\"").unwrap(),
+    ///            "café\t\"😀\"");
+    /// assert!(RdfSyntaxSpecs::unescape_literal("\\x").is_err());
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - Unknown escape sequence or invalid code point.
+    ///
+    pub fn unescape_literal(literal: &str) -> Result<String> {
+        RdfSyntaxSpecs::unescape(literal, true)
+    }
+
+    /// Decodes the numeric escape sequences (`\u00E9` and `\U0001F600`) of an IRI.
+    ///
+    /// # Failures
+    ///
+    /// - Other escape sequences or invalid code point.
+    ///
+    pub fn unescape_iri(iri: &str) -> Result<String> {
+        RdfSyntaxSpecs::unescape(iri, false)
+    }
+
+    /// Checks if an IRI contains only characters that are allowed in IRI references.
+    ///
+    /// Spaces, control characters and `<>"{}|^`\` are not allowed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::specs::rdf_syntax_specs::RdfSyntaxSpecs;
+    ///
+    /// assert!(RdfSyntaxSpecs::is_iri("http://example.org/a%20b"));
+    /// assert!(!RdfSyntaxSpecs::is_iri("http://example.org/a b"));
+    /// ```
+    pub fn is_iri(iri: &str) -> bool {
+        !iri.chars()
+            .any(|c| c <= ' ' || matches!(c, '<' | '>' | '"' | '{' | '}' | '|' | '^' | '`' | '\\'))
+    }
+
+    /// Checks if a language tag is well-formed according to BCP 47.
+    ///
+    /// Only the structure of the subtags is checked, not if they are registered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::specs::rdf_syntax_specs::RdfSyntaxSpecs;
+    ///
+    /// assert!(RdfSyntaxSpecs::is_language_tag("en"));
+    /// assert!(RdfSyntaxSpecs::is_language_tag("zh-Hant-TW"));
+    /// assert!(RdfSyntaxSpecs::is_language_tag("de-CH-1996-x-private"));
+    /// assert!(!RdfSyntaxSpecs::is_language_tag("languages"));
+    /// assert!(!RdfSyntaxSpecs::is_language_tag("en_US"));
+    /// ```
+    pub fn is_language_tag(tag: &str) -> bool {
+        let subtags: Vec<&str> = tag.split('-').collect();
+
+        if subtags
+            .iter()
+            .any(|s| s.is_empty() || s.len() > 8 || !s.chars().all(|c| c.is_ascii_alphanumeric()))
+        {
+            return false;
+        }
+
+        let alpha = |s: &str| s.chars().all(|c| c.is_ascii_alphabetic());
+        let digits = |s: &str| s.chars().all(|c| c.is_ascii_digit());
+        let private_use = |rest: &[&str]| rest.len() > 1 && rest[0].eq_ignore_ascii_case("x");
+
+        if private_use(&subtags) {
+            return true;
+        }
+
+        // primary language with up to three extended language subtags
+        let language = subtags[0];
+        if language.len() < 2 || !alpha(language) {
+            return false;
+        }
+
+        let mut rest = &subtags[1..];
+        if language.len() <= 3 {
+            for _ in 0..3 {
+                match rest.first() {
+                    Some(s) if s.len() == 3 && alpha(s) => rest = &rest[1..],
+                    _ => break,
+                }
+            }
+        }
+
+        // script
+        if rest.first().is_some_and(|s| s.len() == 4 && alpha(s)) {
+            rest = &rest[1..];
+        }
+
+        // region
+        if rest
+            .first()
+            .is_some_and(|s| (s.len() == 2 && alpha(s)) || (s.len() == 3 && digits(s)))
+        {
+            rest = &rest[1..];
+        }
+
+        // variants
+        while rest.first().is_some_and(|s| {
+            s.len() >= 5 || (s.len() == 4 && s.starts_with(|c: char| c.is_ascii_digit()))
+        }) {
+            rest = &rest[1..];
+        }
+
+        // extensions start with a singleton other than 'x' followed by at least one subtag
+        while rest
+            .first()
+            .is_some_and(|s| s.len() == 1 && !s.eq_ignore_ascii_case("x"))
+        {
+            let count = rest[1..].iter().take_while(|s| s.len() >= 2).count();
+            if count == 0 {
+                return false;
+            }
+            rest = &rest[count + 1..];
+        }
+
+        rest.is_empty() || private_use(rest)
+    }
+
+    /// Decodes numeric escape sequences and, if allowed, character escape sequences.
+    fn unescape(input: &str, character_escapes: bool) -> Result<String> {
+        if !input.contains('\\') {
+            return Ok(input.to_string());
+        }
+
+        let mut output = String::with_capacity(input.len());
+        let mut chars = input.chars();
+
+        while let Some(c) = chars.next() {
+            if c != '\\' {
+                output.push(c);
+                continue;
+            }
+
+            let decoded = match chars.next() {
+                Some('u') => RdfSyntaxSpecs::unescape_code_point(&mut chars, 4),
+                Some('U') => RdfSyntaxSpecs::unescape_code_point(&mut chars, 8),
+                Some('t') if character_escapes => Some('\t'),
+                Some('b') if character_escapes => Some('\u{8}'),
+                Some('n') if character_escapes => Some('\n'),
+                Some('r') if character_escapes => Some('\r'),
+                Some('f') if character_escapes => Some('\u{c}'),
+                Some(c @ ('"' | '\'' | '\\')) if character_escapes => Some(c),
+                _ => None,
+            };
+
+            match decoded {
+                Some(c) => output.push(c),
+                None => {
+                    return Err(Error::new(
+                        ErrorType::InvalidReaderInput,
+                        "Invalid escape sequence in: ".to_string() + input,
+                    ))
+                }
+            }
+        }
+
+        Ok(output)
+    }
+
+    /// Reads the hexadecimal digits of a numeric escape sequence and returns the character.
+    fn unescape_code_point(chars: &mut Chars, digits: usize) -> Option<char> {
+        let hex: String = chars.take(digits).collect();
+
+        if hex.len() != digits || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+
+        u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32)
+    }
 }
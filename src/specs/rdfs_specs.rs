@@ -0,0 +1,36 @@
+use crate::uri::Uri;
+use std::fmt;
+
+/// RDF Schema vocabulary.
+pub enum RdfsVocabulary {
+    Label,
+    Comment,
+    Class,
+    Domain,
+    Range,
+    SubClassOf,
+    SubPropertyOf,
+}
+
+impl RdfsVocabulary {
+    /// Returns a specific term as URI.
+    pub fn to_uri(&self) -> Uri {
+        Uri::new(self.to_string())
+    }
+}
+
+impl fmt::Display for RdfsVocabulary {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let schema_name = "http://www.w3.org/2000/01/rdf-schema#";
+
+        match *self {
+            RdfsVocabulary::Label => write!(f, "{}label", schema_name),
+            RdfsVocabulary::Comment => write!(f, "{}comment", schema_name),
+            RdfsVocabulary::Class => write!(f, "{}Class", schema_name),
+            RdfsVocabulary::Domain => write!(f, "{}domain", schema_name),
+            RdfsVocabulary::Range => write!(f, "{}range", schema_name),
+            RdfsVocabulary::SubClassOf => write!(f, "{}subClassOf", schema_name),
+            RdfsVocabulary::SubPropertyOf => write!(f, "{}subPropertyOf", schema_name),
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use crate::uri::Uri;
+use std::fmt;
+
+/// Schema.org vocabulary.
+pub enum SchemaVocabulary {
+    ValidFrom,
+    ValidThrough,
+}
+
+impl SchemaVocabulary {
+    /// Returns a specific term as URI.
+    pub fn to_uri(&self) -> Uri {
+        Uri::new(self.to_string())
+    }
+}
+
+impl fmt::Display for SchemaVocabulary {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let schema_name = "http://schema.org/";
+
+        match *self {
+            SchemaVocabulary::ValidFrom => write!(f, "{}validFrom", schema_name),
+            SchemaVocabulary::ValidThrough => write!(f, "{}validThrough", schema_name),
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use crate::uri::Uri;
+use std::fmt;
+
+/// SHACL vocabulary.
+pub enum ShaclVocabulary {
+    NodeShape,
+    TargetClass,
+    Property,
+    Path,
+    Datatype,
+    Class,
+    MinCount,
+    MaxCount,
+    Name,
+    Description,
+    Order,
+    In,
+    NodeKind,
+    Iri,
+    BlankNode,
+}
+
+impl ShaclVocabulary {
+    /// Returns a specific term as URI.
+    pub fn to_uri(&self) -> Uri {
+        Uri::new(self.to_string())
+    }
+}
+
+impl fmt::Display for ShaclVocabulary {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let schema_name = "http://www.w3.org/ns/shacl#";
+
+        match *self {
+            ShaclVocabulary::NodeShape => write!(f, "{}NodeShape", schema_name),
+            ShaclVocabulary::TargetClass => write!(f, "{}targetClass", schema_name),
+            ShaclVocabulary::Property => write!(f, "{}property", schema_name),
+            ShaclVocabulary::Path => write!(f, "{}path", schema_name),
+            ShaclVocabulary::Datatype => write!(f, "{}datatype", schema_name),
+            ShaclVocabulary::Class => write!(f, "{}class", schema_name),
+            ShaclVocabulary::MinCount => write!(f, "{}minCount", schema_name),
+            ShaclVocabulary::MaxCount => write!(f, "{}maxCount", schema_name),
+            ShaclVocabulary::Name => write!(f, "{}name", schema_name),
+            ShaclVocabulary::Description => write!(f, "{}description", schema_name),
+            ShaclVocabulary::Order => write!(f, "{}order", schema_name),
+            ShaclVocabulary::In => write!(f, "{}in", schema_name),
+            ShaclVocabulary::NodeKind => write!(f, "{}nodeKind", schema_name),
+            ShaclVocabulary::Iri => write!(f, "{}IRI", schema_name),
+            ShaclVocabulary::BlankNode => write!(f, "{}BlankNode", schema_name),
+        }
+    }
+}
@@ -1,5 +1,5 @@
-use specs::xml_specs::XmlDataTypes;
-use uri::Uri;
+use crate::specs::xml_specs::XmlDataTypes;
+use crate::uri::Uri;
 
 /// Contains specifications for validating turtle syntax.
 pub struct TurtleSpecs {}
@@ -94,4 +94,190 @@ impl TurtleSpecs {
             Err(_) => false,
         }
     }
+
+    /// Checks if a literal of the data type can be written without quotes and data type.
+    ///
+    /// This is the case for `xsd:integer`, `xsd:decimal` and `xsd:double` literals that match
+    /// the corresponding numeric productions of the Turtle grammar.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::specs::turtle_specs::TurtleSpecs;
+    /// use rdf::specs::xml_specs::XmlDataTypes;
+    ///
+    /// assert!(TurtleSpecs::is_numeric_shorthand("-42", &Some(XmlDataTypes::Integer.to_uri())));
+    /// assert!(TurtleSpecs::is_numeric_shorthand(".5", &Some(XmlDataTypes::Decimal.to_uri())));
+    /// assert!(TurtleSpecs::is_numeric_shorthand("1.5E2", &Some(XmlDataTypes::Double.to_uri())));
+    /// assert_eq!(TurtleSpecs::is_numeric_shorthand("42", &Some(XmlDataTypes::Decimal.to_uri())), false);
+    /// assert_eq!(TurtleSpecs::is_numeric_shorthand("INF", &Some(XmlDataTypes::Double.to_uri())), false);
+    /// ```
+    pub fn is_numeric_shorthand(literal: &str, data_type: &Option<Uri>) -> bool {
+        let number = strip_sign(literal);
+
+        let data_type = match *data_type {
+            Some(ref data_type) => data_type,
+            None => return false,
+        };
+
+        if *data_type == XmlDataTypes::Integer.to_uri() {
+            is_digits(number)
+        } else if *data_type == XmlDataTypes::Decimal.to_uri() {
+            match number.find('.') {
+                Some(point) => {
+                    is_optional_digits(&number[..point]) && is_digits(&number[point + 1..])
+                }
+                None => false,
+            }
+        } else if *data_type == XmlDataTypes::Double.to_uri() {
+            let (mantissa, exponent) = match number.find(['e', 'E']) {
+                Some(e) => (&number[..e], &number[e + 1..]),
+                None => return false,
+            };
+
+            let is_mantissa = match mantissa.find('.') {
+                Some(point) => {
+                    let (integer, fraction) = (&mantissa[..point], &mantissa[point + 1..]);
+                    is_optional_digits(integer)
+                        && is_optional_digits(fraction)
+                        && !(integer.is_empty() && fraction.is_empty())
+                }
+                None => is_digits(mantissa),
+            };
+
+            is_mantissa && is_digits(strip_sign(exponent))
+        } else {
+            false
+        }
+    }
+
+    /// Checks if the character can be escaped with '\' in the local part of a prefixed name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::specs::turtle_specs::TurtleSpecs;
+    ///
+    /// assert!(TurtleSpecs::is_local_name_escape('/'));
+    /// assert_eq!(TurtleSpecs::is_local_name_escape('a'), false);
+    /// ```
+    pub fn is_local_name_escape(c: char) -> bool {
+        "_~.-!$&'()*+,;=/?#@%".contains(c)
+    }
+
+    /// Checks if a literal is easier to read as long string `"""..."""`.
+    ///
+    /// This is the case if it contains line breaks or more than one `"`, which would have to
+    /// be escaped in a short string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::specs::turtle_specs::TurtleSpecs;
+    ///
+    /// assert!(TurtleSpecs::is_long_string_candidate("first\nsecond"));
+    /// assert!(TurtleSpecs::is_long_string_candidate("say \"hi\""));
+    /// assert_eq!(TurtleSpecs::is_long_string_candidate("5\" tall"), false);
+    /// ```
+    pub fn is_long_string_candidate(literal: &str) -> bool {
+        literal.contains('\n') || literal.matches('"').count() > 1
+    }
+
+    /// Escapes a literal that is written as long string `"""..."""`.
+    ///
+    /// Line breaks, tabs and single `"` are written as they are. Only a `"` that would end the
+    /// string, as third `"` in a row or as last character, is escaped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::specs::turtle_specs::TurtleSpecs;
+    ///
+    /// assert_eq!(TurtleSpecs::escape_long_literal("a \"b\"\nc"), "a \"b\"\nc");
+    /// assert_eq!(TurtleSpecs::escape_long_literal("\"\"\"\\"), "\"\"\\\"\\\\");
+    /// assert_eq!(TurtleSpecs::escape_long_literal("say \"hi\""), "say \"hi\\\"");
+    /// ```
+    pub fn escape_long_literal(literal: &str) -> String {
+        let mut escaped = String::with_capacity(literal.len());
+        let mut quotes = 0;
+        let mut chars = literal.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '"' if quotes == 2 || chars.peek().is_none() => {
+                    escaped.push_str("\\\"");
+                    quotes = 0;
+                    continue;
+                }
+                '"' => {
+                    escaped.push('"');
+                    quotes += 1;
+                    continue;
+                }
+                '\\' => escaped.push_str("\\\\"),
+                '\r' => escaped.push_str("\\r"),
+                '\u{8}' => escaped.push_str("\\b"),
+                '\u{c}' => escaped.push_str("\\f"),
+                c => escaped.push(c),
+            }
+
+            quotes = 0;
+        }
+
+        escaped
+    }
+
+    /// Escapes the local part of a prefixed name, e.g. `a/b` is written as `a\/b`.
+    ///
+    /// Returns `None` if the local part contains characters that cannot be written in a
+    /// prefixed name, like whitespace.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::specs::turtle_specs::TurtleSpecs;
+    ///
+    /// assert_eq!(TurtleSpecs::escape_local_name("v1.2"), Some("v1.2".to_string()));
+    /// assert_eq!(TurtleSpecs::escape_local_name("a/b."), Some("a\\/b\\.".to_string()));
+    /// assert_eq!(TurtleSpecs::escape_local_name("a b"), None);
+    /// ```
+    pub fn escape_local_name(local_name: &str) -> Option<String> {
+        let mut escaped = String::with_capacity(local_name.len());
+        let last = local_name.chars().count().saturating_sub(1);
+
+        for (i, c) in local_name.chars().enumerate() {
+            // '-' and '.' can be written unescaped within the name, '.' not at its end
+            let unescaped = c.is_alphanumeric()
+                || c == '_'
+                || c == ':'
+                || (c == '-' && i > 0)
+                || (c == '.' && i > 0 && i < last);
+
+            if unescaped {
+                escaped.push(c);
+            } else if TurtleSpecs::is_local_name_escape(c) {
+                escaped.push('\\');
+                escaped.push(c);
+            } else {
+                return None;
+            }
+        }
+
+        Some(escaped)
+    }
+}
+
+/// Removes a leading '+' or '-' sign.
+fn strip_sign(number: &str) -> &str {
+    number.strip_prefix(['+', '-']).unwrap_or(number)
+}
+
+/// Checks if the string consists of at least one ASCII digit.
+fn is_digits(digits: &str) -> bool {
+    !digits.is_empty() && is_optional_digits(digits)
+}
+
+/// Checks if the string only consists of ASCII digits, it may be empty.
+fn is_optional_digits(digits: &str) -> bool {
+    digits.chars().all(|c| c.is_ascii_digit())
 }
@@ -0,0 +1,36 @@
+use crate::uri::Uri;
+use std::fmt;
+
+/// VoID vocabulary for describing datasets.
+pub enum VoidVocabulary {
+    Dataset,
+    Subset,
+    Triples,
+    Properties,
+    Classes,
+    DistinctSubjects,
+    DistinctObjects,
+}
+
+impl VoidVocabulary {
+    /// Returns a specific term as URI.
+    pub fn to_uri(&self) -> Uri {
+        Uri::new(self.to_string())
+    }
+}
+
+impl fmt::Display for VoidVocabulary {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let schema_name = "http://rdfs.org/ns/void#";
+
+        match *self {
+            VoidVocabulary::Dataset => write!(f, "{}Dataset", schema_name),
+            VoidVocabulary::Subset => write!(f, "{}subset", schema_name),
+            VoidVocabulary::Triples => write!(f, "{}triples", schema_name),
+            VoidVocabulary::Properties => write!(f, "{}properties", schema_name),
+            VoidVocabulary::Classes => write!(f, "{}classes", schema_name),
+            VoidVocabulary::DistinctSubjects => write!(f, "{}distinctSubjects", schema_name),
+            VoidVocabulary::DistinctObjects => write!(f, "{}distinctObjects", schema_name),
+        }
+    }
+}
@@ -1,16 +1,20 @@
-use uri::Uri;
+use crate::uri::Uri;
 
 /// XML schema data types.
 pub enum XmlDataTypes {
     String,
+    NormalizedString,
     Decimal,
     Double,
     Boolean,
     Date,
+    DateTime,
     Long,
     UnsignedLong,
     Int,
     Integer,
+    HexBinary,
+    Base64Binary,
 }
 
 impl XmlDataTypes {
@@ -29,10 +33,354 @@ impl XmlDataTypes {
             XmlDataTypes::Decimal => schema_name + "decimal",
             XmlDataTypes::Double => schema_name + "double",
             XmlDataTypes::Date => schema_name + "date",
+            XmlDataTypes::DateTime => schema_name + "dateTime",
             XmlDataTypes::Long => schema_name + "long",
             XmlDataTypes::UnsignedLong => schema_name + "unsignedLong",
             XmlDataTypes::Int => schema_name + "int",
             XmlDataTypes::String => schema_name + "string",
+            XmlDataTypes::NormalizedString => schema_name + "normalizedString",
+            XmlDataTypes::HexBinary => schema_name + "hexBinary",
+            XmlDataTypes::Base64Binary => schema_name + "base64Binary",
         }
     }
+
+    /// Returns the data type that is identified by the URI.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::specs::xml_specs::XmlDataTypes;
+    /// use rdf::uri::Uri;
+    ///
+    /// let uri = Uri::new("http://www.w3.org/2001/XMLSchema#integer".to_string());
+    ///
+    /// assert_eq!(XmlDataTypes::from_uri(&uri).unwrap().to_uri(), uri);
+    /// assert!(XmlDataTypes::from_uri(&Uri::new("http://example.org/".to_string())).is_none());
+    /// ```
+    pub fn from_uri(uri: &Uri) -> Option<XmlDataTypes> {
+        let data_types = vec![
+            XmlDataTypes::String,
+            XmlDataTypes::NormalizedString,
+            XmlDataTypes::Decimal,
+            XmlDataTypes::Double,
+            XmlDataTypes::Boolean,
+            XmlDataTypes::Date,
+            XmlDataTypes::DateTime,
+            XmlDataTypes::Long,
+            XmlDataTypes::UnsignedLong,
+            XmlDataTypes::Int,
+            XmlDataTypes::Integer,
+            XmlDataTypes::HexBinary,
+            XmlDataTypes::Base64Binary,
+        ];
+
+        data_types
+            .into_iter()
+            .find(|data_type| data_type.to_string() == *uri.to_string())
+    }
+
+    /// Returns the whiteSpace facet of this data type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::specs::xml_specs::{WhiteSpace, XmlDataTypes};
+    ///
+    /// assert_eq!(XmlDataTypes::String.white_space(), WhiteSpace::Preserve);
+    /// assert_eq!(XmlDataTypes::NormalizedString.white_space(), WhiteSpace::Replace);
+    /// assert_eq!(XmlDataTypes::Integer.white_space(), WhiteSpace::Collapse);
+    /// ```
+    pub fn white_space(&self) -> WhiteSpace {
+        match *self {
+            XmlDataTypes::String => WhiteSpace::Preserve,
+            XmlDataTypes::NormalizedString => WhiteSpace::Replace,
+            _ => WhiteSpace::Collapse,
+        }
+    }
+
+    /// Returns the canonical lexical form of a literal of this data type.
+    ///
+    /// The whiteSpace facet of the data type is applied first, so surrounding line breaks of
+    /// values that are copied from XML do not matter. Canonical forms are supported for
+    /// numeric and boolean data types and `xsd:normalizedString`. Decimals always contain a
+    /// decimal point and doubles are written in scientific notation, e.g. `1.5E2`. Returns
+    /// `None` for other data types or if the literal is not valid for the data type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::specs::xml_specs::XmlDataTypes;
+    ///
+    /// assert_eq!(XmlDataTypes::Integer.canonical_form("+0042"), Some("42".to_string()));
+    /// assert_eq!(XmlDataTypes::Decimal.canonical_form("010.500"), Some("10.5".to_string()));
+    /// assert_eq!(XmlDataTypes::Double.canonical_form("150"), Some("1.5E2".to_string()));
+    /// assert_eq!(XmlDataTypes::Boolean.canonical_form("1"), Some("true".to_string()));
+    /// assert_eq!(XmlDataTypes::Integer.canonical_form("4.2"), None);
+    /// assert_eq!(XmlDataTypes::Integer.canonical_form("\n  42\n"), Some("42".to_string()));
+    /// assert_eq!(XmlDataTypes::NormalizedString.canonical_form("a\tb\n"), Some("a b ".to_string()));
+    /// ```
+    pub fn canonical_form(&self, literal: &str) -> Option<String> {
+        let literal = self.white_space().apply(literal);
+
+        match *self {
+            XmlDataTypes::Integer
+            | XmlDataTypes::Long
+            | XmlDataTypes::Int
+            | XmlDataTypes::UnsignedLong => canonical_integer(&literal),
+            XmlDataTypes::Decimal => canonical_decimal(&literal),
+            XmlDataTypes::Double => canonical_double(&literal),
+            XmlDataTypes::Boolean => match literal.as_str() {
+                "true" | "1" => Some("true".to_string()),
+                "false" | "0" => Some("false".to_string()),
+                _ => None,
+            },
+            XmlDataTypes::NormalizedString => Some(literal),
+            XmlDataTypes::HexBinary | XmlDataTypes::Base64Binary => {
+                self.encode_binary(&self.decode_binary(&literal)?)
+            }
+            XmlDataTypes::String | XmlDataTypes::Date | XmlDataTypes::DateTime => None,
+        }
+    }
+
+    /// Decodes the bytes of an `xsd:hexBinary` or `xsd:base64Binary` literal.
+    ///
+    /// Returns `None` for other data types or if the literal is not valid for the data type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::specs::xml_specs::XmlDataTypes;
+    ///
+    /// assert_eq!(XmlDataTypes::HexBinary.decode_binary("0FB7"), Some(vec![0x0f, 0xb7]));
+    /// assert_eq!(XmlDataTypes::Base64Binary.decode_binary("D7c="), Some(vec![0x0f, 0xb7]));
+    /// assert_eq!(XmlDataTypes::HexBinary.decode_binary("0FB"), None);
+    /// ```
+    pub fn decode_binary(&self, literal: &str) -> Option<Vec<u8>> {
+        let literal = self.white_space().apply(literal);
+
+        match *self {
+            XmlDataTypes::HexBinary => decode_hex(&literal),
+            XmlDataTypes::Base64Binary => decode_base64(&literal),
+            _ => None,
+        }
+    }
+
+    /// Encodes bytes in the canonical form of `xsd:hexBinary` or `xsd:base64Binary`.
+    ///
+    /// Returns `None` for other data types.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::specs::xml_specs::XmlDataTypes;
+    ///
+    /// assert_eq!(XmlDataTypes::HexBinary.encode_binary(&[0x0f, 0xb7]), Some("0FB7".to_string()));
+    /// assert_eq!(XmlDataTypes::Base64Binary.encode_binary(&[0x0f, 0xb7]), Some("D7c=".to_string()));
+    /// ```
+    pub fn encode_binary(&self, bytes: &[u8]) -> Option<String> {
+        match *self {
+            XmlDataTypes::HexBinary => Some(bytes.iter().map(|b| format!("{:02X}", b)).collect()),
+            XmlDataTypes::Base64Binary => Some(encode_base64(bytes)),
+            _ => None,
+        }
+    }
+}
+
+/// The whiteSpace facet of XML schema that normalizes the lexical form of a literal.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum WhiteSpace {
+    /// The literal is kept as it is.
+    Preserve,
+
+    /// Tabs, line feeds and carriage returns are replaced by spaces.
+    Replace,
+
+    /// Like `Replace`, then sequences of spaces are collapsed into a single space and leading
+    /// and trailing spaces are removed.
+    Collapse,
+}
+
+impl WhiteSpace {
+    /// Returns the literal with the facet applied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::specs::xml_specs::WhiteSpace;
+    ///
+    /// assert_eq!(WhiteSpace::Preserve.apply(" a\n b "), " a\n b ");
+    /// assert_eq!(WhiteSpace::Replace.apply(" a\n b "), " a  b ");
+    /// assert_eq!(WhiteSpace::Collapse.apply(" a\n b "), "a b");
+    /// ```
+    pub fn apply(&self, literal: &str) -> String {
+        match *self {
+            WhiteSpace::Preserve => literal.to_string(),
+            WhiteSpace::Replace => literal.replace(['\t', '\n', '\r'], " "),
+            WhiteSpace::Collapse => literal
+                .split([' ', '\t', '\n', '\r'])
+                .filter(|part| !part.is_empty())
+                .collect::<Vec<&str>>()
+                .join(" "),
+        }
+    }
+}
+
+/// Splits the optional sign from a numeric literal.
+fn split_sign(literal: &str) -> (&str, &str) {
+    if literal.starts_with('-') || literal.starts_with('+') {
+        literal.split_at(1)
+    } else {
+        ("", literal)
+    }
+}
+
+/// Checks if the string only consists of ASCII digits.
+fn is_digits(digits: &str) -> bool {
+    digits.chars().all(|c| c.is_ascii_digit())
+}
+
+/// Returns the canonical form of an integer without leading zeros and '+' sign.
+fn canonical_integer(literal: &str) -> Option<String> {
+    let (sign, digits) = split_sign(literal);
+
+    if digits.is_empty() || !is_digits(digits) {
+        return None;
+    }
+
+    let digits = digits.trim_start_matches('0');
+
+    if digits.is_empty() {
+        Some("0".to_string())
+    } else if sign == "-" {
+        Some("-".to_string() + digits)
+    } else {
+        Some(digits.to_string())
+    }
+}
+
+/// Returns the canonical form of a decimal without redundant zeros and '+' sign.
+fn canonical_decimal(literal: &str) -> Option<String> {
+    let (sign, number) = split_sign(literal);
+
+    let (integer, fraction) = match number.find('.') {
+        Some(point) => (&number[..point], &number[point + 1..]),
+        None => (number, ""),
+    };
+
+    if (integer.is_empty() && fraction.is_empty()) || !is_digits(integer) || !is_digits(fraction) {
+        return None;
+    }
+
+    let integer = integer.trim_start_matches('0');
+    let fraction = fraction.trim_end_matches('0');
+
+    let integer = if integer.is_empty() { "0" } else { integer };
+    let fraction = if fraction.is_empty() { "0" } else { fraction };
+
+    if sign == "-" && (integer != "0" || fraction != "0") {
+        Some(format!("-{}.{}", integer, fraction))
+    } else {
+        Some(format!("{}.{}", integer, fraction))
+    }
+}
+
+/// Decodes pairs of hexadecimal digits.
+fn decode_hex(literal: &str) -> Option<Vec<u8>> {
+    if !literal.len().is_multiple_of(2) || !literal.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    (0..literal.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&literal[i..i + 2], 16).ok())
+        .collect()
+}
+
+/// Alphabet of base64 encoded data.
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Encodes bytes as base64 with padding.
+fn encode_base64(bytes: &[u8]) -> String {
+    let mut output = String::with_capacity(bytes.len().div_ceil(3) * 4);
+
+    for chunk in bytes.chunks(3) {
+        let group = chunk.iter().enumerate().fold(0u32, |group, (i, &byte)| {
+            group | (byte as u32) << (16 - 8 * i)
+        });
+
+        // a chunk of n bytes is encoded with n + 1 symbols and padded to four symbols
+        for i in 0..4 {
+            if i <= chunk.len() {
+                output.push(BASE64_ALPHABET[(group >> (18 - 6 * i)) as usize & 63] as char);
+            } else {
+                output.push('=');
+            }
+        }
+    }
+
+    output
+}
+
+/// Decodes base64 with padding, single spaces between the symbols are allowed.
+fn decode_base64(literal: &str) -> Option<Vec<u8>> {
+    let symbols: Vec<u8> = literal.bytes().filter(|&b| b != b' ').collect();
+
+    if !symbols.len().is_multiple_of(4) {
+        return None;
+    }
+
+    let groups = symbols.len() / 4;
+    let mut bytes = Vec::with_capacity(groups * 3);
+
+    for (index, chunk) in symbols.chunks(4).enumerate() {
+        // only the last group can be padded
+        let padding = chunk.iter().rev().take_while(|&&b| b == b'=').count();
+
+        if padding > 2 || (padding > 0 && index + 1 < groups) {
+            return None;
+        }
+
+        let mut group = 0u32;
+
+        for (i, symbol) in chunk[..4 - padding].iter().enumerate() {
+            let value = BASE64_ALPHABET.iter().position(|a| a == symbol)? as u32;
+            group |= value << (18 - 6 * i);
+        }
+
+        bytes.extend_from_slice(&group.to_be_bytes()[1..4 - padding]);
+    }
+
+    Some(bytes)
+}
+
+/// Returns the canonical form of a double in scientific notation.
+fn canonical_double(literal: &str) -> Option<String> {
+    match literal {
+        "INF" | "+INF" => return Some("INF".to_string()),
+        "-INF" => return Some("-INF".to_string()),
+        "NaN" => return Some("NaN".to_string()),
+        _ => {}
+    }
+
+    // exclude values like 'inf' that are accepted by Rust but not by XML schema
+    if !literal
+        .chars()
+        .all(|c| c.is_ascii_digit() || c == '.' || c == 'e' || c == 'E' || c == '+' || c == '-')
+    {
+        return None;
+    }
+
+    let value = literal.parse::<f64>().ok()?;
+    let scientific = format!("{:E}", value);
+
+    // the mantissa of the canonical form always contains a decimal point
+    match scientific.find('E') {
+        Some(exponent) if !scientific[..exponent].contains('.') => Some(format!(
+            "{}.0{}",
+            &scientific[..exponent],
+            &scientific[exponent..]
+        )),
+        _ => Some(scientific),
+    }
 }
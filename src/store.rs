@@ -0,0 +1,564 @@
+#[cfg(feature = "store")]
+use crate::error::{Error, ErrorType};
+use crate::node::Node;
+use crate::triple::{Triple, TripleStore};
+use crate::Result;
+#[cfg(feature = "store")]
+use std::cell::RefCell;
+#[cfg(feature = "store")]
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::slice::Iter;
+
+/// Storage backend of a graph.
+///
+/// The in-memory `TripleStore` is the default backend of `Graph`. Other backends, for example
+/// persistent or remote stores, implement this trait to be used with `Graph::with_store`.
+///
+/// A store holds a set of triples: inserting a triple that is already stored has no effect
+/// and `len` counts each distinct triple once, like `matching(None, None, None)`.
+///
+/// # Examples
+///
+/// ```
+/// use rdf::graph::Graph;
+/// use rdf::store::GraphStore;
+/// use rdf::triple::{Triple, TripleStore};
+///
+/// let mut graph = Graph::with_store(None, TripleStore::new());
+///
+/// let subject = graph.create_blank_node();
+/// let object = graph.create_blank_node();
+///
+/// graph.store_mut().insert(&Triple::new(&subject, &subject, &object)).unwrap();
+///
+/// assert_eq!(graph.store().len().unwrap(), 1);
+/// assert_eq!(graph.triples_matching(None, None, Some(&object)).unwrap().len(), 1);
+/// ```
+pub trait GraphStore {
+    /// Adds the triple to the store unless it is already stored.
+    ///
+    /// # Failures
+    ///
+    /// - The store is read-only or cannot be modified.
+    ///
+    fn insert(&mut self, triple: &Triple) -> Result<()>;
+
+    /// Deletes the triple from the store.
+    ///
+    /// # Failures
+    ///
+    /// - The store is read-only or cannot be modified.
+    ///
+    fn remove(&mut self, triple: &Triple) -> Result<()>;
+
+    /// Returns the distinct triples that match the pattern, `None` matches any node.
+    ///
+    /// # Failures
+    ///
+    /// - The store cannot be queried.
+    ///
+    fn matching(
+        &self,
+        subject: Option<&Node>,
+        predicate: Option<&Node>,
+        object: Option<&Node>,
+    ) -> Result<Vec<Triple>>;
+
+    /// Returns the number of distinct triples in the store.
+    ///
+    /// # Failures
+    ///
+    /// - The store cannot be queried.
+    ///
+    fn len(&self) -> Result<usize>;
+
+    /// Checks if the store does not contain any triples.
+    ///
+    /// # Failures
+    ///
+    /// - The store cannot be queried.
+    ///
+    fn is_empty(&self) -> Result<bool> {
+        Ok(self.len()? == 0)
+    }
+}
+
+impl GraphStore for TripleStore {
+    fn insert(&mut self, triple: &Triple) -> Result<()> {
+        self.add_triple(triple);
+        Ok(())
+    }
+
+    fn remove(&mut self, triple: &Triple) -> Result<()> {
+        self.remove_triple(triple);
+        Ok(())
+    }
+
+    fn matching(
+        &self,
+        subject: Option<&Node>,
+        predicate: Option<&Node>,
+        object: Option<&Node>,
+    ) -> Result<Vec<Triple>> {
+        Ok(self.triples_matching(subject, predicate, object))
+    }
+
+    fn len(&self) -> Result<usize> {
+        Ok(self.count())
+    }
+}
+
+/// Store that keeps its triples in memory, so that a graph can lend references to them.
+///
+/// Graphs with such a store additionally provide `Graph::triples_iter` and the
+/// `Graph::get_triples_with_*` lookups, other stores are queried with `Graph::triples_matching`.
+pub trait InMemoryStore: GraphStore {
+    /// Returns an iterator over the stored triples in the order of the store.
+    fn iter(&self) -> Iter<'_, Triple>;
+
+    /// Returns the stored triples that match the pattern in the order of the store, `None`
+    /// matches any node.
+    fn lookup(
+        &self,
+        subject: Option<&Node>,
+        predicate: Option<&Node>,
+        object: Option<&Node>,
+    ) -> Vec<&Triple>;
+}
+
+impl InMemoryStore for TripleStore {
+    fn iter(&self) -> Iter<'_, Triple> {
+        TripleStore::iter(self)
+    }
+
+    fn lookup(
+        &self,
+        subject: Option<&Node>,
+        predicate: Option<&Node>,
+        object: Option<&Node>,
+    ) -> Vec<&Triple> {
+        TripleStore::lookup(self, subject, predicate, object)
+    }
+}
+
+/// Store that combines the triples of several member stores, for example local files and
+/// remote endpoints.
+///
+/// Pattern queries are answered by all members and the distinct results are merged. New
+/// triples are added to the first member, removed triples are removed from all members
+/// that contain them. Members that do not support a node of the pattern, like SPARQL
+/// endpoints with blank nodes, are treated as if they had no matching triples.
+///
+/// # Examples
+///
+/// ```
+/// use rdf::graph::Graph;
+/// use rdf::node::Node;
+/// use rdf::store::{GraphStore, UnionGraph};
+/// use rdf::triple::{Triple, TripleStore};
+///
+/// let node = |id: &str| Node::BlankNode { id: id.to_string() };
+///
+/// let mut local = TripleStore::new();
+/// local.add_triple(&Triple::new(&node("a"), &node("p"), &node("b")));
+///
+/// let mut other = TripleStore::new();
+/// other.add_triple(&Triple::new(&node("a"), &node("p"), &node("b")));
+/// other.add_triple(&Triple::new(&node("b"), &node("p"), &node("c")));
+///
+/// let graph = Graph::with_store(None, UnionGraph::new(vec![Box::new(local), Box::new(other)]));
+///
+/// assert_eq!(graph.triples_matching(None, Some(&node("p")), None).unwrap().len(), 2);
+/// assert_eq!(graph.store().len().unwrap(), 2);
+/// ```
+#[cfg(feature = "store")]
+pub struct UnionGraph {
+    members: Vec<Box<dyn GraphStore>>,
+}
+
+#[cfg(feature = "store")]
+impl UnionGraph {
+    /// Constructor of `UnionGraph`.
+    pub fn new(members: Vec<Box<dyn GraphStore>>) -> UnionGraph {
+        UnionGraph { members }
+    }
+
+    /// Returns the member stores.
+    pub fn members(&self) -> &[Box<dyn GraphStore>] {
+        &self.members
+    }
+
+    /// Adds a member store.
+    pub fn add_member(&mut self, member: Box<dyn GraphStore>) {
+        self.members.push(member);
+    }
+
+    /// Returns the matching triples of a member, or no triples if the member does not support
+    /// the pattern.
+    fn member_matching(
+        member: &dyn GraphStore,
+        subject: Option<&Node>,
+        predicate: Option<&Node>,
+        object: Option<&Node>,
+    ) -> Result<Vec<Triple>> {
+        match member.matching(subject, predicate, object) {
+            Err(ref err) if matches!(err.error_type(), ErrorType::UnsupportedOperation) => {
+                Ok(Vec::new())
+            }
+            result => result,
+        }
+    }
+}
+
+#[cfg(feature = "store")]
+impl GraphStore for UnionGraph {
+    fn insert(&mut self, triple: &Triple) -> Result<()> {
+        match self.members.first_mut() {
+            Some(member) => member.insert(triple),
+            None => Err(Error::new(
+                ErrorType::UnsupportedOperation,
+                "Triples cannot be added to a union without members.",
+            )),
+        }
+    }
+
+    fn remove(&mut self, triple: &Triple) -> Result<()> {
+        let (s, p, o) = (triple.subject(), triple.predicate(), triple.object());
+
+        for member in &mut self.members {
+            let matching = UnionGraph::member_matching(member.as_ref(), Some(s), Some(p), Some(o))?;
+
+            if !matching.is_empty() {
+                member.remove(triple)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn matching(
+        &self,
+        subject: Option<&Node>,
+        predicate: Option<&Node>,
+        object: Option<&Node>,
+    ) -> Result<Vec<Triple>> {
+        let mut seen = HashSet::new();
+        let mut triples = Vec::new();
+
+        for member in &self.members {
+            let matching =
+                UnionGraph::member_matching(member.as_ref(), subject, predicate, object)?;
+
+            for triple in matching {
+                if seen.insert(triple.clone()) {
+                    triples.push(triple);
+                }
+            }
+        }
+
+        Ok(triples)
+    }
+
+    /// Returns the number of distinct triples of all members.
+    fn len(&self) -> Result<usize> {
+        Ok(self.matching(None, None, None)?.len())
+    }
+}
+
+/// Pattern of a query, `None` matches any node.
+#[cfg(feature = "store")]
+type Pattern = (Option<Node>, Option<Node>, Option<Node>);
+
+/// Results of the recent pattern queries, the least recently used pattern comes first.
+#[cfg(feature = "store")]
+#[derive(Debug, Default)]
+struct QueryCache {
+    results: HashMap<Pattern, Vec<Triple>>,
+    recent: VecDeque<Pattern>,
+    len: Option<usize>,
+}
+
+/// Store that memoizes the results of the recent pattern queries of another store.
+///
+/// Up to `capacity` patterns are cached, the least recently used pattern is dropped first.
+/// The cache is cleared whenever triples are added or removed through this store.
+///
+/// # Examples
+///
+/// ```
+/// use rdf::graph::Graph;
+/// use rdf::node::Node;
+/// use rdf::store::{CachedStore, GraphStore};
+/// use rdf::triple::{Triple, TripleStore};
+///
+/// let node = |id: &str| Node::BlankNode { id: id.to_string() };
+///
+/// let mut graph = Graph::with_store(None, CachedStore::new(TripleStore::new(), 100));
+///
+/// graph.store_mut().insert(&Triple::new(&node("a"), &node("label"), &node("b"))).unwrap();
+///
+/// assert_eq!(graph.triples_matching(Some(&node("a")), None, None).unwrap().len(), 1);
+/// assert_eq!(graph.store().cached_patterns(), 1);
+///
+/// graph.store_mut().insert(&Triple::new(&node("a"), &node("label"), &node("c"))).unwrap();
+///
+/// assert_eq!(graph.store().cached_patterns(), 0);
+/// assert_eq!(graph.triples_matching(Some(&node("a")), None, None).unwrap().len(), 2);
+/// ```
+#[cfg(feature = "store")]
+#[derive(Debug)]
+pub struct CachedStore<S: GraphStore> {
+    store: S,
+    capacity: usize,
+    cache: RefCell<QueryCache>,
+}
+
+#[cfg(feature = "store")]
+impl<S: GraphStore> CachedStore<S> {
+    /// Constructor of `CachedStore` that caches up to `capacity` patterns.
+    pub fn new(store: S, capacity: usize) -> CachedStore<S> {
+        CachedStore {
+            store,
+            capacity,
+            cache: RefCell::new(QueryCache::default()),
+        }
+    }
+
+    /// Returns the underlying store.
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    /// Returns the underlying store and discards the cache.
+    pub fn into_store(self) -> S {
+        self.store
+    }
+
+    /// Returns the number of patterns whose results are cached.
+    pub fn cached_patterns(&self) -> usize {
+        self.cache.borrow().results.len()
+    }
+
+    /// Discards all cached results, e.g. after the underlying store was modified elsewhere.
+    pub fn invalidate(&self) {
+        *self.cache.borrow_mut() = QueryCache::default();
+    }
+}
+
+#[cfg(feature = "store")]
+impl<S: GraphStore> GraphStore for CachedStore<S> {
+    fn insert(&mut self, triple: &Triple) -> Result<()> {
+        self.invalidate();
+        self.store.insert(triple)
+    }
+
+    fn remove(&mut self, triple: &Triple) -> Result<()> {
+        self.invalidate();
+        self.store.remove(triple)
+    }
+
+    fn matching(
+        &self,
+        subject: Option<&Node>,
+        predicate: Option<&Node>,
+        object: Option<&Node>,
+    ) -> Result<Vec<Triple>> {
+        let pattern = (subject.cloned(), predicate.cloned(), object.cloned());
+        let mut cache = self.cache.borrow_mut();
+
+        if let Some(triples) = cache.results.get(&pattern).cloned() {
+            if let Some(i) = cache.recent.iter().position(|p| *p == pattern) {
+                cache.recent.remove(i);
+            }
+
+            cache.recent.push_back(pattern);
+            return Ok(triples);
+        }
+
+        let triples = self.store.matching(subject, predicate, object)?;
+
+        if self.capacity > 0 {
+            if cache.results.len() >= self.capacity {
+                if let Some(oldest) = cache.recent.pop_front() {
+                    cache.results.remove(&oldest);
+                }
+            }
+
+            cache.results.insert(pattern.clone(), triples.clone());
+            cache.recent.push_back(pattern);
+        }
+
+        Ok(triples)
+    }
+
+    fn len(&self) -> Result<usize> {
+        if let Some(len) = self.cache.borrow().len {
+            return Ok(len);
+        }
+
+        let len = self.store.len()?;
+        self.cache.borrow_mut().len = Some(len);
+        Ok(len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "store")]
+    use crate::error::{Error, ErrorType};
+    use crate::node::Node;
+    use crate::store::GraphStore;
+    #[cfg(feature = "store")]
+    use crate::store::{CachedStore, UnionGraph};
+    use crate::triple::{Triple, TripleStore};
+    #[cfg(feature = "store")]
+    use crate::Result;
+
+    #[test]
+    fn triple_store_matches_patterns() {
+        let mut store = TripleStore::new();
+
+        let node = |id: &str| Node::BlankNode { id: id.to_string() };
+        let (a, b, c) = (node("a"), node("b"), node("c"));
+
+        store.insert(&Triple::new(&a, &b, &c)).unwrap();
+        store.insert(&Triple::new(&a, &b, &c)).unwrap();
+        store.insert(&Triple::new(&b, &b, &c)).unwrap();
+        store.insert(&Triple::new(&a, &c, &a)).unwrap();
+
+        assert_eq!(store.len().unwrap(), 3);
+        assert_eq!(store.matching(None, None, None).unwrap().len(), 3);
+        assert_eq!(store.matching(Some(&a), None, None).unwrap().len(), 2);
+        assert_eq!(store.matching(None, Some(&b), Some(&c)).unwrap().len(), 2);
+        assert_eq!(
+            store.matching(Some(&a), None, Some(&a)).unwrap(),
+            vec![Triple::new(&a, &c, &a)]
+        );
+        assert!(store.matching(Some(&c), Some(&b), None).unwrap().is_empty());
+
+        store.remove(&Triple::new(&a, &b, &c)).unwrap();
+
+        assert_eq!(store.len().unwrap(), 2);
+        assert!(!GraphStore::is_empty(&store).unwrap());
+    }
+
+    #[test]
+    #[cfg(feature = "store")]
+    fn union_adds_to_first_and_removes_from_all_members() {
+        let node = |id: &str| Node::BlankNode { id: id.to_string() };
+        let triple = Triple::new(&node("a"), &node("p"), &node("b"));
+
+        let mut first = TripleStore::new();
+        first.add_triple(&triple);
+        let mut second = first.clone();
+        second.add_triple(&Triple::new(&node("b"), &node("p"), &node("c")));
+
+        let mut union = UnionGraph::new(vec![Box::new(first), Box::new(second)]);
+
+        assert_eq!(union.len().unwrap(), 2);
+
+        union.remove(&triple).unwrap();
+        assert_eq!(union.len().unwrap(), 1);
+
+        union.insert(&triple).unwrap();
+        assert_eq!(union.members()[0].len().unwrap(), 1);
+        assert_eq!(union.members()[1].len().unwrap(), 1);
+
+        assert!(UnionGraph::new(Vec::new()).insert(&triple).is_err());
+    }
+
+    /// Store that rejects blank nodes, like a SPARQL endpoint.
+    #[cfg(feature = "store")]
+    struct UriStore(TripleStore);
+
+    #[cfg(feature = "store")]
+    impl GraphStore for UriStore {
+        fn insert(&mut self, triple: &Triple) -> Result<()> {
+            self.0.insert(triple)
+        }
+
+        fn remove(&mut self, triple: &Triple) -> Result<()> {
+            self.0.remove(triple)
+        }
+
+        fn matching(
+            &self,
+            subject: Option<&Node>,
+            predicate: Option<&Node>,
+            object: Option<&Node>,
+        ) -> Result<Vec<Triple>> {
+            let is_blank = |node: Option<&Node>| matches!(node, Some(Node::BlankNode { .. }));
+
+            if is_blank(subject) || is_blank(predicate) || is_blank(object) {
+                return Err(Error::new(
+                    ErrorType::UnsupportedOperation,
+                    "Blank nodes are not supported.",
+                ));
+            }
+
+            self.0.matching(subject, predicate, object)
+        }
+
+        fn len(&self) -> Result<usize> {
+            self.0.len()
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "store")]
+    fn union_skips_members_that_do_not_support_a_node() {
+        let blank = Node::BlankNode {
+            id: "a".to_string(),
+        };
+        let uri = Node::UriNode {
+            uri: crate::uri::Uri::new("http://example.org/p".to_string()),
+        };
+        let triple = Triple::new(&blank, &uri, &uri);
+
+        let mut local = TripleStore::new();
+        local.add_triple(&triple);
+        local.add_triple(&triple);
+
+        let mut union = UnionGraph::new(vec![Box::new(local.clone())]);
+        assert_eq!(union.len().unwrap(), 1);
+
+        union.add_member(Box::new(UriStore(TripleStore::new())));
+        assert_eq!(union.len().unwrap(), 1);
+        assert_eq!(union.matching(Some(&blank), None, None).unwrap().len(), 1);
+
+        union.remove(&triple).unwrap();
+        assert_eq!(union.len().unwrap(), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "store")]
+    fn cache_drops_least_recently_used_patterns() {
+        let node = |id: &str| Node::BlankNode { id: id.to_string() };
+        let (a, b, c) = (node("a"), node("b"), node("c"));
+
+        let mut store = CachedStore::new(TripleStore::new(), 2);
+        store.insert(&Triple::new(&a, &b, &c)).unwrap();
+
+        assert_eq!(store.matching(Some(&a), None, None).unwrap().len(), 1);
+        assert_eq!(store.matching(Some(&b), None, None).unwrap().len(), 0);
+        assert_eq!(store.matching(Some(&a), None, None).unwrap().len(), 1);
+        assert_eq!(store.matching(None, Some(&b), None).unwrap().len(), 1);
+
+        assert_eq!(store.cached_patterns(), 2);
+        assert!(store
+            .cache
+            .borrow()
+            .results
+            .contains_key(&(Some(a.clone()), None, None)));
+        assert!(!store
+            .cache
+            .borrow()
+            .results
+            .contains_key(&(Some(b.clone()), None, None)));
+
+        assert_eq!(store.len().unwrap(), 1);
+        store.remove(&Triple::new(&a, &b, &c)).unwrap();
+        assert_eq!(store.len().unwrap(), 0);
+        assert_eq!(store.matching(Some(&a), None, None).unwrap().len(), 0);
+    }
+}
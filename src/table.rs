@@ -0,0 +1,144 @@
+use crate::node::Node;
+use crate::writer::formatter::n_triples_formatter::NTriplesFormatter;
+use crate::writer::formatter::rdf_formatter::RdfFormatter;
+
+/// Handling of subjects that have several values for the same predicate.
+#[derive(Debug, PartialEq, Clone)]
+pub enum MultiValues {
+    /// Only the first value in node order is kept.
+    First,
+
+    /// All values are kept in the same cell.
+    All,
+
+    /// One row is created for each combination of values.
+    Expand,
+}
+
+/// A row of a table generated from a graph.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Row {
+    subject: Node,
+    cells: Vec<Vec<Node>>,
+}
+
+impl Row {
+    /// Constructor of `Row`.
+    pub fn new(subject: &Node, cells: Vec<Vec<Node>>) -> Row {
+        Row {
+            subject: subject.clone(),
+            cells,
+        }
+    }
+
+    /// Returns the subject the row was generated from.
+    pub fn subject(&self) -> &Node {
+        &self.subject
+    }
+
+    /// Returns the values of each column.
+    pub fn cells(&self) -> &Vec<Vec<Node>> {
+        &self.cells
+    }
+
+    /// Returns the values of the column with the provided index.
+    pub fn cell(&self, index: usize) -> &[Node] {
+        &self.cells[index]
+    }
+}
+
+/// Writes the rows as CSV with a header containing the IRIs of the predicates.
+///
+/// Several values in the same cell are joined with the value separator.
+///
+/// # Examples
+///
+/// ```
+/// use rdf::node::Node;
+/// use rdf::table::{self, Row};
+/// use rdf::uri::Uri;
+///
+/// let name = Node::UriNode { uri: Uri::new("http://xmlns.com/foaf/0.1/name".to_string()) };
+/// let subject = Node::UriNode { uri: Uri::new("http://example.org/art".to_string()) };
+/// let value = Node::LiteralNode { literal: "Art, \"the\" Barstow".to_string(), data_type: None, language: None };
+///
+/// let rows = vec![Row::new(&subject, vec![vec![value]])];
+///
+/// assert_eq!(table::to_csv(&[name], &rows, "|"),
+///            "subject,http://xmlns.com/foaf/0.1/name\nhttp://example.org/art,\"Art, \"\"the\"\" Barstow\"\n");
+/// ```
+pub fn to_csv(predicates: &[Node], rows: &[Row], value_separator: &str) -> String {
+    let mut output_string = "subject".to_string();
+
+    for predicate in predicates {
+        output_string.push(',');
+        output_string.push_str(&escape_csv(&node_value(predicate)));
+    }
+
+    output_string.push('\n');
+
+    for row in rows {
+        output_string.push_str(&escape_csv(&node_value(row.subject())));
+
+        for cell in row.cells() {
+            let values: Vec<String> = cell.iter().map(node_value).collect();
+
+            output_string.push(',');
+            output_string.push_str(&escape_csv(&values.join(value_separator)));
+        }
+
+        output_string.push('\n');
+    }
+
+    output_string
+}
+
+/// Returns the plain value of a node as it is written to a table.
+pub(crate) fn node_value(node: &Node) -> String {
+    match *node {
+        Node::UriNode { ref uri } => uri.to_string().to_owned(),
+        Node::LiteralNode { ref literal, .. } => literal.to_owned(),
+        Node::BlankNode { ref id } => "_:".to_string() + id,
+        Node::TripleNode { ref triple } => NTriplesFormatter::new().format_triple(triple),
+    }
+}
+
+/// Quotes a CSV field if it contains separators, quotes or line breaks.
+pub(crate) fn escape_csv(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        "\"".to_string() + &field.replace('"', "\"\"") + "\""
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::node::Node;
+    use crate::table::*;
+
+    #[test]
+    fn csv_with_multiple_values_and_empty_cells() {
+        let subject = Node::BlankNode {
+            id: "a".to_string(),
+        };
+        let first = Node::LiteralNode {
+            literal: "x".to_string(),
+            data_type: None,
+            language: None,
+        };
+        let second = Node::LiteralNode {
+            literal: "y\nz".to_string(),
+            data_type: None,
+            language: Some("en".to_string()),
+        };
+
+        let rows = vec![Row::new(&subject, vec![vec![first, second], vec![]])];
+        let predicates = vec![subject.clone(), subject.clone()];
+
+        assert_eq!(
+            to_csv(&predicates, &rows, ";"),
+            "subject,_:a,_:a\n_:a,\"x;y\nz\",\n"
+        );
+    }
+}
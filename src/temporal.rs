@@ -0,0 +1,729 @@
+use crate::error::{Error, ErrorType};
+use crate::specs::xml_specs::WhiteSpace;
+use crate::Result;
+use std::cmp::Ordering;
+use std::fmt;
+
+const NANOSECONDS_PER_SECOND: i128 = 1_000_000_000;
+const NANOSECONDS_PER_DAY: i128 = 86_400 * NANOSECONDS_PER_SECOND;
+
+/// Point in time that is parsed from an `xsd:dateTime` literal.
+///
+/// Date and times with a timezone are normalized to UTC, those without a timezone are
+/// interpreted as UTC. Points in time are ordered chronologically.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DateTime {
+    seconds: i64,
+    nanoseconds: u32,
+}
+
+impl DateTime {
+    /// Parses the lexical form of an `xsd:dateTime`, e.g. `2020-01-31T12:00:00+01:00`.
+    ///
+    /// Surrounding whitespace is removed as required by the whiteSpace facet of the data type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::temporal::DateTime;
+    ///
+    /// let utc = DateTime::parse("2020-01-31T11:00:00Z").unwrap();
+    /// let cet = DateTime::parse("2020-01-31T12:00:00+01:00").unwrap();
+    ///
+    /// assert_eq!(utc, cet);
+    /// assert_eq!(utc.unix_seconds(), 1580468400);
+    /// assert!(DateTime::parse("2020-01-31T11:00:00.5Z").unwrap() > utc);
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - The literal is not a valid `xsd:dateTime`.
+    ///
+    pub fn parse(literal: &str) -> Result<DateTime> {
+        let collapsed = WhiteSpace::Collapse.apply(literal);
+
+        DateTime::parse_lexical_form(&collapsed).ok_or_else(|| {
+            Error::new(
+                ErrorType::InvalidLiteral,
+                "Invalid xsd:dateTime: ".to_string() + literal,
+            )
+        })
+    }
+
+    /// Returns the seconds since 1970-01-01T00:00:00Z.
+    pub fn unix_seconds(&self) -> i64 {
+        self.seconds
+    }
+
+    /// Returns the fraction of the second in nanoseconds.
+    pub fn nanoseconds(&self) -> u32 {
+        self.nanoseconds
+    }
+
+    /// Adds a duration and returns `None` if the result is out of range.
+    ///
+    /// Months are added first and the day is clamped to the last day of the resulting month,
+    /// as defined by XML schema.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::temporal::{DateTime, Duration};
+    ///
+    /// let start = DateTime::parse("2020-01-31T12:00:00Z").unwrap();
+    /// let one_month = Duration::parse("P1M").unwrap();
+    ///
+    /// assert_eq!(start.checked_add(&one_month),
+    ///            DateTime::parse("2020-02-29T12:00:00Z").ok());
+    /// ```
+    pub fn checked_add(&self, duration: &Duration) -> Option<DateTime> {
+        let days = self.seconds.div_euclid(86400);
+        let seconds_of_day = self.seconds.rem_euclid(86400);
+
+        let days = if duration.months == 0 {
+            days
+        } else {
+            let (year, month, day) = civil_from_days(days);
+            let months = (year.checked_mul(12)? + month - 1).checked_add(duration.months)?;
+            let year = months.div_euclid(12);
+            let month = months.rem_euclid(12) + 1;
+
+            days_from_civil(year, month, day.min(days_in_month(year, month)))?
+        };
+
+        let nanoseconds = (days as i128 * 86400 + seconds_of_day as i128) * NANOSECONDS_PER_SECOND
+            + self.nanoseconds as i128
+            + duration.nanoseconds;
+
+        Some(DateTime {
+            seconds: i64::try_from(nanoseconds.div_euclid(NANOSECONDS_PER_SECOND)).ok()?,
+            nanoseconds: nanoseconds.rem_euclid(NANOSECONDS_PER_SECOND) as u32,
+        })
+    }
+
+    /// Subtracts a duration and returns `None` if the result is out of range.
+    pub fn checked_sub(&self, duration: &Duration) -> Option<DateTime> {
+        self.checked_add(&Duration {
+            months: duration.months.checked_neg()?,
+            nanoseconds: -duration.nanoseconds,
+        })
+    }
+
+    /// Returns the day-time duration from an earlier point in time to this one.
+    ///
+    /// The duration is negative if the other point in time is later.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::temporal::DateTime;
+    ///
+    /// let start = DateTime::parse("2020-01-01T00:00:00Z").unwrap();
+    /// let end = DateTime::parse("2020-01-02T01:30:00Z").unwrap();
+    ///
+    /// assert_eq!(end.duration_since(&start).to_string(), "P1DT1H30M");
+    /// assert_eq!(start.duration_since(&end).to_string(), "-P1DT1H30M");
+    /// ```
+    pub fn duration_since(&self, earlier: &DateTime) -> Duration {
+        Duration {
+            months: 0,
+            nanoseconds: (self.seconds as i128 - earlier.seconds as i128) * NANOSECONDS_PER_SECOND
+                + self.nanoseconds as i128
+                - earlier.nanoseconds as i128,
+        }
+    }
+
+    /// Parses the lexical form and returns `None` if it is invalid.
+    fn parse_lexical_form(literal: &str) -> Option<DateTime> {
+        if !literal.is_ascii() {
+            return None;
+        }
+
+        let (date, time) = literal.split_at(literal.rfind('T')?);
+        let time = &time[1..];
+
+        // date: -?YYYY-MM-DD, years with more than four digits have no leading zeros
+        let (year_sign, date) = match date.strip_prefix('-') {
+            Some(date) => (-1, date),
+            None => (1, date),
+        };
+
+        let date_parts: Vec<&str> = date.split('-').collect();
+
+        if date_parts.len() != 3
+            || date_parts[0].len() < 4
+            || (date_parts[0].len() > 4 && date_parts[0].starts_with('0'))
+        {
+            return None;
+        }
+
+        let year = year_sign * parse_digits(date_parts[0], date_parts[0].len())?;
+        let month = parse_digits(date_parts[1], 2)?;
+        let day = parse_digits(date_parts[2], 2)?;
+
+        if !(1..=12).contains(&month) || day < 1 || day > days_in_month(year, month) {
+            return None;
+        }
+
+        // timezone: Z, +hh:mm or -hh:mm
+        let (time, offset) = if let Some(time) = time.strip_suffix('Z') {
+            (time, 0)
+        } else if time.len() > 6 && matches!(time.as_bytes()[time.len() - 6], b'+' | b'-') {
+            let (time, timezone) = time.split_at(time.len() - 6);
+            let hours = parse_digits(&timezone[1..3], 2)?;
+            let minutes = parse_digits(&timezone[4..6], 2)?;
+
+            if &timezone[3..4] != ":" || hours > 14 || minutes > 59 {
+                return None;
+            }
+
+            let offset = hours * 3600 + minutes * 60;
+
+            if timezone.starts_with('-') {
+                (time, -offset)
+            } else {
+                (time, offset)
+            }
+        } else {
+            (time, 0)
+        };
+
+        // time: hh:mm:ss with optional fraction of seconds
+        let time_parts: Vec<&str> = time.split(':').collect();
+
+        if time_parts.len() != 3 {
+            return None;
+        }
+
+        let (seconds, fraction) = match time_parts[2].find('.') {
+            Some(point) => (&time_parts[2][..point], &time_parts[2][point + 1..]),
+            None => (time_parts[2], "0"),
+        };
+
+        let hour = parse_digits(time_parts[0], 2)?;
+        let minute = parse_digits(time_parts[1], 2)?;
+        let second = parse_digits(seconds, 2)?;
+        let nanoseconds = parse_fraction(fraction)?;
+
+        // 24:00:00 is only allowed as end of day
+        if minute > 59
+            || second > 59
+            || hour > 24
+            || (hour == 24 && (minute != 0 || second != 0 || nanoseconds != 0))
+        {
+            return None;
+        }
+
+        Some(DateTime {
+            seconds: days_from_civil(year, month, day)?
+                .checked_mul(86400)?
+                .checked_add(hour * 3600 + minute * 60 + second - offset)?,
+            nanoseconds,
+        })
+    }
+}
+
+/// Duration that is parsed from an `xsd:duration` or `xsd:dayTimeDuration` literal.
+///
+/// Durations consist of a number of months and a day-time part in nanoseconds, so `P1Y`
+/// equals `P12M` and `P1D` equals `PT24H`. Day-time durations are ordered totally, other
+/// durations only partially, e.g. `P1M` and `P30D` are not comparable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Duration {
+    months: i64,
+    nanoseconds: i128,
+}
+
+impl Duration {
+    /// Parses the lexical form of an `xsd:duration`, e.g. `P1Y2M3DT4H5M6.7S`.
+    ///
+    /// Surrounding whitespace is removed as required by the whiteSpace facet of the data type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::temporal::Duration;
+    ///
+    /// let duration = Duration::parse("P1Y2M3DT4H5M6.7S").unwrap();
+    ///
+    /// assert_eq!(duration.months(), 14);
+    /// assert_eq!(Duration::parse("P14M").unwrap().to_string(), "P1Y2M");
+    /// assert!(Duration::parse("P1H").is_err());
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - The literal is not a valid `xsd:duration`.
+    ///
+    pub fn parse(literal: &str) -> Result<Duration> {
+        let collapsed = WhiteSpace::Collapse.apply(literal);
+
+        Duration::parse_lexical_form(&collapsed).ok_or_else(|| {
+            Error::new(
+                ErrorType::InvalidLiteral,
+                "Invalid xsd:duration: ".to_string() + literal,
+            )
+        })
+    }
+
+    /// Parses the lexical form of an `xsd:dayTimeDuration`, e.g. `P3DT4H`.
+    ///
+    /// # Failures
+    ///
+    /// - The literal is not a valid `xsd:dayTimeDuration`, e.g. it contains years or months.
+    ///
+    pub fn parse_day_time(literal: &str) -> Result<Duration> {
+        let collapsed = WhiteSpace::Collapse.apply(literal);
+        let date = collapsed.split('T').next().unwrap_or("");
+
+        match Duration::parse_lexical_form(&collapsed) {
+            Some(duration) if !date.contains(['Y', 'M']) => Ok(duration),
+            _ => Err(Error::new(
+                ErrorType::InvalidLiteral,
+                "Invalid xsd:dayTimeDuration: ".to_string() + literal,
+            )),
+        }
+    }
+
+    /// Creates a day-time duration from seconds and additional nanoseconds.
+    pub fn from_seconds(seconds: i64, nanoseconds: u32) -> Duration {
+        Duration {
+            months: 0,
+            nanoseconds: seconds as i128 * NANOSECONDS_PER_SECOND + nanoseconds as i128,
+        }
+    }
+
+    /// Returns the number of months, including the years.
+    pub fn months(&self) -> i64 {
+        self.months
+    }
+
+    /// Returns the day-time part in nanoseconds.
+    pub fn nanoseconds(&self) -> i128 {
+        self.nanoseconds
+    }
+
+    /// Returns `true` if the duration has no months, like an `xsd:dayTimeDuration`.
+    pub fn is_day_time(&self) -> bool {
+        self.months == 0
+    }
+
+    /// Adds two durations.
+    ///
+    /// Returns `None` on overflow or if the months and the day-time part of the result have
+    /// different signs, e.g. for `P1M` plus `-P1D`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::temporal::Duration;
+    ///
+    /// let a = Duration::parse("PT1H30M").unwrap();
+    /// let b = Duration::parse("PT45M").unwrap();
+    ///
+    /// assert_eq!(a.checked_add(&b).unwrap().to_string(), "PT2H15M");
+    /// assert_eq!(Duration::parse("P1M").unwrap().checked_add(&Duration::parse("-P1D").unwrap()),
+    ///            None);
+    /// ```
+    pub fn checked_add(&self, other: &Duration) -> Option<Duration> {
+        let duration = Duration {
+            months: self.months.checked_add(other.months)?,
+            nanoseconds: self.nanoseconds.checked_add(other.nanoseconds)?,
+        };
+
+        if (duration.months < 0 && duration.nanoseconds > 0)
+            || (duration.months > 0 && duration.nanoseconds < 0)
+        {
+            return None;
+        }
+
+        Some(duration)
+    }
+
+    /// Subtracts two durations, see `checked_add`.
+    pub fn checked_sub(&self, other: &Duration) -> Option<Duration> {
+        self.checked_add(&Duration {
+            months: other.months.checked_neg()?,
+            nanoseconds: other.nanoseconds.checked_neg()?,
+        })
+    }
+
+    /// Parses the lexical form and returns `None` if it is invalid.
+    fn parse_lexical_form(literal: &str) -> Option<Duration> {
+        if !literal.is_ascii() {
+            return None;
+        }
+
+        let (negative, literal) = match literal.strip_prefix('-') {
+            Some(literal) => (true, literal),
+            None => (false, literal),
+        };
+
+        let literal = literal.strip_prefix('P')?;
+
+        // the time part must not be empty if it is indicated by 'T'
+        let (date, time) = match literal.find('T') {
+            Some(i) if i + 1 < literal.len() => (&literal[..i], &literal[i + 1..]),
+            Some(_) => return None,
+            None if !literal.is_empty() => (literal, ""),
+            None => return None,
+        };
+
+        let mut months: i64 = 0;
+        let mut nanoseconds: i128 = 0;
+
+        for (value, designator) in split_components(date, "YMD")? {
+            let value = parse_number(value)?;
+
+            match designator {
+                'Y' => months = months.checked_add(value.checked_mul(12)?)?,
+                'M' => months = months.checked_add(value)?,
+                _ => nanoseconds += value as i128 * NANOSECONDS_PER_DAY,
+            }
+        }
+
+        for (value, designator) in split_components(time, "HMS")? {
+            nanoseconds += match designator {
+                'H' => parse_number(value)? as i128 * 3600 * NANOSECONDS_PER_SECOND,
+                'M' => parse_number(value)? as i128 * 60 * NANOSECONDS_PER_SECOND,
+                _ => match value.find('.') {
+                    Some(point) => {
+                        parse_number(&value[..point])? as i128 * NANOSECONDS_PER_SECOND
+                            + parse_fraction(&value[point + 1..])? as i128
+                    }
+                    None => parse_number(value)? as i128 * NANOSECONDS_PER_SECOND,
+                },
+            };
+        }
+
+        if negative {
+            Some(Duration {
+                months: -months,
+                nanoseconds: -nanoseconds,
+            })
+        } else {
+            Some(Duration {
+                months,
+                nanoseconds,
+            })
+        }
+    }
+}
+
+impl PartialOrd for Duration {
+    /// Compares durations by adding them to the four points in time that XML schema uses as
+    /// reference. Returns `None` if the order depends on the point in time.
+    fn partial_cmp(&self, other: &Duration) -> Option<Ordering> {
+        if self.months == other.months {
+            return Some(self.nanoseconds.cmp(&other.nanoseconds));
+        }
+
+        let mut order = None;
+
+        for (year, month) in [(1696, 9), (1697, 2), (1903, 3), (1903, 7)] {
+            let reference = DateTime {
+                seconds: days_from_civil(year, month, 1)? * 86400,
+                nanoseconds: 0,
+            };
+            let current = reference
+                .checked_add(self)?
+                .cmp(&reference.checked_add(other)?);
+
+            match order {
+                Some(previous) if previous != current => return None,
+                _ => order = Some(current),
+            }
+        }
+
+        order
+    }
+}
+
+impl fmt::Display for Duration {
+    /// Formats the duration in its canonical form, e.g. `P1Y2MT3H` or `PT0S`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.months == 0 && self.nanoseconds == 0 {
+            return write!(f, "PT0S");
+        }
+
+        if self.months < 0 || self.nanoseconds < 0 {
+            write!(f, "-")?;
+        }
+
+        write!(f, "P")?;
+
+        let months = self.months.unsigned_abs();
+        let nanoseconds = self.nanoseconds.unsigned_abs();
+        let days = nanoseconds / NANOSECONDS_PER_DAY as u128;
+        let seconds = nanoseconds % NANOSECONDS_PER_DAY as u128 / NANOSECONDS_PER_SECOND as u128;
+        let fraction = nanoseconds % NANOSECONDS_PER_SECOND as u128;
+
+        for (value, designator) in [(months / 12, 'Y'), (months % 12, 'M')] {
+            if value > 0 {
+                write!(f, "{}{}", value, designator)?;
+            }
+        }
+
+        if days > 0 {
+            write!(f, "{}D", days)?;
+        }
+
+        if seconds == 0 && fraction == 0 {
+            return Ok(());
+        }
+
+        write!(f, "T")?;
+
+        for (value, designator) in [(seconds / 3600, 'H'), (seconds % 3600 / 60, 'M')] {
+            if value > 0 {
+                write!(f, "{}{}", value, designator)?;
+            }
+        }
+
+        match (seconds % 60, fraction) {
+            (0, 0) => Ok(()),
+            (seconds, 0) => write!(f, "{}S", seconds),
+            (seconds, fraction) => {
+                let fraction = format!("{:09}", fraction);
+                write!(f, "{}.{}S", seconds, fraction.trim_end_matches('0'))
+            }
+        }
+    }
+}
+
+/// Splits a part of a duration, like `1Y2M`, into the numbers and their designators.
+///
+/// The designators must appear at most once and in the provided order.
+fn split_components<'a>(part: &'a str, designators: &str) -> Option<Vec<(&'a str, char)>> {
+    let mut components = Vec::new();
+    let mut remaining = designators;
+    let mut start = 0;
+
+    for (i, c) in part.char_indices() {
+        if c.is_ascii_digit() || c == '.' {
+            continue;
+        }
+
+        let position = remaining.find(c)?;
+        remaining = &remaining[position + 1..];
+
+        if i == start {
+            return None;
+        }
+
+        components.push((&part[start..i], c));
+        start = i + 1;
+    }
+
+    if start != part.len() {
+        return None;
+    }
+
+    Some(components)
+}
+
+/// Parses a number of arbitrary length that only consists of digits.
+fn parse_number(digits: &str) -> Option<i64> {
+    parse_digits(digits, digits.len())
+}
+
+/// Parses a number consisting of exactly the provided number of digits.
+fn parse_digits(digits: &str, length: usize) -> Option<i64> {
+    if digits.len() != length || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    digits.parse::<i64>().ok()
+}
+
+/// Converts the digits of a fraction of a second to nanoseconds.
+///
+/// Digits beyond nanosecond precision are ignored.
+fn parse_fraction(fraction: &str) -> Option<u32> {
+    if fraction.is_empty() || !fraction.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    let nanoseconds: String = fraction
+        .chars()
+        .chain("000000000".chars())
+        .take(9)
+        .collect();
+
+    nanoseconds.parse::<u32>().ok()
+}
+
+/// Returns the number of days of the month.
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Returns the number of days since 1970-01-01 of a date in the proleptic Gregorian calendar,
+/// or `None` if the number of days is out of range.
+fn days_from_civil(year: i64, month: i64, day: i64) -> Option<i64> {
+    // years start in March, so that the leap day is the last day of the year
+    let year = if month <= 2 {
+        year.checked_sub(1)?
+    } else {
+        year
+    };
+    let era = if year >= 0 {
+        year
+    } else {
+        year.checked_sub(399)?
+    } / 400;
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era.checked_mul(146097)?.checked_add(day_of_era - 719468)
+}
+
+/// Returns the year, month and day of a number of days since 1970-01-01.
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719468;
+    let era = if days >= 0 { days } else { days - 146096 } / 146097;
+    let day_of_era = days - era * 146097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month + 2) / 5 + 1;
+
+    // convert the year starting in March back
+    let month = if month < 10 { month + 3 } else { month - 9 };
+    let year = year_of_era + era * 400;
+
+    (if month <= 2 { year + 1 } else { year }, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::temporal::{DateTime, Duration};
+
+    #[test]
+    fn parse_date_times() {
+        assert_eq!(
+            DateTime::parse("1970-01-01T00:00:00")
+                .unwrap()
+                .unix_seconds(),
+            0
+        );
+        assert_eq!(
+            DateTime::parse("1969-12-31T19:00:00-05:00")
+                .unwrap()
+                .unix_seconds(),
+            0
+        );
+        assert_eq!(
+            DateTime::parse("2000-02-29T24:00:00Z").unwrap(),
+            DateTime::parse("2000-03-01T00:00:00Z").unwrap()
+        );
+        assert_eq!(
+            DateTime::parse("2020-01-01T00:00:00.123Z")
+                .unwrap()
+                .nanoseconds(),
+            123_000_000
+        );
+        assert!(
+            DateTime::parse("-0001-01-01T00:00:00Z").unwrap()
+                < DateTime::parse("0001-01-01T00:00:00Z").unwrap()
+        );
+        assert_eq!(
+            DateTime::parse("\n  2020-01-01T00:00:00Z\n").unwrap(),
+            DateTime::parse("2020-01-01T00:00:00Z").unwrap()
+        );
+    }
+
+    #[test]
+    fn parse_invalid_date_times() {
+        let invalid = vec![
+            "2020-01-01",
+            "2019-02-29T00:00:00",
+            "2020-13-01T00:00:00",
+            "2020-01-01T24:00:01",
+            "2020-01-01T00:00:00+1:00",
+            "20-01-01T00:00:00",
+            "2020-01-01T00:00:00.",
+            "2020-01-01T00:00: 00",
+            "999999999999999-01-01T00:00:00Z",
+            "-9223372036854775807-01-01T00:00:00Z",
+        ];
+
+        for literal in invalid {
+            assert!(DateTime::parse(literal).is_err(), "{}", literal);
+        }
+    }
+
+    #[test]
+    fn parse_and_format_durations() {
+        let literals = vec![
+            ("P1Y2M3DT4H5M6.7S", "P1Y2M3DT4H5M6.7S"),
+            ("P0Y0M0D", "PT0S"),
+            ("-P1D", "-P1D"),
+            ("PT36H", "P1DT12H"),
+            ("PT0.000000001S", "PT0.000000001S"),
+            ("P13M", "P1Y1M"),
+            (" PT90M\n", "PT1H30M"),
+        ];
+
+        for (literal, canonical) in literals {
+            assert_eq!(
+                Duration::parse(literal).unwrap().to_string(),
+                canonical,
+                "{}",
+                literal
+            );
+        }
+
+        for literal in vec![
+            "", "P", "PT", "P1DT", "P1S", "PT1D", "P1M1Y", "P-1D", "P1.5D", "1D",
+        ] {
+            assert!(Duration::parse(literal).is_err(), "{}", literal);
+        }
+
+        assert!(Duration::parse_day_time("P1DT2H").is_ok());
+        assert!(Duration::parse_day_time("P1Y").is_err());
+        assert!(Duration::parse_day_time("P0M1D").is_err());
+    }
+
+    #[test]
+    fn compare_durations() {
+        let duration = |literal| Duration::parse(literal).unwrap();
+
+        assert_eq!(duration("P1Y"), duration("P12M"));
+        assert_eq!(duration("P1D"), duration("PT24H"));
+        assert!(duration("PT1H") < duration("PT61M"));
+        assert!(duration("P1M") > duration("P27D"));
+        assert!(duration("P1M") < duration("P32D"));
+        assert_eq!(duration("P1M").partial_cmp(&duration("P30D")), None);
+    }
+
+    #[test]
+    fn add_durations_to_date_times() {
+        let date_time = |literal| DateTime::parse(literal).unwrap();
+        let duration = |literal| Duration::parse(literal).unwrap();
+
+        assert_eq!(
+            date_time("2000-01-12T12:13:14Z").checked_add(&duration("P1Y3M5DT7H10M3.3S")),
+            Some(date_time("2001-04-17T19:23:17.3Z"))
+        );
+        assert_eq!(
+            date_time("2000-03-31T00:00:00Z").checked_sub(&duration("P1M")),
+            Some(date_time("2000-02-29T00:00:00Z"))
+        );
+        assert_eq!(
+            date_time("0001-01-01T00:00:00Z").checked_sub(&duration("PT0.5S")),
+            Some(date_time("0000-12-31T23:59:59.5Z"))
+        );
+        assert_eq!(
+            date_time("2020-03-01T00:00:00Z").duration_since(&date_time("2020-02-01T00:00:00Z")),
+            duration("P29D")
+        );
+    }
+}
@@ -1,6 +1,9 @@
-use node::Node;
+use crate::node::Node;
 use std::cmp::PartialEq;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
 use std::slice::Iter;
+use std::sync::Arc;
 use std::vec::IntoIter;
 
 /// Triple segment.
@@ -45,6 +48,15 @@ impl Triple {
         }
     }
 
+    /// Constructor for Triple struct that takes ownership of the nodes.
+    pub(crate) fn from_nodes(subject: Node, predicate: Node, object: Node) -> Triple {
+        Triple {
+            subject,
+            predicate,
+            object,
+        }
+    }
+
     /// Returns a reference to the subject node of the triple.
     pub fn subject(&self) -> &Node {
         &self.subject
@@ -63,24 +75,314 @@ impl Triple {
 
 impl PartialEq for Triple {
     fn eq(&self, other: &Triple) -> bool {
-        self.subject() == other.subject() && self.predicate() == other.predicate()
+        self.subject() == other.subject()
+            && self.predicate() == other.predicate()
             && self.object() == other.object()
     }
 }
 
 impl Eq for Triple {}
 
-/// Storage for triples.
-#[derive(Debug, Default)]
+impl Hash for Triple {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.subject().hash(state);
+        self.predicate().hash(state);
+        self.object().hash(state);
+    }
+}
+
+/// Storage for a set of triples.
+///
+/// Triples are kept in the order they were added, except that removing a triple moves the
+/// last triple into its place. Clones of the store share the stored triples until one of them
+/// is modified (copy-on-write).
+#[derive(Debug, Default, Clone)]
 pub struct TripleStore {
-    triples: Vec<Triple>,
+    triples: Arc<Vec<Triple>>,
+    index: Arc<TripleIndex>,
+}
+
+/// Nested map from the IDs of the first to the second to the third nodes of the triples and
+/// the positions of the triples in the store.
+type IdIndex = HashMap<usize, HashMap<usize, HashMap<usize, usize>>>;
+
+/// Indexes of the stored triples by subject, predicate and object.
+///
+/// Each distinct node is kept once, the indexes refer to nodes by their IDs and to triples by
+/// their positions in the store.
+#[derive(Debug, Default, Clone)]
+struct TripleIndex {
+    ids: HashMap<Arc<Node>, usize>,
+    /// Nodes by ID and the number of triples using them, `None` for IDs that can be reused.
+    nodes: Vec<Option<(Arc<Node>, usize)>>,
+    free_ids: Vec<usize>,
+    spo: IdIndex,
+    pos: IdIndex,
+    osp: IdIndex,
+}
+
+impl TripleIndex {
+    /// Returns the ID of an indexed node.
+    fn id(&self, node: &Node) -> Option<usize> {
+        self.ids.get(node).copied()
+    }
+
+    /// Returns the node with the ID.
+    fn node(&self, id: usize) -> &Node {
+        match self.nodes[id] {
+            Some((ref node, _)) => node,
+            None => panic!("Node ID {} is not in use.", id),
+        }
+    }
+
+    /// Returns the IDs of the nodes of a pattern, `None` if one of its nodes is not indexed.
+    fn pattern_ids(
+        &self,
+        subject: Option<&Node>,
+        predicate: Option<&Node>,
+        object: Option<&Node>,
+    ) -> Option<(Option<usize>, Option<usize>, Option<usize>)> {
+        let id = |node: Option<&Node>| match node {
+            Some(node) => self.id(node).map(Some),
+            None => Some(None),
+        };
+
+        Some((id(subject)?, id(predicate)?, id(object)?))
+    }
+
+    /// Returns the ID of the node for one more triple and adds the node if it is new.
+    fn acquire(&mut self, node: &Node) -> usize {
+        let id = match self.ids.get(node) {
+            Some(&id) => id,
+            None => {
+                let id = self.free_ids.pop().unwrap_or_else(|| {
+                    self.nodes.push(None);
+                    self.nodes.len() - 1
+                });
+                let node = Arc::new(node.clone());
+
+                self.ids.insert(node.clone(), id);
+                self.nodes[id] = Some((node, 0));
+                id
+            }
+        };
+
+        if let Some((_, ref mut uses)) = self.nodes[id] {
+            *uses += 1;
+        }
+
+        id
+    }
+
+    /// Releases the node with the ID for one triple and drops the node once it is unused.
+    fn release(&mut self, id: usize) {
+        let unused = match self.nodes[id] {
+            Some((_, ref mut uses)) => {
+                *uses -= 1;
+                *uses == 0
+            }
+            None => false,
+        };
+
+        if unused {
+            if let Some((node, _)) = self.nodes[id].take() {
+                self.ids.remove(&*node);
+            }
+
+            self.free_ids.push(id);
+        }
+    }
+
+    /// Adds the triple at the position of the store to the indexes.
+    ///
+    /// Returns `false` and leaves the indexes unchanged if the triple is already indexed.
+    fn insert(&mut self, triple: &Triple, position: usize) -> bool {
+        let (s, p, o) = (triple.subject(), triple.predicate(), triple.object());
+
+        if self.contains_matching(Some(s), Some(p), Some(o)) {
+            return false;
+        }
+
+        let (s, p, o) = (self.acquire(s), self.acquire(p), self.acquire(o));
+
+        insert_into(&mut self.spo, s, p, o, position);
+        insert_into(&mut self.pos, p, o, s, position);
+        insert_into(&mut self.osp, o, s, p, position);
+
+        true
+    }
+
+    /// Removes the triple from the indexes and returns its position in the store.
+    fn remove(&mut self, triple: &Triple) -> Option<usize> {
+        let (s, p, o) = match self.pattern_ids(
+            Some(triple.subject()),
+            Some(triple.predicate()),
+            Some(triple.object()),
+        ) {
+            Some((Some(s), Some(p), Some(o))) => (s, p, o),
+            _ => return None,
+        };
+
+        let position = remove_from(&mut self.spo, s, p, o)?;
+        remove_from(&mut self.pos, p, o, s);
+        remove_from(&mut self.osp, o, s, p);
+
+        for id in [s, p, o] {
+            self.release(id);
+        }
+
+        Some(position)
+    }
+
+    /// Changes the position of an indexed triple that was moved within the store.
+    fn move_to(&mut self, triple: &Triple, position: usize) {
+        if let Some((Some(s), Some(p), Some(o))) = self.pattern_ids(
+            Some(triple.subject()),
+            Some(triple.predicate()),
+            Some(triple.object()),
+        ) {
+            for (index, a, b, c) in [
+                (&mut self.spo, s, p, o),
+                (&mut self.pos, p, o, s),
+                (&mut self.osp, o, s, p),
+            ] {
+                if let Some(stored) = index
+                    .get_mut(&a)
+                    .and_then(|bs| bs.get_mut(&b))
+                    .and_then(|cs| cs.get_mut(&c))
+                {
+                    *stored = position;
+                }
+            }
+        }
+    }
+
+    /// Returns the ascending positions of the triples that match the pattern, `None` matches
+    /// any node.
+    fn positions(
+        &self,
+        subject: Option<&Node>,
+        predicate: Option<&Node>,
+        object: Option<&Node>,
+    ) -> Vec<usize> {
+        let (s, p, o) = match self.pattern_ids(subject, predicate, object) {
+            Some(ids) => ids,
+            None => return Vec::new(),
+        };
+
+        let mut positions = match (s, p, o) {
+            (Some(_), Some(_), _) | (Some(_), None, None) | (None, None, None) => {
+                walk(&self.spo, s, p, o)
+            }
+            (_, Some(_), _) => walk(&self.pos, p, o, s),
+            (_, None, Some(_)) => walk(&self.osp, o, s, p),
+        };
+
+        positions.sort_unstable();
+        positions
+    }
+
+    /// Checks if a triple matches the pattern, `None` matches any node.
+    fn contains_matching(
+        &self,
+        subject: Option<&Node>,
+        predicate: Option<&Node>,
+        object: Option<&Node>,
+    ) -> bool {
+        let ids = match self.pattern_ids(subject, predicate, object) {
+            Some(ids) => ids,
+            None => return false,
+        };
+
+        match ids {
+            (Some(s), Some(p), Some(o)) => self
+                .spo
+                .get(&s)
+                .and_then(|ps| ps.get(&p))
+                .is_some_and(|os| os.contains_key(&o)),
+            (Some(s), Some(p), None) => self.spo.get(&s).is_some_and(|ps| ps.contains_key(&p)),
+            (None, Some(p), Some(o)) => self.pos.get(&p).is_some_and(|os| os.contains_key(&o)),
+            (Some(s), None, Some(o)) => self.osp.get(&o).is_some_and(|ss| ss.contains_key(&s)),
+            (Some(s), None, None) => self.spo.contains_key(&s),
+            (None, Some(p), None) => self.pos.contains_key(&p),
+            (None, None, Some(o)) => self.osp.contains_key(&o),
+            (None, None, None) => !self.spo.is_empty(),
+        }
+    }
+}
+
+/// Returns the positions of the triples whose paths in the nested map match the IDs, `None`
+/// matches any node.
+fn walk(index: &IdIndex, a: Option<usize>, b: Option<usize>, c: Option<usize>) -> Vec<usize> {
+    let mut positions = Vec::new();
+
+    let bss: Vec<_> = match a {
+        Some(a) => index.get(&a).into_iter().collect(),
+        None => index.values().collect(),
+    };
+
+    for bs in bss {
+        let css: Vec<_> = match b {
+            Some(b) => bs.get(&b).into_iter().collect(),
+            None => bs.values().collect(),
+        };
+
+        for cs in css {
+            match c {
+                Some(c) => positions.extend(cs.get(&c)),
+                None => positions.extend(cs.values()),
+            }
+        }
+    }
+
+    positions
+}
+
+/// Adds the path with the position of its triple to the nested map.
+fn insert_into(index: &mut IdIndex, a: usize, b: usize, c: usize, position: usize) {
+    index
+        .entry(a)
+        .or_default()
+        .entry(b)
+        .or_default()
+        .insert(c, position);
+}
+
+/// Removes the path from the nested map, drops maps that become empty and returns the
+/// position of the removed path.
+fn remove_from(index: &mut IdIndex, a: usize, b: usize, c: usize) -> Option<usize> {
+    let bs = index.get_mut(&a)?;
+    let cs = bs.get_mut(&b)?;
+    let position = cs.remove(&c)?;
+
+    if cs.is_empty() {
+        bs.remove(&b);
+    }
+
+    if bs.is_empty() {
+        index.remove(&a);
+    }
+
+    Some(position)
+}
+
+/// Adds the blank nodes of the triple and of the triples it quotes.
+pub(crate) fn push_blank_nodes<'a>(triple: &'a Triple, blank_nodes: &mut Vec<&'a Node>) {
+    for node in [triple.subject(), triple.predicate(), triple.object()] {
+        match *node {
+            Node::BlankNode { .. } => blank_nodes.push(node),
+            Node::TripleNode { ref triple } => push_blank_nodes(triple, blank_nodes),
+            _ => {}
+        }
+    }
 }
 
 impl TripleStore {
     /// Constructs a new triple store.
     pub fn new() -> TripleStore {
         TripleStore {
-            triples: Vec::new(),
+            triples: Arc::new(Vec::new()),
+            index: Arc::new(TripleIndex::default()),
         }
     }
 
@@ -94,38 +396,138 @@ impl TripleStore {
         self.count() == 0
     }
 
-    /// Adds a new triple to the store.
+    /// Adds a new triple to the store, a triple that is already stored is not added again.
     pub fn add_triple(&mut self, triple: &Triple) {
-        self.triples.push(triple.clone());
+        // checked first, so that a shared store is not copied for nothing
+        if self.contains(triple) {
+            return;
+        }
+
+        Arc::make_mut(&mut self.index).insert(triple, self.triples.len());
+        Arc::make_mut(&mut self.triples).push(triple.clone());
+    }
+
+    /// Adds all triples of the iterator that are not stored yet to the store.
+    ///
+    /// The storage is only prepared once for the whole batch.
+    pub fn insert_batch<I: IntoIterator<Item = Triple>>(&mut self, triples: I) {
+        let index = Arc::make_mut(&mut self.index);
+        let stored = Arc::make_mut(&mut self.triples);
+
+        for triple in triples {
+            if index.insert(&triple, stored.len()) {
+                stored.push(triple);
+            }
+        }
     }
 
-    /// Deletes the triple from the store.
+    /// Deletes the triple from the store, the last stored triple takes its place.
     pub fn remove_triple(&mut self, triple: &Triple) {
-        self.triples.retain(|t| t != triple);
+        if !self.contains(triple) {
+            return;
+        }
+
+        let index = Arc::make_mut(&mut self.index);
+        let stored = Arc::make_mut(&mut self.triples);
+
+        if let Some(position) = index.remove(triple) {
+            stored.swap_remove(position);
+
+            if let Some(moved) = stored.get(position) {
+                index.move_to(moved, position);
+            }
+        }
+    }
+
+    /// Checks if the triple is stored.
+    pub fn contains(&self, triple: &Triple) -> bool {
+        self.contains_matching(
+            Some(triple.subject()),
+            Some(triple.predicate()),
+            Some(triple.object()),
+        )
+    }
+
+    /// Checks if a stored triple matches the pattern, `None` matches any node.
+    pub fn contains_matching(
+        &self,
+        subject: Option<&Node>,
+        predicate: Option<&Node>,
+        object: Option<&Node>,
+    ) -> bool {
+        self.index.contains_matching(subject, predicate, object)
+    }
+
+    /// Returns the distinct triples that match the pattern, `None` matches any node.
+    pub fn triples_matching(
+        &self,
+        subject: Option<&Node>,
+        predicate: Option<&Node>,
+        object: Option<&Node>,
+    ) -> Vec<Triple> {
+        self.lookup(subject, predicate, object)
+            .into_iter()
+            .cloned()
+            .collect()
+    }
+
+    /// Returns an iterator over the distinct subjects.
+    pub fn subjects(&self) -> impl Iterator<Item = &Node> {
+        self.index.spo.keys().map(|&id| self.index.node(id))
+    }
+
+    /// Returns an iterator over the distinct predicates.
+    pub fn predicates(&self) -> impl Iterator<Item = &Node> {
+        self.index.pos.keys().map(|&id| self.index.node(id))
+    }
+
+    /// Returns an iterator over the distinct objects.
+    pub fn objects(&self) -> impl Iterator<Item = &Node> {
+        self.index.osp.keys().map(|&id| self.index.node(id))
+    }
+
+    /// Returns an iterator over the distinct predicate and object pairs of the subject.
+    pub fn predicate_objects<'a>(
+        &'a self,
+        subject: &Node,
+    ) -> impl Iterator<Item = (&'a Node, &'a Node)> {
+        let index = &*self.index;
+
+        index
+            .id(subject)
+            .and_then(|id| index.spo.get(&id))
+            .into_iter()
+            .flat_map(|ps| ps.iter())
+            .flat_map(move |(&p, os)| os.keys().map(move |&o| (index.node(p), index.node(o))))
+    }
+
+    /// Returns the stored triples that match the pattern in the order of the store.
+    pub(crate) fn lookup(
+        &self,
+        subject: Option<&Node>,
+        predicate: Option<&Node>,
+        object: Option<&Node>,
+    ) -> Vec<&Triple> {
+        self.index
+            .positions(subject, predicate, object)
+            .into_iter()
+            .map(|position| &self.triples[position])
+            .collect()
     }
 
     /// Returns all triples where the subject node matches the provided node.
     pub fn get_triples_with_subject(&self, node: &Node) -> Vec<&Triple> {
-        self.triples
-            .iter()
-            .filter(|t| t.subject() == node)
-            .collect::<Vec<_>>()
+        self.lookup(Some(node), None, None)
     }
 
     /// Returns all triples where the predicate node matches the provided node.
     pub fn get_triples_with_predicate(&self, node: &Node) -> Vec<&Triple> {
-        self.triples
-            .iter()
-            .filter(|t| t.predicate() == node)
-            .collect::<Vec<_>>()
+        self.lookup(None, Some(node), None)
     }
 
     /// Returns all triples where the object node matches the provided node.
     pub fn get_triples_with_object(&self, node: &Node) -> Vec<&Triple> {
-        self.triples
-            .iter()
-            .filter(|t| t.object() == node)
-            .collect::<Vec<_>>()
+        self.lookup(None, None, Some(node))
     }
 
     /// Returns all triples where the subject and object nodes match the provided nodes.
@@ -134,10 +536,7 @@ impl TripleStore {
         subject_node: &Node,
         object_node: &Node,
     ) -> Vec<&Triple> {
-        self.triples
-            .iter()
-            .filter(|t| t.object() == object_node && t.subject() == subject_node)
-            .collect::<Vec<_>>()
+        self.lookup(Some(subject_node), None, Some(object_node))
     }
 
     /// Returns all triples where the subject and predicate nodes match the provided nodes.
@@ -146,10 +545,7 @@ impl TripleStore {
         subject_node: &Node,
         predicate_node: &Node,
     ) -> Vec<&Triple> {
-        self.triples
-            .iter()
-            .filter(|t| t.predicate() == predicate_node && t.subject() == subject_node)
-            .collect::<Vec<_>>()
+        self.lookup(Some(subject_node), Some(predicate_node), None)
     }
 
     /// Returns all triples where the predicate and object nodes match the provided nodes.
@@ -158,36 +554,15 @@ impl TripleStore {
         predicate_node: &Node,
         object_node: &Node,
     ) -> Vec<&Triple> {
-        self.triples
-            .iter()
-            .filter(|t| t.predicate() == predicate_node && t.object() == object_node)
-            .collect::<Vec<_>>()
+        self.lookup(None, Some(predicate_node), Some(object_node))
     }
 
-    /// Returns all blank nodes of the store.
+    /// Returns all blank nodes of the store, including those of quoted triples.
     pub fn get_blank_nodes(&self) -> Vec<&Node> {
         let mut blank_nodes = Vec::new();
 
-        for triple in &self.triples {
-            match *triple {
-                Triple {
-                    subject: Node::BlankNode { .. },
-                    object: Node::BlankNode { .. },
-                    ..
-                } => {
-                    blank_nodes.push(triple.subject());
-                    blank_nodes.push(triple.object());
-                }
-                Triple {
-                    subject: Node::BlankNode { .. },
-                    ..
-                } => blank_nodes.push(triple.subject()),
-                Triple {
-                    object: Node::BlankNode { .. },
-                    ..
-                } => blank_nodes.push(triple.object()),
-                _ => {}
-            }
+        for triple in self.triples.iter() {
+            push_blank_nodes(triple, &mut blank_nodes);
         }
 
         blank_nodes
@@ -195,11 +570,11 @@ impl TripleStore {
 
     /// Returns the stored triples as vector.
     pub fn into_vec(self) -> Vec<Triple> {
-        self.triples
+        Arc::try_unwrap(self.triples).unwrap_or_else(|triples| (*triples).clone())
     }
 
     /// Returns an iterator over the stored triples.
-    pub fn iter(&self) -> Iter<Triple> {
+    pub fn iter(&self) -> Iter<'_, Triple> {
         self.triples.iter()
     }
 }
@@ -209,14 +584,14 @@ impl IntoIterator for TripleStore {
     type IntoIter = IntoIter<Triple>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.triples.into_iter()
+        self.into_vec().into_iter()
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use node::*;
-    use triple::*;
+    use crate::node::*;
+    use crate::triple::*;
 
     #[test]
     fn empty_triple_store() {
@@ -253,4 +628,101 @@ mod tests {
 
         assert_eq!(store.count(), 1);
     }
+
+    #[test]
+    fn cloned_triple_store_is_copied_on_write() {
+        let mut store = TripleStore::new();
+
+        let node = Node::BlankNode {
+            id: "a".to_string(),
+        };
+
+        store.add_triple(&Triple::new(&node, &node, &node));
+
+        let mut snapshot = store.clone();
+        assert!(Arc::ptr_eq(&store.triples, &snapshot.triples));
+
+        snapshot.remove_triple(&Triple::new(&node, &node, &node));
+        assert!(!Arc::ptr_eq(&store.triples, &snapshot.triples));
+
+        assert_eq!(store.count(), 1);
+        assert!(snapshot.is_empty());
+    }
+
+    #[test]
+    fn indexes_follow_added_and_removed_triples() {
+        let mut store = TripleStore::new();
+
+        let node = |id: &str| Node::BlankNode { id: id.to_string() };
+        let (a, b, c) = (node("a"), node("b"), node("c"));
+
+        store.insert_batch(vec![Triple::new(&a, &b, &c), Triple::new(&a, &b, &c)]);
+        store.add_triple(&Triple::new(&a, &c, &b));
+
+        assert!(store.contains(&Triple::new(&a, &b, &c)));
+        assert!(store.contains_matching(None, Some(&c), Some(&b)));
+        assert!(!store.contains_matching(Some(&b), None, None));
+
+        store.remove_triple(&Triple::new(&a, &b, &c));
+
+        assert_eq!(store.count(), 1);
+        assert!(!store.contains(&Triple::new(&a, &b, &c)));
+        assert!(!store.contains_matching(Some(&a), Some(&b), None));
+        assert!(store.contains_matching(Some(&a), None, None));
+
+        store.remove_triple(&Triple::new(&a, &c, &b));
+
+        assert!(!store.contains_matching(None, None, None));
+        assert!(store.index.spo.is_empty() && store.index.pos.is_empty());
+        assert!(store.index.ids.is_empty());
+    }
+
+    #[test]
+    fn blank_nodes_include_those_of_quoted_triples() {
+        let mut store = TripleStore::new();
+
+        let node = |id: &str| Node::BlankNode { id: id.to_string() };
+        let (a, b) = (node("a"), node("b"));
+        let quoted = Node::TripleNode {
+            triple: Box::new(Triple::new(&a, &b, &b)),
+        };
+
+        store.add_triple(&Triple::new(&quoted, &b, &a));
+
+        assert_eq!(store.get_blank_nodes(), vec![&a, &b, &b, &b, &a]);
+    }
+
+    #[test]
+    fn lookups_use_positions_that_follow_removed_triples() {
+        let mut store = TripleStore::new();
+
+        let node = |id: &str| Node::BlankNode { id: id.to_string() };
+        let (a, b, c) = (node("a"), node("b"), node("c"));
+        let (abc, bbc, acb) = (
+            Triple::new(&a, &b, &c),
+            Triple::new(&b, &b, &c),
+            Triple::new(&a, &c, &b),
+        );
+
+        store.insert_batch(vec![abc.clone(), bbc.clone(), abc.clone(), acb.clone()]);
+
+        assert_eq!(store.count(), 3);
+        assert_eq!(store.get_triples_with_subject(&a), vec![&abc, &acb]);
+        assert_eq!(store.get_triples_with_object(&c), vec![&abc, &bbc]);
+
+        store.remove_triple(&abc);
+
+        assert_eq!(store.iter().collect::<Vec<_>>(), vec![&acb, &bbc]);
+        assert_eq!(store.get_triples_with_subject(&a), vec![&acb]);
+        assert_eq!(store.get_triples_with_predicate(&b), vec![&bbc]);
+        assert_eq!(
+            store.get_triples_with_subject_and_predicate(&a, &c),
+            vec![&acb]
+        );
+        assert_eq!(
+            store.get_triples_with_predicate_and_object(&b, &c),
+            vec![&bbc]
+        );
+        assert!(store.get_triples_with_subject_and_object(&a, &c).is_empty());
+    }
 }
@@ -1,6 +1,6 @@
 // todo: implement
 
-#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
 pub struct Uri {
     uri: String,
 }
@@ -19,6 +19,612 @@ impl Uri {
     /// todo
     pub fn append_resource_path(&mut self, path: &str) {
         // todo: check if URI ends with '/', if not add '/'
-        self.uri.push_str(&path.to_string());
+        self.uri.push_str(path);
+    }
+
+    /// Converts the IRI to an URI that only contains ASCII characters.
+    ///
+    /// Follows the mapping of RFC 3987: non-ASCII labels of the host are encoded with Punycode,
+    /// all other non-ASCII characters are percent-encoded as UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::uri::Uri;
+    ///
+    /// let iri = Uri::new("http://bücher.example/straße?q=ü#ä".to_string());
+    ///
+    /// assert_eq!(iri.to_ascii_uri().to_string(),
+    ///            "http://xn--bcher-kva.example/stra%C3%9Fe?q=%C3%BC#%C3%A4");
+    /// ```
+    pub fn to_ascii_uri(&self) -> Uri {
+        let (before_host, host, after_host) = split_host(&self.uri);
+        let mut ascii_uri = percent_encode(before_host);
+
+        for (i, label) in host.split('.').enumerate() {
+            if i > 0 {
+                ascii_uri.push('.');
+            }
+
+            match punycode_encode(&label.to_lowercase()) {
+                Some(ref encoded) if !label.is_ascii() => {
+                    ascii_uri.push_str("xn--");
+                    ascii_uri.push_str(encoded);
+                }
+                _ => ascii_uri.push_str(&percent_encode(label)),
+            }
+        }
+
+        ascii_uri.push_str(&percent_encode(after_host));
+        Uri::new(ascii_uri)
+    }
+
+    /// Converts an ASCII URI to an IRI.
+    ///
+    /// Follows the mapping of RFC 3987: Punycode labels of the host are decoded and
+    /// percent-encoded UTF-8 sequences of non-ASCII characters are replaced by the characters.
+    /// Percent-encoded ASCII characters stay encoded, since decoding them could change the
+    /// meaning of the URI.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::uri::Uri;
+    ///
+    /// let iri = Uri::from_ascii_uri("http://xn--bcher-kva.example/stra%C3%9Fe%2Fa");
+    ///
+    /// assert_eq!(iri.to_string(), "http://bücher.example/straße%2Fa");
+    /// ```
+    pub fn from_ascii_uri(uri: &str) -> Uri {
+        let (before_host, host, after_host) = split_host(uri);
+        let mut iri = percent_decode(before_host);
+
+        for (i, label) in host.split('.').enumerate() {
+            if i > 0 {
+                iri.push('.');
+            }
+
+            let decoded = match label.get(..4) {
+                Some(prefix) if label.len() > 4 && prefix.eq_ignore_ascii_case("xn--") => {
+                    punycode_decode(&label[4..])
+                }
+                _ => None,
+            };
+
+            match decoded {
+                Some(decoded) => iri.push_str(&decoded),
+                None => iri.push_str(&percent_decode(label)),
+            }
+        }
+
+        iri.push_str(&percent_decode(after_host));
+        Uri::new(iri)
+    }
+
+    /// Resolves a relative reference against this URI as base.
+    ///
+    /// Follows the algorithm of RFC 3986: references with scheme are absolute, other
+    /// references inherit the missing components from the base and dot segments like `./` and
+    /// `../` are removed from the resulting path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::uri::Uri;
+    ///
+    /// let base = Uri::new("http://example.org/data/people?v=1#me".to_string());
+    ///
+    /// assert_eq!(base.resolve("alice").to_string(), "http://example.org/data/alice");
+    /// assert_eq!(base.resolve("/terms").to_string(), "http://example.org/terms");
+    /// assert_eq!(base.resolve("#you").to_string(), "http://example.org/data/people?v=1#you");
+    /// assert_eq!(base.resolve("../terms/./a").to_string(), "http://example.org/terms/a");
+    /// assert_eq!(base.resolve("//example.com/x").to_string(), "http://example.com/x");
+    /// assert_eq!(base.resolve("urn:isbn:0451450523").to_string(), "urn:isbn:0451450523");
+    /// ```
+    pub fn resolve(&self, reference: &str) -> Uri {
+        let base = Components::split(&self.uri);
+        let reference = Components::split(reference);
+
+        let target = if reference.scheme.is_some() {
+            Components {
+                path: remove_dot_segments(&reference.path),
+                ..reference
+            }
+        } else if reference.authority.is_some() {
+            Components {
+                scheme: base.scheme,
+                path: remove_dot_segments(&reference.path),
+                ..reference
+            }
+        } else if reference.path.is_empty() {
+            Components {
+                path: base.path,
+                query: reference.query.or(base.query),
+                fragment: reference.fragment,
+                ..base
+            }
+        } else {
+            let path = if reference.path.starts_with('/') {
+                reference.path.to_string()
+            } else if base.authority.is_some() && base.path.is_empty() {
+                "/".to_string() + &reference.path
+            } else {
+                let directory = base.path.rfind('/').map_or("", |i| &base.path[..i + 1]);
+                directory.to_string() + &reference.path
+            };
+
+            Components {
+                scheme: base.scheme,
+                authority: base.authority,
+                path: remove_dot_segments(&path),
+                query: reference.query,
+                fragment: reference.fragment,
+            }
+        };
+
+        Uri::new(target.recompose())
+    }
+}
+
+/// Components of an URI reference as defined by RFC 3986.
+struct Components<'a> {
+    scheme: Option<&'a str>,
+    authority: Option<&'a str>,
+    path: String,
+    query: Option<&'a str>,
+    fragment: Option<&'a str>,
+}
+
+impl<'a> Components<'a> {
+    /// Splits an URI reference into its components.
+    fn split(uri: &'a str) -> Components<'a> {
+        let (rest, fragment) = match uri.find('#') {
+            Some(i) => (&uri[..i], Some(&uri[i + 1..])),
+            None => (uri, None),
+        };
+
+        let (rest, query) = match rest.find('?') {
+            Some(i) => (&rest[..i], Some(&rest[i + 1..])),
+            None => (rest, None),
+        };
+
+        let (scheme, rest) = match rest.find(':') {
+            Some(i) if is_scheme(&rest[..i]) => (Some(&rest[..i]), &rest[i + 1..]),
+            _ => (None, rest),
+        };
+
+        let (authority, path) = match rest.strip_prefix("//") {
+            Some(rest) => {
+                let end = rest.find('/').unwrap_or(rest.len());
+                (Some(&rest[..end]), &rest[end..])
+            }
+            None => (None, rest),
+        };
+
+        Components {
+            scheme,
+            authority,
+            path: path.to_string(),
+            query,
+            fragment,
+        }
+    }
+
+    /// Recomposes the components to an URI.
+    fn recompose(&self) -> String {
+        let mut uri = String::new();
+
+        if let Some(scheme) = self.scheme {
+            uri.push_str(scheme);
+            uri.push(':');
+        }
+
+        if let Some(authority) = self.authority {
+            uri.push_str("//");
+            uri.push_str(authority);
+        }
+
+        uri.push_str(&self.path);
+
+        if let Some(query) = self.query {
+            uri.push('?');
+            uri.push_str(query);
+        }
+
+        if let Some(fragment) = self.fragment {
+            uri.push('#');
+            uri.push_str(fragment);
+        }
+
+        uri
+    }
+}
+
+/// Checks if the input is a valid URI scheme, like `http` or `urn`.
+fn is_scheme(input: &str) -> bool {
+    let mut chars = input.chars();
+
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '+' || c == '-' || c == '.')
+}
+
+/// Removes the `.` and `..` segments of a path as described in section 5.2.4 of RFC 3986.
+fn remove_dot_segments(path: &str) -> String {
+    let mut input = path;
+    let mut output = String::with_capacity(path.len());
+
+    while !input.is_empty() {
+        if let Some(rest) = input.strip_prefix("../") {
+            input = rest;
+        } else if let Some(rest) = input.strip_prefix("./") {
+            input = rest;
+        } else if input.starts_with("/./") {
+            input = &input[2..];
+        } else if input == "/." {
+            input = "/";
+        } else if input.starts_with("/../") || input == "/.." {
+            input = if input == "/.." { "/" } else { &input[3..] };
+            output.truncate(output.rfind('/').unwrap_or(0));
+        } else if input == "." || input == ".." {
+            input = "";
+        } else {
+            // move the first segment including its leading '/' to the output
+            let end = input[1..].find('/').map_or(input.len(), |i| i + 1);
+            output.push_str(&input[..end]);
+            input = &input[end..];
+        }
+    }
+
+    output
+}
+
+/// Splits an URI into the part before the host, the host and the part after the host.
+///
+/// The host is empty if the URI has no authority or the host is an IP literal.
+fn split_host(uri: &str) -> (&str, &str, &str) {
+    let authority_start = match uri.find("://") {
+        Some(i) if !uri[..i].contains(['/', '?', '#']) => i + 3,
+        _ => return (uri, "", ""),
+    };
+
+    let authority_end = uri[authority_start..]
+        .find(['/', '?', '#'])
+        .map_or(uri.len(), |i| authority_start + i);
+
+    let host_start = uri[authority_start..authority_end]
+        .rfind('@')
+        .map_or(authority_start, |i| authority_start + i + 1);
+
+    if uri[host_start..].starts_with('[') {
+        return (uri, "", "");
+    }
+
+    let host_end = uri[host_start..authority_end]
+        .find(':')
+        .map_or(authority_end, |i| host_start + i);
+
+    (
+        &uri[..host_start],
+        &uri[host_start..host_end],
+        &uri[host_end..],
+    )
+}
+
+/// Percent-encodes the UTF-8 bytes of all non-ASCII characters.
+fn percent_encode(input: &str) -> String {
+    let mut output = String::with_capacity(input.len());
+
+    for c in input.chars() {
+        if c.is_ascii() {
+            output.push(c);
+        } else {
+            let mut buffer = [0; 4];
+
+            for byte in c.encode_utf8(&mut buffer).bytes() {
+                output.push_str(&format!("%{:02X}", byte));
+            }
+        }
+    }
+
+    output
+}
+
+/// Decodes percent-encoded UTF-8 sequences of non-ASCII characters.
+///
+/// Encoded ASCII characters and invalid UTF-8 sequences stay encoded.
+fn percent_decode(input: &str) -> String {
+    let bytes = input.as_bytes();
+    let mut output = String::with_capacity(input.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        // collect consecutive encoded non-ASCII bytes
+        let start = i;
+        let mut sequence = Vec::new();
+
+        while let Some(byte) = encoded_byte(&input[i..]) {
+            if byte < 0x80 {
+                break;
+            }
+
+            sequence.push(byte);
+            i += 3;
+        }
+
+        if sequence.is_empty() {
+            let c = input[i..].chars().next().unwrap_or_default();
+            output.push(c);
+            i += c.len_utf8().max(1);
+            continue;
+        }
+
+        // keep the original encoding of invalid parts
+        let mut position = 0;
+
+        while position < sequence.len() {
+            match ::std::str::from_utf8(&sequence[position..]) {
+                Ok(decoded) => {
+                    output.push_str(decoded);
+                    position = sequence.len();
+                }
+                Err(error) => {
+                    let valid = position + error.valid_up_to();
+                    output
+                        .push_str(::std::str::from_utf8(&sequence[position..valid]).unwrap_or(""));
+
+                    let invalid = valid + error.error_len().unwrap_or(sequence.len() - valid);
+                    output.push_str(&input[start + valid * 3..start + invalid * 3]);
+                    position = invalid;
+                }
+            }
+        }
+    }
+
+    output
+}
+
+/// Returns the byte of a percent-encoded octet at the start of the input.
+fn encoded_byte(input: &str) -> Option<u8> {
+    if input.len() < 3 || !input.starts_with('%') || !input.is_char_boundary(3) {
+        return None;
+    }
+
+    u8::from_str_radix(&input[1..3], 16).ok()
+}
+
+// parameters of Punycode, see RFC 3492
+const PUNYCODE_BASE: u32 = 36;
+const PUNYCODE_T_MIN: u32 = 1;
+const PUNYCODE_T_MAX: u32 = 26;
+const PUNYCODE_SKEW: u32 = 38;
+const PUNYCODE_DAMP: u32 = 700;
+const PUNYCODE_INITIAL_BIAS: u32 = 72;
+const PUNYCODE_INITIAL_N: u32 = 128;
+
+/// Encodes a label with Punycode, returns `None` on overflow.
+fn punycode_encode(input: &str) -> Option<String> {
+    let code_points: Vec<u32> = input.chars().map(|c| c as u32).collect();
+    let mut output: String = input.chars().filter(|c| c.is_ascii()).collect();
+
+    let basic_length = output.len() as u32;
+    let mut handled = basic_length;
+
+    if basic_length > 0 {
+        output.push('-');
+    }
+
+    let mut n = PUNYCODE_INITIAL_N;
+    let mut delta: u32 = 0;
+    let mut bias = PUNYCODE_INITIAL_BIAS;
+
+    while (handled as usize) < code_points.len() {
+        let m = code_points.iter().cloned().filter(|&c| c >= n).min()?;
+        delta = delta.checked_add((m - n).checked_mul(handled + 1)?)?;
+        n = m;
+
+        for &c in &code_points {
+            if c < n {
+                delta = delta.checked_add(1)?;
+            }
+
+            if c == n {
+                let mut q = delta;
+                let mut k = PUNYCODE_BASE;
+
+                loop {
+                    let t = punycode_threshold(k, bias);
+
+                    if q < t {
+                        break;
+                    }
+
+                    output.push(punycode_digit(t + (q - t) % (PUNYCODE_BASE - t)));
+                    q = (q - t) / (PUNYCODE_BASE - t);
+                    k += PUNYCODE_BASE;
+                }
+
+                output.push(punycode_digit(q));
+                bias = punycode_adapt(delta, handled + 1, handled == basic_length);
+                delta = 0;
+                handled += 1;
+            }
+        }
+
+        delta = delta.checked_add(1)?;
+        n = n.checked_add(1)?;
+    }
+
+    Some(output)
+}
+
+/// Decodes a Punycode label, returns `None` if it is invalid.
+fn punycode_decode(input: &str) -> Option<String> {
+    let (basic, extended) = match input.rfind('-') {
+        Some(i) => (&input[..i], &input[i + 1..]),
+        None => ("", input),
+    };
+
+    if !basic.is_ascii() {
+        return None;
+    }
+
+    let mut output: Vec<char> = basic.chars().collect();
+    let mut digits = extended.bytes().peekable();
+
+    let mut n = PUNYCODE_INITIAL_N;
+    let mut i: u32 = 0;
+    let mut bias = PUNYCODE_INITIAL_BIAS;
+
+    while digits.peek().is_some() {
+        let old_i = i;
+        let mut w: u32 = 1;
+        let mut k = PUNYCODE_BASE;
+
+        loop {
+            let digit = match digits.next()? {
+                byte @ b'a'..=b'z' => byte - b'a',
+                byte @ b'A'..=b'Z' => byte - b'A',
+                byte @ b'0'..=b'9' => byte - b'0' + 26,
+                _ => return None,
+            } as u32;
+
+            i = i.checked_add(digit.checked_mul(w)?)?;
+            let t = punycode_threshold(k, bias);
+
+            if digit < t {
+                break;
+            }
+
+            w = w.checked_mul(PUNYCODE_BASE - t)?;
+            k += PUNYCODE_BASE;
+        }
+
+        let length = output.len() as u32 + 1;
+        bias = punycode_adapt(i - old_i, length, old_i == 0);
+        n = n.checked_add(i / length)?;
+        i %= length;
+
+        output.insert(i as usize, ::std::char::from_u32(n)?);
+        i += 1;
+    }
+
+    Some(output.into_iter().collect())
+}
+
+/// Returns the threshold of the digit at position `k`.
+fn punycode_threshold(k: u32, bias: u32) -> u32 {
+    if k <= bias {
+        PUNYCODE_T_MIN
+    } else if k >= bias + PUNYCODE_T_MAX {
+        PUNYCODE_T_MAX
+    } else {
+        k - bias
+    }
+}
+
+/// Returns the character of a Punycode digit.
+fn punycode_digit(digit: u32) -> char {
+    if digit < 26 {
+        (b'a' + digit as u8) as char
+    } else {
+        (b'0' + (digit - 26) as u8) as char
+    }
+}
+
+/// Adapts the bias after a code point has been encoded or decoded.
+fn punycode_adapt(delta: u32, code_points: u32, first: bool) -> u32 {
+    let mut delta = if first {
+        delta / PUNYCODE_DAMP
+    } else {
+        delta / 2
+    };
+    delta += delta / code_points;
+
+    let mut k = 0;
+
+    while delta > ((PUNYCODE_BASE - PUNYCODE_T_MIN) * PUNYCODE_T_MAX) / 2 {
+        delta /= PUNYCODE_BASE - PUNYCODE_T_MIN;
+        k += PUNYCODE_BASE;
+    }
+
+    k + (PUNYCODE_BASE - PUNYCODE_T_MIN + 1) * delta / (delta + PUNYCODE_SKEW)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::uri::*;
+
+    #[test]
+    fn punycode_round_trip() {
+        let labels = vec![
+            ("münchen", "mnchen-3ya"),
+            ("例え", "r8jz45g"),
+            ("bücher", "bcher-kva"),
+        ];
+
+        for (label, encoded) in labels {
+            assert_eq!(punycode_encode(label), Some(encoded.to_string()));
+            assert_eq!(punycode_decode(encoded), Some(label.to_string()));
+        }
+
+        assert_eq!(punycode_decode("a-b!"), None);
+    }
+
+    #[test]
+    fn ascii_uri_round_trip_keeps_port_and_invalid_encodings() {
+        let iri = Uri::new("http://user@例え.テスト:8080/über?x=%FF%C3".to_string());
+        let uri = iri.to_ascii_uri();
+
+        assert_eq!(
+            uri.to_string(),
+            "http://user@xn--r8jz45g.xn--zckzah:8080/%C3%BCber?x=%FF%C3"
+        );
+        assert_eq!(Uri::from_ascii_uri(uri.to_string()), iri);
+        assert_eq!(
+            Uri::new("urn:ex:ä".to_string()).to_ascii_uri().to_string(),
+            "urn:ex:%C3%A4"
+        );
+    }
+
+    #[test]
+    fn from_ascii_uri_keeps_non_ascii_labels() {
+        assert_eq!(
+            Uri::from_ascii_uri("http://abcé.example/").to_string(),
+            "http://abcé.example/"
+        );
+        assert_eq!(
+            Uri::from_ascii_uri("http://é.xn--bcher-kva.example/").to_string(),
+            "http://é.bücher.example/"
+        );
+    }
+
+    #[test]
+    fn resolve_rfc_3986_examples() {
+        let base = Uri::new("http://a/b/c/d;p?q".to_string());
+        let examples = vec![
+            ("g:h", "g:h"),
+            ("./g", "http://a/b/c/g"),
+            ("g/", "http://a/b/c/g/"),
+            ("//g", "http://g"),
+            ("?y", "http://a/b/c/d;p?y"),
+            ("g?y#s", "http://a/b/c/g?y#s"),
+            ("", "http://a/b/c/d;p?q"),
+            (".", "http://a/b/c/"),
+            ("..", "http://a/b/"),
+            ("../..", "http://a/"),
+            ("../../../g", "http://a/g"),
+            ("/./g", "http://a/g"),
+            ("g/../h", "http://a/b/c/h"),
+            ("g;x=1/./y", "http://a/b/c/g;x=1/y"),
+        ];
+
+        for (reference, expected) in examples {
+            assert_eq!(
+                base.resolve(reference).to_string(),
+                expected,
+                "{}",
+                reference
+            );
+        }
     }
 }
@@ -0,0 +1,373 @@
+use crate::dataset::Dataset;
+use crate::graph::Graph;
+use crate::node::Node;
+use crate::specs::rdf_syntax_specs::RdfSyntaxDataTypes;
+use crate::specs::void_specs::VoidVocabulary;
+use crate::specs::xml_specs::XmlDataTypes;
+use crate::triple::{push_blank_nodes, Triple};
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+/// Statistics of a graph that correspond to the VoID dataset properties.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct GraphStatistics {
+    triples: usize,
+    properties: usize,
+    classes: usize,
+    distinct_subjects: usize,
+    distinct_objects: usize,
+}
+
+impl GraphStatistics {
+    /// Computes the statistics of the graph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::Graph;
+    /// use rdf::triple::Triple;
+    /// use rdf::uri::Uri;
+    /// use rdf::void::GraphStatistics;
+    ///
+    /// let mut graph = Graph::new(None);
+    ///
+    /// let rdf_type = graph.create_uri_node(&Uri::new("http://www.w3.org/1999/02/22-rdf-syntax-ns#type".to_string()));
+    /// let person = graph.create_uri_node(&Uri::new("http://xmlns.com/foaf/0.1/Person".to_string()));
+    /// let a = graph.create_blank_node();
+    /// let b = graph.create_blank_node();
+    ///
+    /// graph.add_triple(&Triple::new(&a, &rdf_type, &person));
+    /// graph.add_triple(&Triple::new(&b, &rdf_type, &person));
+    ///
+    /// let statistics = GraphStatistics::new(&graph);
+    ///
+    /// assert_eq!(statistics.triples(), 2);
+    /// assert_eq!(statistics.distinct_subjects(), 2);
+    /// assert_eq!(statistics.classes(), 1);
+    /// ```
+    pub fn new(graph: &Graph) -> GraphStatistics {
+        let mut counter = Counter::default();
+
+        for triple in graph.triples_iter() {
+            counter.add(triple);
+        }
+
+        counter.statistics()
+    }
+
+    /// Returns the number of triples, `void:triples`.
+    pub fn triples(&self) -> usize {
+        self.triples
+    }
+
+    /// Returns the number of distinct predicates, `void:properties`.
+    pub fn properties(&self) -> usize {
+        self.properties
+    }
+
+    /// Returns the number of distinct objects of `rdf:type` triples, `void:classes`.
+    pub fn classes(&self) -> usize {
+        self.classes
+    }
+
+    /// Returns the number of distinct subjects, `void:distinctSubjects`.
+    pub fn distinct_subjects(&self) -> usize {
+        self.distinct_subjects
+    }
+
+    /// Returns the number of distinct objects, `void:distinctObjects`.
+    pub fn distinct_objects(&self) -> usize {
+        self.distinct_objects
+    }
+
+    /// Returns a VoID description of the statistics with `dataset` as `void:Dataset`.
+    pub fn to_void(&self, dataset: &Node) -> Graph {
+        let mut graph = Graph::new(None);
+        self.describe(&mut graph, dataset);
+        graph
+    }
+
+    /// Adds the type and statistics of the dataset node to the graph.
+    fn describe(&self, graph: &mut Graph, dataset: &Node) {
+        let uri_node = |term: VoidVocabulary| Node::UriNode { uri: term.to_uri() };
+        let integer = |count: usize| Node::LiteralNode {
+            literal: count.to_string(),
+            data_type: Some(XmlDataTypes::Integer.to_uri()),
+            language: None,
+        };
+
+        let rdf_type = Node::UriNode {
+            uri: RdfSyntaxDataTypes::A.to_uri(),
+        };
+
+        graph.add_triple(&Triple::new(
+            dataset,
+            &rdf_type,
+            &uri_node(VoidVocabulary::Dataset),
+        ));
+
+        for (term, count) in [
+            (VoidVocabulary::Triples, self.triples),
+            (VoidVocabulary::Properties, self.properties),
+            (VoidVocabulary::Classes, self.classes),
+            (VoidVocabulary::DistinctSubjects, self.distinct_subjects),
+            (VoidVocabulary::DistinctObjects, self.distinct_objects),
+        ] {
+            graph.add_triple(&Triple::new(dataset, &uri_node(term), &integer(count)));
+        }
+    }
+}
+
+/// Distinct nodes of triples that are counted for `GraphStatistics`.
+#[derive(Default)]
+struct Counter<'a> {
+    triples: usize,
+    properties: HashSet<&'a Node>,
+    classes: HashSet<&'a Node>,
+    subjects: HashSet<&'a Node>,
+    objects: HashSet<&'a Node>,
+}
+
+impl<'a> Counter<'a> {
+    fn add(&mut self, triple: &'a Triple) {
+        self.triples += 1;
+        self.properties.insert(triple.predicate());
+        self.subjects.insert(triple.subject());
+        self.objects.insert(triple.object());
+
+        if let Node::UriNode { ref uri } = *triple.predicate() {
+            if *uri == RdfSyntaxDataTypes::A.to_uri() {
+                self.classes.insert(triple.object());
+            }
+        }
+    }
+
+    fn statistics(&self) -> GraphStatistics {
+        GraphStatistics {
+            triples: self.triples,
+            properties: self.properties.len(),
+            classes: self.classes.len(),
+            distinct_subjects: self.subjects.len(),
+            distinct_objects: self.objects.len(),
+        }
+    }
+}
+
+/// Statistics of a dataset, of its default graph and of each named graph.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct DatasetStatistics {
+    dataset: GraphStatistics,
+    default_graph: GraphStatistics,
+    named_graphs: BTreeMap<Node, GraphStatistics>,
+    shared_blank_nodes: BTreeMap<Node, Vec<Option<Node>>>,
+}
+
+impl DatasetStatistics {
+    /// Computes the statistics of the dataset and of each of its graphs.
+    ///
+    /// Blank nodes are scoped to the dataset, so a blank node that occurs in several graphs
+    /// is the same node and reported by `shared_blank_nodes`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::dataset::{Dataset, Quad};
+    /// use rdf::node::Node;
+    /// use rdf::triple::Triple;
+    /// use rdf::void::DatasetStatistics;
+    ///
+    /// let mut dataset = Dataset::new();
+    ///
+    /// let node = |id: &str| Node::BlankNode { id: id.to_string() };
+    /// let (g1, g2) = (node("g1"), node("g2"));
+    ///
+    /// dataset.add_quad(&Quad::new(&Triple::new(&node("a"), &node("p"), &node("b")), Some(&g1)));
+    /// dataset.add_quad(&Quad::new(&Triple::new(&node("b"), &node("p"), &node("c")), Some(&g2)));
+    ///
+    /// let statistics = DatasetStatistics::new(&dataset);
+    ///
+    /// assert_eq!(statistics.dataset().triples(), 2);
+    /// assert_eq!(statistics.named_graph(&g1).unwrap().triples(), 1);
+    /// assert_eq!(statistics.shared_blank_nodes()[&node("b")], vec![Some(g1), Some(g2)]);
+    /// ```
+    pub fn new(dataset: &Dataset) -> DatasetStatistics {
+        let mut total = Counter::default();
+        let mut graphs: BTreeMap<Node, Vec<Option<Node>>> = BTreeMap::new();
+
+        let named_graphs = dataset
+            .named_graphs()
+            .iter()
+            .map(|(name, graph)| (Some(name), graph));
+        let all_graphs = Some((None, dataset.default_graph()))
+            .into_iter()
+            .chain(named_graphs);
+
+        for (name, graph) in all_graphs {
+            let mut blank_nodes = Vec::new();
+
+            for triple in graph.triples_iter() {
+                total.add(triple);
+                push_blank_nodes(triple, &mut blank_nodes);
+            }
+
+            for blank_node in blank_nodes.into_iter().collect::<BTreeSet<_>>() {
+                graphs
+                    .entry(blank_node.clone())
+                    .or_default()
+                    .push(name.cloned());
+            }
+        }
+
+        graphs.retain(|_, names| names.len() > 1);
+
+        DatasetStatistics {
+            dataset: total.statistics(),
+            default_graph: GraphStatistics::new(dataset.default_graph()),
+            named_graphs: dataset
+                .named_graphs()
+                .iter()
+                .map(|(name, graph)| (name.clone(), GraphStatistics::new(graph)))
+                .collect(),
+            shared_blank_nodes: graphs,
+        }
+    }
+
+    /// Returns the statistics over all graphs, triples in several graphs are counted per graph.
+    pub fn dataset(&self) -> &GraphStatistics {
+        &self.dataset
+    }
+
+    /// Returns the statistics of the default graph.
+    pub fn default_graph(&self) -> &GraphStatistics {
+        &self.default_graph
+    }
+
+    /// Returns the statistics of the named graph, if it exists.
+    pub fn named_graph(&self, name: &Node) -> Option<&GraphStatistics> {
+        self.named_graphs.get(name)
+    }
+
+    /// Returns the statistics of the named graphs ordered by name.
+    pub fn named_graphs(&self) -> &BTreeMap<Node, GraphStatistics> {
+        &self.named_graphs
+    }
+
+    /// Returns the blank nodes that occur in more than one graph, each with the names of
+    /// its graphs in order, `None` for the default graph.
+    pub fn shared_blank_nodes(&self) -> &BTreeMap<Node, Vec<Option<Node>>> {
+        &self.shared_blank_nodes
+    }
+
+    /// Returns a VoID description of the dataset with each named graph as `void:subset`.
+    ///
+    /// The name of a named graph is used as the node of its subset. The default graph has
+    /// no name and is only part of the statistics of `dataset`.
+    pub fn to_void(&self, dataset: &Node) -> Graph {
+        let mut graph = self.dataset.to_void(dataset);
+        let subset = Node::UriNode {
+            uri: VoidVocabulary::Subset.to_uri(),
+        };
+
+        for (name, statistics) in &self.named_graphs {
+            graph.add_triple(&Triple::new(dataset, &subset, name));
+            statistics.describe(&mut graph, name);
+        }
+
+        graph
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dataset::{Dataset, Quad};
+    use crate::node::Node;
+    use crate::specs::rdf_syntax_specs::RdfSyntaxDataTypes;
+    use crate::specs::void_specs::VoidVocabulary;
+    use crate::specs::xml_specs::XmlDataTypes;
+    use crate::triple::Triple;
+    use crate::uri::Uri;
+    use crate::void::DatasetStatistics;
+
+    #[test]
+    fn dataset_statistics_with_void_subsets() {
+        let uri = |name: &str| Node::UriNode {
+            uri: Uri::new(format!("http://example.org/{}", name)),
+        };
+        let blank = |id: &str| Node::BlankNode { id: id.to_string() };
+        let rdf_type = Node::UriNode {
+            uri: RdfSyntaxDataTypes::A.to_uri(),
+        };
+        let (g1, g2) = (uri("g1"), uri("g2"));
+        let quoted = Node::TripleNode {
+            triple: Box::new(Triple::new(&blank("x"), &uri("p"), &uri("o"))),
+        };
+
+        let mut dataset = Dataset::new();
+        dataset.add_quad(&Quad::new(
+            &Triple::new(&blank("x"), &uri("p"), &uri("o")),
+            None,
+        ));
+        dataset.add_quad(&Quad::new(
+            &Triple::new(&blank("x"), &rdf_type, &uri("C")),
+            Some(&g1),
+        ));
+        dataset.add_quad(&Quad::new(
+            &Triple::new(&blank("y"), &rdf_type, &uri("C")),
+            Some(&g1),
+        ));
+        dataset.add_quad(&Quad::new(
+            &Triple::new(&quoted, &uri("q"), &blank("y")),
+            Some(&g2),
+        ));
+
+        let statistics = DatasetStatistics::new(&dataset);
+
+        let total = statistics.dataset();
+        assert_eq!(total.triples(), 4);
+        assert_eq!(total.properties(), 3);
+        assert_eq!(total.classes(), 1);
+        assert_eq!(total.distinct_subjects(), 3);
+        assert_eq!(total.distinct_objects(), 3);
+
+        assert_eq!(statistics.default_graph().triples(), 1);
+        assert_eq!(statistics.named_graph(&g1).unwrap().distinct_subjects(), 2);
+        assert_eq!(statistics.named_graph(&g2).unwrap().classes(), 0);
+        assert!(statistics.named_graph(&uri("g3")).is_none());
+
+        // _:x of the quoted triple is shared as well
+        assert_eq!(statistics.shared_blank_nodes().len(), 2);
+        assert_eq!(
+            statistics.shared_blank_nodes()[&blank("x")],
+            vec![None, Some(g1.clone()), Some(g2.clone())]
+        );
+        assert_eq!(
+            statistics.shared_blank_nodes()[&blank("y")],
+            vec![Some(g1.clone()), Some(g2.clone())]
+        );
+
+        let void = statistics.to_void(&uri("dataset"));
+        let term = |term: VoidVocabulary| Node::UriNode { uri: term.to_uri() };
+        let integer = |count: usize| Node::LiteralNode {
+            literal: count.to_string(),
+            data_type: Some(XmlDataTypes::Integer.to_uri()),
+            language: None,
+        };
+
+        assert_eq!(void.count(), 6 + 2 * 7);
+        assert!(void.contains(&Triple::new(
+            &uri("dataset"),
+            &term(VoidVocabulary::Subset),
+            &g1
+        )));
+        assert!(void.contains(&Triple::new(&g2, &rdf_type, &term(VoidVocabulary::Dataset))));
+        assert!(void.contains(&Triple::new(
+            &uri("dataset"),
+            &term(VoidVocabulary::Triples),
+            &integer(4)
+        )));
+        assert!(void.contains(&Triple::new(
+            &g1,
+            &term(VoidVocabulary::Triples),
+            &integer(2)
+        )));
+    }
+}
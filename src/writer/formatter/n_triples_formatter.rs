@@ -1,17 +1,25 @@
-use node::Node;
-use specs::rdf_syntax_specs::RdfSyntaxSpecs;
-use uri::Uri;
-use writer::formatter::rdf_formatter::RdfFormatter;
+use crate::node::Node;
+use crate::specs::rdf_syntax_specs::RdfSyntaxSpecs;
+use crate::uri::Uri;
+use crate::writer::formatter::rdf_formatter::RdfFormatter;
+use crate::writer::writer_options::WriterOptions;
 
 /// Formatter for formatting nodes to N-Triple syntax.
 /// This formatter is used by `NTriplesWriter`.
 #[derive(Default)]
-pub struct NTriplesFormatter {}
+pub struct NTriplesFormatter {
+    options: WriterOptions,
+}
 
 impl NTriplesFormatter {
     /// Constructor of `NTriplesFormatter`.
     pub fn new() -> NTriplesFormatter {
-        NTriplesFormatter {}
+        NTriplesFormatter::with_options(WriterOptions::new())
+    }
+
+    /// Constructor of `NTriplesFormatter` with options for writing literals.
+    pub fn with_options(options: WriterOptions) -> NTriplesFormatter {
+        NTriplesFormatter { options }
     }
 }
 
@@ -29,6 +37,7 @@ impl RdfFormatter for NTriplesFormatter {
                 ref language,
             } => self.format_literal(literal, data_type, language),
             Node::UriNode { ref uri } => self.format_uri(uri),
+            Node::TripleNode { ref triple } => self.format_triple(triple),
         }
     }
 
@@ -42,8 +51,10 @@ impl RdfFormatter for NTriplesFormatter {
         data_type: &Option<Uri>,
         language: &Option<String>,
     ) -> String {
+        let (literal, data_type) = self.options.literal_output(literal, data_type, language);
+
         let mut output_string = "\"".to_string();
-        output_string.push_str(&RdfSyntaxSpecs::escape_literal(literal));
+        output_string.push_str(&RdfSyntaxSpecs::escape_literal(&literal));
         output_string.push_str("\"");
 
         if let Some(ref lang) = *language {
@@ -51,7 +62,7 @@ impl RdfFormatter for NTriplesFormatter {
             output_string.push_str(lang);
         }
 
-        if let Some(ref dt) = *data_type {
+        if let Some(ref dt) = data_type {
             output_string.push_str("^^");
             output_string.push_str(&self.format_uri(dt));
         }
@@ -76,10 +87,10 @@ impl RdfFormatter for NTriplesFormatter {
 
 #[cfg(test)]
 mod tests {
-    use node::*;
-    use uri::Uri;
-    use writer::formatter::n_triples_formatter::NTriplesFormatter;
-    use writer::formatter::rdf_formatter::RdfFormatter;
+    use crate::node::*;
+    use crate::uri::Uri;
+    use crate::writer::formatter::n_triples_formatter::NTriplesFormatter;
+    use crate::writer::formatter::rdf_formatter::RdfFormatter;
 
     #[test]
     fn test_n_triples_blank_node_formatting() {
@@ -142,7 +153,7 @@ mod tests {
 
         assert_eq!(
             formatter.format_node(&node),
-            "\"literal \' \" \"".to_string()
+            "\"literal ' \\\" \"".to_string()
         );
     }
 
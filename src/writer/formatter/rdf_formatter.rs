@@ -1,5 +1,6 @@
-use node::Node;
-use uri::Uri;
+use crate::node::Node;
+use crate::triple::Triple;
+use crate::uri::Uri;
 
 /// Trait implemented by RDF formatters for formatting nodes.
 pub trait RdfFormatter {
@@ -19,4 +20,15 @@ pub trait RdfFormatter {
 
     /// Formats a URI.
     fn format_uri(&self, uri: &Uri) -> String;
+
+    /// Formats a quoted triple as `<< subject predicate object >>`.
+    fn format_triple(&self, triple: &Triple) -> String {
+        "<< ".to_string()
+            + &self.format_node(triple.subject())
+            + " "
+            + &self.format_node(triple.predicate())
+            + " "
+            + &self.format_node(triple.object())
+            + " >>"
+    }
 }
@@ -1,20 +1,33 @@
-use node::Node;
-use specs::rdf_syntax_specs::RdfSyntaxSpecs;
-use specs::turtle_specs::TurtleSpecs;
+use crate::node::Node;
+use crate::specs::rdf_syntax_specs::RdfSyntaxSpecs;
+use crate::specs::turtle_specs::TurtleSpecs;
+use crate::uri::Uri;
+use crate::writer::formatter::rdf_formatter::RdfFormatter;
+use crate::writer::writer_options::WriterOptions;
 use std::collections::HashMap;
-use uri::Uri;
-use writer::formatter::rdf_formatter::RdfFormatter;
 
 /// Formatter for formatting nodes to Turtle syntax.
 /// This formatter is used by `TurtleWriter`.
 pub struct TurtleFormatter<'a> {
     namespaces: &'a HashMap<String, Uri>,
+    options: WriterOptions,
 }
 
 impl<'a> TurtleFormatter<'a> {
     /// Constructor of `TurtleFormatter`.
     pub fn new(namespaces: &'a HashMap<String, Uri>) -> TurtleFormatter<'a> {
-        TurtleFormatter { namespaces }
+        TurtleFormatter::with_options(namespaces, WriterOptions::new())
+    }
+
+    /// Constructor of `TurtleFormatter` with options for writing literals.
+    pub fn with_options(
+        namespaces: &'a HashMap<String, Uri>,
+        options: WriterOptions,
+    ) -> TurtleFormatter<'a> {
+        TurtleFormatter {
+            namespaces,
+            options,
+        }
     }
 }
 
@@ -32,6 +45,7 @@ impl<'a> RdfFormatter for TurtleFormatter<'a> {
                 ref language,
             } => self.format_literal(literal, data_type, language),
             Node::UriNode { ref uri } => self.format_uri(uri),
+            Node::TripleNode { ref triple } => self.format_triple(triple),
         }
     }
 
@@ -44,23 +58,37 @@ impl<'a> RdfFormatter for TurtleFormatter<'a> {
         data_type: &Option<Uri>,
         language: &Option<String>,
     ) -> String {
+        let (literal, data_type) = self.options.literal_output(literal, data_type, language);
+        let literal = literal.as_str();
+
+        if self.options.numeric_shorthand()
+            && language.is_none()
+            && TurtleSpecs::is_numeric_shorthand(literal, &data_type)
+        {
+            return literal.to_string();
+        }
+
         let mut output_string = "".to_string();
 
-        if TurtleSpecs::is_boolean_literal(literal) && *language == None && *data_type == None {
+        if TurtleSpecs::is_boolean_literal(literal) && language.is_none() && data_type.is_none() {
             // some number or boolean
             output_string.push_str(literal);
+        } else if self.options.long_strings() && TurtleSpecs::is_long_string_candidate(literal) {
+            output_string.push_str("\"\"\"");
+            output_string.push_str(&TurtleSpecs::escape_long_literal(literal));
+            output_string.push_str("\"\"\"");
         } else {
-            output_string.push_str("\"");
+            output_string.push('"');
             output_string.push_str(&RdfSyntaxSpecs::escape_literal(literal));
-            output_string.push_str("\"");
+            output_string.push('"');
         }
 
         if let Some(ref lang) = *language {
-            output_string.push_str("@");
+            output_string.push('@');
             output_string.push_str(lang);
         }
 
-        if let Some(ref dt) = *data_type {
+        if let Some(ref dt) = data_type {
             output_string.push_str("^^");
             output_string.push_str(&self.format_uri(dt));
         }
@@ -77,25 +105,25 @@ impl<'a> RdfFormatter for TurtleFormatter<'a> {
     fn format_uri(&self, uri: &Uri) -> String {
         let mut output_string = "".to_string();
 
-        // write QName if namespace for URI exists
+        // write QName if namespace for URI exists and the rest can be written as local name
         for (prefix, namespace_uri) in self.namespaces.iter() {
-            if uri.to_string().starts_with(namespace_uri.to_string()) {
-                output_string.push_str(prefix);
-                output_string.push_str(":");
+            let local_name = uri
+                .to_string()
+                .strip_prefix(namespace_uri.to_string().as_str())
+                .and_then(TurtleSpecs::escape_local_name);
 
-                let path = uri.to_string()
-                    .to_owned()
-                    .replace(namespace_uri.to_string(), "")
-                    .replace("/", ":");
-                output_string.push_str(&path);
+            if let Some(local_name) = local_name {
+                output_string.push_str(prefix);
+                output_string.push(':');
+                output_string.push_str(&local_name);
 
                 return output_string;
             }
         }
 
-        output_string.push_str("<");
+        output_string.push('<');
         output_string.push_str(uri.to_string());
-        output_string.push_str(">");
+        output_string.push('>');
 
         output_string
     }
@@ -103,12 +131,13 @@ impl<'a> RdfFormatter for TurtleFormatter<'a> {
 
 #[cfg(test)]
 mod tests {
-    use node::*;
-    use specs::xml_specs::XmlDataTypes;
+    use crate::node::*;
+    use crate::specs::xml_specs::XmlDataTypes;
+    use crate::uri::Uri;
+    use crate::writer::formatter::rdf_formatter::RdfFormatter;
+    use crate::writer::formatter::turtle_formatter::TurtleFormatter;
+    use crate::writer::writer_options::{StringDataType, WriterOptions};
     use std::collections::HashMap;
-    use uri::Uri;
-    use writer::formatter::rdf_formatter::RdfFormatter;
-    use writer::formatter::turtle_formatter::TurtleFormatter;
 
     #[test]
     fn test_turtle_blank_node_formatting() {
@@ -150,7 +179,7 @@ mod tests {
 
         assert_eq!(
             formatter.format_node(&node),
-            "example:show:localName".to_string()
+            "example:show\\/localName".to_string()
         );
     }
 
@@ -179,7 +208,7 @@ mod tests {
 
         assert_eq!(
             formatter.format_node(&node),
-            "\"literal \' \" \"".to_string()
+            "\"literal ' \\\" \"".to_string()
         );
     }
 
@@ -259,4 +288,46 @@ mod tests {
             "\"123.123\"^^<http://www.w3.org/2001/XMLSchema#decimal>".to_string()
         );
     }
+
+    #[test]
+    fn test_turtle_literal_formatting_with_options() {
+        let hashmap = HashMap::new();
+
+        let mut options = WriterOptions::new();
+        options.set_string_data_type(StringDataType::Omitted);
+        options.set_numeric_shorthand(true);
+        options.set_canonical_forms(true);
+
+        let formatter = TurtleFormatter::with_options(&hashmap, options);
+        let literal = |literal: &str, data_type: XmlDataTypes| Node::LiteralNode {
+            literal: literal.to_string(),
+            data_type: Some(data_type.to_uri()),
+            language: None,
+        };
+
+        assert_eq!(
+            formatter.format_node(&literal("a", XmlDataTypes::String)),
+            "\"a\""
+        );
+        assert_eq!(
+            formatter.format_node(&literal("-0", XmlDataTypes::Integer)),
+            "0"
+        );
+        assert_eq!(
+            formatter.format_node(&literal("1.50", XmlDataTypes::Decimal)),
+            "1.5"
+        );
+        assert_eq!(
+            formatter.format_node(&literal("15", XmlDataTypes::Double)),
+            "1.5E1"
+        );
+        assert_eq!(
+            formatter.format_node(&literal("INF", XmlDataTypes::Double)),
+            "\"INF\"^^<http://www.w3.org/2001/XMLSchema#double>"
+        );
+        assert_eq!(
+            formatter.format_node(&literal("1", XmlDataTypes::Long)),
+            "\"1\"^^<http://www.w3.org/2001/XMLSchema#long>"
+        );
+    }
 }
@@ -0,0 +1,399 @@
+use crate::error::{Error, ErrorType};
+use crate::graph::Graph;
+use crate::jsonld::Context;
+use crate::node::Node;
+use crate::specs::json_specs::JsonSpecs;
+use crate::specs::rdf_syntax_specs::RdfSyntaxDataTypes;
+use crate::triple::Triple;
+use crate::writer::rdf_writer::RdfWriter;
+use crate::Result;
+use std::collections::BTreeMap;
+
+/// RDF writer to generate JSON-LD.
+///
+/// Without context the graph is written in expanded form, with a context the IRIs are compacted
+/// to terms and compact IRIs and values are written as strings where the terms coerce them.
+#[derive(Default)]
+pub struct JsonLdWriter {
+    context: Option<Context>,
+}
+
+impl RdfWriter for JsonLdWriter {
+    /// Generates JSON-LD with a node object for each subject of the provided graph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::Graph;
+    /// use rdf::triple::Triple;
+    /// use rdf::uri::Uri;
+    /// use rdf::writer::json_ld_writer::JsonLdWriter;
+    /// use rdf::writer::rdf_writer::RdfWriter;
+    ///
+    /// let mut graph = Graph::new(None);
+    ///
+    /// let subject = graph.create_uri_node(&Uri::new("http://example.org/art".to_string()));
+    /// let predicate = graph.create_uri_node(&Uri::new("http://xmlns.com/foaf/0.1/name".to_string()));
+    /// let object = graph.create_literal_node_with_language("Art".to_string(), "en".to_string());
+    ///
+    /// graph.add_triple(&Triple::new(&subject, &predicate, &object));
+    ///
+    /// assert_eq!(JsonLdWriter::new().write_to_string(&graph).unwrap(),
+    ///            "[\n  {\"@id\": \"http://example.org/art\", \
+    ///                   \"http://xmlns.com/foaf/0.1/name\": [{\"@value\": \"Art\", \"@language\": \"en\"}]}\n]");
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - Literals or quoted triples as subject or object.
+    /// - Predicates that are not URIs.
+    ///
+    fn write_to_string(&self, graph: &Graph) -> Result<String> {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("write", format = "JSON-LD").entered();
+
+        let mut triples: Vec<&Triple> = graph.triples_iter().collect();
+        triples.sort();
+
+        // triples grouped by subject
+        let mut subjects: Vec<(&Node, Vec<&Triple>)> = Vec::new();
+
+        for triple in triples {
+            match subjects.last_mut() {
+                Some(&mut (subject, ref mut triples)) if subject == triple.subject() => {
+                    triples.push(triple)
+                }
+                _ => subjects.push((triple.subject(), vec![triple])),
+            }
+        }
+
+        let mut node_objects = Vec::new();
+
+        for (subject, triples) in subjects {
+            node_objects.push(self.node_object(subject, &triples)?);
+        }
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(triples = graph.count(), "Wrote JSON-LD");
+
+        let output = match self.context {
+            Some(ref context) => {
+                let context = context.to_json();
+
+                // the `@graph` member is added to the object with the `@context` member
+                context[..context.len() - 1].to_string()
+                    + ",\n\"@graph\": ["
+                    + &join_node_objects(&node_objects)
+                    + "]}"
+            }
+            None => "[".to_string() + &join_node_objects(&node_objects) + "]",
+        };
+
+        Ok(output)
+    }
+}
+
+impl JsonLdWriter {
+    /// Constructor of `JsonLdWriter` that writes expanded JSON-LD.
+    pub fn new() -> JsonLdWriter {
+        JsonLdWriter::default()
+    }
+
+    /// Constructor of `JsonLdWriter` that compacts the output against the context.
+    ///
+    /// The context is written as `@context` of the document and the node objects are written
+    /// in its `@graph`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::graph::Graph;
+    /// use rdf::jsonld;
+    /// use rdf::namespace::Namespace;
+    /// use rdf::triple::Triple;
+    /// use rdf::uri::Uri;
+    /// use rdf::writer::json_ld_writer::JsonLdWriter;
+    /// use rdf::writer::rdf_writer::RdfWriter;
+    ///
+    /// let mut graph = Graph::new(None);
+    /// graph.add_namespace(&Namespace::new("foaf".to_string(), Uri::new("http://xmlns.com/foaf/0.1/".to_string())));
+    ///
+    /// let art = graph.create_uri_node(&Uri::new("http://example.org/art".to_string()));
+    /// let dan = graph.create_blank_node();
+    /// let knows = graph.create_uri_node(&Uri::new("http://xmlns.com/foaf/0.1/knows".to_string()));
+    ///
+    /// graph.add_triple(&Triple::new(&art, &knows, &dan));
+    ///
+    /// let writer = JsonLdWriter::with_context(jsonld::derive_context(&graph));
+    ///
+    /// assert_eq!(writer.write_to_string(&graph).unwrap(),
+    ///            "{\"@context\": {\n  \
+    ///               \"foaf\": \"http://xmlns.com/foaf/0.1/\",\n  \
+    ///               \"knows\": {\"@id\": \"foaf:knows\", \"@type\": \"@id\"}\n},\n\
+    ///             \"@graph\": [\n  {\"@id\": \"http://example.org/art\", \"knows\": \"_:auto0\"}\n]}");
+    /// ```
+    pub fn with_context(context: Context) -> JsonLdWriter {
+        JsonLdWriter {
+            context: Some(context),
+        }
+    }
+
+    /// Returns the node object with all triples of a subject.
+    fn node_object(&self, subject: &Node, triples: &[&Triple]) -> Result<String> {
+        let rdf_type = Node::UriNode {
+            uri: RdfSyntaxDataTypes::A.to_uri(),
+        };
+
+        let mut types = Vec::new();
+        let mut properties: BTreeMap<String, Vec<String>> = BTreeMap::new();
+
+        for triple in triples {
+            let object = triple.object();
+
+            if *triple.predicate() == rdf_type && self.is_resource(object) {
+                types.push(JsonSpecs::escape_string(&self.id(object)?));
+                continue;
+            }
+
+            let (key, value) = self.property_value(triple.predicate(), object)?;
+            properties.entry(key).or_default().push(value);
+        }
+
+        let mut members =
+            vec!["\"@id\": ".to_string() + &JsonSpecs::escape_string(&self.id(subject)?)];
+
+        if !types.is_empty() {
+            members.push("\"@type\": ".to_string() + &self.values(types));
+        }
+
+        for (key, values) in properties {
+            members.push(JsonSpecs::escape_string(&key) + ": " + &self.values(values));
+        }
+
+        Ok("{".to_string() + &members.join(", ") + "}")
+    }
+
+    /// Returns if the node can be written as `@id`.
+    fn is_resource(&self, node: &Node) -> bool {
+        matches!(*node, Node::UriNode { .. } | Node::BlankNode { .. })
+    }
+
+    /// Returns the compacted IRI or blank node identifier of a subject or object.
+    fn id(&self, node: &Node) -> Result<String> {
+        match *node {
+            Node::UriNode { ref uri } => Ok(self.compact_iri(uri.to_string())),
+            Node::BlankNode { ref id } => Ok("_:".to_string() + id),
+            Node::LiteralNode { .. } => Err(Error::new(
+                ErrorType::InvalidWriterOutput,
+                "Literals are not allowed as subjects in JSON-LD.",
+            )),
+            Node::TripleNode { .. } => Err(Error::new(
+                ErrorType::InvalidWriterOutput,
+                "Quoted triples are not supported in JSON-LD.",
+            )),
+        }
+    }
+
+    /// Returns the key of the property and the JSON value of the object.
+    ///
+    /// The key is a term of the context if the term coerces the values to the type of the
+    /// object, otherwise the value is written with its type or language.
+    fn property_value(&self, predicate: &Node, object: &Node) -> Result<(String, String)> {
+        let predicate = match *predicate {
+            Node::UriNode { ref uri } => uri,
+            _ => {
+                return Err(Error::new(
+                    ErrorType::InvalidWriterOutput,
+                    "Predicates have to be URIs in JSON-LD.",
+                ))
+            }
+        };
+
+        // type coercion of the value if written with the key
+        let coercion = match *object {
+            Node::LiteralNode {
+                data_type: Some(ref data_type),
+                language: None,
+                ..
+            } => Some(data_type.to_string().clone()),
+            Node::LiteralNode { .. } => None,
+            _ => Some("@id".to_string()),
+        };
+
+        let term = self.context.as_ref().and_then(|context| {
+            context.terms().iter().find(|&(_, definition)| {
+                definition.iri() == predicate
+                    && (definition.type_coercion().is_none()
+                        || *definition.type_coercion() == coercion)
+            })
+        });
+
+        let key = match term {
+            Some((term, _)) => term.clone(),
+            None => self.compact_iri(predicate.to_string()),
+        };
+
+        let value = match (
+            term.and_then(|(_, definition)| definition.type_coercion().as_ref()),
+            object,
+        ) {
+            (Some(_), Node::LiteralNode { literal, .. }) => JsonSpecs::escape_string(literal),
+            (Some(_), _) => JsonSpecs::escape_string(&self.id(object)?),
+            (
+                None,
+                Node::LiteralNode {
+                    literal,
+                    data_type,
+                    language,
+                },
+            ) => {
+                let mut members =
+                    vec!["\"@value\": ".to_string() + &JsonSpecs::escape_string(literal)];
+
+                if let Some(ref data_type) = *data_type {
+                    members.push(
+                        "\"@type\": ".to_string()
+                            + &JsonSpecs::escape_string(&self.compact_iri(data_type.to_string())),
+                    );
+                }
+
+                if let Some(ref language) = *language {
+                    members
+                        .push("\"@language\": ".to_string() + &JsonSpecs::escape_string(language));
+                }
+
+                // plain strings need no value object in compacted JSON-LD
+                if self.context.is_some() && members.len() == 1 {
+                    JsonSpecs::escape_string(literal)
+                } else {
+                    "{".to_string() + &members.join(", ") + "}"
+                }
+            }
+            (None, _) => {
+                "{\"@id\": ".to_string() + &JsonSpecs::escape_string(&self.id(object)?) + "}"
+            }
+        };
+
+        Ok((key, value))
+    }
+
+    /// Returns the values of a member, compacted output writes single values without array.
+    fn values(&self, values: Vec<String>) -> String {
+        if self.context.is_some() && values.len() == 1 {
+            return values.into_iter().next().unwrap_or_default();
+        }
+
+        "[".to_string() + &values.join(", ") + "]"
+    }
+
+    /// Returns the IRI as compact IRI if a context is used.
+    fn compact_iri(&self, iri: &str) -> String {
+        match self.context {
+            Some(ref context) => context.compact_iri(iri),
+            None => iri.to_string(),
+        }
+    }
+}
+
+/// Returns the node objects with one node object per line.
+fn join_node_objects(node_objects: &[String]) -> String {
+    if node_objects.is_empty() {
+        return String::new();
+    }
+
+    "\n  ".to_string() + &node_objects.join(",\n  ") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::Graph;
+    use crate::jsonld;
+    use crate::namespace::Namespace;
+    use crate::reader::jsonld_parser::JsonLdParser;
+    use crate::reader::rdf_parser::RdfParser;
+    use crate::specs::rdf_syntax_specs::RdfSyntaxDataTypes;
+    use crate::specs::xml_specs::XmlDataTypes;
+    use crate::triple::Triple;
+    use crate::uri::Uri;
+    use crate::writer::json_ld_writer::JsonLdWriter;
+    use crate::writer::rdf_writer::RdfWriter;
+
+    #[test]
+    fn write_compacted_json_ld_with_types_and_mixed_values() {
+        let mut graph = Graph::new(None);
+        graph.add_namespace(&Namespace::new(
+            "ex".to_string(),
+            Uri::new("http://example.org/".to_string()),
+        ));
+
+        let a = graph.create_uri_node(&Uri::new("http://example.org/a".to_string()));
+        let b = graph.create_blank_node();
+        let rdf_type = graph.create_uri_node(&RdfSyntaxDataTypes::A.to_uri());
+        let class = graph.create_uri_node(&Uri::new("http://example.org/Task".to_string()));
+        let size = graph.create_uri_node(&Uri::new("http://example.org/size".to_string()));
+        let label = graph.create_uri_node(&Uri::new("http://example.org/label".to_string()));
+
+        graph.add_triple(&Triple::new(&a, &rdf_type, &class));
+        graph.add_triple(&Triple::new(
+            &a,
+            &size,
+            &graph.create_literal_node_with_data_type(
+                "2".to_string(),
+                &XmlDataTypes::Integer.to_uri(),
+            ),
+        ));
+        graph.add_triple(&Triple::new(&a, &label, &b));
+        graph.add_triple(&Triple::new(
+            &a,
+            &label,
+            &graph.create_literal_node_with_language("Aufgabe \"A\"".to_string(), "de".to_string()),
+        ));
+        graph.add_triple(&Triple::new(
+            &b,
+            &label,
+            &graph.create_literal_node("b".to_string()),
+        ));
+
+        let writer = JsonLdWriter::with_context(jsonld::derive_context(&graph));
+        let output = writer.write_to_string(&graph).unwrap();
+
+        assert_eq!(
+            output,
+            "{\"@context\": {\n  \
+               \"ex\": \"http://example.org/\",\n  \
+               \"label\": \"ex:label\",\n  \
+               \"size\": {\"@id\": \"ex:size\", \"@type\": \"http://www.w3.org/2001/XMLSchema#integer\"}\n},\n\
+             \"@graph\": [\n  \
+               {\"@id\": \"ex:a\", \"@type\": \"ex:Task\", \
+                 \"label\": [{\"@value\": \"Aufgabe \\\"A\\\"\", \"@language\": \"de\"}, {\"@id\": \"_:auto0\"}], \
+                 \"size\": \"2\"},\n  \
+               {\"@id\": \"_:auto0\", \"label\": \"b\"}\n\
+             ]}"
+        );
+
+        let reparsed = JsonLdParser::from_string(output).decode().unwrap();
+        let mut expected: Vec<Triple> = graph.triples_iter().cloned().collect();
+        let mut triples: Vec<Triple> = reparsed.triples_iter().cloned().collect();
+        expected.sort();
+        triples.sort();
+
+        assert_eq!(triples, expected);
+    }
+
+    #[test]
+    fn literals_are_rejected_as_subjects() {
+        let mut graph = Graph::new(None);
+
+        let literal = graph.create_literal_node("a".to_string());
+        let predicate = graph.create_uri_node(&Uri::new("http://example.org/p".to_string()));
+        graph.add_triple(&Triple::new(&literal, &predicate, &literal));
+
+        assert!(JsonLdWriter::new().write_to_string(&graph).is_err());
+        assert_eq!(
+            JsonLdWriter::new()
+                .write_to_string(&Graph::new(None))
+                .unwrap(),
+            "[]"
+        );
+    }
+}
@@ -0,0 +1,163 @@
+use crate::dataset::{Dataset, Quad};
+use crate::error::{Error, ErrorType};
+use crate::graph::Graph;
+use crate::node::Node;
+use crate::triple::TripleSegment;
+use crate::writer::n_triples_writer::NTriplesWriter;
+use crate::writer::rdf_writer::{RdfDatasetWriter, RdfWriter};
+use crate::writer::writer_options::WriterOptions;
+use crate::Result;
+
+/// RDF writer to generate N-Quads syntax.
+///
+/// Each quad is written as N-Triples line with the graph label before the final `.`, triples
+/// of the default graph are written without graph label.
+#[derive(Default)]
+pub struct NQuadsWriter {
+    writer: NTriplesWriter,
+}
+
+impl RdfDatasetWriter for NQuadsWriter {
+    /// Generates the N-Quads syntax for each quad of the provided dataset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::dataset::{Dataset, Quad};
+    /// use rdf::node::Node;
+    /// use rdf::triple::Triple;
+    /// use rdf::uri::Uri;
+    /// use rdf::writer::n_quads_writer::NQuadsWriter;
+    /// use rdf::writer::rdf_writer::RdfDatasetWriter;
+    ///
+    /// let subject = Node::BlankNode { id: "art".to_string() };
+    /// let predicate = Node::UriNode { uri: Uri::new("http://xmlns.com/foaf/0.1/name".to_string()) };
+    /// let object = Node::LiteralNode { literal: "Art".to_string(), data_type: None, language: None };
+    /// let people = Node::UriNode { uri: Uri::new("http://example.org/people".to_string()) };
+    ///
+    /// let mut dataset = Dataset::new();
+    /// dataset.add_quad(&Quad::new(&Triple::new(&subject, &predicate, &object), Some(&people)));
+    ///
+    /// assert_eq!(NQuadsWriter::new().write_dataset_to_string(&dataset).unwrap(),
+    ///            "_:art <http://xmlns.com/foaf/0.1/name> \"Art\" <http://example.org/people> .\n");
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - Invalid triples are to be written to the output that do not conform the N-Quads syntax standard.
+    /// - A graph label is a literal or quoted triple.
+    ///
+    fn write_dataset_to_string(&self, dataset: &Dataset) -> Result<String> {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("write", format = "N-Quads").entered();
+
+        let mut output_string = "".to_string();
+
+        for quad in dataset.quads() {
+            output_string.push_str(&self.quad_to_n_quads(&quad)?);
+            output_string.push('\n');
+        }
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(quads = dataset.count(), "Wrote N-Quads");
+
+        Ok(output_string)
+    }
+}
+
+impl RdfWriter for NQuadsWriter {
+    /// Generates the N-Quads syntax of a graph, which is written as default graph.
+    ///
+    /// # Failures
+    ///
+    /// - Invalid triples are to be written to the output that do not conform the N-Quads syntax standard.
+    ///
+    fn write_to_string(&self, graph: &Graph) -> Result<String> {
+        self.writer.write_to_string(graph)
+    }
+}
+
+impl NQuadsWriter {
+    /// Constructor of `NQuadsWriter`.
+    pub fn new() -> NQuadsWriter {
+        NQuadsWriter {
+            writer: NTriplesWriter::new(),
+        }
+    }
+
+    /// Constructor of `NQuadsWriter` with options for writing literals.
+    pub fn with_options(options: WriterOptions) -> NQuadsWriter {
+        NQuadsWriter {
+            writer: NTriplesWriter::with_options(options),
+        }
+    }
+
+    /// Generates the corresponding N-Quads syntax of the provided quad.
+    ///
+    /// # Failures
+    ///
+    /// - Invalid node type for a certain position.
+    /// - The graph label is a literal or quoted triple.
+    ///
+    pub fn quad_to_n_quads(&self, quad: &Quad) -> Result<String> {
+        let triple = self.writer.triple_to_n_triples(quad.triple())?;
+
+        match *quad.graph_name() {
+            None => Ok(triple),
+            Some(ref name @ Node::UriNode { .. }) | Some(ref name @ Node::BlankNode { .. }) => {
+                let label = self
+                    .writer
+                    .node_to_n_triples(name, &TripleSegment::Subject)?;
+
+                // insert the graph label before the final dot
+                Ok(triple[..triple.len() - 1].to_string() + &label + " .")
+            }
+            Some(_) => Err(Error::new(
+                ErrorType::InvalidWriterOutput,
+                "Graph labels have to be URIs or blank nodes.",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::node::Node;
+    use crate::reader::n_quads_parser::NQuadsParser;
+    use crate::reader::rdf_parser::RdfDatasetParser;
+    use crate::writer::n_quads_writer::NQuadsWriter;
+    use crate::writer::rdf_writer::RdfDatasetWriter;
+
+    #[test]
+    fn write_n_quads_round_trip() {
+        let input = "<http://example.org/a> <http://example.org/p> <http://example.org/b> .\n\
+                     <http://example.org/a> <http://example.org/p> \"a\"@en <http://example.org/g1> .\n\
+                     << _:a <http://example.org/p> _:b >> <http://example.org/q> _:c _:g2 .\n";
+
+        let dataset = NQuadsParser::from_string(input).decode_dataset().unwrap();
+        let output = NQuadsWriter::new()
+            .write_dataset_to_string(&dataset)
+            .unwrap();
+
+        assert_eq!(output, input);
+
+        let mut dataset = dataset;
+        let literal = Node::LiteralNode {
+            literal: "g".to_string(),
+            data_type: None,
+            language: None,
+        };
+        let triple = dataset
+            .default_graph()
+            .triples_iter()
+            .next()
+            .unwrap()
+            .clone();
+
+        dataset.named_graph_mut(&literal).add_triple(&triple);
+
+        assert!(NQuadsWriter::new()
+            .write_dataset_to_string(&dataset)
+            .is_err());
+    }
+}
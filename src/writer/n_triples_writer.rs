@@ -1,11 +1,12 @@
-use Result;
-use error::*;
-use graph::Graph;
-use node::Node;
-use triple::*;
-use writer::formatter::n_triples_formatter::NTriplesFormatter;
-use writer::formatter::rdf_formatter::*;
-use writer::rdf_writer::RdfWriter;
+use crate::error::*;
+use crate::graph::Graph;
+use crate::node::Node;
+use crate::triple::*;
+use crate::writer::formatter::n_triples_formatter::NTriplesFormatter;
+use crate::writer::formatter::rdf_formatter::*;
+use crate::writer::rdf_writer::RdfWriter;
+use crate::writer::writer_options::WriterOptions;
+use crate::Result;
 
 /// RDF writer to generate N-Triples syntax.
 #[derive(Default)]
@@ -36,6 +37,9 @@ impl RdfWriter for NTriplesWriter {
     /// - Invalid triples are to be written to the output that do not conform the NTriples syntax standard.
     ///
     fn write_to_string(&self, graph: &Graph) -> Result<String> {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("write", format = "N-Triples").entered();
+
         let mut output_string = "".to_string();
 
         for triple in graph.triples_iter() {
@@ -49,6 +53,9 @@ impl RdfWriter for NTriplesWriter {
             }
         }
 
+        #[cfg(feature = "tracing")]
+        tracing::debug!(triples = graph.count(), "Wrote N-Triples");
+
         Ok(output_string)
     }
 }
@@ -70,6 +77,25 @@ impl NTriplesWriter {
         }
     }
 
+    /// Constructor of `NTriplesWriter` with options for writing literals.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::writer::n_triples_writer::NTriplesWriter;
+    /// use rdf::writer::writer_options::{StringDataType, WriterOptions};
+    ///
+    /// let mut options = WriterOptions::new();
+    /// options.set_string_data_type(StringDataType::Explicit);
+    ///
+    /// let writer = NTriplesWriter::with_options(options);
+    /// ```
+    pub fn with_options(options: WriterOptions) -> NTriplesWriter {
+        NTriplesWriter {
+            formatter: NTriplesFormatter::with_options(options),
+        }
+    }
+
     /// Generates the corresponding N-Triples syntax of the provided triple.
     ///
     /// # Examples
@@ -105,7 +131,7 @@ impl NTriplesWriter {
             Err(error) => return Err(error),
         }
 
-        output_string.push_str(" ");
+        output_string.push(' ');
 
         // convert predicate
         match self.node_to_n_triples(triple.predicate(), &TripleSegment::Predicate) {
@@ -113,7 +139,7 @@ impl NTriplesWriter {
             Err(error) => return Err(error),
         }
 
-        output_string.push_str(" ");
+        output_string.push(' ');
 
         // convert object
         match self.node_to_n_triples(triple.object(), &TripleSegment::Object) {
@@ -183,6 +209,20 @@ impl NTriplesWriter {
                     ));
                 }
             }
+            Node::TripleNode { ref triple } => {
+                // quoted triples are not allowed as predicates
+                if *segment == TripleSegment::Predicate {
+                    return Err(Error::new(
+                        ErrorType::InvalidWriterOutput,
+                        "Quoted triples are not allowed as predicates.",
+                    ));
+                }
+
+                // the nodes of the quoted triple have to be valid as well
+                self.node_to_n_triples(triple.subject(), &TripleSegment::Subject)?;
+                self.node_to_n_triples(triple.predicate(), &TripleSegment::Predicate)?;
+                self.node_to_n_triples(triple.object(), &TripleSegment::Object)?;
+            }
             _ => {}
         }
 
@@ -1,5 +1,6 @@
-use Result;
-use graph::Graph;
+use crate::dataset::Dataset;
+use crate::graph::Graph;
+use crate::Result;
 
 /// Trait implemented by RDF writers to generate a specific syntax.
 pub trait RdfWriter {
@@ -7,3 +8,277 @@ pub trait RdfWriter {
     /// Returns an error if invalid RDF would be generated.
     fn write_to_string(&self, graph: &Graph) -> Result<String>;
 }
+
+/// Trait implemented by writers of RDF syntaxes with named graphs to serialize an RDF dataset.
+pub trait RdfDatasetWriter {
+    /// Generates RDF syntax from a provided RDF dataset and writes it to a string.
+    /// Returns an error if invalid RDF would be generated.
+    fn write_dataset_to_string(&self, dataset: &Dataset) -> Result<String>;
+}
+
+#[cfg(all(test, feature = "ntriples", feature = "turtle"))]
+mod tests {
+    use crate::dataset::{Dataset, Quad};
+    use crate::graph::Graph;
+    #[cfg(feature = "jsonld")]
+    use crate::jsonld;
+    use crate::node::Node;
+    #[cfg(feature = "jsonld")]
+    use crate::reader::jsonld_parser::JsonLdParser;
+    use crate::reader::n_triples_parser::NTriplesParser;
+    use crate::reader::rdf_parser::{RdfDatasetParser, RdfParser};
+    use crate::reader::trig_parser::TriGParser;
+    use crate::reader::turtle_parser::TurtleParser;
+    use crate::specs::xml_specs::XmlDataTypes;
+    use crate::triple::Triple;
+    use crate::uri::Uri;
+    #[cfg(feature = "jsonld")]
+    use crate::writer::json_ld_writer::JsonLdWriter;
+    use crate::writer::n_triples_writer::NTriplesWriter;
+    use crate::writer::rdf_writer::{RdfDatasetWriter, RdfWriter};
+    use crate::writer::trig_writer::TriGWriter;
+    use crate::writer::turtle_writer::TurtleWriter;
+    use proptest::prelude::*;
+    use std::collections::{BTreeSet, HashMap};
+
+    /// Absolute IRIs with non-ASCII characters in their path and fragment.
+    fn arb_uri() -> impl Strategy<Value = Uri> {
+        "[a-zA-Z0-9_~é€中-]{0,8}(/[a-z0-9ü]{1,4})?(#[a-zA-Zß]{0,4})?"
+            .prop_map(|path| Uri::new("http://example.org/".to_string() + &path))
+    }
+
+    /// Blank nodes from a small set of labels, so that generated triples share them.
+    fn arb_blank_node() -> impl Strategy<Value = Node> {
+        "b[0-3]".prop_map(|id| Node::BlankNode { id })
+    }
+
+    /// Literals with unicode text, language tags and data types.
+    ///
+    /// The text contains quotes, backslashes and line breaks that have to be escaped, but no
+    /// other control characters.
+    fn arb_literal() -> impl Strategy<Value = Node> {
+        let text = "([^\\p{Cc}]|[\n\r\t]){0,12}";
+        let data_type = prop_oneof![
+            Just(XmlDataTypes::Integer.to_uri()),
+            Just(XmlDataTypes::String.to_uri()),
+            arb_uri(),
+        ];
+
+        prop_oneof![
+            text.prop_map(|literal| Node::LiteralNode {
+                literal,
+                data_type: None,
+                language: None,
+            }),
+            (text, "[a-z]{2,3}(-[A-Z0-9]{2,4})?").prop_map(|(literal, language)| {
+                Node::LiteralNode {
+                    literal,
+                    data_type: None,
+                    language: Some(language),
+                }
+            }),
+            (text, data_type).prop_map(|(literal, data_type)| Node::LiteralNode {
+                literal,
+                data_type: Some(data_type),
+                language: None,
+            }),
+        ]
+    }
+
+    fn arb_uri_node() -> impl Strategy<Value = Node> {
+        arb_uri().prop_map(|uri| Node::UriNode { uri })
+    }
+
+    /// Triples without quoted triples.
+    fn arb_flat_triple() -> impl Strategy<Value = Triple> {
+        (
+            prop_oneof![arb_uri_node(), arb_blank_node()],
+            arb_uri_node(),
+            prop_oneof![arb_uri_node(), arb_blank_node(), arb_literal()],
+        )
+            .prop_map(|(subject, predicate, object)| Triple::new(&subject, &predicate, &object))
+    }
+
+    fn arb_triple() -> impl Strategy<Value = Triple> {
+        let quoted = || {
+            arb_flat_triple().prop_map(|triple| Node::TripleNode {
+                triple: Box::new(triple),
+            })
+        };
+
+        (
+            prop_oneof![4 => arb_uri_node(), 4 => arb_blank_node(), 1 => quoted()],
+            arb_uri_node(),
+            prop_oneof![
+                3 => arb_uri_node(),
+                3 => arb_blank_node(),
+                3 => arb_literal(),
+                1 => quoted()
+            ],
+        )
+            .prop_map(|(subject, predicate, object)| Triple::new(&subject, &predicate, &object))
+    }
+
+    fn arb_graph() -> impl Strategy<Value = Graph> {
+        graph_of(arb_triple())
+    }
+
+    /// Graphs without quoted triples, for syntaxes without RDF-star.
+    #[cfg(feature = "jsonld")]
+    fn arb_flat_graph() -> impl Strategy<Value = Graph> {
+        graph_of(arb_flat_triple())
+    }
+
+    fn graph_of(triples: impl Strategy<Value = Triple>) -> impl Strategy<Value = Graph> {
+        prop::collection::vec(triples, 0..16).prop_map(|triples| {
+            let mut graph = Graph::new(None);
+            graph.add_triples(&triples);
+            graph
+        })
+    }
+
+    /// Collects the labels of all blank nodes, including those in quoted triples.
+    fn collect_blank_ids(node: &Node, ids: &mut BTreeSet<String>) {
+        match *node {
+            Node::BlankNode { ref id } => {
+                ids.insert(id.clone());
+            }
+            Node::TripleNode { ref triple } => {
+                collect_blank_ids(triple.subject(), ids);
+                collect_blank_ids(triple.object(), ids);
+            }
+            _ => {}
+        }
+    }
+
+    fn blank_ids(graph: &Graph) -> Vec<String> {
+        let mut ids = BTreeSet::new();
+
+        for triple in graph.triples_iter() {
+            collect_blank_ids(triple.subject(), &mut ids);
+            collect_blank_ids(triple.object(), &mut ids);
+        }
+
+        ids.into_iter().collect()
+    }
+
+    fn relabel(node: &Node, mapping: &HashMap<String, String>) -> Node {
+        match *node {
+            Node::BlankNode { ref id } => Node::BlankNode {
+                id: mapping[id].clone(),
+            },
+            Node::TripleNode { ref triple } => Node::TripleNode {
+                triple: Box::new(relabel_triple(triple, mapping)),
+            },
+            ref node => node.clone(),
+        }
+    }
+
+    fn relabel_triple(triple: &Triple, mapping: &HashMap<String, String>) -> Triple {
+        Triple::new(
+            &relabel(triple.subject(), mapping),
+            triple.predicate(),
+            &relabel(triple.object(), mapping),
+        )
+    }
+
+    /// Tries all bijections between the remaining blank node labels.
+    fn find_mapping(
+        triples: &[Triple],
+        ids: &[String],
+        candidates: &[String],
+        mapping: &mut HashMap<String, String>,
+        expected: &[Triple],
+    ) -> bool {
+        let (id, rest) = match ids.split_first() {
+            Some(split) => split,
+            None => {
+                let mut relabeled: Vec<Triple> = triples
+                    .iter()
+                    .map(|triple| relabel_triple(triple, mapping))
+                    .collect();
+                relabeled.sort();
+
+                return relabeled == expected;
+            }
+        };
+
+        for candidate in candidates {
+            if mapping.values().any(|used| used == candidate) {
+                continue;
+            }
+
+            mapping.insert(id.clone(), candidate.clone());
+            if find_mapping(triples, rest, candidates, mapping, expected) {
+                return true;
+            }
+            mapping.remove(id);
+        }
+
+        false
+    }
+
+    /// Returns if the graphs are equal up to the labels of their blank nodes.
+    fn is_isomorphic(graph: &Graph, other: &Graph) -> bool {
+        let triples: Vec<Triple> = graph.triples_iter().cloned().collect();
+        let mut expected: Vec<Triple> = other.triples_iter().cloned().collect();
+        expected.sort();
+
+        let (ids, candidates) = (blank_ids(graph), blank_ids(other));
+
+        triples.len() == expected.len()
+            && ids.len() == candidates.len()
+            && find_mapping(&triples, &ids, &candidates, &mut HashMap::new(), &expected)
+    }
+
+    proptest! {
+        #[test]
+        fn n_triples_output_reparses_to_isomorphic_graph(graph in arb_graph()) {
+            let output = NTriplesWriter::new().write_to_string(&graph).unwrap();
+            let reparsed = NTriplesParser::from_string(output.clone()).decode().unwrap();
+
+            prop_assert!(is_isomorphic(&graph, &reparsed), "{}", output);
+        }
+
+        #[test]
+        fn turtle_output_reparses_to_isomorphic_graph(graph in arb_graph()) {
+            let output = TurtleWriter::new(&HashMap::new()).write_to_string(&graph).unwrap();
+            let reparsed = TurtleParser::from_string(output.clone()).decode().unwrap();
+
+            prop_assert!(is_isomorphic(&graph, &reparsed), "{}", output);
+        }
+
+        #[test]
+        fn trig_output_reparses_to_isomorphic_graph(graph in arb_graph()) {
+            prop_assume!(!graph.is_empty());
+
+            let name = Node::UriNode { uri: Uri::new("http://example.org/g".to_string()) };
+            let mut dataset = Dataset::new();
+
+            for triple in graph.triples_iter() {
+                dataset.add_quad(&Quad::new(triple, Some(&name)));
+            }
+
+            let output = TriGWriter::new(&HashMap::new()).write_dataset_to_string(&dataset).unwrap();
+            let reparsed = TriGParser::from_string(output.clone()).decode_dataset().unwrap();
+
+            prop_assert!(reparsed.default_graph().is_empty(), "{}", output);
+            prop_assert!(is_isomorphic(&graph, reparsed.named_graph(&name).unwrap()), "{}", output);
+        }
+
+        #[test]
+        #[cfg(feature = "jsonld")]
+        fn json_ld_output_reparses_to_isomorphic_graph(graph in arb_flat_graph()) {
+            let expanded = JsonLdWriter::new().write_to_string(&graph).unwrap();
+            let reparsed = JsonLdParser::from_string(expanded.clone()).decode().unwrap();
+
+            prop_assert!(is_isomorphic(&graph, &reparsed), "{}", expanded);
+
+            let writer = JsonLdWriter::with_context(jsonld::derive_context(&graph));
+            let compacted = writer.write_to_string(&graph).unwrap();
+            let reparsed = JsonLdParser::from_string(compacted.clone()).decode().unwrap();
+
+            prop_assert!(is_isomorphic(&graph, &reparsed), "{}", compacted);
+        }
+    }
+}
@@ -0,0 +1,217 @@
+use crate::dataset::Dataset;
+use crate::error::{Error, ErrorType};
+use crate::graph::Graph;
+use crate::node::Node;
+use crate::triple::Triple;
+use crate::uri::Uri;
+use crate::writer::formatter::rdf_formatter::RdfFormatter;
+use crate::writer::formatter::turtle_formatter::TurtleFormatter;
+use crate::writer::rdf_writer::{RdfDatasetWriter, RdfWriter};
+use crate::writer::turtle_writer::TurtleWriter;
+use crate::writer::writer_options::WriterOptions;
+use crate::Result;
+use std::collections::{BTreeMap, HashMap};
+
+/// RDF writer to generate TriG syntax.
+///
+/// The triples of each graph are written in Turtle syntax, the triples of named graphs are
+/// enclosed in `GRAPH` blocks.
+pub struct TriGWriter<'a> {
+    writer: TurtleWriter<'a>,
+    formatter: TurtleFormatter<'a>,
+}
+
+impl<'a> RdfDatasetWriter for TriGWriter<'a> {
+    /// Generates the TriG syntax of the default graph and the named graphs of the dataset.
+    ///
+    /// The prefixes of all graphs are written once at the beginning, the base URI is taken from
+    /// the default graph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::dataset::{Dataset, Quad};
+    /// use rdf::node::Node;
+    /// use rdf::triple::Triple;
+    /// use rdf::uri::Uri;
+    /// use rdf::writer::rdf_writer::RdfDatasetWriter;
+    /// use rdf::writer::trig_writer::TriGWriter;
+    /// use std::collections::HashMap;
+    ///
+    /// let uri = |uri: &str| Node::UriNode { uri: Uri::new(uri.to_string()) };
+    /// let triple = Triple::new(&uri("http://example.org/a"), &uri("http://example.org/p"), &uri("http://example.org/b"));
+    ///
+    /// let mut dataset = Dataset::new();
+    /// dataset.add_quad(&Quad::new(&triple, None));
+    /// dataset.add_quad(&Quad::new(&triple, Some(&uri("http://example.org/g"))));
+    ///
+    /// let namespaces = HashMap::new();
+    ///
+    /// assert_eq!(TriGWriter::new(&namespaces).write_dataset_to_string(&dataset).unwrap(),
+    ///            "<http://example.org/a> <http://example.org/p> <http://example.org/b> .\n\n\
+    ///             GRAPH <http://example.org/g> {\n    \
+    ///                 <http://example.org/a> <http://example.org/p> <http://example.org/b> .\n}\n");
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// - The node type is invalid for the triple segment.
+    /// - A graph name is a literal or quoted triple.
+    ///
+    fn write_dataset_to_string(&self, dataset: &Dataset) -> Result<String> {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("write", format = "TriG").entered();
+
+        let mut output_string = self.writer.write_base_uri(dataset.default_graph());
+
+        // prefixes of all graphs, the default graph takes precedence
+        let mut prefixes: BTreeMap<&str, &Uri> = BTreeMap::new();
+
+        for graph in dataset
+            .named_graphs()
+            .values()
+            .chain(Some(dataset.default_graph()))
+        {
+            for (prefix, namespace_uri) in graph.namespaces() {
+                prefixes.insert(prefix, namespace_uri);
+            }
+        }
+
+        for (prefix, namespace_uri) in &prefixes {
+            output_string.push_str(&format!(
+                "@prefix {}: <{}> .\n",
+                prefix,
+                namespace_uri.to_string()
+            ));
+        }
+
+        let mut blocks = Vec::new();
+
+        if !dataset.default_graph().is_empty() {
+            blocks.push(self.write_triples(dataset.default_graph())?);
+        }
+
+        for (name, graph) in dataset.named_graphs() {
+            let body = self.write_triples(graph)?;
+            let body = match body.is_empty() {
+                true => body,
+                false => "    ".to_string() + &body.replace('\n', "\n    ") + "\n",
+            };
+
+            blocks.push(format!("GRAPH {} {{\n{}}}", self.graph_name(name)?, body));
+        }
+
+        if !prefixes.is_empty() && !blocks.is_empty() {
+            output_string.push('\n');
+        }
+
+        output_string.push_str(&blocks.join("\n\n"));
+
+        if !blocks.is_empty() {
+            output_string.push('\n');
+        }
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(quads = dataset.count(), "Wrote TriG");
+
+        Ok(output_string)
+    }
+}
+
+impl<'a> RdfWriter for TriGWriter<'a> {
+    /// Generates the TriG syntax of a graph, which is written as default graph.
+    ///
+    /// # Failures
+    ///
+    /// - The node type is invalid for the triple segment.
+    ///
+    fn write_to_string(&self, graph: &Graph) -> Result<String> {
+        self.writer.write_to_string(graph)
+    }
+}
+
+impl<'a> TriGWriter<'a> {
+    /// Constructor of `TriGWriter`, URIs are abbreviated with the provided namespaces.
+    pub fn new(namespaces: &'a HashMap<String, Uri>) -> TriGWriter<'a> {
+        TriGWriter {
+            writer: TurtleWriter::new(namespaces),
+            formatter: TurtleFormatter::new(namespaces),
+        }
+    }
+
+    /// Constructor of `TriGWriter` with options for writing literals.
+    pub fn with_options(
+        namespaces: &'a HashMap<String, Uri>,
+        options: WriterOptions,
+    ) -> TriGWriter<'a> {
+        TriGWriter {
+            writer: TurtleWriter::with_options(namespaces, options),
+            formatter: TurtleFormatter::new(namespaces),
+        }
+    }
+
+    /// Returns the Turtle syntax of the sorted triples of the graph.
+    fn write_triples(&self, graph: &Graph) -> Result<String> {
+        let mut triples: Vec<Triple> = graph.triples_iter().cloned().collect();
+        triples.sort();
+
+        self.writer.write_triples(&triples)
+    }
+
+    /// Returns the graph name as URI or blank node label.
+    fn graph_name(&self, name: &Node) -> Result<String> {
+        match *name {
+            Node::UriNode { .. } | Node::BlankNode { .. } => Ok(self.formatter.format_node(name)),
+            _ => Err(Error::new(
+                ErrorType::InvalidWriterOutput,
+                "Graph names have to be URIs or blank nodes in TriG.",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::node::Node;
+    use crate::reader::rdf_parser::RdfDatasetParser;
+    use crate::reader::trig_parser::TriGParser;
+    use crate::writer::rdf_writer::RdfDatasetWriter;
+    use crate::writer::trig_writer::TriGWriter;
+
+    #[test]
+    fn write_trig_with_prefixes_and_named_graphs() {
+        let input = "@prefix ex: <http://example.org/> .
+                     ex:a ex:p ex:b .
+                     GRAPH ex:g { ex:a ex:p ex:b , ex:c ; ex:q \"x\" . ex:d ex:p ex:a . }
+                     _:h { _:s ex:p ex:a . }";
+
+        let dataset = TriGParser::from_string(input).decode_dataset().unwrap();
+        let namespaces = dataset.default_graph().namespaces().clone();
+        let output = TriGWriter::new(&namespaces)
+            .write_dataset_to_string(&dataset)
+            .unwrap();
+
+        assert!(output.starts_with("@prefix ex: <http://example.org/> .\n\nex:a ex:p ex:b .\n\n"));
+        assert!(output.contains("GRAPH ex:g {\n    ex:a ex:p ex:b ,\n"));
+        assert!(output.contains("GRAPH _:h {\n    _:s ex:p ex:a .\n}\n"));
+
+        let reparsed = TriGParser::from_string(output).decode_dataset().unwrap();
+        let g = Node::UriNode {
+            uri: crate::uri::Uri::new("http://example.org/g".to_string()),
+        };
+
+        assert_eq!(reparsed.count(), dataset.count());
+        let triples = |dataset: &crate::dataset::Dataset| {
+            let mut triples: Vec<_> = dataset
+                .named_graph(&g)
+                .unwrap()
+                .triples_iter()
+                .cloned()
+                .collect();
+            triples.sort();
+            triples
+        };
+
+        assert_eq!(triples(&reparsed), triples(&dataset));
+    }
+}
@@ -1,15 +1,16 @@
-use Result;
-use error::{Error, ErrorType};
-use graph::Graph;
-use node::Node;
+use crate::error::{Error, ErrorType};
+use crate::graph::Graph;
+use crate::node::Node;
+use crate::triple::Triple;
+use crate::triple::TripleSegment;
+use crate::uri::Uri;
+use crate::writer::formatter::rdf_formatter::*;
+use crate::writer::formatter::turtle_formatter::TurtleFormatter;
+use crate::writer::rdf_writer::RdfWriter;
+use crate::writer::writer_options::WriterOptions;
+use crate::Result;
 use std::collections::HashMap;
 use std::iter::repeat;
-use triple::Triple;
-use triple::TripleSegment;
-use uri::Uri;
-use writer::formatter::rdf_formatter::*;
-use writer::formatter::turtle_formatter::TurtleFormatter;
-use writer::rdf_writer::RdfWriter;
 
 /// RDF writer to generate Turtle syntax.
 pub struct TurtleWriter<'a> {
@@ -52,6 +53,9 @@ impl<'a> RdfWriter for TurtleWriter<'a> {
     /// - The node type is invalid for the triple segment.
     ///
     fn write_to_string(&self, graph: &Graph) -> Result<String> {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("write", format = "Turtle").entered();
+
         let mut output_string = "".to_string();
 
         output_string.push_str(&self.write_base_uri(graph));
@@ -60,6 +64,70 @@ impl<'a> RdfWriter for TurtleWriter<'a> {
         let mut triples_vec: Vec<Triple> = graph.triples_iter().cloned().collect();
         triples_vec.sort();
 
+        output_string.push_str(&self.write_triples(&triples_vec)?);
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(triples = graph.count(), "Wrote Turtle");
+
+        Ok(output_string)
+    }
+}
+
+impl<'a> TurtleWriter<'a> {
+    /// Constructor of `TurtleWriter`.
+    pub fn new(namespaces: &'a HashMap<String, Uri>) -> TurtleWriter<'a> {
+        TurtleWriter {
+            formatter: TurtleFormatter::new(namespaces),
+        }
+    }
+
+    /// Constructor of `TurtleWriter` with options for writing literals.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::writer::turtle_writer::TurtleWriter;
+    /// use rdf::writer::rdf_writer::RdfWriter;
+    /// use rdf::writer::writer_options::WriterOptions;
+    /// use rdf::graph::Graph;
+    /// use rdf::specs::xml_specs::XmlDataTypes;
+    /// use rdf::triple::Triple;
+    /// use rdf::uri::Uri;
+    ///
+    /// let mut graph = Graph::new(None);
+    ///
+    /// let subject = graph.create_blank_node();
+    /// let predicate = graph.create_uri_node(&Uri::new("http://example.org/p".to_string()));
+    /// let object = graph.create_literal_node_with_data_type("+042".to_string(), &XmlDataTypes::Integer.to_uri());
+    ///
+    /// graph.add_triple(&Triple::new(&subject, &predicate, &object));
+    ///
+    /// let mut options = WriterOptions::new();
+    /// options.set_numeric_shorthand(true);
+    /// options.set_canonical_forms(true);
+    ///
+    /// let writer = TurtleWriter::with_options(graph.namespaces(), options);
+    ///
+    /// assert_eq!(writer.write_to_string(&graph).unwrap(), "_:auto0 <http://example.org/p> 42 .");
+    /// ```
+    pub fn with_options(
+        namespaces: &'a HashMap<String, Uri>,
+        options: WriterOptions,
+    ) -> TurtleWriter<'a> {
+        TurtleWriter {
+            formatter: TurtleFormatter::with_options(namespaces, options),
+        }
+    }
+
+    /// Writes the sorted triples and groups the predicates and objects of each subject.
+    ///
+    /// # Failures
+    ///
+    /// - The node type is invalid for the triple segment.
+    ///
+    pub(crate) fn write_triples(&self, triples_vec: &[Triple]) -> Result<String> {
+        let mut output_string = "".to_string();
+
         // store subjects and predicates for grouping
         let mut previous_subject: Option<&Node> = None;
         let mut previous_predicate: Option<&Node> = None;
@@ -68,7 +136,7 @@ impl<'a> RdfWriter for TurtleWriter<'a> {
         let mut predicate_indentation = 0;
         let mut object_indentation = 0;
 
-        for triple in &triples_vec {
+        for triple in triples_vec {
             if previous_subject == Some(triple.subject()) {
                 // continue group
                 if previous_predicate == Some(triple.predicate()) {
@@ -121,30 +189,22 @@ impl<'a> RdfWriter for TurtleWriter<'a> {
             output_string.push_str(&turtle_object);
         }
 
-        if !graph.is_empty() {
+        if !triples_vec.is_empty() {
             output_string.push_str(" .");
         }
 
         Ok(output_string)
     }
-}
-
-impl<'a> TurtleWriter<'a> {
-    /// Constructor of `TurtleWriter`.
-    pub fn new(namespaces: &'a HashMap<String, Uri>) -> TurtleWriter<'a> {
-        TurtleWriter {
-            formatter: TurtleFormatter::new(namespaces),
-        }
-    }
 
     /// Returns the formatted base URI as string.
-    fn write_base_uri(&self, graph: &Graph) -> String {
+    pub(crate) fn write_base_uri(&self, graph: &Graph) -> String {
         let mut output_string = "".to_string();
 
         if let Some(ref base) = *graph.base_uri() {
-            output_string.push_str("@base ");
-            output_string.push_str(&self.formatter.format_uri(base));
-            output_string.push_str(" .\n");
+            // never abbreviated, since the prefixes are not declared yet
+            output_string.push_str("@base <");
+            output_string.push_str(base.to_string());
+            output_string.push_str("> .\n");
         }
 
         output_string
@@ -207,6 +267,20 @@ impl<'a> TurtleWriter<'a> {
                     ));
                 }
             }
+            Node::TripleNode { ref triple } => {
+                // quoted triples are not allowed as predicates
+                if *segment == TripleSegment::Predicate {
+                    return Err(Error::new(
+                        ErrorType::InvalidWriterOutput,
+                        "Quoted triples are not allowed as predicates in Turtle.",
+                    ));
+                }
+
+                // the nodes of the quoted triple have to be valid as well
+                self.node_to_turtle(triple.subject(), &TripleSegment::Subject)?;
+                self.node_to_turtle(triple.predicate(), &TripleSegment::Predicate)?;
+                self.node_to_turtle(triple.object(), &TripleSegment::Object)?;
+            }
             _ => {}
         }
 
@@ -217,12 +291,16 @@ impl<'a> TurtleWriter<'a> {
 
 #[cfg(test)]
 mod tests {
-    use graph::Graph;
-    use namespace::Namespace;
-    use triple::*;
-    use uri::Uri;
-    use writer::rdf_writer::RdfWriter;
-    use writer::turtle_writer::TurtleWriter;
+    use crate::graph::Graph;
+    use crate::namespace::Namespace;
+    use crate::node::Node;
+    use crate::reader::rdf_parser::RdfParser;
+    use crate::reader::turtle_parser::TurtleParser;
+    use crate::triple::*;
+    use crate::uri::Uri;
+    use crate::writer::rdf_writer::RdfWriter;
+    use crate::writer::turtle_writer::TurtleWriter;
+    use crate::writer::writer_options::WriterOptions;
 
     #[test]
     fn test_turtle_writer() {
@@ -336,4 +414,100 @@ _:auto2 <http://example.org/show/localName> _:auto1 ,
             Err(_) => assert!(false),
         }
     }
+
+    #[test]
+    fn base_uri_and_prefixes_are_read_back() {
+        let base = Uri::new("http://example.org/".to_string());
+        let mut graph = Graph::new(Some(&base));
+
+        graph.add_namespace(&Namespace::new("ex".to_string(), base.clone()));
+        graph.add_namespace(&Namespace::new(
+            "foaf".to_string(),
+            Uri::new("http://xmlns.com/foaf/0.1/".to_string()),
+        ));
+
+        let subject = graph.create_uri_node(&Uri::new("http://example.org/art".to_string()));
+        let predicate =
+            graph.create_uri_node(&Uri::new("http://xmlns.com/foaf/0.1/knows".to_string()));
+        let object = graph.create_uri_node(&base);
+        graph.add_triple(&Triple::new(&subject, &predicate, &object));
+
+        let turtle = TurtleWriter::new(graph.namespaces())
+            .write_to_string(&graph)
+            .unwrap();
+        assert!(turtle.starts_with("@base <http://example.org/> .\n"));
+
+        let reparsed = TurtleParser::from_string(turtle).decode().unwrap();
+
+        assert_eq!(reparsed.base_uri(), &Some(base));
+        assert_eq!(reparsed.namespaces(), graph.namespaces());
+        assert_eq!(reparsed.count(), 1);
+        assert!(reparsed
+            .triples_iter()
+            .all(|t| graph.triples_iter().any(|u| u == t)));
+    }
+
+    #[test]
+    fn test_turtle_writer_quoted_triples() {
+        let mut graph = Graph::new(None);
+
+        let subject = graph.create_blank_node();
+        let predicate = graph.create_uri_node(&Uri::new("http://example.org/p".to_string()));
+        let object = graph.create_literal_node("o".to_string());
+        let quoted = graph.create_triple_node(&Triple::new(&subject, &predicate, &object));
+
+        graph.add_triple(&Triple::new(&quoted, &predicate, &subject));
+
+        let writer = TurtleWriter::new(graph.namespaces());
+
+        assert_eq!(
+            writer.write_to_string(&graph).unwrap(),
+            "<< _:auto0 <http://example.org/p> \"o\" >> <http://example.org/p> _:auto0 ."
+        );
+
+        let mut invalid_graph = Graph::new(None);
+        invalid_graph.add_triple(&Triple::new(&subject, &quoted, &object));
+
+        assert!(writer.write_to_string(&invalid_graph).is_err());
+    }
+
+    #[test]
+    fn long_strings_are_read_back_unchanged() {
+        let mut graph = Graph::new(None);
+        let subject = graph.create_blank_node();
+        let predicate = graph.create_uri_node(&Uri::new("http://example.org/note".to_string()));
+
+        let mut literals = vec![
+            "first line\nsecond \"line\"",
+            "quotes \"\"\"\" and \\ at the end\"",
+            "\"\"",
+            "tab\tand\r\nbreak",
+        ];
+
+        for literal in &literals {
+            let object = graph.create_literal_node(literal.to_string());
+            graph.add_triple(&Triple::new(&subject, &predicate, &object));
+        }
+
+        let mut options = WriterOptions::new();
+        options.set_long_strings(true);
+
+        let turtle = TurtleWriter::with_options(graph.namespaces(), options)
+            .write_to_string(&graph)
+            .unwrap();
+        assert!(turtle.contains("\"\"\"first line\nsecond \"line\\\"\"\"\""));
+
+        let reparsed = TurtleParser::from_string(turtle).decode().unwrap();
+        let mut reparsed_literals: Vec<String> = reparsed
+            .triples_iter()
+            .map(|triple| match *triple.object() {
+                Node::LiteralNode { ref literal, .. } => literal.clone(),
+                _ => String::new(),
+            })
+            .collect();
+
+        literals.sort();
+        reparsed_literals.sort();
+        assert_eq!(reparsed_literals, literals);
+    }
 }
@@ -0,0 +1,185 @@
+use crate::specs::xml_specs::XmlDataTypes;
+use crate::uri::Uri;
+
+/// Handling of the `xsd:string` data type when writing literals.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum StringDataType {
+    /// Literals are written with the data type they have in the graph.
+    AsStored,
+
+    /// Literals without data type and language are written with `^^xsd:string`.
+    Explicit,
+
+    /// `^^xsd:string` is never written.
+    Omitted,
+}
+
+/// Options controlling how writers output literals.
+///
+/// By default literals are written exactly as they are stored in the graph.
+#[derive(Debug, Clone)]
+pub struct WriterOptions {
+    string_data_type: StringDataType,
+    numeric_shorthand: bool,
+    canonical_forms: bool,
+    long_strings: bool,
+}
+
+impl Default for WriterOptions {
+    fn default() -> WriterOptions {
+        WriterOptions::new()
+    }
+}
+
+impl WriterOptions {
+    /// Constructor of `WriterOptions`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::writer::writer_options::{StringDataType, WriterOptions};
+    ///
+    /// let mut options = WriterOptions::new();
+    /// options.set_string_data_type(StringDataType::Omitted);
+    /// options.set_numeric_shorthand(true);
+    /// options.set_canonical_forms(true);
+    /// options.set_long_strings(true);
+    /// ```
+    pub fn new() -> WriterOptions {
+        WriterOptions {
+            string_data_type: StringDataType::AsStored,
+            numeric_shorthand: false,
+            canonical_forms: false,
+            long_strings: false,
+        }
+    }
+
+    /// Returns how the `xsd:string` data type is written.
+    pub fn string_data_type(&self) -> StringDataType {
+        self.string_data_type
+    }
+
+    /// Sets how the `xsd:string` data type is written.
+    pub fn set_string_data_type(&mut self, string_data_type: StringDataType) {
+        self.string_data_type = string_data_type;
+    }
+
+    /// Returns if numeric literals are written in Turtle shorthand, e.g. `42` instead of `"42"^^xsd:integer`.
+    pub fn numeric_shorthand(&self) -> bool {
+        self.numeric_shorthand
+    }
+
+    /// Sets if numeric literals are written in Turtle shorthand.
+    ///
+    /// Formats without shorthand syntax, like N-Triples, ignore this option.
+    pub fn set_numeric_shorthand(&mut self, numeric_shorthand: bool) {
+        self.numeric_shorthand = numeric_shorthand;
+    }
+
+    /// Returns if numeric and boolean literals are written in their canonical lexical form.
+    pub fn canonical_forms(&self) -> bool {
+        self.canonical_forms
+    }
+
+    /// Sets if numeric and boolean literals are written in their canonical lexical form.
+    ///
+    /// Literals that are not valid for their data type are written unchanged.
+    pub fn set_canonical_forms(&mut self, canonical_forms: bool) {
+        self.canonical_forms = canonical_forms;
+    }
+
+    /// Returns if literals with line breaks or several `"` are written as long strings.
+    pub fn long_strings(&self) -> bool {
+        self.long_strings
+    }
+
+    /// Sets if literals with line breaks or several `"` are written as long strings `"""..."""`,
+    /// which span multiple lines and contain most `"` unescaped.
+    ///
+    /// Formats without long strings, like N-Triples, ignore this option.
+    pub fn set_long_strings(&mut self, long_strings: bool) {
+        self.long_strings = long_strings;
+    }
+
+    /// Returns the lexical form and data type of a literal as it is written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rdf::specs::xml_specs::XmlDataTypes;
+    /// use rdf::writer::writer_options::{StringDataType, WriterOptions};
+    ///
+    /// let mut options = WriterOptions::new();
+    /// options.set_string_data_type(StringDataType::Explicit);
+    /// options.set_canonical_forms(true);
+    ///
+    /// assert_eq!(options.literal_output("a", &None, &None),
+    ///            ("a".to_string(), Some(XmlDataTypes::String.to_uri())));
+    /// assert_eq!(options.literal_output("007", &Some(XmlDataTypes::Integer.to_uri()), &None),
+    ///            ("7".to_string(), Some(XmlDataTypes::Integer.to_uri())));
+    /// ```
+    pub fn literal_output(
+        &self,
+        literal: &str,
+        data_type: &Option<Uri>,
+        language: &Option<String>,
+    ) -> (String, Option<Uri>) {
+        let string_uri = XmlDataTypes::String.to_uri();
+
+        let data_type = match self.string_data_type {
+            StringDataType::AsStored => data_type.clone(),
+            StringDataType::Explicit if data_type.is_none() && language.is_none() => {
+                Some(string_uri)
+            }
+            StringDataType::Explicit => data_type.clone(),
+            StringDataType::Omitted => data_type.clone().filter(|dt| *dt != string_uri),
+        };
+
+        let canonical = if self.canonical_forms {
+            data_type
+                .as_ref()
+                .and_then(XmlDataTypes::from_uri)
+                .and_then(|dt| dt.canonical_form(literal))
+        } else {
+            None
+        };
+
+        (canonical.unwrap_or_else(|| literal.to_string()), data_type)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::specs::xml_specs::XmlDataTypes;
+    use crate::writer::writer_options::*;
+
+    #[test]
+    fn omit_string_data_type() {
+        let mut options = WriterOptions::new();
+        options.set_string_data_type(StringDataType::Omitted);
+
+        assert_eq!(
+            options.literal_output("a", &Some(XmlDataTypes::String.to_uri()), &None),
+            ("a".to_string(), None)
+        );
+        assert_eq!(
+            options.literal_output("1", &Some(XmlDataTypes::Integer.to_uri()), &None),
+            ("1".to_string(), Some(XmlDataTypes::Integer.to_uri()))
+        );
+    }
+
+    #[test]
+    fn keep_invalid_literals_with_canonical_forms() {
+        let mut options = WriterOptions::new();
+        options.set_canonical_forms(true);
+
+        assert_eq!(
+            options.literal_output("inf", &Some(XmlDataTypes::Double.to_uri()), &None),
+            ("inf".to_string(), Some(XmlDataTypes::Double.to_uri()))
+        );
+        assert_eq!(
+            options.literal_output("-0.000", &Some(XmlDataTypes::Decimal.to_uri()), &None),
+            ("0.0".to_string(), Some(XmlDataTypes::Decimal.to_uri()))
+        );
+    }
+}